@@ -0,0 +1,70 @@
+use pdfium_render::prelude::*;
+use std::time::Instant;
+
+pub fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let document = pdfium.load_pdf_from_file("test/text-test.pdf", None)?;
+
+    let page = document.pages().first()?;
+
+    let text = page.text()?;
+
+    const ITERATIONS: usize = 100;
+
+    // Retrieve the Unicode value, origin, bounds, and font size of every character on the page
+    // using the per-character accessors on PdfPageTextChar. Each accessor is a separate call
+    // into Pdfium.
+
+    let start = Instant::now();
+
+    let mut per_char_count = 0;
+
+    for _ in 0..ITERATIONS {
+        for char in text.chars().iter() {
+            let _ = char.unicode_char();
+            let _ = char.origin()?;
+            let _ = char.loose_bounds()?;
+            let _ = char.unscaled_font_size();
+
+            per_char_count += 1;
+        }
+    }
+
+    let per_char_elapsed = start.elapsed();
+
+    // Retrieve the same values using PdfPageText::chars_fast(), which retrieves the Unicode
+    // values of all characters in a single call.
+
+    let start = Instant::now();
+
+    let mut fast_count = 0;
+
+    for _ in 0..ITERATIONS {
+        for info in text.chars_fast() {
+            let _ = (info.unicode, info.origin, info.bounds, info.font_size);
+
+            fast_count += 1;
+        }
+    }
+
+    let fast_elapsed = start.elapsed();
+
+    assert_eq!(per_char_count, fast_count);
+
+    println!(
+        "PdfPageTextChar accessors: {} characters in {:?}",
+        per_char_count, per_char_elapsed
+    );
+
+    println!(
+        "PdfPageText::chars_fast(): {} characters in {:?} ({:.2}x)",
+        fast_count,
+        fast_elapsed,
+        per_char_elapsed.as_secs_f64() / fast_elapsed.as_secs_f64()
+    );
+
+    Ok(())
+}
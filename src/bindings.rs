@@ -76,6 +76,9 @@ use crate::bindgen::FPDF_STRUCTELEMENT_ATTR_VALUE;
 #[cfg(feature = "pdfium_use_skia")]
 use crate::bindgen::FPDF_SKIA_CANVAS;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::bindgen::UNSUPPORT_INFO;
+
 #[cfg(feature = "pdfium_enable_xfa")]
 use crate::bindgen::{FPDF_BSTR, FPDF_RESULT};
 
@@ -4583,6 +4586,20 @@ pub trait PdfiumLibraryBindings {
     #[allow(non_snake_case)]
     fn FPDFDoc_GetPageMode(&self, document: FPDF_DOCUMENT) -> c_int;
 
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Note that this function is not available when compiling to WASM, as Pdfium's WASM
+    /// module has no way of calling back into a handler function defined in Rust.
+    ///
+    /// Sets the handler that Pdfium will call whenever it encounters a document feature
+    /// it does not support, such as an XFA form or a 3D annotation.
+    ///
+    ///    `unsp_info` - Pointer to an `UNSUPPORT_INFO` structure. The structure must remain
+    ///                  valid for as long as the handler is in use.
+    ///
+    /// Returns `TRUE` on success.
+    #[allow(non_snake_case)]
+    fn FSDK_SetUnSpObjProcessHandler(&self, unsp_info: *mut UNSUPPORT_INFO) -> FPDF_BOOL;
+
     /// Flattens annotations and form fields into the page contents.
     ///
     ///    `page`  - handle to the page.
@@ -26,6 +26,8 @@ use crate::bindgen::FPDF_STRUCTELEMENT_ATTR_VALUE;
 #[cfg(feature = "pdfium_use_skia")]
 use crate::bindgen::FPDF_SKIA_CANVAS;
 
+use crate::bindgen::UNSUPPORT_INFO;
+
 #[cfg(feature = "pdfium_enable_xfa")]
 use crate::bindgen::{FPDF_BSTR, FPDF_RESULT};
 
@@ -1090,6 +1092,8 @@ pub(crate) struct DynamicPdfiumBindings {
     extern_FORM_OnAfterLoadPage: unsafe extern "C" fn(page: FPDF_PAGE, handle: FPDF_FORMHANDLE),
     extern_FORM_OnBeforeClosePage: unsafe extern "C" fn(page: FPDF_PAGE, handle: FPDF_FORMHANDLE),
     extern_FPDFDoc_GetPageMode: unsafe extern "C" fn(document: FPDF_DOCUMENT) -> c_int,
+    extern_FSDK_SetUnSpObjProcessHandler:
+        unsafe extern "C" fn(unsp_info: *mut UNSUPPORT_INFO) -> FPDF_BOOL,
     extern_FPDFPage_Flatten: unsafe extern "C" fn(page: FPDF_PAGE, nFlag: c_int) -> c_int,
     extern_FORM_DoDocumentJSAction: unsafe extern "C" fn(hHandle: FPDF_FORMHANDLE),
     extern_FORM_DoDocumentOpenAction: unsafe extern "C" fn(hHandle: FPDF_FORMHANDLE),
@@ -2736,6 +2740,10 @@ impl DynamicPdfiumBindings {
             extern_FORM_OnAfterLoadPage: *(Self::bind(&library, "FORM_OnAfterLoadPage")?),
             extern_FORM_OnBeforeClosePage: *(Self::bind(&library, "FORM_OnBeforeClosePage")?),
             extern_FPDFDoc_GetPageMode: *(Self::bind(&library, "FPDFDoc_GetPageMode")?),
+            extern_FSDK_SetUnSpObjProcessHandler: *(Self::bind(
+                &library,
+                "FSDK_SetUnSpObjProcessHandler",
+            )?),
             extern_FPDFPage_Flatten: *(Self::bind(&library, "FPDFPage_Flatten")?),
             extern_FORM_DoDocumentJSAction: *(Self::bind(&library, "FORM_DoDocumentJSAction")?),
             extern_FORM_DoDocumentOpenAction: *(Self::bind(&library, "FORM_DoDocumentOpenAction")?),
@@ -5686,6 +5694,12 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         unsafe { (self.extern_FPDFDoc_GetPageMode)(document) }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FSDK_SetUnSpObjProcessHandler(&self, unsp_info: *mut UNSUPPORT_INFO) -> FPDF_BOOL {
+        unsafe { (self.extern_FSDK_SetUnSpObjProcessHandler)(unsp_info) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_Flatten(&self, page: FPDF_PAGE, nFlag: c_int) -> c_int {
@@ -26,6 +26,8 @@ use crate::bindgen::FPDF_STRUCTELEMENT_ATTR_VALUE;
 #[cfg(feature = "pdfium_use_skia")]
 use crate::bindgen::FPDF_SKIA_CANVAS;
 
+use crate::bindgen::UNSUPPORT_INFO;
+
 #[cfg(feature = "pdfium_enable_xfa")]
 use crate::bindgen::{FPDF_BSTR, FPDF_RESULT};
 
@@ -2355,6 +2357,12 @@ impl PdfiumLibraryBindings for StaticPdfiumBindings {
         unsafe { crate::bindgen::FPDFDoc_GetPageMode(document) }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FSDK_SetUnSpObjProcessHandler(&self, unsp_info: *mut UNSUPPORT_INFO) -> FPDF_BOOL {
+        unsafe { crate::bindgen::FSDK_SetUnSpObjProcessHandler(unsp_info) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_Flatten(&self, page: FPDF_PAGE, nFlag: c_int) -> c_int {
@@ -33,6 +33,9 @@ use crate::bindgen::FPDF_STRUCTELEMENT_ATTR_VALUE;
 #[cfg(feature = "pdfium_use_skia")]
 use crate::bindgen::FPDF_SKIA_CANVAS;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::bindgen::UNSUPPORT_INFO;
+
 #[cfg(feature = "pdfium_enable_xfa")]
 use crate::bindgen::{FPDF_BSTR, FPDF_RESULT};
 
@@ -2375,6 +2378,13 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         self.bindings.FPDFDoc_GetPageMode(document)
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FSDK_SetUnSpObjProcessHandler(&self, unsp_info: *mut UNSUPPORT_INFO) -> FPDF_BOOL {
+        self.bindings.FSDK_SetUnSpObjProcessHandler(unsp_info)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_Flatten(&self, page: FPDF_PAGE, nFlag: c_int) -> c_int {
@@ -4,6 +4,8 @@ use crate::bindgen::{
     FPDF_ERR_FILE, FPDF_ERR_FORMAT, FPDF_ERR_PAGE, FPDF_ERR_PASSWORD, FPDF_ERR_SECURITY,
     FPDF_ERR_UNKNOWN,
 };
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::security_policy::PdfSecurityPolicyViolation;
 use std::error::Error;
 use std::ffi::IntoStringError;
 use std::fmt::{Display, Formatter, Result};
@@ -116,6 +118,63 @@ pub enum PdfiumError {
     PageAnnotationAttachmentPointIndexOutOfBounds,
     NoAttachmentPointsInPageAnnotation,
     CoordinateConversionFunctionIndicatedError,
+    BitmapPixelOutOfBounds,
+
+    /// The operation requires a `PdfBitmap` containing four-channel BGRA or BGRx pixel data
+    /// in Pdfium's native byte order, but the bitmap uses a different pixel format, or its
+    /// byte order was reversed during rendering.
+    BitmapFormatNotBgra,
+
+    /// The operation requires two `PdfBitmap` objects with the same width, height, pixel format,
+    /// and byte order, but the given bitmaps differ.
+    BitmapDimensionsOrFormatMismatch,
+
+    /// Changes made to a `PdfMetadata` collection could not be written to the document's
+    /// information dictionary, either because the document is encrypted or because the
+    /// document trailer or existing information dictionary could not be located reliably.
+    MetadataNotWritable,
+
+    /// A `PdfPendingBookmark` passed to `PdfBookmarks::add_on_save()` does not reference
+    /// a bookmark previously queued in the same `PdfBookmarks` collection.
+    PendingBookmarkInvalid,
+
+    /// Bookmarks added to a `PdfBookmarks` collection could not be written to the document's
+    /// outline, most likely because the document is encrypted or its outline is malformed.
+    BookmarksNotWritable,
+
+    /// A change to a document's viewer preferences could not be written to the document catalog,
+    /// most likely because the catalog is malformed or stored in a compressed object stream.
+    ViewerPreferencesNotWritable,
+
+    /// A `/UserUnit` value set using `PdfPages::set_user_unit()` could not be written to the
+    /// page's dictionary, most likely because the page tree is malformed or stored in a
+    /// compressed object stream.
+    PageUserUnitNotWritable,
+
+    /// A `/UserUnit` value passed to `PdfPages::set_user_unit()` was not a positive finite number.
+    PageUserUnitInvalid,
+
+    /// The `/UserUnit` entry of a page could not be read, because the page's dictionary could
+    /// not be located in the serialized document, most likely because the page tree is malformed
+    /// or stored in a compressed object stream.
+    PageUserUnitNotReadable,
+
+    /// A page could not be resized by `PdfPage::scale_to_fit()`, because the width or height
+    /// of its existing media box is zero or negative.
+    PageSizeInvalid,
+
+    /// A `/Lang` value passed to `PdfStructElement::set_lang()` could not be written to the
+    /// structure element's dictionary, most likely because the dictionary is a direct object,
+    /// is stored in a compressed object stream, or belongs to an encrypted document.
+    StructElementLangNotWritable,
+
+    /// A document could not be opened because it breaks a `PdfSecurityPolicy`.
+    /// The wrapped list contains every violation found.
+    SecurityPolicyViolated(Vec<PdfSecurityPolicyViolation>),
+
+    /// A text search ended early because it reached the maximum number of results or the
+    /// timeout set in its `PdfSearchOptions`.
+    SearchLimitReached,
 
     /// A call to `FPDFDest_GetView()` returned a valid `FPDFDEST_VIEW_*` value, but the number
     /// of view parameters returned does not match the PDF specification.
@@ -125,10 +184,16 @@ pub enum PdfiumError {
     /// in `PdfColor::from_hex()`.
     ParseHexadecimalColorError(ParseIntError),
 
-    /// The hexadecimal string given to `PdfColor::from_hex()` was not either exactly 7 or 9
-    /// characters long.
+    /// The hexadecimal string given to `PdfColor::from_hex()` was not either exactly 4, 7, or 9
+    /// characters long, or the hexadecimal string given to `PdfColor::from_hex_rgba()` was not
+    /// either exactly 5 or 9 characters long.
     ParseHexadecimalColorUnexpectedLength,
 
+    /// The hexadecimal string given to `PdfColor::from_hex()` or `PdfColor::from_hex_rgba()`
+    /// contained a character other than a leading `#` and the hexadecimal digits `0-9`, `a-f`,
+    /// and `A-F`.
+    ParseHexadecimalColorInvalidCharacter,
+
     /// The leading `#` character was not found while attempting to parse a `PdfColor` from
     /// a hexadecimal string in `PdfColor::from_hex()`.
     ParseHexadecimalColorMissingLeadingHash,
@@ -215,6 +280,13 @@ pub enum PdfiumError {
 
     /// A wrapped internal library error from Pdfium's `FPDF_ERR_*` constant values.
     PdfiumLibraryInternalError(PdfiumInternalError),
+
+    /// Pdfium was unable to load the page at the given index, most likely because the page
+    /// is missing from the document or its page dictionary is corrupt. Other pages in the
+    /// same document may still be loadable.
+    PageLoadFailed {
+        index: PdfPageIndex,
+    },
 }
 
 impl Display for PdfiumError {
@@ -94,8 +94,10 @@ pub mod prelude {
         pdf::document::attachments::*,
         pdf::document::bookmark::*,
         pdf::document::bookmarks::*,
+        pdf::document::diff::*,
         pdf::document::fonts::*,
         pdf::document::form::*,
+        pdf::document::mark_info::*,
         pdf::document::metadata::*,
         pdf::document::page::annotation::attachment_points::*,
         pdf::document::page::annotation::circle::*,
@@ -116,7 +118,7 @@ pub mod prelude {
         pdf::document::page::annotation::widget::*,
         pdf::document::page::annotation::xfa_widget::*,
         pdf::document::page::annotation::{
-            PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationType,
+            PdfAnnotationFlags, PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationType,
         },
         pdf::document::page::annotations::*,
         pdf::document::page::boundaries::*,
@@ -130,10 +132,13 @@ pub mod prelude {
         pdf::document::page::field::signature::*,
         pdf::document::page::field::text::*,
         pdf::document::page::field::unknown::*,
-        pdf::document::page::field::{PdfFormField, PdfFormFieldCommon, PdfFormFieldType},
+        pdf::document::page::field::{
+            PdfFormField, PdfFormFieldCommon, PdfFormFieldTrigger, PdfFormFieldType,
+        },
         pdf::document::page::links::*,
         pdf::document::page::object::group::*,
         pdf::document::page::object::image::*,
+        pdf::document::page::object::mark::*,
         pdf::document::page::object::path::*,
         pdf::document::page::object::shading::*,
         pdf::document::page::object::text::*,
@@ -146,32 +151,48 @@ pub mod prelude {
         pdf::document::page::objects::common::*,
         pdf::document::page::objects::*,
         pdf::document::page::render_config::*,
+        pdf::document::page::rich_media::*,
         pdf::document::page::size::*,
+        pdf::document::page::struct_tree::*,
+        pdf::document::page::svg::*,
         pdf::document::page::text::char::*,
+        pdf::document::page::text::char_info::*,
         pdf::document::page::text::chars::*,
+        pdf::document::page::text::paragraph::*,
         pdf::document::page::text::search::*,
         pdf::document::page::text::segment::*,
         pdf::document::page::text::segments::*,
+        pdf::document::page::text::word::*,
         pdf::document::page::text::*,
+        pdf::document::page::watermark::*,
         pdf::document::page::{
-            PdfBitmapRotation, PdfPage, PdfPageContentRegenerationStrategy, PdfPageOrientation,
-            PdfPageRenderRotation,
+            PdfBitmapRotation, PdfPage, PdfPageContentRegenerationStrategy, PdfPageFitMode,
+            PdfPageOrientation, PdfPageRenderRotation,
         },
         pdf::document::pages::*,
+        pdf::document::pdf_a::*,
         pdf::document::permissions::*,
+        pdf::document::repair::*,
+        pdf::document::save_options::*,
+        pdf::document::security_policy::*,
         pdf::document::signature::*,
         pdf::document::signatures::*,
+        pdf::document::usage_rights::*,
         pdf::document::{PdfDocument, PdfDocumentVersion},
         pdf::font::glyph::*,
         pdf::font::glyphs::*,
         pdf::font::*,
         pdf::link::*,
         pdf::matrix::*,
+        pdf::path::clip_path::*,
         pdf::path::segment::*,
         pdf::path::segments::*,
         pdf::points::*,
         pdf::quad_points::*,
         pdf::rect::*,
+        pdf::scale::*,
+        pdf::unsupported_feature::*,
+        pdfium::config::*,
         pdfium::*,
     };
 }
@@ -12,4 +12,6 @@ pub mod path;
 pub mod points;
 pub mod quad_points;
 pub mod rect;
+pub mod scale;
 mod transform; // Keep private so that internal macros are not exposed.
+pub mod unsupported_feature;
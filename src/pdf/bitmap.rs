@@ -6,6 +6,7 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::render_config::PdfPageRenderSettings;
 use crate::utils::pixels::{aligned_bgr_to_rgba, aligned_rgb_to_rgba, bgra_to_rgba};
 use std::os::raw::c_int;
@@ -33,6 +34,9 @@ use {
 // They allow documentation of WASM-specific functionality to be included
 // in documentation generated on non-WASM targets.
 
+#[cfg(doc)]
+use crate::pdf::document::page::render_config::PdfRenderConfig;
+
 #[cfg(doc)]
 struct Uint8Array;
 
@@ -284,6 +288,334 @@ impl<'a> PdfBitmap<'a> {
         }
     }
 
+    /// Returns an owned copy of the bitmap buffer backing this [PdfBitmap], along with the
+    /// stride (the number of bytes in each row of pixels, including any alignment padding).
+    /// The buffer contains four-channel pixel data in Pdfium's native BGRA byte order; no
+    /// color channel conversion is performed.
+    ///
+    /// Returns [PdfiumError::BitmapFormatNotBgra] if this [PdfBitmap] does not use the
+    /// [PdfBitmapFormat::BGRA] or [PdfBitmapFormat::BGRx] pixel format, or if its byte order was
+    /// reversed during rendering by a call to [PdfRenderConfig::set_reverse_byte_order()].
+    pub fn as_bgra8_raw(&self) -> Result<(Vec<u8>, usize), PdfiumError> {
+        if self.was_byte_order_reversed_during_rendering {
+            return Err(PdfiumError::BitmapFormatNotBgra);
+        }
+
+        match self.format()? {
+            #[allow(deprecated)]
+            PdfBitmapFormat::BGRA | PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => Ok((
+                self.as_raw_bytes(),
+                self.bindings.FPDFBitmap_GetStride(self.handle) as usize,
+            )),
+            _ => Err(PdfiumError::BitmapFormatNotBgra),
+        }
+    }
+
+    /// Returns the color of the pixel at the given coordinates in this [PdfBitmap], measured
+    /// from the top-left corner of the bitmap.
+    ///
+    /// Color channels are normalized from the bitmap's pixel format, taking into account any
+    /// byte order reversal requested during rendering. Pixels in [PdfBitmapFormat::BGR]
+    /// and [PdfBitmapFormat::BGRx] bitmaps are always fully opaque; pixels in
+    /// [PdfBitmapFormat::Gray] bitmaps are returned as an opaque shade of grey.
+    ///
+    /// Returns [PdfiumError::BitmapPixelOutOfBounds] if the given coordinates lie outside
+    /// the bounds of this [PdfBitmap].
+    pub fn get_pixel(&self, x: Pixels, y: Pixels) -> Result<PdfColor, PdfiumError> {
+        let (offset, format) = self.pixel_offset(x, y)?;
+
+        let bytes = self.pixel_bytes(offset, Self::bytes_per_pixel(format));
+
+        let color = match format {
+            PdfBitmapFormat::Gray => PdfColor::new(bytes[0], bytes[0], bytes[0], 255),
+            PdfBitmapFormat::BGRA => PdfColor::new(bytes[2], bytes[1], bytes[0], bytes[3]),
+            #[allow(deprecated)]
+            PdfBitmapFormat::BGR | PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => {
+                PdfColor::new(bytes[2], bytes[1], bytes[0], 255)
+            }
+        };
+
+        if self.was_byte_order_reversed_during_rendering && format != PdfBitmapFormat::Gray {
+            Ok(PdfColor::new(
+                color.blue(),
+                color.green(),
+                color.red(),
+                color.alpha(),
+            ))
+        } else {
+            Ok(color)
+        }
+    }
+
+    /// Sets the color of the pixel at the given coordinates in this [PdfBitmap], measured
+    /// from the top-left corner of the bitmap.
+    ///
+    /// The given color is converted into the bitmap's pixel format, taking into account any
+    /// byte order reversal requested during rendering. The alpha channel is discarded for
+    /// bitmaps without an alpha channel; for [PdfBitmapFormat::Gray] bitmaps, the color is
+    /// converted to its luminance.
+    ///
+    /// Returns [PdfiumError::BitmapPixelOutOfBounds] if the given coordinates lie outside
+    /// the bounds of this [PdfBitmap].
+    pub fn set_pixel(&mut self, x: Pixels, y: Pixels, color: PdfColor) -> Result<(), PdfiumError> {
+        let (offset, format) = self.pixel_offset(x, y)?;
+
+        let (red, blue) = if self.was_byte_order_reversed_during_rendering {
+            (color.blue(), color.red())
+        } else {
+            (color.red(), color.blue())
+        };
+
+        let bytes = match format {
+            PdfBitmapFormat::Gray => vec![(0.299 * color.red() as f32
+                + 0.587 * color.green() as f32
+                + 0.114 * color.blue() as f32)
+                .round() as u8],
+            PdfBitmapFormat::BGR => vec![blue, color.green(), red],
+            PdfBitmapFormat::BGRA => vec![blue, color.green(), red, color.alpha()],
+            #[allow(deprecated)]
+            PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => vec![blue, color.green(), red, 255],
+        };
+
+        self.set_pixel_bytes(offset, bytes.as_slice());
+
+        Ok(())
+    }
+
+    /// Returns the bytes of the given row of pixels in this [PdfBitmap], measured from the
+    /// top of the bitmap. The returned slice contains exactly [PdfBitmap::width()] pixels
+    /// in the bitmap's raw pixel format, excluding any alignment padding at the end of the row.
+    ///
+    /// Returns [PdfiumError::BitmapPixelOutOfBounds] if the given row lies outside
+    /// the bounds of this [PdfBitmap].
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn row(&self, y: Pixels) -> Result<&[u8], PdfiumError> {
+        let (offset, format) = self.pixel_offset(0, y)?;
+
+        let length = self.width() as usize * Self::bytes_per_pixel(format);
+
+        Ok(&self.bindings.FPDFBitmap_GetBuffer_as_slice(self.handle)[offset..offset + length])
+    }
+
+    /// Calls the given closure with mutable access to the bitmap buffer backing this [PdfBitmap],
+    /// returning the closure's result. This allows custom drawing, such as overlays composited
+    /// on top of a rendered page, to be applied directly to the bitmap before it is converted
+    /// or encoded.
+    ///
+    /// The closure receives the buffer, the width and height of the bitmap in pixels, and the
+    /// stride: the number of bytes in each row of pixels, including any alignment padding.
+    /// The first byte of the pixel at `(x, y)` is at offset `y * stride + x * bytes_per_pixel`,
+    /// where `bytes_per_pixel` is determined by the bitmap's [PdfBitmapFormat]. Four-channel
+    /// pixels are stored in BGRA order unless the byte order was reversed during rendering
+    /// by a call to [PdfRenderConfig::set_reverse_byte_order()], in which case they are
+    /// stored in RGBA order.
+    ///
+    /// When compiling to WASM, the buffer is copied out of Pdfium's memory before the closure
+    /// is called and copied back afterwards.
+    pub fn with_pixels_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [u8], Pixels, Pixels, usize) -> R,
+    {
+        let width = self.width();
+
+        let height = self.height();
+
+        let stride = self.bindings.FPDFBitmap_GetStride(self.handle) as usize;
+
+        self.with_buffer_mut(|buffer| f(buffer, width, height, stride))
+    }
+
+    /// Compares the pixels in this [PdfBitmap] against the pixels in the given [PdfBitmap],
+    /// returning a new [PdfBitmap] visualizing the differences between the two, along with
+    /// the fraction of pixels that differ, in the range `0.0..=1.0`.
+    ///
+    /// A pixel is considered to differ if any of its color or alpha channels differ by more than
+    /// the given threshold. In the returned [PdfBitmap], which uses the [PdfBitmapFormat::BGRA]
+    /// pixel format, differing pixels are drawn in opaque red; all other pixels are drawn
+    /// as a faded greyscale copy of this [PdfBitmap], so that the differences can be located
+    /// on the page.
+    ///
+    /// Returns [PdfiumError::BitmapDimensionsOrFormatMismatch] if the two bitmaps do not share
+    /// the same width, height, pixel format, and byte order.
+    pub fn diff(
+        &self,
+        other: &PdfBitmap,
+        threshold: u8,
+    ) -> Result<(PdfBitmap<'a>, f64), PdfiumError> {
+        let width = self.width();
+
+        let height = self.height();
+
+        let format = self.format()?;
+
+        if width != other.width()
+            || height != other.height()
+            || format != other.format()?
+            || self.was_byte_order_reversed_during_rendering
+                != other.was_byte_order_reversed_during_rendering
+        {
+            return Err(PdfiumError::BitmapDimensionsOrFormatMismatch);
+        }
+
+        let bytes_per_pixel = Self::bytes_per_pixel(format);
+
+        let color_channels = bytes_per_pixel.min(3);
+
+        let has_alpha = format == PdfBitmapFormat::BGRA;
+
+        let a = self.as_raw_bytes();
+
+        let a_stride = self.bindings.FPDFBitmap_GetStride(self.handle) as usize;
+
+        let b = other.as_raw_bytes();
+
+        let b_stride = other.bindings.FPDFBitmap_GetStride(other.handle) as usize;
+
+        let mut result = PdfBitmap::empty(width, height, PdfBitmapFormat::BGRA, self.bindings)?;
+
+        let differing = result.with_pixels_mut(|buffer, width, height, stride| {
+            let mut differing = 0;
+
+            for y in 0..height as usize {
+                for x in 0..width as usize {
+                    let a_pixel = &a[y * a_stride + x * bytes_per_pixel..][..bytes_per_pixel];
+
+                    let b_pixel = &b[y * b_stride + x * bytes_per_pixel..][..bytes_per_pixel];
+
+                    let channels = if has_alpha {
+                        bytes_per_pixel
+                    } else {
+                        color_channels
+                    };
+
+                    let is_different = a_pixel[..channels]
+                        .iter()
+                        .zip(&b_pixel[..channels])
+                        .any(|(a, b)| (*a as i16 - *b as i16).unsigned_abs() > threshold as u16);
+
+                    let offset = y * stride + x * 4;
+
+                    if is_different {
+                        differing += 1;
+
+                        buffer[offset..offset + 4].copy_from_slice(&[0, 0, 255, 255]);
+                    } else {
+                        let luminance = a_pixel[..color_channels]
+                            .iter()
+                            .map(|channel| *channel as usize)
+                            .sum::<usize>()
+                            / color_channels;
+
+                        let faded = (255 - (255 - luminance) / 4) as u8;
+
+                        buffer[offset..offset + 4].copy_from_slice(&[faded, faded, faded, 255]);
+                    }
+                }
+            }
+
+            differing
+        });
+
+        let total = width as usize * height as usize;
+
+        let fraction = if total == 0 {
+            0.0
+        } else {
+            differing as f64 / total as f64
+        };
+
+        Ok((result, fraction))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn with_buffer_mut<R>(&mut self, f: impl FnOnce(&mut [u8]) -> R) -> R {
+        let length =
+            self.bindings.FPDFBitmap_GetStride(self.handle) as usize * self.height() as usize;
+
+        let buffer = self.bindings.FPDFBitmap_GetBuffer(self.handle) as *mut u8;
+
+        // The buffer is owned by the bitmap, and we hold a mutable reference to the bitmap
+        // for the lifetime of the slice, so no other access to the buffer is possible.
+
+        f(unsafe { std::slice::from_raw_parts_mut(buffer, length) })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn with_buffer_mut<R>(&mut self, f: impl FnOnce(&mut [u8]) -> R) -> R {
+        let mut buffer = self.as_raw_bytes();
+
+        let result = f(buffer.as_mut_slice());
+
+        self.set_pixel_bytes(0, buffer.as_slice());
+
+        result
+    }
+
+    /// Returns the number of bytes used to store a single pixel in the given format.
+    #[inline]
+    fn bytes_per_pixel(format: PdfBitmapFormat) -> usize {
+        match format {
+            PdfBitmapFormat::Gray => 1,
+            PdfBitmapFormat::BGR => 3,
+            #[allow(deprecated)]
+            PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx | PdfBitmapFormat::BGRA => 4,
+        }
+    }
+
+    /// Returns the offset into the bitmap buffer of the first byte of the pixel at the given
+    /// coordinates, along with the bitmap's pixel format, respecting the bitmap's stride.
+    fn pixel_offset(&self, x: Pixels, y: Pixels) -> Result<(usize, PdfBitmapFormat), PdfiumError> {
+        if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+            return Err(PdfiumError::BitmapPixelOutOfBounds);
+        }
+
+        let format = self.format()?;
+
+        let stride = self.bindings.FPDFBitmap_GetStride(self.handle) as usize;
+
+        Ok((
+            y as usize * stride + x as usize * Self::bytes_per_pixel(format),
+            format,
+        ))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn pixel_bytes(&self, offset: usize, length: usize) -> Vec<u8> {
+        self.bindings.FPDFBitmap_GetBuffer_as_slice(self.handle)[offset..offset + length].to_vec()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn pixel_bytes(&self, offset: usize, length: usize) -> Vec<u8> {
+        self.bindings
+            .FPDFBitmap_GetBuffer_as_array(self.handle)
+            .subarray(offset as u32, (offset + length) as u32)
+            .to_vec()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn set_pixel_bytes(&mut self, offset: usize, bytes: &[u8]) {
+        // The offset has already been bounds-checked by pixel_offset(), so the write
+        // is guaranteed to lie within the bitmap buffer.
+
+        let buffer = self.bindings.FPDFBitmap_GetBuffer(self.handle) as *mut u8;
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.add(offset), bytes.len());
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn set_pixel_bytes(&mut self, offset: usize, bytes: &[u8]) {
+        // Changes made to the array returned by FPDFBitmap_GetBuffer_as_array()
+        // directly mutate the pixel data of the bitmap.
+
+        self.bindings
+            .FPDFBitmap_GetBuffer_as_array(self.handle)
+            .set(&Uint8Array::from(bytes), offset as u32);
+    }
+
     /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing this [PdfBitmap].
     ///
     /// This function is only available when this crate's `image` feature is enabled.
@@ -313,6 +645,35 @@ impl<'a> PdfBitmap<'a> {
         .unwrap()
     }
 
+    /// Returns a new `Image::RgbaImage` created from the bitmap buffer backing this [PdfBitmap].
+    /// Color channels are normalized into RGBA irrespective of the original pixel format;
+    /// pixels in [PdfBitmapFormat::Gray] bitmaps are converted to opaque shades of grey.
+    ///
+    /// Unlike [PdfBitmap::as_image()], the returned image is not wrapped in an
+    /// `Image::DynamicImage`, so it can be used directly without matching on the image type.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn as_rgba8(&self) -> Result<RgbaImage, PdfiumError> {
+        let width = self.width() as usize;
+
+        let bytes = match self.format()? {
+            PdfBitmapFormat::Gray => {
+                let stride = self.bindings.FPDFBitmap_GetStride(self.handle) as usize;
+
+                self.as_raw_bytes()
+                    .chunks(stride)
+                    .flat_map(|row| row[..width].iter())
+                    .flat_map(|value| [*value, *value, *value, 255])
+                    .collect::<Vec<_>>()
+            }
+            _ => self.as_rgba_bytes(),
+        };
+
+        RgbaImage::from_raw(width as u32, self.height() as u32, bytes)
+            .ok_or(PdfiumError::ImageError)
+    }
+
     // TODO: AJRC - 29/7/22 - remove deprecated PdfBitmap::render() function in 0.9.0
     // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Prior to 0.7.12, this function rendered the referenced page into a bitmap buffer.
@@ -435,4 +796,169 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_and_set_pixel() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut bitmap = PdfBitmap::empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        let color = PdfColor::new(10, 20, 30, 40);
+
+        bitmap.set_pixel(3, 7, color)?;
+
+        let pixel = bitmap.get_pixel(3, 7)?;
+
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()),
+            (10, 20, 30, 40)
+        );
+
+        // The pixel should be stored in BGRA order at the expected position in its row.
+
+        assert_eq!(&bitmap.row(7)?[12..16], &[30, 20, 10, 40]);
+        assert_eq!(bitmap.row(7)?.len(), 40);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pixel_coordinates_out_of_bounds() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut bitmap = PdfBitmap::empty(10, 10, PdfBitmapFormat::BGR, pdfium.bindings())?;
+
+        assert!(matches!(
+            bitmap.get_pixel(10, 0),
+            Err(PdfiumError::BitmapPixelOutOfBounds)
+        ));
+        assert!(matches!(
+            bitmap.set_pixel(0, -1, PdfColor::RED),
+            Err(PdfiumError::BitmapPixelOutOfBounds)
+        ));
+        assert!(matches!(
+            bitmap.row(10),
+            Err(PdfiumError::BitmapPixelOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_rgba8_and_as_bgra8_raw_channel_order() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut bitmap = PdfBitmap::empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        bitmap.set_pixel(3, 7, PdfColor::new(10, 20, 30, 40))?;
+
+        let image = bitmap.as_rgba8()?;
+
+        assert_eq!((image.width(), image.height()), (10, 10));
+        assert_eq!(image.get_pixel(3, 7).0, [10, 20, 30, 40]);
+
+        let (bytes, stride) = bitmap.as_bgra8_raw()?;
+
+        assert_eq!(stride, 40);
+        assert_eq!(&bytes[7 * stride + 12..7 * stride + 16], &[30, 20, 10, 40]);
+
+        let gray = PdfBitmap::empty(10, 10, PdfBitmapFormat::Gray, pdfium.bindings())?;
+
+        assert!(matches!(
+            gray.as_bgra8_raw(),
+            Err(PdfiumError::BitmapFormatNotBgra)
+        ));
+        assert_eq!(gray.as_rgba8()?.get_pixel(0, 0).0[3], 255);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_pixels_mut_draws_overlay() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let mut bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_width(100))?;
+
+        // Draw an opaque red square, in BGRA order, into the rendered bitmap.
+
+        bitmap.with_pixels_mut(|buffer, width, height, stride| {
+            assert_eq!(width, 100);
+            assert!(height > 20);
+            assert!(stride >= width as usize * 4);
+
+            for y in 10..20 {
+                for x in 10..20 {
+                    let offset = y * stride + x * 4;
+
+                    buffer[offset..offset + 4].copy_from_slice(&[0, 0, 255, 255]);
+                }
+            }
+        });
+
+        let image = bitmap.as_image().into_rgba8();
+
+        assert_eq!(image.get_pixel(15, 15).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(25, 25).0, [255, 255, 255, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut a = PdfBitmap::empty(20, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        let mut b = PdfBitmap::empty(20, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        for y in 0..10 {
+            for x in 0..20 {
+                a.set_pixel(x, y, PdfColor::WHITE)?;
+                b.set_pixel(x, y, PdfColor::WHITE)?;
+            }
+        }
+
+        let (_, fraction) = a.diff(&b, 0)?;
+
+        assert_eq!(fraction, 0.0);
+
+        b.set_pixel(5, 5, PdfColor::new(250, 255, 255, 255))?;
+
+        // A difference below the threshold is ignored.
+
+        assert_eq!(a.diff(&b, 10)?.1, 0.0);
+
+        let (visualization, fraction) = a.diff(&b, 0)?;
+
+        assert_eq!(fraction, 1.0 / 200.0);
+
+        let pixel = visualization.get_pixel(5, 5)?;
+
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()),
+            (255, 0, 0, 255)
+        );
+
+        let pixel = visualization.get_pixel(6, 5)?;
+
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()),
+            (255, 255, 255, 255)
+        );
+
+        let c = PdfBitmap::empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        assert!(matches!(
+            a.diff(&c, 0),
+            Err(PdfiumError::BitmapDimensionsOrFormatMismatch)
+        ));
+
+        Ok(())
+    }
 }
@@ -238,34 +238,109 @@ impl PdfColor {
 
     /// Returns the result of importing the given hexadecimal color specification,
     /// as in HTML. For example, `#800080` represents a shade of purple with 100% opacity,
-    /// and `#40800080` is the same shade of purple with 25% opacity. The leading hash
-    /// symbol is required.
+    /// and `#40800080` is the same shade of purple with 25% opacity. The three-digit
+    /// shorthand form is also supported, so `#F0A` is equivalent to `#FF00AA`.
+    /// The leading hash symbol is required.
+    ///
+    /// Note that when an alpha channel value is included, it precedes the red, green,
+    /// and blue color components. Use the [PdfColor::from_hex_rgba()] function to import
+    /// an `#RRGGBBAA` color specification, where the alpha channel value follows
+    /// the color components.
     pub fn from_hex(hex: &str) -> Result<Self, PdfiumError> {
-        if hex.starts_with('#') {
-            match hex.len() {
-                7 => {
-                    // Potential HTML-style RGB triplet in hexadecimal format
-                    // with leading #.
-
-                    FPDF_DWORD::from_str_radix(&hex[1..hex.len()], 16)
-                        .map(PdfColor::from_pdfium)
-                        .map(|color| color.with_alpha(255))
-                        .map_err(PdfiumError::ParseHexadecimalColorError)
-                }
-                9 => {
-                    // Potential ARGB quadruplet in hexadecimal format with leading #.
-
-                    FPDF_DWORD::from_str_radix(&hex[1..hex.len()], 16)
-                        .map(PdfColor::from_pdfium)
-                        .map_err(PdfiumError::ParseHexadecimalColorError)
-                }
-                _ => Err(PdfiumError::ParseHexadecimalColorUnexpectedLength),
+        let digits = Self::hex_digits(hex)?;
+
+        match digits.len() {
+            3 => {
+                // Potential HTML-style shorthand RGB triplet in hexadecimal format
+                // with leading #. Each digit is repeated to form the full component value,
+                // so #F0A expands to #FF00AA.
+
+                let expanded = digits
+                    .chars()
+                    .flat_map(|digit| [digit, digit])
+                    .collect::<String>();
+
+                Self::parse_hex_digits(&expanded)
+                    .map(PdfColor::from_pdfium)
+                    .map(|color| color.with_alpha(255))
+            }
+            6 => {
+                // Potential HTML-style RGB triplet in hexadecimal format
+                // with leading #.
+
+                Self::parse_hex_digits(digits)
+                    .map(PdfColor::from_pdfium)
+                    .map(|color| color.with_alpha(255))
+            }
+            8 => {
+                // Potential ARGB quadruplet in hexadecimal format with leading #.
+
+                Self::parse_hex_digits(digits).map(PdfColor::from_pdfium)
             }
+            _ => Err(PdfiumError::ParseHexadecimalColorUnexpectedLength),
+        }
+    }
+
+    /// Returns the result of importing the given hexadecimal color specification in
+    /// `#RRGGBBAA` format, as in CSS, where the alpha channel value follows the red, green,
+    /// and blue color components. For example, `#80008040` represents a shade of purple
+    /// with 25% opacity. The four-digit shorthand form is also supported, so `#F0A8` is
+    /// equivalent to `#FF00AA88`. The leading hash symbol is required.
+    ///
+    /// Use the [PdfColor::to_hex_rgba()] function to export a color in the same format, or the
+    /// [PdfColor::from_hex()] function to import an `#AARRGGBB` color specification, where the
+    /// alpha channel value precedes the color components.
+    pub fn from_hex_rgba(hex: &str) -> Result<Self, PdfiumError> {
+        let digits = Self::hex_digits(hex)?;
+
+        let rgba = match digits.len() {
+            4 => {
+                // CSS-style shorthand RGBA quadruplet. Each digit is repeated to form
+                // the full component value, so #F0A8 expands to #FF00AA88.
+
+                let expanded = digits
+                    .chars()
+                    .flat_map(|digit| [digit, digit])
+                    .collect::<String>();
+
+                Self::parse_hex_digits(&expanded)?
+            }
+            8 => Self::parse_hex_digits(digits)?,
+            _ => return Err(PdfiumError::ParseHexadecimalColorUnexpectedLength),
+        };
+
+        Ok(Self::new(
+            ((rgba & 0xFF000000) >> 24) as u8,
+            ((rgba & 0xFF0000) >> 16) as u8,
+            ((rgba & 0xFF00) >> 8) as u8,
+            (rgba & 0xFF) as u8,
+        ))
+    }
+
+    /// Returns the hexadecimal digits following the leading hash symbol in the given
+    /// hexadecimal color specification, checking that every character is a valid
+    /// hexadecimal digit.
+    fn hex_digits(hex: &str) -> Result<&str, PdfiumError> {
+        let digits = hex
+            .strip_prefix('#')
+            .ok_or(PdfiumError::ParseHexadecimalColorMissingLeadingHash)?;
+
+        // FPDF_DWORD::from_str_radix() accepts a leading sign character, so we check
+        // each character explicitly rather than relying on it to reject malformed input.
+
+        if digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            Ok(digits)
         } else {
-            Err(PdfiumError::ParseHexadecimalColorMissingLeadingHash)
+            Err(PdfiumError::ParseHexadecimalColorInvalidCharacter)
         }
     }
 
+    /// Parses the given string of hexadecimal digits into a 32-bit value.
+    #[inline]
+    fn parse_hex_digits(digits: &str) -> Result<FPDF_DWORD, PdfiumError> {
+        FPDF_DWORD::from_str_radix(digits, 16).map_err(PdfiumError::ParseHexadecimalColorError)
+    }
+
     /// Returns the result of averaging the RGB and alpha values of the two given [PdfColor] objects.
     #[inline]
     pub const fn mix(a: &PdfColor, b: &PdfColor) -> Self {
@@ -295,6 +370,51 @@ impl PdfColor {
         }
     }
 
+    /// Returns the result of linearly interpolating the RGB and alpha values of this [PdfColor]
+    /// towards the given [PdfColor]. A `t` value of 0.0 returns this color, and a `t` value
+    /// of 1.0 returns the given color. Values of `t` outside the range `0.0..=1.0` are clamped.
+    pub fn lerp(&self, other: &PdfColor, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+
+        let lerp_component = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+
+        Self {
+            r: lerp_component(self.r, other.r),
+            g: lerp_component(self.g, other.g),
+            b: lerp_component(self.b, other.b),
+            a: lerp_component(self.a, other.a),
+        }
+    }
+
+    /// Returns the relative luminance of this color, as defined by the Web Content Accessibility
+    /// Guidelines (WCAG), in the range 0.0 (black) to 1.0 (white). The alpha value is ignored.
+    pub fn luminance(&self) -> f64 {
+        let linearize = |component: u8| {
+            let value = component as f64 / 255.0;
+
+            if value <= 0.03928 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linearize(self.r) + 0.7152 * linearize(self.g) + 0.0722 * linearize(self.b)
+    }
+
+    /// Returns the contrast ratio between this color and the given color, as defined by the
+    /// Web Content Accessibility Guidelines (WCAG), in the range 1.0 (no contrast) to
+    /// 21.0 (black against white). The alpha values of both colors are ignored.
+    ///
+    /// WCAG recommends a contrast ratio of at least 4.5 between normal text and its background.
+    pub fn contrast_ratio(&self, other: &PdfColor) -> f64 {
+        let a = self.luminance();
+
+        let b = other.luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
     /// Returns the alpha (opacity) component of this color, with 0 = completely transparent
     /// and 255 = completely opaque (solid).
     #[inline]
@@ -331,6 +451,10 @@ impl PdfColor {
     /// Returns the hexadecimal representation of this color, as in HTML, without
     /// a leading hash symbol. Includes the alpha channel value. For example,
     /// `PdfColor::PURPLE.to_hex_with_alpha()` will return "FF800080".
+    ///
+    /// Note that the alpha channel value precedes the red, green, and blue color components,
+    /// matching the format accepted by [PdfColor::from_hex()]. Use the [PdfColor::to_hex_rgba()]
+    /// function to export a color where the alpha channel value follows the color components.
     #[inline]
     pub fn to_hex_with_alpha(&self) -> String {
         format!(
@@ -339,6 +463,21 @@ impl PdfColor {
         )
     }
 
+    /// Returns the hexadecimal representation of this color, as in CSS, without
+    /// a leading hash symbol. The alpha channel value follows the color components.
+    /// For example, `PdfColor::PURPLE.to_hex_rgba()` will return "800080FF".
+    ///
+    /// Use the [PdfColor::from_hex_rgba()] function to import a color in the same format, or the
+    /// [PdfColor::to_hex_with_alpha()] function to export a color where the alpha channel value
+    /// precedes the color components.
+    #[inline]
+    pub fn to_hex_rgba(&self) -> String {
+        format!(
+            "{:02X?}{:02X?}{:02X?}{:02X?}",
+            self.r, self.g, self.b, self.a
+        )
+    }
+
     /// Returns this color encoded as a 32-bit hexadecimal 0xAARRGGBB value,
     /// suitable for passing to Pdfium.
     #[inline]
@@ -392,6 +531,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_hex_shorthand() {
+        assert_eq!(
+            PdfColor::from_hex("#F0A").unwrap().color_components(),
+            PdfColor::new(255, 0, 170, 255).color_components()
+        );
+        assert_eq!(
+            PdfColor::from_hex("#808").unwrap().color_components(),
+            PdfColor::new(136, 0, 136, 255).color_components()
+        );
+    }
+
+    #[test]
+    fn test_from_hex_rgba() {
+        assert_eq!(
+            PdfColor::from_hex_rgba("#800080FF")
+                .unwrap()
+                .color_components(),
+            PdfColor::PURPLE.color_components()
+        );
+        assert_eq!(
+            PdfColor::from_hex_rgba("#80008040")
+                .unwrap()
+                .color_components(),
+            PdfColor::PURPLE.with_alpha(64).color_components()
+        );
+    }
+
+    #[test]
+    fn test_from_hex_rgba_shorthand() {
+        assert_eq!(
+            PdfColor::from_hex_rgba("#f00f").unwrap().color_components(),
+            PdfColor::new(255, 0, 0, 255).color_components()
+        );
+        assert_eq!(
+            PdfColor::from_hex_rgba("#F0A8").unwrap().color_components(),
+            PdfColor::new(255, 0, 170, 136).color_components()
+        );
+        assert_eq!(
+            PdfColor::from_hex_rgba("#f00f").unwrap().to_hex_rgba(),
+            "FF0000FF"
+        );
+    }
+
+    #[test]
+    fn test_to_hex_rgba_round_trip() {
+        let color = PdfColor::PURPLE.with_alpha(64);
+
+        assert_eq!(color.to_hex_rgba(), "80008040");
+        assert_eq!(
+            PdfColor::from_hex_rgba(&format!("#{}", color.to_hex_rgba()))
+                .unwrap()
+                .color_components(),
+            color.color_components()
+        );
+    }
+
+    #[test]
+    fn test_from_hex_rejects_malformed_input() {
+        assert!(matches!(
+            PdfColor::from_hex("800080"),
+            Err(PdfiumError::ParseHexadecimalColorMissingLeadingHash)
+        ));
+        assert!(matches!(
+            PdfColor::from_hex("#8000"),
+            Err(PdfiumError::ParseHexadecimalColorUnexpectedLength)
+        ));
+        assert!(matches!(
+            PdfColor::from_hex("#80G080"),
+            Err(PdfiumError::ParseHexadecimalColorInvalidCharacter)
+        ));
+        assert!(matches!(
+            PdfColor::from_hex("#+80080"),
+            Err(PdfiumError::ParseHexadecimalColorInvalidCharacter)
+        ));
+        assert!(matches!(
+            PdfColor::from_hex_rgba("#800080"),
+            Err(PdfiumError::ParseHexadecimalColorUnexpectedLength)
+        ));
+        assert!(matches!(
+            PdfColor::from_hex_rgba("#F0A"),
+            Err(PdfiumError::ParseHexadecimalColorUnexpectedLength)
+        ));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let mid = PdfColor::BLACK.lerp(&PdfColor::WHITE, 0.5);
+
+        assert_eq!((mid.red(), mid.green(), mid.blue()), (128, 128, 128));
+        assert_eq!(mid.alpha(), 255);
+
+        let start = PdfColor::BLACK.lerp(&PdfColor::WHITE, -1.0);
+
+        assert_eq!((start.red(), start.green(), start.blue()), (0, 0, 0));
+
+        let end = PdfColor::BLACK.lerp(&PdfColor::WHITE, 2.0);
+
+        assert_eq!((end.red(), end.green(), end.blue()), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_luminance_and_contrast_ratio() {
+        assert_eq!(PdfColor::BLACK.luminance(), 0.0);
+        assert!((PdfColor::WHITE.luminance() - 1.0).abs() < 1e-9);
+
+        assert!((PdfColor::BLACK.contrast_ratio(&PdfColor::WHITE) - 21.0).abs() < 1e-9);
+        assert!((PdfColor::WHITE.contrast_ratio(&PdfColor::BLACK) - 21.0).abs() < 1e-9);
+        assert!((PdfColor::RED.contrast_ratio(&PdfColor::RED) - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_to_hex() {
         assert_eq!(PdfColor::PURPLE.to_hex(), "800080");
@@ -231,6 +231,50 @@ impl PdfDestinationViewSettings {
             _ => Err(PdfiumError::UnknownPdfDestinationViewType),
         }
     }
+
+    /// Returns the view type and parameters of these [PdfDestinationViewSettings], as they
+    /// should appear after the page reference in a PDF destination array. Unknown view
+    /// settings are written as `/Fit`.
+    pub(crate) fn as_pdf_destination_suffix(&self) -> String {
+        fn value(value: Option<f32>) -> String {
+            value
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| "null".to_string())
+        }
+
+        fn points(points: &Option<PdfPoints>) -> String {
+            value(points.map(|points| points.value))
+        }
+
+        match self {
+            PdfDestinationViewSettings::Unknown | PdfDestinationViewSettings::FitPageToWindow => {
+                "/Fit".to_string()
+            }
+            PdfDestinationViewSettings::SpecificCoordinatesAndZoom(x, y, zoom) => {
+                format!("/XYZ {} {} {}", points(x), points(y), value(*zoom))
+            }
+            PdfDestinationViewSettings::FitPageHorizontallyToWindow(y) => {
+                format!("/FitH {}", points(y))
+            }
+            PdfDestinationViewSettings::FitPageVerticallyToWindow(x) => {
+                format!("/FitV {}", points(x))
+            }
+            PdfDestinationViewSettings::FitPageToRectangle(rect) => format!(
+                "/FitR {} {} {} {}",
+                rect.left().value,
+                rect.bottom().value,
+                rect.right().value,
+                rect.top().value
+            ),
+            PdfDestinationViewSettings::FitBoundsToWindow => "/FitB".to_string(),
+            PdfDestinationViewSettings::FitBoundsHorizontallyToWindow(y) => {
+                format!("/FitBH {}", points(y))
+            }
+            PdfDestinationViewSettings::FitBoundsVerticallyToWindow(x) => {
+                format!("/FitBV {}", points(x))
+            }
+        }
+    }
 }
 
 /// The page and region, if any, that will be the target of any behaviour that will occur
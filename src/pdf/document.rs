@@ -5,39 +5,79 @@ pub mod attachment;
 pub mod attachments;
 pub mod bookmark;
 pub mod bookmarks;
+pub mod diff;
 pub mod fonts;
 pub mod form;
+pub mod mark_info;
 pub mod metadata;
 pub mod page;
 pub mod pages;
+pub mod pdf_a;
 pub mod permissions;
+pub(crate) mod raw;
+pub mod repair;
+pub mod save_options;
+pub mod security_policy;
 pub mod signature;
 pub mod signatures;
+pub mod usage_rights;
 
 use crate::bindgen::FPDF_DOCUMENT;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::error::PdfiumInternalError;
+use crate::pdf::document::attachment::PdfAttachment;
 use crate::pdf::document::attachments::PdfAttachments;
 use crate::pdf::document::bookmarks::PdfBookmarks;
+use crate::pdf::document::diff::PdfDocumentDiff;
 use crate::pdf::document::fonts::PdfFonts;
 use crate::pdf::document::form::PdfForm;
+use crate::pdf::document::mark_info::PdfMarkInfo;
 use crate::pdf::document::metadata::PdfMetadata;
-use crate::pdf::document::pages::PdfPages;
+use crate::pdf::document::page::object::PdfPageObject;
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::pages::{PdfPageIndex, PdfPages};
+use crate::pdf::document::pdf_a::{pdf_a_issues, PdfAIssue};
 use crate::pdf::document::permissions::PdfPermissions;
+use crate::pdf::document::raw::{
+    append_viewer_preference_flag, catalog_contains_key, usage_rights, viewer_preference_flag,
+};
+use crate::pdf::document::save_options::PdfSaveOptions;
+use crate::pdf::document::security_policy::{PdfSecurityPolicy, PdfSecurityPolicyViolation};
 use crate::pdf::document::signatures::PdfSignatures;
+use crate::pdf::document::usage_rights::PdfUsageRights;
+use crate::pdf::font::substitution::{font_file_names, is_font_available};
+use crate::pdfium::config::active_config;
+use crate::pdfium::Pdfium;
 use crate::utils::files::get_pdfium_file_writer_from_writer;
 use crate::utils::files::FpdfFileAccessExt;
+use once_cell::sync::OnceCell;
 use std::fmt::{Debug, Formatter};
 use std::io::Cursor;
 use std::io::Write;
+use std::os::raw::c_uint;
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs::File;
 
+#[cfg(feature = "pdfium_enable_xfa")]
+use crate::utils::mem::create_byte_buffer;
+
+#[cfg(feature = "pdfium_enable_xfa")]
+use std::os::raw::{c_ulong, c_void};
+
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::Path;
 
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+use {
+    crate::pdf::document::page::object::image::PdfPageImageObject,
+    crate::pdf::document::page::render_config::PdfRenderConfig,
+    crate::pdf::document::page::size::PdfPagePaperSize,
+    crate::pdf::document::page::PdfPageRenderRotation, crate::pdf::document::raw::image_streams,
+    crate::utils::files::get_pdfium_document_bytes,
+};
+
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Uint8Array};
 
@@ -54,6 +94,15 @@ use web_sys::Blob;
 #[cfg(doc)]
 struct Blob;
 
+#[cfg(doc)]
+use crate::pdf::document::metadata::PdfDocumentMetadataTagType;
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+#[cfg(doc)]
+use crate::pdfium::config::PdfiumConfig;
+
 /// The file version of a [PdfDocument].
 ///
 /// A list of PDF file versions is available at <https://en.wikipedia.org/wiki/History_of_PDF>.
@@ -165,6 +214,8 @@ pub struct PdfDocument<'a> {
     signatures: PdfSignatures<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
     source_byte_buffer: Option<Vec<u8>>,
+    display_doc_title: Option<bool>,
+    is_portfolio: OnceCell<bool>,
 
     #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
     // This field is never used when compiling to WASM.
@@ -195,6 +246,8 @@ impl<'a> PdfDocument<'a> {
             signatures: PdfSignatures::from_pdfium(handle, bindings),
             bindings,
             source_byte_buffer: None,
+            display_doc_title: None,
+            is_portfolio: OnceCell::new(),
             file_access_reader: None,
         }
     }
@@ -243,6 +296,77 @@ impl<'a> PdfDocument<'a> {
         self.output_version = Some(version);
     }
 
+    /// Returns `true` if a PDF viewer should display the document title, taken from the
+    /// [PdfDocumentMetadataTagType::Title] metadata tag, in the title bar of its window,
+    /// rather than the name of the file containing the document. This is the value of the
+    /// `/DisplayDocTitle` entry in the document's viewer preferences dictionary, and
+    /// defaults to `false` when the entry is absent.
+    ///
+    /// Accessibility standards such as PDF/UA require this preference to be set.
+    ///
+    /// Pdfium does not provide access to boolean viewer preferences, so unless the preference has
+    /// been changed using [PdfDocument::set_display_doc_title()], this function serializes the
+    /// document in memory and inspects the serialized catalog. Returns `false` if the document
+    /// could not be serialized, or if the catalog could not be located in the serialized data;
+    /// the inspection cannot see objects stored in compressed object streams.
+    pub fn display_doc_title(&self) -> bool {
+        match self.display_doc_title {
+            Some(display) => display,
+            None => self
+                .save_to_bytes()
+                .ok()
+                .and_then(|bytes| viewer_preference_flag(bytes.as_slice(), b"/DisplayDocTitle"))
+                .unwrap_or(false),
+        }
+    }
+
+    /// Sets whether a PDF viewer should display the document title in the title bar of its
+    /// window, rather than the name of the file containing the document. The change will be
+    /// written to the document's viewer preferences dictionary the next time this [PdfDocument]
+    /// is saved.
+    #[inline]
+    pub fn set_display_doc_title(&mut self, display: bool) {
+        self.display_doc_title = Some(display);
+    }
+
+    /// Returns the byte offsets of the end of each trailer in the file data from which
+    /// this [PdfDocument] was loaded, in ascending order.
+    ///
+    /// Each incremental update appended to a document, for instance when a document is
+    /// signed or when form data is saved, adds a new trailer, so the number of offsets
+    /// returned is the number of revisions in the document's history. The data up to each
+    /// offset is the complete document as it existed at that revision. An empty list is returned
+    /// for documents created in memory rather than loaded from file data.
+    pub fn revision_boundaries(&self) -> Vec<usize> {
+        // Retrieving the trailer ends from Pdfium is a two-step operation. First, we call
+        // FPDF_GetTrailerEnds() with a null buffer; this will retrieve the number of
+        // trailer ends. If the number is zero, then the document has no trailers.
+
+        // If the number is non-zero, then we reserve a buffer of the given length and call
+        // FPDF_GetTrailerEnds() again with a pointer to the buffer; this will write the
+        // trailer end offsets to the buffer.
+
+        let length = self
+            .bindings
+            .FPDF_GetTrailerEnds(self.handle, std::ptr::null_mut(), 0);
+
+        if length == 0 {
+            return Vec::new();
+        }
+
+        let mut buffer: Vec<c_uint> = vec![0; length as usize];
+
+        let result = self
+            .bindings
+            .FPDF_GetTrailerEnds(self.handle, buffer.as_mut_ptr(), length);
+
+        if result == length {
+            buffer.into_iter().map(|offset| offset as usize).collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Returns an immutable collection of all the [PdfAttachments] embedded in this [PdfDocument].
     #[inline]
     pub fn attachments(&self) -> &PdfAttachments {
@@ -255,18 +379,175 @@ impl<'a> PdfDocument<'a> {
         &mut self.attachments
     }
 
+    /// Returns `true` if this [PdfDocument] is a portable collection, also known as a
+    /// PDF portfolio or PDF package, i.e. if its document catalog contains a `/Collection`
+    /// dictionary. A portfolio packages multiple embedded files together; a conforming
+    /// PDF viewer will display the embedded files in place of, or alongside, the document's
+    /// own pages.
+    ///
+    /// Pdfium does not provide direct access to the document catalog, so the first call to
+    /// this function serializes the document in memory and inspects the serialized catalog.
+    /// This may be slow for large documents. Pdfium cannot add or remove a `/Collection`
+    /// dictionary, so the result is cached and later calls, including those made by
+    /// [PdfDocument::collection_items()], return immediately.
+    /// Returns `false` if the document could not be serialized, or if the catalog could not
+    /// be located in the serialized data; the inspection cannot see objects stored in
+    /// compressed object streams.
+    pub fn is_portfolio(&self) -> bool {
+        if let Some(is_portfolio) = self.is_portfolio.get() {
+            return *is_portfolio;
+        }
+
+        match self
+            .save_to_bytes()
+            .ok()
+            .and_then(|bytes| catalog_contains_key(bytes.as_slice(), b"/Collection"))
+        {
+            Some(is_portfolio) => *self.is_portfolio.get_or_init(|| is_portfolio),
+            None => false,
+        }
+    }
+
+    /// Returns the embedded files packaged in this [PdfDocument] if it is a portfolio,
+    /// or an empty list if it is not. See [PdfDocument::is_portfolio()] for details of how
+    /// portfolios are detected.
+    ///
+    /// Pdfium does not expose the collection schema or the per-file collection item
+    /// dictionaries that hold a portfolio's custom display fields, so only the name and
+    /// content of each embedded file are available via the returned [PdfAttachment] objects.
+    pub fn collection_items(&self) -> Vec<PdfAttachment<'_>> {
+        if self.is_portfolio() {
+            self.attachments().iter().collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Returns an immutable collection of all the [PdfBookmarks] in this [PdfDocument].
     #[inline]
     pub fn bookmarks(&self) -> &PdfBookmarks {
         &self.bookmarks
     }
 
+    /// Returns a mutable collection of all the [PdfBookmarks] in this [PdfDocument].
+    #[inline]
+    pub fn bookmarks_mut(&mut self) -> &mut PdfBookmarks<'a> {
+        &mut self.bookmarks
+    }
+
+    /// Compares this [PdfDocument] with the given other version of the document, returning
+    /// the pages that were added, removed, moved, or changed, together with the differences
+    /// in the text of each changed page. This [PdfDocument] is treated as the old version.
+    ///
+    /// See the [PdfDocumentDiff] documentation for details of how pages are matched.
+    #[inline]
+    pub fn diff(&self, other: &PdfDocument) -> Result<PdfDocumentDiff, PdfiumError> {
+        PdfDocumentDiff::from_documents(self, other)
+    }
+
     /// Returns an immutable reference to the [PdfForm] embedded in this [PdfDocument], if any.
     #[inline]
     pub fn form(&self) -> Option<&PdfForm> {
         self.form.as_ref()
     }
 
+    /// Returns the name and raw XML content of each packet in this [PdfDocument]'s
+    /// XFA form, if any. Typical packet names include `template`, `datasets`, and `config`;
+    /// the `datasets` packet contains any user-supplied field data. A document whose XFA
+    /// entry is a single stream rather than an array of packets returns a single packet
+    /// with an empty name.
+    ///
+    /// The underlying Pdfium functions are only bound when the `pdfium_enable_xfa` crate
+    /// feature is enabled. An empty list is always returned if the feature is not enabled
+    /// or if the document does not contain an XFA form. Use [PdfForm::form_type()] to
+    /// determine whether a document's form is XFA-based.
+    pub fn xfa_packets(&self) -> Vec<(String, Vec<u8>)> {
+        #[cfg(feature = "pdfium_enable_xfa")]
+        {
+            let count = self.bindings.FPDF_GetXFAPacketCount(self.handle);
+
+            (0..count.max(0))
+                .filter_map(|index| {
+                    // Retrieving a packet name is a two-step operation. First, we call
+                    // FPDF_GetXFAPacketName() with a null buffer; this will retrieve the length
+                    // of the name in bytes, including the trailing NUL. If the length is zero,
+                    // then the packet is invalid.
+
+                    // If the length is non-zero, then we create a buffer of that length and
+                    // call FPDF_GetXFAPacketName() again with a pointer to the buffer.
+
+                    let name_length = self.bindings.FPDF_GetXFAPacketName(
+                        self.handle,
+                        index,
+                        std::ptr::null_mut(),
+                        0,
+                    );
+
+                    if name_length == 0 {
+                        return None;
+                    }
+
+                    let mut name_buffer = create_byte_buffer(name_length as usize);
+
+                    self.bindings.FPDF_GetXFAPacketName(
+                        self.handle,
+                        index,
+                        name_buffer.as_mut_ptr() as *mut c_void,
+                        name_length,
+                    );
+
+                    let name = String::from_utf8_lossy(&name_buffer)
+                        .trim_end_matches(char::from(0))
+                        .to_string();
+
+                    // Packet content is retrieved the same way.
+
+                    let mut content_length: c_ulong = 0;
+
+                    if !self
+                        .bindings
+                        .is_true(self.bindings.FPDF_GetXFAPacketContent(
+                            self.handle,
+                            index,
+                            std::ptr::null_mut(),
+                            0,
+                            &mut content_length,
+                        ))
+                    {
+                        return None;
+                    }
+
+                    let mut content = create_byte_buffer(content_length as usize);
+
+                    let mut required_length: c_ulong = 0;
+
+                    if self
+                        .bindings
+                        .is_true(self.bindings.FPDF_GetXFAPacketContent(
+                            self.handle,
+                            index,
+                            content.as_mut_ptr() as *mut c_void,
+                            content_length,
+                            &mut required_length,
+                        ))
+                        && required_length <= content_length
+                    {
+                        content.truncate(required_length as usize);
+
+                        Some((name, content))
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        }
+
+        #[cfg(not(feature = "pdfium_enable_xfa"))]
+        {
+            Vec::new()
+        }
+    }
+
     /// Returns an immutable collection of all the [PdfFonts] in this [PdfDocument].
     #[inline]
     pub fn fonts(&self) -> &PdfFonts {
@@ -279,12 +560,66 @@ impl<'a> PdfDocument<'a> {
         &mut self.fonts
     }
 
+    /// Returns the names of the fonts used by text in this [PdfDocument] that are neither
+    /// embedded in the document nor available from the font handling options given to
+    /// [Pdfium::new_with_config()]. Pdfium renders text in these fonts using whichever
+    /// installed system font most closely matches, or a built-in fallback font if there is
+    /// none, so the rendered text may not match the original appearance.
+    ///
+    /// A non-embedded font is considered available if it is one of the 14 standard PDF fonts,
+    /// which Pdfium always provides; if it matches a font substitution added using
+    /// [PdfiumConfig::add_font_substitution()]; or if a font file whose file name matches the
+    /// font name is located in one of the directories set using [PdfiumConfig::set_user_font_paths()].
+    /// Font files are matched by file name only, using the same rules as font substitutions.
+    ///
+    /// Names are returned in the order in which the fonts are first used, without duplicates.
+    pub fn missing_fonts(&self) -> Vec<String> {
+        let mut names = Vec::new();
+
+        for page in self.pages().iter() {
+            for object in page.objects().iter() {
+                collect_non_embedded_font_names(&object, &mut names);
+            }
+        }
+
+        if names.is_empty() {
+            return names;
+        }
+
+        let config = active_config().unwrap_or_default();
+
+        let font_file_names = font_file_names(config.user_font_paths());
+
+        names
+            .into_iter()
+            .filter(|name| !is_font_available(name, &config, &font_file_names))
+            .collect()
+    }
+
+    /// Returns the settings of the `/MarkInfo` dictionary of this [PdfDocument], indicating
+    /// whether or not this [PdfDocument] is a Tagged PDF.
+    ///
+    /// Some of these settings are not exposed by Pdfium, so the returned [PdfMarkInfo]
+    /// serializes the document in memory to read them the first time they are retrieved.
+    /// This may be slow for large documents. Checking [PdfMarkInfo::is_marked()] alone
+    /// does not serialize the document.
+    #[inline]
+    pub fn mark_info(&self) -> PdfMarkInfo<'a> {
+        PdfMarkInfo::from_pdfium(self.handle, self.bindings)
+    }
+
     /// Returns an immutable collection of all the [PdfMetadata] tags in this [PdfDocument].
     #[inline]
     pub fn metadata(&self) -> &PdfMetadata {
         &self.metadata
     }
 
+    /// Returns a mutable collection of all the [PdfMetadata] tags in this [PdfDocument].
+    #[inline]
+    pub fn metadata_mut(&mut self) -> &mut PdfMetadata<'a> {
+        &mut self.metadata
+    }
+
     /// Returns an immutable collection of all the [PdfPages] in this [PdfDocument].
     #[inline]
     pub fn pages(&self) -> &PdfPages<'a> {
@@ -297,30 +632,354 @@ impl<'a> PdfDocument<'a> {
         &mut self.pages
     }
 
+    /// Copies the pages with the given indices from the given source [PdfDocument], appending
+    /// them sequentially to the end of this [PdfDocument]. An empty list of indices copies
+    /// every page in the source document.
+    ///
+    /// This is a convenience function that calls [PdfPages::import_from()]; use that function
+    /// directly to insert the copied pages elsewhere in this document.
+    #[inline]
+    pub fn merge_pages_from(
+        &mut self,
+        source: &PdfDocument,
+        source_page_indices: &[PdfPageIndex],
+    ) -> Result<(), PdfiumError> {
+        let destination_page_index = self.pages().len();
+
+        self.pages_mut()
+            .import_from(source, source_page_indices, destination_page_index)
+    }
+
+    /// Returns the index of the first page in this [PdfDocument] whose page label matches
+    /// the given label, if any.
+    ///
+    /// Page labels are the page numbers shown to a reader, such as "iv" or "12", and often differ
+    /// from zero-based page indices, particularly in documents with roman-numeral front matter.
+    /// If more than one page has the same label, the index of the first such page is returned.
+    pub fn page_index_for_label(&self, label: &str) -> Option<PdfPageIndex> {
+        self.pages()
+            .as_range()
+            .find(|index| self.pages().label(*index).as_deref() == Some(label))
+    }
+
+    /// Resamples every image object in this [PdfDocument] whose effective resolution on its page
+    /// exceeds the given resolution in dots per inch, re-embedding each resampled image
+    /// as a JPEG image with the given quality, from 1 (smallest) to 100 (best).
+    /// This can substantially reduce the size of scanned documents.
+    ///
+    /// Only image objects placed directly on each page are considered; images nested
+    /// inside form XObjects are not resampled. Images that are drawn through a mask, or that
+    /// use a color space other than `DeviceRGB` or `DeviceGray`, are also left untouched;
+    /// see [PdfPageImageObject::downsample_to_dpi()] for more information. Returns the number
+    /// of images that were resampled.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    /// It is not available when compiling to WASM.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn optimize_images(
+        &mut self,
+        target_dpi: f32,
+        jpeg_quality: u8,
+    ) -> Result<usize, PdfiumError> {
+        let mut count = 0;
+
+        // Serialize the document once to determine which images are masked.
+
+        let bytes = get_pdfium_document_bytes(self.handle, self.bindings).unwrap_or_default();
+
+        let images = image_streams(bytes.as_slice());
+
+        for index in self.pages().as_range() {
+            let mut page = self.pages().get(index)?;
+
+            let mut is_page_changed = false;
+
+            for object_index in 0..page.objects().len() {
+                let mut object = page.objects().get(object_index)?;
+
+                if let Some(image) = object.as_image_object_mut() {
+                    if image.downsample_to_dpi_with_image_streams(
+                        target_dpi,
+                        jpeg_quality,
+                        images.as_slice(),
+                    )? {
+                        count += 1;
+
+                        is_page_changed = true;
+                    }
+                }
+            }
+
+            if is_page_changed {
+                page.regenerate_content()?;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Replaces the transparent content on every page in this [PdfDocument] with opaque images
+    /// rendered at the given resolution in dots per inch, producing a document that prints
+    /// consistently on output devices that handle transparency poorly. Opaque vector content
+    /// outside the transparent regions is left untouched. Returns the number of pages
+    /// that were flattened.
+    ///
+    /// See [PdfPage::flatten_transparency()] for more information.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn flatten_transparency(&mut self, dpi: f32) -> Result<usize, PdfiumError> {
+        let mut count = 0;
+
+        for index in self.pages().as_range() {
+            let mut page = self.pages().get(index)?;
+
+            if page.flatten_transparency(dpi)? {
+                page.regenerate_content()?;
+
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Creates a new image-only [PdfDocument] in which every page of this [PdfDocument] is
+    /// replaced by a single image of that page, rendered at the given resolution in dots per inch
+    /// and embedded as a JPEG image with the given quality, from 1 (smallest) to 100 (best).
+    /// The new document is created using the given [Pdfium] instance.
+    ///
+    /// Each page in the new document has the same media box, crop box, and rotation as the
+    /// corresponding page in this document, so it renders the same way, but the new document
+    /// contains no text, vector graphics, annotations, or form fields. This prevents text from
+    /// being copied or extracted, and ensures the document renders consistently in every viewer.
+    /// Document-level data such as metadata, bookmarks, and attachments are not copied.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    /// It is not available when compiling to WASM.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn rasterize_to_image_pdf<'b>(
+        &self,
+        pdfium: &'b Pdfium,
+        dpi: f32,
+        jpeg_quality: u8,
+    ) -> Result<PdfDocument<'b>, PdfiumError> {
+        let mut rasterized = pdfium.create_new_pdf()?;
+
+        let config = PdfRenderConfig::new().set_target_dpi(dpi);
+
+        for page in self.pages().iter() {
+            let media = page
+                .boundaries()
+                .media()
+                .map(|boundary| boundary.bounds)
+                .unwrap_or_else(|_| page.page_size());
+
+            let visible = page
+                .boundaries()
+                .crop()
+                .map(|boundary| boundary.bounds)
+                .unwrap_or(media);
+
+            let rotation = page.rotation()?;
+
+            // Pdfium renders the visible area of the page with the page's intrinsic rotation
+            // applied. Undo the rotation, so that the image can be placed in the unrotated
+            // coordinate space of the new page; the new page is then given the same rotation.
+
+            let image = page.render_with_config(&config)?.as_image();
+
+            let image = match rotation {
+                PdfPageRenderRotation::None => image,
+                PdfPageRenderRotation::Degrees90 => image.rotate270(),
+                PdfPageRenderRotation::Degrees180 => image.rotate180(),
+                PdfPageRenderRotation::Degrees270 => image.rotate90(),
+            };
+
+            let mut object =
+                PdfPageImageObject::new_from_handle(rasterized.handle(), rasterized.bindings())?;
+
+            object.set_image_as_jpeg(&image, jpeg_quality)?;
+
+            object.scale(visible.width().value, visible.height().value)?;
+
+            object.translate(visible.left(), visible.bottom())?;
+
+            let mut rasterized_page = rasterized
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::from_points(media.width(), media.height()))?;
+
+            rasterized_page.boundaries_mut().set_media(media)?;
+
+            if visible != media {
+                rasterized_page.boundaries_mut().set_crop(visible)?;
+            }
+
+            rasterized_page.set_rotation(rotation);
+
+            rasterized_page.objects_mut().add_image_object(object)?;
+        }
+
+        Ok(rasterized)
+    }
+
+    /// Returns all the text in this [PdfDocument], in logical reading order, with the text
+    /// of each page separated by a line break.
+    ///
+    /// For tagged PDF documents, the reading order of each page is taken from the document's
+    /// structure tree; otherwise, Pdfium's layout analysis is used.
+    /// See [PdfPage::text_in_reading_order()] for more information.
+    pub fn text_in_reading_order(&self) -> Result<String, PdfiumError> {
+        self.pages()
+            .iter()
+            .map(|page| page.text_in_reading_order())
+            .collect::<Result<Vec<_>, _>>()
+            .map(|pages| pages.join("\n"))
+    }
+
     /// Returns an immutable collection of all the [PdfPermissions] applied to this [PdfDocument].
     #[inline]
     pub fn permissions(&self) -> &PdfPermissions {
         &self.permissions
     }
 
+    /// Returns every way in which this [PdfDocument] breaks the given [PdfSecurityPolicy].
+    /// An empty list indicates that this document complies with the policy.
+    #[inline]
+    pub fn security_policy_violations(
+        &self,
+        policy: &PdfSecurityPolicy,
+    ) -> Vec<PdfSecurityPolicyViolation> {
+        policy.violations(self)
+    }
+
+    /// Returns every key requirement of the PDF/A archival standard that this [PdfDocument]
+    /// fails. An empty list indicates that the document passes all the checks.
+    ///
+    /// The document is checked for non-embedded fonts, encryption, JavaScript, a missing
+    /// output intent, and missing XMP metadata or PDF/A identification. This is a lightweight
+    /// preflight check that catches common causes of PDF/A validation failures; it is not
+    /// a substitute for a full PDF/A validator. See [PdfAIssue] for details of each check.
+    ///
+    /// Pdfium does not provide access to output intents or XMP metadata, so this function
+    /// serializes the document in memory and inspects the serialized catalog. This may be
+    /// slow for large documents. The inspection cannot see objects stored in compressed
+    /// object streams, so a catalog, output intent, or metadata stream stored in one is
+    /// reported as missing. Returns an error if the document could not be serialized.
+    ///
+    /// Color is only checked for the presence of an output intent; the color spaces used
+    /// by the document's content are not inspected. See [PdfAIssue::MissingOutputIntent].
+    #[inline]
+    pub fn pdf_a_issues(&self) -> Result<Vec<PdfAIssue>, PdfiumError> {
+        pdf_a_issues(self)
+    }
+
     /// Returns an immutable collection of all the [PdfSignatures] attached to this [PdfDocument].
     #[inline]
     pub fn signatures(&self) -> &PdfSignatures {
         &self.signatures
     }
 
+    /// Returns the additional rights granted to users of Adobe Reader by the usage rights
+    /// signature in the document catalog's `/Perms` dictionary, if this [PdfDocument] is
+    /// a Reader-enabled document. Returns `None` if the document carries no usage rights
+    /// signature, or if the document could not be serialized.
+    ///
+    /// A usage rights signature stored in a compressed object stream cannot be located,
+    /// and is reported as absent.
+    ///
+    /// Pdfium does not provide access to the `/Perms` dictionary, and does not include usage
+    /// rights signatures in [PdfDocument::signatures()], so this function serializes the
+    /// document in memory and inspects the serialized catalog. This may be slow for
+    /// large documents.
+    pub fn usage_rights(&self) -> Option<PdfUsageRights> {
+        usage_rights(self.save_to_bytes().ok()?.as_slice()).map(|rights| {
+            PdfUsageRights::new(
+                rights.name,
+                rights.message,
+                rights.document,
+                rights.form,
+                rights.annotations,
+                rights.signature,
+                rights.embedded_files,
+                rights.is_restrictive,
+            )
+        })
+    }
+
     /// Writes this [PdfDocument] to the given writer.
+    ///
+    /// If any metadata tags have been changed using the [PdfMetadata::set()] function, or any
+    /// bookmarks have been queued using the [PdfBookmarks::add_on_save()] function, the document
+    /// is first saved to memory so the updated information dictionary and outline can be
+    /// appended before the document data is passed to the writer. The update can only be
+    /// appended to a document whose last cross-reference section is a classic cross-reference
+    /// table; otherwise, an error is returned and nothing is written.
+    ///
+    /// Pdfium does not support linearized ("fast web view") output, so the saved document
+    /// is never linearized, even if it was linearized when it was loaded. Use the
+    /// [Pdfium::is_linearized()] function to check whether existing document data is linearized.
     pub fn save_to_writer<W: Write + 'static>(&self, writer: &mut W) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL,
-        // and FPDF_REMOVE_SECURITY flags defined in fpdf_save.h. There's not a lot of information
-        // on what they actually do, however.
-        // Some small info at https://forum.patagames.com/posts/t155-PDF-SaveFlags.
+        self.save_to_writer_with_options(writer, &PdfSaveOptions::default())
+    }
+
+    /// Writes this [PdfDocument] to the given writer, using the given [PdfSaveOptions].
+    ///
+    /// Apart from the handling of the given options, this function behaves identically
+    /// to [PdfDocument::save_to_writer()].
+    pub fn save_to_writer_with_options<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        if self.metadata.is_modified()
+            || self.bookmarks.is_modified()
+            || self.pages.is_modified()
+            || self.display_doc_title.is_some()
+        {
+            let mut cursor = Cursor::new(Vec::new());
+
+            self.save_to_writer_with_pdfium(&mut cursor, options)?;
+
+            let mut bytes = cursor.into_inner();
+
+            if self.bookmarks.is_modified() {
+                bytes = self.bookmarks.apply_to_saved_bytes(bytes)?;
+            }
 
-        let flags = 0;
+            if self.pages.is_modified() {
+                bytes = self.pages.apply_to_saved_bytes(bytes)?;
+            }
+
+            if let Some(display) = self.display_doc_title {
+                bytes = append_viewer_preference_flag(bytes.as_slice(), "DisplayDocTitle", display)
+                    .ok_or(PdfiumError::ViewerPreferencesNotWritable)?;
+            }
+
+            if self.metadata.is_modified() {
+                bytes = self.metadata.apply_to_saved_bytes(bytes)?;
+            }
+
+            writer
+                .write_all(bytes.as_slice())
+                .and_then(|_| writer.flush())
+                .map_err(PdfiumError::IoError)
+        } else {
+            self.save_to_writer_with_pdfium(writer, options)
+        }
+    }
+
+    /// Writes this [PdfDocument] to the given writer using Pdfium's own save functions.
+    fn save_to_writer_with_pdfium<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        let flags = options.as_pdfium_flags();
 
         let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(writer);
 
-        let result = match self.output_version {
+        let result = match options.version().or(self.output_version) {
             Some(version) => self.bindings.FPDF_SaveWithVersion(
                 self.handle,
                 pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
@@ -367,13 +1026,80 @@ impl<'a> PdfDocument<'a> {
 
     /// Writes this [PdfDocument] to a new byte buffer, returning the byte buffer.
     pub fn save_to_bytes(&self) -> Result<Vec<u8>, PdfiumError> {
+        self.save_to_bytes_with_options(&PdfSaveOptions::default())
+    }
+
+    /// Writes this [PdfDocument] to a new byte buffer using the given file version,
+    /// returning the byte buffer.
+    ///
+    /// This is a convenience function equivalent to calling
+    /// [PdfDocument::save_to_bytes_with_options()] with [PdfSaveOptions::set_version()].
+    #[inline]
+    pub fn save_to_bytes_with_version(
+        &self,
+        version: PdfDocumentVersion,
+    ) -> Result<Vec<u8>, PdfiumError> {
+        self.save_to_bytes_with_options(&PdfSaveOptions::new().set_version(version))
+    }
+
+    /// Writes this [PdfDocument] to a new byte buffer using the given [PdfSaveOptions],
+    /// returning the byte buffer.
+    pub fn save_to_bytes_with_options(
+        &self,
+        options: &PdfSaveOptions,
+    ) -> Result<Vec<u8>, PdfiumError> {
         let mut cursor = Cursor::new(Vec::new());
 
-        self.save_to_writer(&mut cursor)?;
+        self.save_to_writer_with_options(&mut cursor, options)?;
 
         Ok(cursor.into_inner())
     }
 
+    /// Creates an independent in-memory copy of this [PdfDocument], including all pages,
+    /// metadata, bookmarks, attachments, and viewer preferences. Changes made to the copy
+    /// will not affect this document, and vice versa.
+    ///
+    /// Pages are imported into a new document in a single operation, without serializing
+    /// this document. Pdfium provides no means of writing metadata and bookmarks directly,
+    /// so these are queued on the copy in the same way as [PdfMetadata::set()] and
+    /// [PdfBookmarks::add_on_save()]: metadata values are immediately visible through
+    /// [PdfDocument::metadata()], but copied bookmarks are only written to the copy's outline
+    /// when the copy is saved. Bookmarks that do not target a page in this document are
+    /// not copied.
+    ///
+    /// Other document-level data, such as the interactive form, named destinations, and
+    /// document-level JavaScript, is not copied. To copy a document in its entirety,
+    /// save it using [PdfDocument::save_to_bytes()] and load the copy from the saved bytes.
+    pub fn duplicate(&self) -> Result<PdfDocument<'a>, PdfiumError> {
+        let mut copy = Pdfium::pdfium_document_handle_to_result(
+            self.bindings.FPDF_CreateNewDocument(),
+            self.bindings,
+        )?;
+
+        copy.pages_mut().import_from(self, &[], 0)?;
+
+        self.bindings
+            .FPDF_CopyViewerPreferences(copy.handle(), self.handle());
+
+        copy.set_version(match self.version() {
+            PdfDocumentVersion::Unset => PdfDocumentVersion::DEFAULT_VERSION,
+            version => version,
+        });
+
+        for tag in self.metadata().iter() {
+            copy.metadata_mut().set(tag.tag_type(), tag.value());
+        }
+
+        for attachment in self.attachments().iter() {
+            copy.attachments_mut()
+                .create_attachment_from_bytes(&attachment.name(), &attachment.save_to_bytes()?)?;
+        }
+
+        copy.bookmarks_mut().add_on_save_from(self.bookmarks())?;
+
+        Ok(copy)
+    }
+
     /// Writes this [PdfDocument] to a new `Blob`, returning the `Blob`.
     ///
     /// This function is only available when compiling to WASM.
@@ -416,8 +1142,665 @@ impl<'a> Debug for PdfDocument<'a> {
     }
 }
 
+/// Appends the name of the font used by the given object to the given list of names,
+/// if the object is a text object using a font that is not embedded in the document.
+/// Objects inside form XObjects are included.
+pub(crate) fn collect_non_embedded_font_names(object: &PdfPageObject, names: &mut Vec<String>) {
+    match object {
+        PdfPageObject::Text(text) => {
+            let font = text.font();
+
+            if matches!(font.is_embedded(), Ok(false)) {
+                let name = font.base_name();
+
+                if !name.is_empty() && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        PdfPageObject::XObjectForm(form) => {
+            for index in 0..form.len() {
+                if let Ok(object) = form.get(index) {
+                    collect_non_embedded_font_names(&object, names);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 #[cfg(feature = "sync")]
 unsafe impl<'a> Sync for PdfDocument<'a> {}
 
 #[cfg(feature = "sync")]
 unsafe impl<'a> Send for PdfDocument<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdf::document::raw::append_information_dictionary;
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+    use image_025::{DynamicImage, Rgb, RgbImage};
+
+    #[test]
+    fn test_xfa_packets() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /AcroForm 4 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+            b"<< /Fields [] /XFA [(template) 5 0 R (datasets) 6 0 R] >>",
+            b"<< >>\nstream\n\
+            <template xmlns=\"http://www.xfa.org/schema\"/>",
+            b"<< >>\nstream\n\
+            <xfa:datasets><xfa:data><name>Jane Citizen</name></xfa:data></xfa:datasets>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let packets = document.xfa_packets();
+
+        #[cfg(feature = "pdfium_enable_xfa")]
+        {
+            let names = packets
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+
+            assert_eq!(names, vec!["template", "datasets"]);
+
+            let datasets = String::from_utf8_lossy(&packets[1].1);
+
+            assert!(datasets.contains("<name>Jane Citizen</name>"));
+        }
+
+        #[cfg(not(feature = "pdfium_enable_xfa"))]
+        assert!(packets.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_page_index_for_label() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Two pages of roman-numeral front matter, followed by two arabic-numbered pages,
+        // followed by a final page whose arabic numbering restarts at 1.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /PageLabels << /Nums [0 << /S /r >> 2 << /S \
+            /D >> 4 << /S /D >>] >> >>",
+            b"<< /Type /Pages /Kids [3 0 R 4 0 R 5 0 R 6 0 R 7 0 R] /Count 5 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(document.page_index_for_label("i"), Some(0));
+        assert_eq!(document.page_index_for_label("ii"), Some(1));
+        assert_eq!(document.page_index_for_label("2"), Some(3));
+
+        // Page 4 is also labelled "1"; the first match must be returned.
+
+        assert_eq!(document.page_index_for_label("1"), Some(2));
+
+        assert_eq!(document.page_index_for_label("iii"), None);
+        assert_eq!(document.page_index_for_label("12"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_images_skips_masked_images() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Two 4 x 4 pixel images, each placed into a 72 x 72 point (1 inch) square, giving
+        // an effective resolution of 4 DPI. Only the first image has a soft mask.
+
+        let masked = [
+            b"<< /Type /XObject /Subtype /Image /Width 4 /Height 4 /ColorSpace /DeviceRGB \
+            /BitsPerComponent 8 /SMask 7 0 R >>\nstream\n"
+                .to_vec(),
+            vec![0x40; 48],
+        ]
+        .concat();
+
+        let unmasked = [
+            b"<< /Type /XObject /Subtype /Image /Width 4 /Height 4 /ColorSpace /DeviceRGB \
+            /BitsPerComponent 8 >>\nstream\n"
+                .to_vec(),
+            vec![0x80; 48],
+        ]
+        .concat();
+
+        let mask = [
+            b"<< /Type /XObject /Subtype /Image /Width 4 /Height 4 /ColorSpace /DeviceGray \
+            /BitsPerComponent 8 >>\nstream\n"
+                .to_vec(),
+            vec![0xC0; 16],
+        ]
+        .concat();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 144 144] /Contents 4 0 R \
+            /Resources << /XObject << /Im1 5 0 R /Im2 6 0 R >> >> >>",
+            b"<< >>\nstream\nq 72 0 0 72 0 0 cm /Im1 Do Q q 72 0 0 72 72 72 cm /Im2 Do Q",
+            masked.as_slice(),
+            unmasked.as_slice(),
+            mask.as_slice(),
+        ]);
+
+        let mut document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(document.optimize_images(2.0, 75)?, 1);
+
+        let page = document.pages().first()?;
+
+        let masked = page.objects().get(0)?;
+
+        assert!(masked.as_image_object().unwrap().filter_names().is_empty());
+
+        let unmasked = page.objects().get(1)?;
+
+        assert_eq!(
+            unmasked.as_image_object().unwrap().filter_names(),
+            vec!["DCTDecode"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_images() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // A 1200 x 1200 pixel image, placed into a 144 x 144 point (2 inch) square,
+        // giving an effective resolution of 600 DPI. The left half is red, the right half blue.
+
+        let image = DynamicImage::ImageRgb8(RgbImage::from_fn(1200, 1200, |x, y| {
+            let noise = ((x * 7 + y * 13) % 16) as u8;
+
+            if x < 600 {
+                Rgb([240 - noise, noise, noise])
+            } else {
+                Rgb([noise, noise, 240 - noise])
+            }
+        }));
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(144.0),
+                PdfPoints::new(144.0),
+            ))?;
+
+        page.objects_mut().create_image_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            &image,
+            Some(PdfPoints::new(144.0)),
+            Some(PdfPoints::new(144.0)),
+        )?;
+
+        let original_size = document.save_to_bytes()?.len();
+
+        assert_eq!(document.optimize_images(100.0, 75)?, 1);
+
+        // A second pass finds nothing left to resample.
+
+        assert_eq!(document.optimize_images(100.0, 75)?, 0);
+
+        let bytes = document.save_to_bytes()?;
+
+        assert!(bytes.len() < original_size);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let image_object = page.objects().get(0)?;
+
+        let image_object = image_object.as_image_object().unwrap();
+
+        assert!(image_object.horizontal_dpi()? <= 100.5);
+        assert_eq!(image_object.filter_names(), vec!["DCTDecode"]);
+
+        let rendered = page
+            .render_with_config(&PdfRenderConfig::new().set_target_size(144, 144))?
+            .as_image()
+            .into_rgb8();
+
+        let left = rendered.get_pixel(36, 72);
+
+        let right = rendered.get_pixel(108, 72);
+
+        assert!(left[0] > 180 && left[2] < 80);
+        assert!(right[2] > 180 && right[0] < 80);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_revision_boundaries() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let original = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+        ]);
+
+        // Append two incremental updates, each with its own trailer.
+
+        let first_update = append_information_dictionary(&original, &[("Title", "First")]).unwrap();
+
+        let second_update =
+            append_information_dictionary(&first_update, &[("Title", "Second")]).unwrap();
+
+        let count = |bytes: &[u8]| bytes.windows(5).filter(|window| window == b"%%EOF").count();
+
+        assert_eq!(count(&second_update), count(&original) + 2);
+
+        let length = second_update.len();
+
+        let document = pdfium.load_pdf_from_byte_vec(second_update.clone(), None)?;
+
+        let boundaries = document.revision_boundaries();
+
+        assert_eq!(boundaries.len(), count(&second_update));
+        assert!(boundaries.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(*boundaries.last().unwrap() <= length);
+        assert!(*boundaries.last().unwrap() > first_update.len() - 8);
+
+        assert!(pdfium.create_new_pdf()?.revision_boundaries().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_to_bytes_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/page-sizes-test.pdf", None)?;
+
+        let bytes = document.save_to_bytes()?;
+
+        let reloaded = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        assert_eq!(reloaded.pages().len(), document.pages().len());
+        assert_eq!(
+            reloaded.pages().page_sizes()?,
+            document.pages().page_sizes()?
+        );
+
+        // The requested version should be written to the saved document.
+
+        let bytes = document.save_to_bytes_with_version(PdfDocumentVersion::Pdf1_4)?;
+
+        assert!(bytes.starts_with(b"%PDF-1.4"));
+        assert!(bytes.ends_with(b"%%EOF\r\n") || bytes.ends_with(b"%%EOF\n"));
+
+        let reloaded = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        assert_eq!(reloaded.version(), PdfDocumentVersion::Pdf1_4);
+        assert_eq!(reloaded.pages().len(), document.pages().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/test-toc.pdf", None)?;
+
+        let copy = document.duplicate()?;
+
+        assert_eq!(copy.pages().len(), document.pages().len());
+        assert_eq!(
+            copy.metadata().iter().collect::<Vec<_>>(),
+            document.metadata().iter().collect::<Vec<_>>()
+        );
+
+        // Copied bookmarks are written to the copy's outline when the copy is saved.
+
+        let saved = pdfium.load_pdf_from_byte_vec(copy.save_to_bytes()?, None)?;
+
+        assert_eq!(saved.pages().len(), document.pages().len());
+        assert_eq!(
+            saved
+                .bookmarks()
+                .iter()
+                .map(|bookmark| bookmark.title())
+                .collect::<Vec<_>>(),
+            document
+                .bookmarks()
+                .iter()
+                .map(|bookmark| bookmark.title())
+                .collect::<Vec<_>>()
+        );
+
+        // Destructive edits to the copy should not affect the original.
+
+        copy.pages().first()?.delete()?;
+
+        assert_eq!(copy.pages().len(), document.pages().len() - 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_metadata() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let title = "Quarterly r\u{e9}sum\u{e9} \u{2014} \u{65e5}\u{672c}\u{8a9e}";
+
+        document
+            .metadata_mut()
+            .set(PdfDocumentMetadataTagType::Title, title);
+        document
+            .metadata_mut()
+            .set(PdfDocumentMetadataTagType::Author, "pdfium-render");
+
+        assert_eq!(
+            document
+                .metadata()
+                .get(PdfDocumentMetadataTagType::Title)
+                .unwrap()
+                .value(),
+            title
+        );
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(reloaded.pages().len(), 1);
+        assert_eq!(
+            reloaded
+                .metadata()
+                .get(PdfDocumentMetadataTagType::Title)
+                .unwrap()
+                .value(),
+            title
+        );
+        assert_eq!(
+            reloaded
+                .metadata()
+                .get(PdfDocumentMetadataTagType::Author)
+                .unwrap()
+                .value(),
+            "pdfium-render"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_display_doc_title() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert!(!document.display_doc_title());
+
+        document.set_display_doc_title(true);
+
+        assert!(document.display_doc_title());
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(reloaded.pages().len(), 1);
+        assert!(reloaded.display_doc_title());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_bookmarks() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for _ in 0..3 {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        let bookmarks = document.bookmarks_mut();
+
+        let chapter = bookmarks.add_on_save(
+            "Chapter 1",
+            0,
+            PdfDestinationViewSettings::FitPageToWindow,
+            None,
+        )?;
+
+        bookmarks.add_on_save(
+            "Section 1.1",
+            1,
+            PdfDestinationViewSettings::FitPageHorizontallyToWindow(Some(PdfPoints::new(700.0))),
+            Some(chapter),
+        )?;
+
+        bookmarks.add_on_save(
+            "Chapter 2",
+            2,
+            PdfDestinationViewSettings::FitPageToWindow,
+            None,
+        )?;
+
+        assert!(matches!(
+            bookmarks.add_on_save(
+                "Missing",
+                3,
+                PdfDestinationViewSettings::FitPageToWindow,
+                None
+            ),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        // Pending bookmarks are not visible until the document is saved and loaded again.
+
+        assert!(bookmarks.root().is_none());
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let first = reloaded.bookmarks().root().unwrap();
+
+        assert_eq!(first.title().unwrap(), "Chapter 1");
+        assert_eq!(first.destination().unwrap().page_index()?, 0);
+
+        let child = first.first_child().unwrap();
+
+        assert_eq!(child.title().unwrap(), "Section 1.1");
+        assert_eq!(child.destination().unwrap().page_index()?, 1);
+        assert!(child.next_sibling().is_none());
+
+        let second = first.next_sibling().unwrap();
+
+        assert_eq!(second.title().unwrap(), "Chapter 2");
+        assert_eq!(second.destination().unwrap().page_index()?, 2);
+        assert!(second.next_sibling().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_fonts() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Resources << /Font << \
+            /F1 5 0 R /F2 6 0 R >> >> /Contents 4 0 R >>",
+            b"<< >>\nstream\n\
+            BT /F1 12 Tf 72 720 Td (Missing) Tj ET BT /F2 12 Tf 72 700 Td (Standard) Tj ET",
+            b"<< /Type /Font /Subtype /TrueType /BaseFont /UnavailableGrotesk-Regular \
+            /FirstChar 32 /LastChar 126 /FontDescriptor 7 0 R >>",
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+            b"<< /Type /FontDescriptor /FontName /UnavailableGrotesk-Regular /Flags 32 /FontBBox \
+            [0 0 1000 1000] /ItalicAngle 0 /Ascent 800 /Descent -200 /CapHeight 700 /StemV 80 >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(
+            document.missing_fonts(),
+            vec!["UnavailableGrotesk-Regular".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_usage_rights() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        assert!(pdfium.create_new_pdf()?.usage_rights().is_none());
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /Perms << /UR3 4 0 R >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+            b"<< /Type /Sig /Filter /Adobe.PPKLite /SubFilter /adbe.pkcs7.detached /Name \
+            (ARE Acrobat Product v8.0 P23 0002337) /Reference [<< /Type /SigRef \
+            /TransformMethod /UR3 /TransformParams << /Type /TransformParams /V /2.2 /Document \
+            [/FullSave] /Form [/FillIn/Import/Export/SubmitStandalone] /Annots \
+            [/Create/Delete/Modify] /Signature [/Modify] >> >>] /ByteRange [0 0 0 0] \
+            /Contents <00> >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let rights = document.usage_rights().unwrap();
+
+        assert!(rights.can_fill_in_forms());
+        assert!(rights.can_create_annotations());
+        assert!(rights.can_save());
+        assert_eq!(
+            rights.form_rights(),
+            ["FillIn", "Import", "Export", "SubmitStandalone"]
+        );
+        assert_eq!(
+            rights.signer_name(),
+            Some("ARE Acrobat Product v8.0 P23 0002337")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rasterize_to_image_pdf() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(300.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(20.0, 20.0, 180.0, 120.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(20.0),
+            PdfPoints::new(150.0),
+            "Copy me if you can",
+            font,
+            PdfPoints::new(24.0),
+        )?;
+
+        let mut rotated = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        rotated.set_rotation(PdfPageRenderRotation::Degrees90);
+
+        rotated.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(20.0, 20.0, 200.0, 100.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let rasterized = document.rasterize_to_image_pdf(&pdfium, 144.0, 95)?;
+
+        assert_eq!(rasterized.pages().len(), document.pages().len());
+
+        let config = PdfRenderConfig::new().set_target_dpi(72.0);
+
+        for (original, copy) in document.pages().iter().zip(rasterized.pages().iter()) {
+            assert_eq!(copy.width(), original.width());
+            assert_eq!(copy.height(), original.height());
+            assert_eq!(copy.rotation()?, original.rotation()?);
+
+            assert_eq!(copy.objects().len(), 1);
+            assert!(copy.objects().get(0)?.as_image_object().is_some());
+            assert!(copy.text()?.all().is_empty());
+
+            let (_, difference) = original
+                .render_with_config(&config)?
+                .diff(&copy.render_with_config(&config)?, 48)?;
+
+            assert!(difference < 0.02);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_portfolio() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let portfolio = pdfium.load_pdf_from_byte_vec(
+            test_document(&[
+                b"<< /Type /Catalog /Pages 2 0 R /Collection << /Type /Collection /View /D >> >>",
+                b"<< /Type /Pages /Kids [] /Count 0 >>",
+            ]),
+            None,
+        )?;
+
+        assert!(portfolio.is_portfolio());
+
+        // The second call returns the cached result.
+
+        assert!(portfolio.is_portfolio());
+        assert!(portfolio.collection_items().is_empty());
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert!(!document.is_portfolio());
+        assert!(document.collection_items().is_empty());
+
+        Ok(())
+    }
+}
@@ -1,13 +1,17 @@
 //! Defines the [PdfAttachment] struct, exposing functionality related to a single
 //! attachment in a `PdfAttachments` collection.
 
-use crate::bindgen::{FPDF_ATTACHMENT, FPDF_WCHAR};
+use crate::bindgen::{FPDF_ATTACHMENT, FPDF_DOCUMENT, FPDF_WCHAR};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::document::raw::{embedded_file_entries, RawEmbeddedFileEntry};
+use crate::utils::files::get_pdfium_document_bytes;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::cell::Cell;
 use std::io::Write;
-use std::os::raw::{c_ulong, c_void};
+use std::os::raw::{c_int, c_ulong, c_void};
+use std::sync::{Arc, Mutex};
 
 #[cfg(not(target_arch = "wasm32"))]
 use {std::fs::File, std::path::Path};
@@ -29,9 +33,100 @@ struct Blob;
 #[cfg(doc)]
 use crate::pdf::document::PdfDocument;
 
+/// The relationship between an embedded file and the [PdfDocument] it is attached to,
+/// as given by the `/AFRelationship` entry of the file's file specification dictionary.
+/// This entry is required for documents conforming to PDF/A-3, including electronic invoices
+/// in the Factur-X and ZUGFeRD formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfAttachmentRelationship {
+    /// The embedded file is the original source material for the associated content.
+    Source,
+
+    /// The embedded file represents information used to derive a visual presentation,
+    /// such as a table or a graph.
+    Data,
+
+    /// The embedded file is an alternative representation of content, such as audio.
+    Alternative,
+
+    /// The embedded file represents a supplemental representation of the original source
+    /// or data that may be more easily consumable.
+    Supplement,
+
+    /// The embedded file is an encrypted payload document that should be displayed to the user
+    /// if the PDF processor has the cryptographic filter needed to decrypt the document.
+    EncryptedPayload,
+
+    /// The embedded file contains data associated with an interactive form in this document.
+    FormData,
+
+    /// The embedded file is a schema definition for the associated object.
+    Schema,
+
+    /// The relationship is not known, or cannot be described using any of the other values.
+    /// Unrecognised relationship names are also reported as [PdfAttachmentRelationship::Unspecified].
+    Unspecified,
+}
+
+impl PdfAttachmentRelationship {
+    pub(crate) fn from_name(name: &str) -> Self {
+        match name {
+            "Source" => PdfAttachmentRelationship::Source,
+            "Data" => PdfAttachmentRelationship::Data,
+            "Alternative" => PdfAttachmentRelationship::Alternative,
+            "Supplement" => PdfAttachmentRelationship::Supplement,
+            "EncryptedPayload" => PdfAttachmentRelationship::EncryptedPayload,
+            "FormData" => PdfAttachmentRelationship::FormData,
+            "Schema" => PdfAttachmentRelationship::Schema,
+            _ => PdfAttachmentRelationship::Unspecified,
+        }
+    }
+}
+
+/// The file specification entries that Pdfium does not expose for the attachments in a
+/// [PdfDocument], held by a `PdfAttachments` collection and shared with every [PdfAttachment]
+/// retrieved from it, so that the entries of all attachments are read from a single
+/// serialization of the document.
+#[derive(Default)]
+pub(crate) struct PdfAttachmentEntries {
+    entries: Option<Vec<RawEmbeddedFileEntry>>,
+}
+
+impl PdfAttachmentEntries {
+    /// Returns the entries of the file specification at the given index in the `/EmbeddedFiles`
+    /// name tree of the given document, reading the entries of every file specification if
+    /// they have not already been read or if the number of attachments has changed.
+    fn get(
+        &mut self,
+        document_handle: FPDF_DOCUMENT,
+        index: usize,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Option<RawEmbeddedFileEntry> {
+        let count = bindings.FPDFDoc_GetAttachmentCount(document_handle).max(0) as usize;
+
+        if self.entries.as_ref().map(|entries| entries.len()) != Some(count) {
+            self.entries = get_pdfium_document_bytes(document_handle, bindings)
+                .and_then(|bytes| embedded_file_entries(bytes.as_slice()))
+                .filter(|entries| entries.len() == count);
+        }
+
+        self.entries.as_ref()?.get(index).cloned()
+    }
+
+    /// Discards the entries read from the document, so that they are read again the next
+    /// time they are needed. Called whenever an attachment is added or deleted.
+    #[inline]
+    pub(crate) fn invalidate(&mut self) {
+        self.entries = None;
+    }
+}
+
 /// A single attached data file embedded in a [PdfDocument].
 pub struct PdfAttachment<'a> {
     handle: FPDF_ATTACHMENT,
+    document_handle: FPDF_DOCUMENT,
+    index: Cell<usize>,
+    entries: Arc<Mutex<PdfAttachmentEntries>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -39,9 +134,18 @@ impl<'a> PdfAttachment<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         handle: FPDF_ATTACHMENT,
+        document_handle: FPDF_DOCUMENT,
+        index: usize,
+        entries: Arc<Mutex<PdfAttachmentEntries>>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
-        PdfAttachment { handle, bindings }
+        PdfAttachment {
+            handle,
+            document_handle,
+            index: Cell::new(index),
+            entries,
+            bindings,
+        }
     }
 
     /// Returns the [PdfiumLibraryBindings] used by this [PdfAttachment].
@@ -84,6 +188,71 @@ impl<'a> PdfAttachment<'a> {
         get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
     }
 
+    /// Returns the MIME type of this [PdfAttachment], such as `text/xml`, if one is given
+    /// by the `/Subtype` entry of the attachment's embedded file stream.
+    ///
+    /// Pdfium does not expose this entry directly, so the first call to this function or to
+    /// [PdfAttachment::relationship()] for any attachment in the containing document serializes
+    /// the document in memory and reads the file specifications of all attachments in the
+    /// document's `/EmbeddedFiles` name tree. This may be slow for large documents; the entries
+    /// are retained by the containing `PdfAttachments` collection, so later calls for this or any
+    /// other attachment are inexpensive until attachments are added or deleted. Returns `None`
+    /// if the entry is absent or the document could not be serialized, or if the file
+    /// specification could not be located in the serialized data; the inspection cannot see
+    /// objects stored in compressed object streams.
+    pub fn mime_type(&self) -> Option<String> {
+        self.entry()?.mime_type
+    }
+
+    /// Returns the relationship between this [PdfAttachment] and its containing document,
+    /// if one is given by the `/AFRelationship` entry of the attachment's file specification.
+    ///
+    /// As with [PdfAttachment::mime_type()], this entry is read from a serialized copy of the
+    /// containing document, with the same limitations. Returns `None` if the entry is absent
+    /// or the document could not be serialized.
+    pub fn relationship(&self) -> Option<PdfAttachmentRelationship> {
+        self.entry()?
+            .relationship
+            .map(|name| PdfAttachmentRelationship::from_name(name.as_str()))
+    }
+
+    /// Returns the entries of the file specification of this [PdfAttachment] that Pdfium
+    /// does not expose.
+    fn entry(&self) -> Option<RawEmbeddedFileEntry> {
+        let index = self.index()?;
+
+        self.entries
+            .lock()
+            .unwrap()
+            .get(self.document_handle, index, self.bindings)
+    }
+
+    /// Returns the current position of this [PdfAttachment] in the document's `/EmbeddedFiles`
+    /// name tree. Pdfium returns the file specification dictionary itself as the
+    /// `FPDF_ATTACHMENT` handle, so the position is confirmed by comparing handles. The position
+    /// at which the attachment was retrieved is checked first; the whole name tree is searched
+    /// only if attachments have since been added or deleted.
+    fn index(&self) -> Option<usize> {
+        let is_at_index = |index: c_int| {
+            self.bindings()
+                .FPDFDoc_GetAttachment(self.document_handle, index)
+                == self.handle
+        };
+
+        if is_at_index(self.index.get() as c_int) {
+            return Some(self.index.get());
+        }
+
+        let index = (0..self
+            .bindings()
+            .FPDFDoc_GetAttachmentCount(self.document_handle))
+            .find(|index| is_at_index(*index))? as usize;
+
+        self.index.set(index);
+
+        Some(index)
+    }
+
     /// Returns the size of this [PdfAttachment] in bytes.
     pub fn len(&self) -> usize {
         // Calling FPDFAttachment_GetFile() with a null buffer will retrieve the length of the
@@ -197,3 +366,69 @@ impl<'a> PdfAttachment<'a> {
         Ok(blob)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_factur_x_attachment_properties() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Object 4 is a file specification with the same file name as the Factur-X invoice,
+        // attached to a page annotation rather than listed in the /EmbeddedFiles name tree,
+        // so it must not be mistaken for the invoice.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /AF [5 0 R] /Names << /EmbeddedFiles \
+            << /Names [(factur-x.xml) 5 0 R (notes.txt) 7 0 R] >> >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [<< /Type /Annot \
+            /Subtype /FileAttachment /Rect [0 0 10 10] /FS 4 0 R >>] >>",
+            b"<< /Type /Filespec /F (factur-x.xml) /AFRelationship /Source \
+            /EF << /F 8 0 R >> >>",
+            b"<< /Type /Filespec /F (factur-x.xml) /UF (factur-x.xml) /AFRelationship /Data \
+            /EF << /F 6 0 R /UF 6 0 R >> >>",
+            b"<< /Type /EmbeddedFile /Subtype /text#2Fxml >>\nstream\n<x/>",
+            b"<< /Type /Filespec /F (notes.txt) /EF << /F 8 0 R >> >>",
+            b"<< /Type /EmbeddedFile /Subtype /text#2Fplain >>\nstream\nnote",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let attachments = document.attachments();
+
+        let invoice = attachments
+            .iter()
+            .find(|attachment| attachment.name() == "factur-x.xml")
+            .unwrap();
+
+        assert_eq!(invoice.mime_type(), Some("text/xml".to_owned()));
+        assert_eq!(
+            invoice.relationship(),
+            Some(PdfAttachmentRelationship::Data)
+        );
+
+        let notes = attachments
+            .iter()
+            .find(|attachment| attachment.name() == "notes.txt")
+            .unwrap();
+
+        assert_eq!(notes.mime_type(), Some("text/plain".to_owned()));
+        assert_eq!(notes.relationship(), None);
+
+        // Attachments created after loading are located by their position in the name tree.
+
+        let mut document = document;
+
+        let created = document
+            .attachments_mut()
+            .create_attachment_from_bytes("created.txt", b"created")?;
+
+        assert_eq!(created.mime_type(), None);
+        assert_eq!(created.relationship(), None);
+
+        Ok(())
+    }
+}
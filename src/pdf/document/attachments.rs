@@ -4,10 +4,11 @@
 use crate::bindgen::FPDF_DOCUMENT;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
-use crate::pdf::document::attachment::PdfAttachment;
+use crate::pdf::document::attachment::{PdfAttachment, PdfAttachmentEntries};
 use std::io::Read;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::{c_int, c_ulong, c_void};
+use std::sync::{Arc, Mutex};
 
 #[cfg(not(target_arch = "wasm32"))]
 use {std::fs::File, std::path::Path};
@@ -36,6 +37,7 @@ pub type PdfAttachmentIndex = u16;
 /// The collection of [PdfAttachment] objects embedded in a [PdfDocument].
 pub struct PdfAttachments<'a> {
     document_handle: FPDF_DOCUMENT,
+    entries: Arc<Mutex<PdfAttachmentEntries>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -47,6 +49,7 @@ impl<'a> PdfAttachments<'a> {
     ) -> Self {
         PdfAttachments {
             document_handle,
+            entries: Arc::new(Mutex::new(PdfAttachmentEntries::default())),
             bindings,
         }
     }
@@ -101,7 +104,13 @@ impl<'a> PdfAttachments<'a> {
                 PdfiumInternalError::Unknown,
             ))
         } else {
-            Ok(PdfAttachment::from_pdfium(handle, self.bindings()))
+            Ok(PdfAttachment::from_pdfium(
+                handle,
+                self.document_handle,
+                index as usize,
+                Arc::clone(&self.entries),
+                self.bindings(),
+            ))
         }
     }
 
@@ -120,6 +129,8 @@ impl<'a> PdfAttachments<'a> {
             .bindings()
             .FPDFDoc_AddAttachment_str(self.document_handle, name);
 
+        self.entries.lock().unwrap().invalidate();
+
         if handle.is_null() {
             Err(PdfiumError::PdfiumLibraryInternalError(
                 PdfiumInternalError::Unknown,
@@ -136,7 +147,16 @@ impl<'a> PdfAttachments<'a> {
                     bytes.len() as c_ulong,
                 ))
             {
-                Ok(PdfAttachment::from_pdfium(handle, self.bindings))
+                // Pdfium keeps the name tree sorted by name, so the new attachment's position
+                // is not known until it is searched for.
+
+                Ok(PdfAttachment::from_pdfium(
+                    handle,
+                    self.document_handle,
+                    0,
+                    Arc::clone(&self.entries),
+                    self.bindings,
+                ))
             } else {
                 // The return value from FPDFAttachment_SetFile() indicates failure.
 
@@ -273,6 +293,8 @@ impl<'a> PdfAttachments<'a> {
             self.bindings()
                 .FPDFDoc_DeleteAttachment(self.document_handle, index as c_int),
         ) {
+            self.entries.lock().unwrap().invalidate();
+
             Ok(())
         } else {
             Err(PdfiumError::PdfiumLibraryInternalError(
@@ -4,8 +4,11 @@
 use crate::bindgen::{FPDF_BOOKMARK, FPDF_DOCUMENT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::destination::PdfDestinationViewSettings;
 use crate::pdf::document::bookmark::PdfBookmark;
-use std::collections::HashSet;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::raw::outline::{append_outline, PdfOutlineEntry};
+use std::collections::{HashMap, HashSet};
 use std::ptr::null_mut;
 
 /// The bookmarks contained within a single `PdfDocument`.
@@ -18,8 +21,12 @@ use std::ptr::null_mut;
 /// To search the tree for a bookmark with a specific title, use the [PdfBookmarks::find_first_by_title()]
 /// and [PdfBookmarks::find_all_by_title()] functions. To traverse the tree breadth-first, visiting
 /// every bookmark in the tree, create an iterator using the [PdfBookmarks::iter()] function.
+///
+/// New bookmarks can be queued for addition to the tree using the [PdfBookmarks::add_on_save()]
+/// function; they become visible once the document has been saved and loaded again.
 pub struct PdfBookmarks<'a> {
     document_handle: FPDF_DOCUMENT,
+    additions: Vec<PdfOutlineEntry>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -31,6 +38,7 @@ impl<'a> PdfBookmarks<'a> {
     ) -> Self {
         Self {
             document_handle,
+            additions: Vec::new(),
             bindings,
         }
     }
@@ -107,6 +115,105 @@ impl<'a> PdfBookmarks<'a> {
             .collect()
     }
 
+    /// Queues a new bookmark with the given title, targeting the page at the given index using
+    /// the given view settings, to be added to the document's outline the next time the
+    /// containing `PdfDocument` is saved. If a parent is given, the new bookmark is added as
+    /// the last child of that parent; otherwise, it is added after all existing top-level
+    /// bookmarks. Returns a [PdfPendingBookmark] that can be used as the parent of further
+    /// pending bookmarks.
+    ///
+    /// Pdfium does not support editing the document outline, so there is no [PdfBookmark]
+    /// for a pending bookmark. Pending bookmarks are written to the document as an incremental
+    /// update when the containing `PdfDocument` is saved, and are not returned by
+    /// [PdfBookmarks::root()], [PdfBookmarks::iter()], or any other function in this collection
+    /// until the saved document is loaded again. For the same reason, only pending bookmarks
+    /// can be used as parents. Saving fails with [PdfiumError::BookmarksNotWritable]
+    /// if the existing outline could not be located in the saved document data.
+    pub fn add_on_save(
+        &mut self,
+        title: impl ToString,
+        page_index: PdfPageIndex,
+        view: PdfDestinationViewSettings,
+        parent: Option<PdfPendingBookmark>,
+    ) -> Result<PdfPendingBookmark, PdfiumError> {
+        if i32::from(page_index) >= self.bindings.FPDF_GetPageCount(self.document_handle) {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        if let Some(parent) = parent {
+            if parent.index() >= self.additions.len() {
+                return Err(PdfiumError::PendingBookmarkInvalid);
+            }
+        }
+
+        self.additions.push(PdfOutlineEntry {
+            title: title.to_string(),
+            parent: parent.map(|parent| parent.index()),
+            page_index: page_index as usize,
+            view: view.as_pdf_destination_suffix(),
+        });
+
+        Ok(PdfPendingBookmark(self.additions.len() - 1))
+    }
+
+    /// Queues a copy of every bookmark in the given source [PdfBookmarks] collection to be added
+    /// to the document's outline the next time the containing `PdfDocument` is saved, preserving
+    /// the structure of the source bookmark tree. Each copy targets the page with the same index
+    /// as its source bookmark, so the containing `PdfDocument` must already hold copies of the
+    /// source document's pages in their original order.
+    ///
+    /// Bookmarks that do not target a page in the source document, such as bookmarks that
+    /// only trigger a URI or JavaScript action, are not copied; their children are attached to
+    /// the nearest copied ancestor instead.
+    pub(crate) fn add_on_save_from(&mut self, source: &PdfBookmarks) -> Result<(), PdfiumError> {
+        // The iterator visits every parent before its children, so the pending bookmark
+        // standing in for each child's parent has always been queued by the time the child
+        // is reached.
+
+        let mut copies: HashMap<FPDF_BOOKMARK, Option<PdfPendingBookmark>> = HashMap::new();
+
+        for bookmark in source.iter() {
+            let parent = bookmark
+                .parent()
+                .and_then(|parent| copies.get(&parent.bookmark_handle()).copied().flatten());
+
+            let copy = match bookmark
+                .destination()
+                .and_then(|destination| Some((destination.page_index().ok()?, destination)))
+            {
+                Some((page_index, destination)) => Some(
+                    self.add_on_save(
+                        bookmark.title().unwrap_or_default(),
+                        page_index,
+                        destination
+                            .view_settings()
+                            .unwrap_or(PdfDestinationViewSettings::Unknown),
+                        parent,
+                    )?,
+                ),
+                None => parent,
+            };
+
+            copies.insert(bookmark.bookmark_handle(), copy);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if any bookmarks have been added to this [PdfBookmarks] collection
+    /// since the containing `PdfDocument` was loaded.
+    #[inline]
+    pub(crate) fn is_modified(&self) -> bool {
+        !self.additions.is_empty()
+    }
+
+    /// Appends the bookmarks added to this [PdfBookmarks] collection to the given document
+    /// data, as saved by Pdfium, as an incremental update to the document's outline.
+    pub(crate) fn apply_to_saved_bytes(&self, bytes: Vec<u8>) -> Result<Vec<u8>, PdfiumError> {
+        append_outline(bytes.as_slice(), self.additions.as_slice())
+            .ok_or(PdfiumError::BookmarksNotWritable)
+    }
+
     /// Returns a depth-first prefix-order iterator over all the [PdfBookmark]
     /// objects in the containing `PdfDocument`, starting from the top-level
     /// root bookmark.
@@ -122,6 +229,19 @@ impl<'a> PdfBookmarks<'a> {
     }
 }
 
+/// A reference to a bookmark queued using the [PdfBookmarks::add_on_save()] function that has
+/// not yet been written to the document's outline. It can be used as the parent of further
+/// pending bookmarks in the same [PdfBookmarks] collection.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PdfPendingBookmark(usize);
+
+impl PdfPendingBookmark {
+    #[inline]
+    pub(crate) fn index(&self) -> usize {
+        self.0
+    }
+}
+
 /// An iterator over all the [PdfBookmark] objects in a [PdfBookmarks] collection.
 pub struct PdfBookmarksIterator<'a> {
     // If true, recurse into descendants.
@@ -0,0 +1,478 @@
+//! Defines the [PdfDocumentDiff] struct, describing the structural differences between
+//! two versions of a `PdfDocument`.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::page::object::PdfPageObjectCommon;
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The differences between two versions of a [PdfDocument], as returned by the
+/// [PdfDocument::diff()] function.
+///
+/// Pages in the two documents are matched by a hash of their content, consisting of
+/// the page's size, its text, and the type and bounds of each of its page objects. Matched pages that appear
+/// in the same relative order in both documents are reported as unchanged; matched pages
+/// that have changed position are reported as moved. Unmatched pages lying between
+/// the same unchanged pages in both documents are paired up in order and reported as changed,
+/// together with the differences in their text. Any remaining pages are reported as
+/// added or removed.
+///
+/// Because the hash does not include the appearance of individual page objects, a page
+/// whose only changes leave every object's type and bounds intact, such as replacing an image
+/// with a different image of the same size or changing the color of a path, is reported
+/// as unchanged.
+///
+/// To bound the memory used when comparing large documents, the pages and words that differ
+/// between the two versions are only aligned with each other when there are at most a few
+/// thousand of them on each side; pages and words common to the start and end of both
+/// versions do not count towards this limit. When a difference is too large to align, every
+/// differing page is reported as changed, added, or removed, and every differing word in
+/// a changed page is reported in a single removal followed by a single insertion.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PdfDocumentDiff {
+    pages: Vec<PdfPageDiff>,
+}
+
+impl PdfDocumentDiff {
+    pub(crate) fn from_documents(
+        old: &PdfDocument,
+        new: &PdfDocument,
+    ) -> Result<Self, PdfiumError> {
+        let old = page_fingerprints(old)?;
+
+        let new = page_fingerprints(new)?;
+
+        let old_hashes = old.iter().map(|(hash, _)| *hash).collect::<Vec<_>>();
+
+        let new_hashes = new.iter().map(|(hash, _)| *hash).collect::<Vec<_>>();
+
+        let anchors = longest_common_subsequence(&old_hashes, &new_hashes);
+
+        let mut old_matched = vec![false; old.len()];
+
+        let mut new_matched = vec![false; new.len()];
+
+        // Each entry is paired with a sort key that places it at its position in the new document.
+        // Removed pages sort before any other page at the same position.
+
+        let mut pages = Vec::with_capacity(old.len().max(new.len()));
+
+        for (old_index, new_index) in anchors.iter().copied() {
+            old_matched[old_index] = true;
+            new_matched[new_index] = true;
+
+            pages.push((
+                (new_index, 1),
+                PdfPageDiff::Unchanged {
+                    old_index: old_index as PdfPageIndex,
+                    new_index: new_index as PdfPageIndex,
+                },
+            ));
+        }
+
+        // Pages with identical content that are not part of the common sequence have moved.
+
+        for new_index in 0..new.len() {
+            if new_matched[new_index] {
+                continue;
+            }
+
+            if let Some(old_index) = (0..old.len())
+                .find(|old_index| !old_matched[*old_index] && old[*old_index].0 == new[new_index].0)
+            {
+                old_matched[old_index] = true;
+                new_matched[new_index] = true;
+
+                pages.push((
+                    (new_index, 1),
+                    PdfPageDiff::Moved {
+                        old_index: old_index as PdfPageIndex,
+                        new_index: new_index as PdfPageIndex,
+                    },
+                ));
+            }
+        }
+
+        // Pair up the remaining pages lying between the same two unchanged pages.
+
+        let mut segment_start = (0, 0);
+
+        for (old_end, new_end) in anchors
+            .iter()
+            .copied()
+            .chain(std::iter::once((old.len(), new.len())))
+        {
+            let old_unmatched = (segment_start.0..old_end)
+                .filter(|index| !old_matched[*index])
+                .collect::<Vec<_>>();
+
+            let new_unmatched = (segment_start.1..new_end)
+                .filter(|index| !new_matched[*index])
+                .collect::<Vec<_>>();
+
+            for (position, old_index) in old_unmatched.iter().copied().enumerate() {
+                match new_unmatched.get(position).copied() {
+                    Some(new_index) => pages.push((
+                        (new_index, 1),
+                        PdfPageDiff::Changed {
+                            old_index: old_index as PdfPageIndex,
+                            new_index: new_index as PdfPageIndex,
+                            changes: diff_text(&old[old_index].1, &new[new_index].1),
+                        },
+                    )),
+                    None => pages.push((
+                        (segment_start.1, 0),
+                        PdfPageDiff::Removed {
+                            old_index: old_index as PdfPageIndex,
+                        },
+                    )),
+                }
+            }
+
+            for new_index in new_unmatched.iter().skip(old_unmatched.len()).copied() {
+                pages.push((
+                    (new_index, 1),
+                    PdfPageDiff::Added {
+                        new_index: new_index as PdfPageIndex,
+                    },
+                ));
+            }
+
+            segment_start = (old_end + 1, new_end + 1);
+        }
+
+        pages.sort_by_key(|(key, _)| *key);
+
+        Ok(PdfDocumentDiff {
+            pages: pages.into_iter().map(|(_, page)| page).collect(),
+        })
+    }
+
+    /// Returns the differences for every page in both documents, in the order the pages
+    /// appear in the new document. Removed pages are listed at the position they were
+    /// removed from.
+    #[inline]
+    pub fn pages(&self) -> &[PdfPageDiff] {
+        self.pages.as_slice()
+    }
+
+    /// Returns `true` if both documents contain the same pages in the same order.
+    pub fn is_unchanged(&self) -> bool {
+        self.pages
+            .iter()
+            .all(|page| matches!(page, PdfPageDiff::Unchanged { .. }))
+    }
+
+    /// Returns the indices in the new document of all pages that were added.
+    pub fn added_pages(&self) -> Vec<PdfPageIndex> {
+        self.pages
+            .iter()
+            .filter_map(|page| match page {
+                PdfPageDiff::Added { new_index } => Some(*new_index),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the indices in the old document of all pages that were removed.
+    pub fn removed_pages(&self) -> Vec<PdfPageIndex> {
+        self.pages
+            .iter()
+            .filter_map(|page| match page {
+                PdfPageDiff::Removed { old_index } => Some(*old_index),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The difference between a single page in two versions of a [PdfDocument].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfPageDiff {
+    /// The page is identical in both documents, and appears in the same relative position.
+    Unchanged {
+        old_index: PdfPageIndex,
+        new_index: PdfPageIndex,
+    },
+
+    /// The page is identical in both documents, but has changed position.
+    Moved {
+        old_index: PdfPageIndex,
+        new_index: PdfPageIndex,
+    },
+
+    /// The page appears in the same relative position in both documents, but its
+    /// content differs. The differences in the page's text are listed in order; if only
+    /// non-text content differs, the list is empty.
+    Changed {
+        old_index: PdfPageIndex,
+        new_index: PdfPageIndex,
+        changes: Vec<PdfTextChange>,
+    },
+
+    /// The page only exists in the new document.
+    Added { new_index: PdfPageIndex },
+
+    /// The page only exists in the old document.
+    Removed { old_index: PdfPageIndex },
+}
+
+/// A single run of consecutive words that differs between the text of two versions of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfTextChange {
+    /// The words were inserted into the new version of the page.
+    Inserted(String),
+
+    /// The words were removed from the old version of the page.
+    Removed(String),
+}
+
+/// Returns a content hash and the text of every page in the given document.
+fn page_fingerprints(document: &PdfDocument) -> Result<Vec<(u64, String)>, PdfiumError> {
+    document
+        .pages()
+        .iter()
+        .map(|page| {
+            let text = page.text()?.all();
+
+            let mut hasher = DefaultHasher::new();
+
+            page.width().value.to_bits().hash(&mut hasher);
+            page.height().value.to_bits().hash(&mut hasher);
+            text.hash(&mut hasher);
+
+            for object in page.objects().iter() {
+                object.object_type().hash(&mut hasher);
+
+                if let Ok(bounds) = object.bounds() {
+                    let bounds = bounds.to_rect();
+
+                    bounds.left().value.to_bits().hash(&mut hasher);
+                    bounds.bottom().value.to_bits().hash(&mut hasher);
+                    bounds.right().value.to_bits().hash(&mut hasher);
+                    bounds.top().value.to_bits().hash(&mut hasher);
+                }
+            }
+
+            Ok((hasher.finish(), text))
+        })
+        .collect()
+}
+
+/// Returns the differences between two versions of a page's text, compared word by word.
+fn diff_text(old: &str, new: &str) -> Vec<PdfTextChange> {
+    let old = old.split_whitespace().collect::<Vec<_>>();
+
+    let new = new.split_whitespace().collect::<Vec<_>>();
+
+    let mut changes = Vec::new();
+
+    let mut removed = Vec::new();
+
+    let mut inserted = Vec::new();
+
+    let mut flush = |removed: &mut Vec<&str>, inserted: &mut Vec<&str>| {
+        if !removed.is_empty() {
+            changes.push(PdfTextChange::Removed(removed.join(" ")));
+            removed.clear();
+        }
+
+        if !inserted.is_empty() {
+            changes.push(PdfTextChange::Inserted(inserted.join(" ")));
+            inserted.clear();
+        }
+    };
+
+    let (mut old_index, mut new_index) = (0, 0);
+
+    for (old_end, new_end) in longest_common_subsequence(&old, &new)
+        .into_iter()
+        .chain(std::iter::once((old.len(), new.len())))
+    {
+        removed.extend_from_slice(&old[old_index..old_end]);
+        inserted.extend_from_slice(&new[new_index..new_end]);
+
+        if old_end < old.len() || new_end < new.len() {
+            // This is a common word, so it ends any run of changed words.
+
+            flush(&mut removed, &mut inserted);
+        }
+
+        old_index = old_end + 1;
+        new_index = new_end + 1;
+    }
+
+    flush(&mut removed, &mut inserted);
+
+    changes
+}
+
+/// The maximum number of entries in the table used to align the differing items
+/// of two sequences, limiting the table to 16 MB.
+const MAX_ALIGNMENT_TABLE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Returns the index pairs of the longest sequence of items common to both given slices,
+/// in ascending order.
+///
+/// Items common to the start and end of both slices are always matched. If aligning
+/// the remaining items would need a table larger than [MAX_ALIGNMENT_TABLE_SIZE],
+/// none of the remaining items are matched.
+fn longest_common_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let prefix = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let a_middle = &a[prefix..a.len() - suffix];
+
+    let b_middle = &b[prefix..b.len() - suffix];
+
+    let mut result = (0..prefix).map(|index| (index, index)).collect::<Vec<_>>();
+
+    if (a_middle.len() + 1)
+        .checked_mul(b_middle.len() + 1)
+        .map_or(false, |size| size <= MAX_ALIGNMENT_TABLE_SIZE)
+    {
+        result.extend(
+            align(a_middle, b_middle)
+                .into_iter()
+                .map(|(i, j)| (prefix + i, prefix + j)),
+        );
+    }
+
+    result.extend((0..suffix).map(|index| (a.len() - suffix + index, b.len() - suffix + index)));
+
+    result
+}
+
+/// Returns the index pairs of the longest sequence of items common to both given slices,
+/// in ascending order, using a table with an entry for every pair of items.
+fn align<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let width = b.len() + 1;
+
+    // lengths[i * width + j] holds the length of the longest common subsequence
+    // of a[i..] and b[j..].
+
+    let mut lengths = vec![0_u32; (a.len() + 1) * width];
+
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i * width + j] = if a[i] == b[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(lengths[0] as usize);
+
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            result.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_text;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_diff_text() {
+        assert_eq!(
+            diff_text("the quick brown fox jumps", "the slow brown fox jumps high"),
+            vec![
+                PdfTextChange::Removed("quick".to_owned()),
+                PdfTextChange::Inserted("slow".to_owned()),
+                PdfTextChange::Inserted("high".to_owned()),
+            ]
+        );
+        assert!(diff_text("unchanged text", "unchanged  text").is_empty());
+    }
+
+    #[test]
+    fn test_diff_text_too_large_to_align() {
+        let words = |prefix: &str| {
+            (0..3000)
+                .map(|index| format!("{}{}", prefix, index))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let old = format!("start {} middle {} end", words("a"), words("b"));
+
+        let new = format!("start {} middle {} end", words("c"), words("d"));
+
+        // The common word "middle" cannot be aligned, so all words between the common
+        // start and end are reported as a single replacement.
+
+        assert_eq!(
+            diff_text(&old, &new),
+            vec![
+                PdfTextChange::Removed(format!("{} middle {}", words("a"), words("b"))),
+                PdfTextChange::Inserted(format!("{} middle {}", words("c"), words("d"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_appended_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let mut copy = document.duplicate()?;
+
+        let font = copy.fonts_mut().helvetica();
+
+        copy.pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?
+            .objects_mut()
+            .create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+                "Appendix",
+                font,
+                PdfPoints::new(12.0),
+            )?;
+
+        let diff = document.diff(&copy)?;
+
+        let page_count = document.pages().len();
+
+        assert_eq!(diff.added_pages(), vec![page_count]);
+        assert!(diff.removed_pages().is_empty());
+        assert_eq!(diff.pages().len(), page_count as usize + 1);
+        assert_eq!(
+            diff.pages()
+                .iter()
+                .filter(|page| matches!(page, PdfPageDiff::Unchanged { .. }))
+                .count(),
+            page_count as usize
+        );
+
+        assert!(document.diff(&document.duplicate()?)?.is_unchanged());
+
+        Ok(())
+    }
+}
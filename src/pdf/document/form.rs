@@ -7,10 +7,11 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::document::page::field::options::PdfFormFieldOptions;
 use crate::pdf::document::page::field::PdfFormFieldCommon;
-use crate::pdf::document::page::field::PdfFormFieldType;
+use crate::pdf::document::page::field::{PdfFormField, PdfFormFieldType};
 use crate::pdf::document::pages::PdfPages;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::DerefMut;
 use std::pin::Pin;
 use std::ptr::null_mut;
@@ -54,6 +55,28 @@ impl PdfFormType {
     }
 }
 
+/// The typed value of a single form field, as captured by the [PdfForm::typed_field_values()]
+/// function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfFormFieldValue {
+    /// The current value of a text field, or an empty string if the field has no value.
+    Text(String),
+
+    /// The checked state of a checkbox field or checkbox control group. A control group
+    /// is considered checked if any checkbox in the group is checked.
+    Checkbox(bool),
+
+    /// The group value of the currently selected radio button in a radio button control group,
+    /// or `None` if no radio button in the group is selected.
+    Radio(Option<String>),
+
+    /// The labels of all currently selected options in a combo box or list box field.
+    Choice(Vec<String>),
+
+    /// Whether or not a signature field contains a signature.
+    Signature(bool),
+}
+
 /// The [PdfForm] embedded inside a [PdfDocument].
 ///
 /// Form fields in Pdfium are exposed as page annotations of type `PdfPageAnnotationType::Widget`
@@ -73,7 +96,9 @@ impl PdfFormType {
 /// ```
 ///
 /// Alternatively, use the [PdfForm::field_values()] function to eagerly retrieve the values of all
-/// fields in the document as a map of (field name, field value) pairs.
+/// fields in the document as a map of (field name, field value) pairs, or the
+/// [PdfForm::typed_field_values()] function to retrieve the same values as [PdfFormFieldValue]
+/// variants that preserve the type of each field.
 pub struct PdfForm<'a> {
     form_handle: FPDF_FORMHANDLE,
     document_handle: FPDF_DOCUMENT,
@@ -269,6 +294,80 @@ impl<'a> PdfForm<'a> {
 
         result
     }
+
+    /// Captures the typed value of every form field on every page of the given [PdfPages]
+    /// collection, returning a map of (field name, field value) pairs ordered by field name.
+    ///
+    /// Field names are fully qualified, such that a field nested inside a parent field
+    /// will be keyed as `parent.child`. Push buttons and fields of unknown type do not carry
+    /// a value and are not included in the returned map.
+    ///
+    /// This function assumes that all form fields in the document have unique field names
+    /// except for radio button and checkbox control groups.
+    pub fn typed_field_values(
+        &self,
+        pages: &'a PdfPages<'a>,
+    ) -> BTreeMap<String, PdfFormFieldValue> {
+        let mut result = BTreeMap::new();
+
+        for page in pages.iter() {
+            for annotation in page.annotations().iter() {
+                if let Some(field) = annotation.as_form_field() {
+                    let field_value = match field {
+                        PdfFormField::Checkbox(field) => {
+                            PdfFormFieldValue::Checkbox(field.is_checked().unwrap_or(false))
+                        }
+                        PdfFormField::RadioButton(field) => {
+                            PdfFormFieldValue::Radio(if field.is_checked().unwrap_or(false) {
+                                field.group_value()
+                            } else {
+                                None
+                            })
+                        }
+                        PdfFormField::ComboBox(field) => {
+                            PdfFormFieldValue::Choice(Self::selected_labels(field.options()))
+                        }
+                        PdfFormField::ListBox(field) => {
+                            PdfFormFieldValue::Choice(Self::selected_labels(field.options()))
+                        }
+                        PdfFormField::Text(field) => {
+                            PdfFormFieldValue::Text(field.value().unwrap_or_default())
+                        }
+                        PdfFormField::Signature(field) => {
+                            PdfFormFieldValue::Signature(field.is_signed())
+                        }
+                        PdfFormField::PushButton(_) | PdfFormField::Unknown(_) => continue,
+                    };
+
+                    let field_name = field.name().unwrap_or_default();
+
+                    // As in field_values(), we prefer to capture the value of a checked control
+                    // in a checkbox or radio button control group in preference to the value
+                    // of an unchecked control.
+
+                    let is_unset_group_member = matches!(
+                        field_value,
+                        PdfFormFieldValue::Checkbox(false) | PdfFormFieldValue::Radio(None)
+                    );
+
+                    if !(is_unset_group_member && result.contains_key(&field_name)) {
+                        result.insert(field_name, field_value);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the labels of all currently selected options in the given [PdfFormFieldOptions].
+    fn selected_labels(options: &PdfFormFieldOptions) -> Vec<String> {
+        options
+            .iter()
+            .filter(|option| option.is_set())
+            .filter_map(|option| option.label().cloned())
+            .collect()
+    }
 }
 
 impl<'a> Drop for PdfForm<'a> {
@@ -279,3 +378,75 @@ impl<'a> Drop for PdfForm<'a> {
             .FPDFDOC_ExitFormFillEnvironment(self.form_handle);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_typed_field_values() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let field_values = form.field_values(document.pages());
+
+        let typed_field_values = form.typed_field_values(document.pages());
+
+        assert!(!typed_field_values.is_empty());
+
+        for (name, value) in typed_field_values.iter() {
+            // Every typed value should correspond to an untyped value of the same name.
+
+            let untyped_value = field_values.get(name).unwrap();
+
+            match value {
+                PdfFormFieldValue::Text(text) => {
+                    assert_eq!(text, &untyped_value.clone().unwrap_or_default())
+                }
+                PdfFormFieldValue::Checkbox(is_checked) => {
+                    assert_eq!(untyped_value.as_deref() == Some("true"), *is_checked)
+                }
+                PdfFormFieldValue::Radio(Some(group_value)) => {
+                    assert_eq!(untyped_value.as_ref(), Some(group_value))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_action_script() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /AcroForm << /Fields [4 0 R] >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R] >>",
+            b"<< /Type /Annot /Subtype /Widget /FT /Tx /T (amount) /Rect [100 700 300 720] \
+            /P 3 0 R /AA << /F << /S /JavaScript /JS \
+            (AFNumber_Format\\(2, 0, 0, 0, \"$\", true\\);) >> >> >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().first()?;
+
+        let field = annotation.as_form_field().unwrap();
+
+        assert_eq!(
+            field.action_script(PdfFormFieldTrigger::Format).as_deref(),
+            Some("AFNumber_Format(2, 0, 0, 0, \"$\", true);")
+        );
+        assert!(field.action_script(PdfFormFieldTrigger::Validate).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+//! Defines the [PdfMarkInfo] struct, exposing the mark information dictionary settings
+//! of a single `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::pdf::document::raw::mark_info_flag;
+use crate::utils::files::get_pdfium_document_bytes;
+use std::cell::Cell;
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// The settings of the `/MarkInfo` dictionary in the document catalog of a single [PdfDocument].
+///
+/// The mark information dictionary indicates whether a document conforms to the Tagged PDF
+/// conventions described in section 10.7 of the PDF Reference Manual, version 1.7, on page 883.
+/// Accessibility tools can use these settings to decide how far the document's logical
+/// structure can be trusted. If the document has no `/MarkInfo` dictionary, all settings
+/// are `false`.
+pub struct PdfMarkInfo<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+
+    // The /UserProperties and /Suspects settings, read together on first access.
+    flags: Cell<Option<(Option<bool>, Option<bool>)>>,
+}
+
+impl<'a> PdfMarkInfo<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfMarkInfo {
+            document_handle,
+            bindings,
+            flags: Cell::new(None),
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfMarkInfo].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns `true` if the containing [PdfDocument] declares that it conforms to the
+    /// Tagged PDF conventions.
+    #[inline]
+    pub fn is_marked(&self) -> bool {
+        // Pdfium's FPDFCatalog_IsTagged() function returns true only if the document catalog
+        // contains both a /MarkInfo dictionary with a /Marked value of true and
+        // a /StructTreeRoot dictionary.
+
+        self.bindings
+            .is_true(self.bindings.FPDFCatalog_IsTagged(self.document_handle))
+    }
+
+    /// Returns `Some(true)` if the structure tree of the containing [PdfDocument] contains
+    /// `/UserProperties` attributes.
+    ///
+    /// Pdfium does not currently expose a function to read this setting, so it is read
+    /// from the document catalog in a serialized copy of the document the first time either
+    /// this function or [PdfMarkInfo::has_suspects()] is called. Returns `None` if the catalog
+    /// could not be located in the serialized data, for instance because it is stored in
+    /// a compressed object stream.
+    #[inline]
+    pub fn has_user_properties(&self) -> Option<bool> {
+        self.flags().0
+    }
+
+    /// Returns `Some(true)` if the containing [PdfDocument] contains tag suspects: marked
+    /// content whose conformance to the Tagged PDF conventions the creating application
+    /// was unable to guarantee.
+    ///
+    /// As with [PdfMarkInfo::has_user_properties()], this setting is read from a serialized
+    /// copy of the document. Returns `None` if the document catalog could not be located.
+    #[inline]
+    pub fn has_suspects(&self) -> Option<bool> {
+        self.flags().1
+    }
+
+    /// Returns the `/UserProperties` and `/Suspects` settings, reading both from a serialized
+    /// copy of the document if they have not already been read.
+    fn flags(&self) -> (Option<bool>, Option<bool>) {
+        if let Some(flags) = self.flags.get() {
+            return flags;
+        }
+
+        let bytes = get_pdfium_document_bytes(self.document_handle, self.bindings);
+
+        let flag = |key: &[u8]| {
+            bytes
+                .as_ref()
+                .and_then(|bytes| mark_info_flag(bytes.as_slice(), key))
+        };
+
+        let flags = (flag(b"/UserProperties"), flag(b"/Suspects"));
+
+        self.flags.set(Some(flags));
+
+        flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_mark_info_defaults_for_untagged_document() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mark_info = document.mark_info();
+
+        assert!(!mark_info.is_marked());
+        assert_eq!(mark_info.has_user_properties(), Some(false));
+        assert_eq!(mark_info.has_suspects(), Some(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mark_info_settings() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_byte_vec(
+            test_document(&[
+                b"<< /Type /Catalog /Pages 2 0 R /StructTreeRoot 3 0 R \
+                /MarkInfo << /Marked true /UserProperties true /Suspects false >> >>",
+                b"<< /Type /Pages /Kids [] /Count 0 >>",
+                b"<< /Type /StructTreeRoot >>",
+            ]),
+            None,
+        )?;
+
+        let mark_info = document.mark_info();
+
+        assert!(mark_info.is_marked());
+        assert_eq!(mark_info.has_user_properties(), Some(true));
+        assert_eq!(mark_info.has_suspects(), Some(false));
+
+        Ok(())
+    }
+}
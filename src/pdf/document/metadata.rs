@@ -2,8 +2,12 @@
 
 use crate::bindgen::FPDF_DOCUMENT;
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::raw::append_information_dictionary;
+use crate::utils::dates::pdf_string_to_date_time;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use chrono::{DateTime, FixedOffset};
 use std::os::raw::c_void;
 use std::slice::Iter;
 
@@ -49,11 +53,41 @@ impl PdfDocumentMetadataTag {
     }
 }
 
+impl PdfDocumentMetadataTagType {
+    /// Returns the key used for this tag type in a document's information dictionary.
+    #[inline]
+    pub(crate) fn as_pdf_key(&self) -> &'static str {
+        match self {
+            PdfDocumentMetadataTagType::Title => "Title",
+            PdfDocumentMetadataTagType::Author => "Author",
+            PdfDocumentMetadataTagType::Subject => "Subject",
+            PdfDocumentMetadataTagType::Keywords => "Keywords",
+            PdfDocumentMetadataTagType::Creator => "Creator",
+            PdfDocumentMetadataTagType::Producer => "Producer",
+            PdfDocumentMetadataTagType::CreationDate => "CreationDate",
+            PdfDocumentMetadataTagType::ModificationDate => "ModDate",
+        }
+    }
+}
+
 /// A collection of all the metadata tags in a [PdfDocument].
+///
+/// Pdfium does not provide a way to write metadata tags, so changes made using the
+/// [PdfMetadata::set()] function are held in this collection and written to the document's
+/// information dictionary as an incremental update the next time the containing [PdfDocument]
+/// is saved. The changed tags are merged into the document's existing information dictionary,
+/// so entries not listed in [PdfDocumentMetadataTagType] are retained.
+///
+/// If the document catalog references an uncompressed XMP metadata stream, the XMP properties
+/// corresponding to the changed tags, such as `dc:title` for [PdfDocumentMetadataTagType::Title],
+/// are updated as well, so that the two sources of metadata do not disagree. Properties that are
+/// not already present in the XMP packet are not added, and compressed XMP metadata streams
+/// are not updated.
 pub struct PdfMetadata<'a> {
     document_handle: FPDF_DOCUMENT,
     bindings: &'a dyn PdfiumLibraryBindings,
     tags: Vec<PdfDocumentMetadataTag>,
+    is_modified: bool,
 }
 
 impl<'a> PdfMetadata<'a> {
@@ -65,6 +99,7 @@ impl<'a> PdfMetadata<'a> {
             document_handle,
             bindings,
             tags: vec![],
+            is_modified: false,
         };
 
         if let Some(tag) = result.get(PdfDocumentMetadataTagType::Title) {
@@ -122,20 +157,74 @@ impl<'a> PdfMetadata<'a> {
 
     /// Returns one metadata tag from this [PdfMetadata] collection, if it is defined.
     pub fn get(&self, tag: PdfDocumentMetadataTagType) -> Option<PdfDocumentMetadataTag> {
-        let result = match tag {
-            PdfDocumentMetadataTagType::Title => self.get_raw_metadata_tag("Title"),
-            PdfDocumentMetadataTagType::Author => self.get_raw_metadata_tag("Author"),
-            PdfDocumentMetadataTagType::Subject => self.get_raw_metadata_tag("Subject"),
-            PdfDocumentMetadataTagType::Keywords => self.get_raw_metadata_tag("Keywords"),
-            PdfDocumentMetadataTagType::Creator => self.get_raw_metadata_tag("Creator"),
-            PdfDocumentMetadataTagType::Producer => self.get_raw_metadata_tag("Producer"),
-            PdfDocumentMetadataTagType::CreationDate => self.get_raw_metadata_tag("CreationDate"),
-            PdfDocumentMetadataTagType::ModificationDate => {
-                self.get_raw_metadata_tag("ModificationDate")
-            }
-        };
+        if self.is_modified {
+            // Return the value set by a call to PdfMetadata::set(), since it will not be
+            // visible to Pdfium until the document is saved.
+
+            return self.tags.iter().find(|t| t.tag_type() == tag).cloned();
+        }
+
+        self.get_raw_metadata_tag(tag.as_pdf_key())
+            .map(|value| PdfDocumentMetadataTag::new(tag, value))
+    }
+
+    /// Sets the value of the given metadata tag in this [PdfMetadata] collection, replacing
+    /// any existing value.
+    ///
+    /// The new value will be written to the document's information dictionary the next time
+    /// the containing [PdfDocument] is saved. Values can contain any Unicode characters.
+    /// Date values should be formatted as PDF date strings, e.g. `D:20240315093000+05'30'`.
+    ///
+    /// Saving fails with [PdfiumError::MetadataNotWritable] if the trailer of the saved
+    /// document data, or the existing information dictionary it references, could not be
+    /// located reliably.
+    pub fn set(&mut self, tag: PdfDocumentMetadataTagType, value: impl ToString) {
+        let value = PdfDocumentMetadataTag::new(tag, value.to_string());
+
+        match self.tags.iter_mut().find(|t| t.tag_type() == tag) {
+            Some(existing) => *existing = value,
+            None => self.tags.push(value),
+        }
 
-        result.map(|value| PdfDocumentMetadataTag::new(tag, value))
+        self.is_modified = true;
+    }
+
+    /// Returns `true` if any metadata tags in this [PdfMetadata] collection have been changed
+    /// since the containing [PdfDocument] was loaded.
+    #[inline]
+    pub(crate) fn is_modified(&self) -> bool {
+        self.is_modified
+    }
+
+    /// Appends the metadata tags in this [PdfMetadata] collection to the given document data,
+    /// as saved by Pdfium, as an incremental update to the document's information dictionary.
+    pub(crate) fn apply_to_saved_bytes(&self, bytes: Vec<u8>) -> Result<Vec<u8>, PdfiumError> {
+        let entries = self
+            .tags
+            .iter()
+            .map(|tag| (tag.tag_type().as_pdf_key(), tag.value()))
+            .collect::<Vec<_>>();
+
+        append_information_dictionary(bytes.as_slice(), entries.as_slice())
+            .ok_or(PdfiumError::MetadataNotWritable)
+    }
+
+    /// Returns the date and time the containing [PdfDocument] was created, parsed from the
+    /// [PdfDocumentMetadataTagType::CreationDate] metadata tag. Returns `None` if the tag is
+    /// not defined or its value is not a valid PDF date string.
+    #[inline]
+    pub fn creation_date(&self) -> Option<DateTime<FixedOffset>> {
+        self.get(PdfDocumentMetadataTagType::CreationDate)
+            .and_then(|tag| pdf_string_to_date_time(tag.value()))
+    }
+
+    /// Returns the date and time the containing [PdfDocument] was most recently modified,
+    /// parsed from the [PdfDocumentMetadataTagType::ModificationDate] metadata tag. Returns
+    /// `None` if the tag is not defined or its value is not a valid PDF date string.
+    #[inline]
+    pub fn modification_date(&self) -> Option<DateTime<FixedOffset>> {
+        self.get(PdfDocumentMetadataTagType::ModificationDate)
+            .and_then(|tag| pdf_string_to_date_time(tag.value()))
     }
 
     #[inline]
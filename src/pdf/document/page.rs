@@ -10,8 +10,12 @@ pub mod links;
 pub mod object;
 pub mod objects;
 pub mod render_config;
+pub mod rich_media;
 pub mod size;
+pub mod struct_tree;
+pub mod svg;
 pub mod text;
+pub mod watermark;
 
 #[cfg(feature = "paragraph")]
 pub mod paragraph;
@@ -21,30 +25,52 @@ mod flatten; // Keep internal flatten operation private.
 
 use object::ownership::PdfPageObjectOwnership;
 
+#[cfg(any(feature = "image_latest", feature = "image_025"))]
+use image_025::DynamicImage;
+
+#[cfg(feature = "image_024")]
+use image_024::DynamicImage;
+
+#[cfg(feature = "image_023")]
+use image_023::DynamicImage;
+
 use crate::bindgen::{
     FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_PRINT, FPDF_DOCUMENT, FPDF_FORMHANDLE,
-    FPDF_PAGE,
+    FPDF_PAGE, FS_RECTF,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::create_transform_setters;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
+use crate::pdf::document::page::annotation::PdfPageAnnotationType;
 use crate::pdf::document::page::annotations::PdfPageAnnotations;
 use crate::pdf::document::page::boundaries::PdfPageBoundaries;
 use crate::pdf::document::page::index_cache::PdfPageIndexCache;
 use crate::pdf::document::page::links::PdfPageLinks;
+use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
 use crate::pdf::document::page::objects::PdfPageObjects;
 use crate::pdf::document::page::render_config::{PdfPageRenderSettings, PdfRenderConfig};
+use crate::pdf::document::page::rich_media::{PdfPageRichMediaAnnotation, PdfRichMediaAsset};
 use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::struct_tree::{PdfPageStructTree, PdfStructElement};
+use crate::pdf::document::page::svg::{page_to_svg, PdfSvgConfig};
+use crate::pdf::document::page::text::word::PdfPageTextWord;
 use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::document::page::watermark::{PdfWatermarkOptions, PdfWatermarkPosition};
+use crate::pdf::document::pages::PdfPageDictionaryState;
+use crate::pdf::document::raw::rich_media_assets;
 use crate::pdf::font::PdfFont;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
+use crate::pdf::scale::PdfScale;
+use crate::utils::files::get_pdfium_document_bytes;
 use std::collections::{hash_map::Entry, HashMap};
 use std::f32::consts::{FRAC_PI_2, PI};
 use std::os::raw::{c_double, c_int};
+use std::sync::{Arc, Mutex};
 
 #[cfg(doc)]
 use crate::pdf::document::PdfDocument;
@@ -67,6 +93,26 @@ impl PdfPageOrientation {
     }
 }
 
+/// Controls how the existing content of a [PdfPage] is scaled when the page is resized
+/// using the [PdfPage::scale_to_fit()] function, or how an image is scaled when it is
+/// positioned using the `PdfPageImageObject::fit_into_rect()` function.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PdfPageFitMode {
+    /// The content is scaled independently along each axis so that it exactly fills
+    /// the new page size. The aspect ratio of the content is not preserved.
+    Stretch,
+
+    /// The content is scaled uniformly so that it fits entirely within the new page size,
+    /// and is centered on the page. The aspect ratio of the content is preserved; any
+    /// unused space is left blank.
+    Contain,
+
+    /// The content is scaled uniformly so that it covers the entire new page size,
+    /// and is centered on the page. The aspect ratio of the content is preserved;
+    /// any content extending beyond the new page size is clipped.
+    Cover,
+}
+
 /// A rotation transformation that should be applied to a [PdfPage] when it is rendered
 /// into a [PdfBitmap].
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -190,6 +236,7 @@ pub struct PdfPage<'a> {
     boundaries: PdfPageBoundaries<'a>,
     links: PdfPageLinks<'a>,
     objects: PdfPageObjects<'a>,
+    dictionaries: Option<Arc<Mutex<PdfPageDictionaryState>>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -223,6 +270,7 @@ impl<'a> PdfPage<'a> {
             boundaries: PdfPageBoundaries::from_pdfium(page_handle, bindings),
             links: PdfPageLinks::from_pdfium(page_handle, document_handle, bindings),
             objects: PdfPageObjects::from_pdfium(document_handle, page_handle, bindings),
+            dictionaries: None,
             bindings,
         };
 
@@ -233,6 +281,18 @@ impl<'a> PdfPage<'a> {
         result
     }
 
+    /// Shares the given page dictionary state, held by the `PdfPages` collection this [PdfPage]
+    /// was retrieved from, with this [PdfPage].
+    #[inline]
+    pub(crate) fn with_dictionary_state(
+        mut self,
+        dictionaries: Arc<Mutex<PdfPageDictionaryState>>,
+    ) -> Self {
+        self.dictionaries = Some(dictionaries);
+
+        self
+    }
+
     /// Returns the internal `FPDF_PAGE` handle for this [PdfPage].
     #[inline]
     pub(crate) fn page_handle(&self) -> FPDF_PAGE {
@@ -282,6 +342,71 @@ impl<'a> PdfPage<'a> {
         )
     }
 
+    /// Returns `true` if this [PdfPage] passes a cheap validity check suggesting it can
+    /// be rendered: the page handle is valid, the page has a finite non-zero size, and
+    /// Pdfium was able to parse the page's content stream into page objects.
+    ///
+    /// This check does not render the page, so it cannot guarantee that rendering will
+    /// succeed, but it allows batch jobs to skip pages that are obviously broken.
+    pub fn is_renderable(&self) -> bool {
+        if self.page_handle.is_null() {
+            return false;
+        }
+
+        let width = self.width().value;
+
+        let height = self.height().value;
+
+        width.is_finite()
+            && height.is_finite()
+            && width > 0.0
+            && height > 0.0
+            && self.bindings.FPDFPage_CountObjects(self.page_handle) >= 0
+    }
+
+    /// Returns the `/UserUnit` scale factor of this [PdfPage]: the size of one default
+    /// coordinate unit, expressed as a multiple of 1/72 inch. The default is `1.0`, meaning
+    /// that one unit is one point.
+    ///
+    /// Large-format documents such as architectural drawings and maps may set a larger user unit
+    /// in order to describe pages bigger than the PDF specification's limit of 14,400 default units.
+    /// Pdfium ignores the user unit, so all measurements returned by `pdfium-render`, including
+    /// [PdfPage::width()] and [PdfPage::height()], are expressed in default units. Multiply any
+    /// such measurement by the user unit to obtain its real-world size in points; for instance,
+    /// the real-world width of a page in inches is `page.width().value * page.user_unit()? / 72.0`.
+    ///
+    /// Pdfium does not provide access to the `/UserUnit` entry, so the first call to this
+    /// function serializes the containing document in memory and inspects the serialized page
+    /// dictionaries. This may be slow for large documents; the user units of all pages are
+    /// retained by the containing `PdfPages` collection, so later calls for this or any other
+    /// page are inexpensive. Any user unit set using `PdfPages::set_user_unit()` that has not
+    /// yet been saved is returned in preference to the saved value.
+    ///
+    /// Returns [PdfiumError::PageUserUnitNotReadable] if the page dictionary could not be located,
+    /// for instance because it is stored in a compressed object stream.
+    ///
+    /// There is no corresponding `set_user_unit()` function on [PdfPage], since Pdfium cannot
+    /// write the `/UserUnit` entry; the new value must instead be held by the containing
+    /// document until it is saved. Use `PdfPages::set_user_unit()` to change the user unit.
+    pub fn user_unit(&self) -> Result<f32, PdfiumError> {
+        let index = PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
+            .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+
+        match self.dictionaries.as_ref() {
+            Some(dictionaries) => {
+                dictionaries
+                    .lock()
+                    .unwrap()
+                    .user_unit(self.document_handle, index, self.bindings)
+            }
+            None => PdfPageDictionaryState::default().user_unit(
+                self.document_handle,
+                index,
+                self.bindings,
+            ),
+        }
+    }
+
     /// Returns [PdfPageOrientation::Landscape] if the width of this [PdfPage]
     /// is greater than its height; otherwise returns [PdfPageOrientation::Portrait].
     #[inline]
@@ -303,12 +428,20 @@ impl<'a> PdfPage<'a> {
 
     /// Returns any intrinsic rotation encoded into this document indicating a rotation
     /// should be applied to this [PdfPage] during rendering.
+    ///
+    /// The returned rotation is the effective rotation of this [PdfPage]. If the page
+    /// does not specify a rotation itself, the rotation is inherited from the nearest ancestor
+    /// node in the document's page tree that does.
     #[inline]
     pub fn rotation(&self) -> Result<PdfPageRenderRotation, PdfiumError> {
         PdfPageRenderRotation::from_pdfium(self.bindings.FPDFPage_GetRotation(self.page_handle))
     }
 
     /// Sets the intrinsic rotation that should be applied to this [PdfPage] during rendering.
+    ///
+    /// The rotation is always written to this [PdfPage] itself, overriding any rotation
+    /// it would otherwise inherit from the document's page tree. Other pages inheriting
+    /// the same rotation are not affected.
     #[inline]
     pub fn set_rotation(&mut self, rotation: PdfPageRenderRotation) {
         self.bindings
@@ -381,6 +514,11 @@ impl<'a> PdfPage<'a> {
     }
 
     /// Returns the collection of text boxes contained within this [PdfPage].
+    ///
+    /// Loading the text of a page is relatively expensive. The returned [PdfPageText] keeps
+    /// the loaded text open until it is dropped, so any number of queries can be run against
+    /// it without reloading. The [PdfPageText] borrows this [PdfPage], and so cannot outlive it.
+    #[doc(alias = "open_text")]
     pub fn text(&self) -> Result<PdfPageText, PdfiumError> {
         let text_handle = self.bindings().FPDFText_LoadPage(self.page_handle);
 
@@ -393,6 +531,115 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns the logical structure tree of this [PdfPage], if the containing document
+    /// is a tagged PDF document with structure elements referring to content on this page.
+    pub fn struct_tree(&self) -> Option<PdfPageStructTree<'_>> {
+        let handle = self.bindings().FPDF_StructTree_GetForPage(self.page_handle);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfPageStructTree::from_pdfium(
+                handle,
+                self.document_handle,
+                self.dictionaries.clone(),
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Returns all the text on this [PdfPage], in logical reading order.
+    ///
+    /// If the containing document is a tagged PDF document with a structure tree referring
+    /// to content on this page, the structure tree is walked depth-first, and the text of the
+    /// marked content belonging to each structure element is emitted in turn, one line
+    /// per element. Text that is not part of the structure tree, such as running headers and
+    /// footers marked as artifacts, is omitted.
+    ///
+    /// Otherwise, all the text on the page is returned in the order determined by Pdfium's
+    /// layout analysis, as returned by [PdfPageText::all()].
+    pub fn text_in_reading_order(&self) -> Result<String, PdfiumError> {
+        let text = self.text()?;
+
+        let tree = match self.struct_tree() {
+            Some(tree) if !tree.is_empty() => tree,
+            _ => return Ok(text.all()),
+        };
+
+        // Map the marked content ID of each text object on the page to its text.
+
+        let mut marked_content: HashMap<i32, Vec<String>> = HashMap::new();
+
+        for object in self.objects().iter() {
+            if let Some(text_object) = object.as_text_object() {
+                if let Some(id) = self.marked_content_id(&object) {
+                    marked_content
+                        .entry(id)
+                        .or_default()
+                        .push(text.for_object(text_object));
+                }
+            }
+        }
+
+        if marked_content.is_empty() {
+            return Ok(text.all());
+        }
+
+        let mut lines = Vec::new();
+
+        for element in tree.elements() {
+            Self::collect_struct_element_text(&element, &marked_content, &mut lines);
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    /// Appends the text of the marked content belonging to the given [PdfStructElement]
+    /// and its descendants, in depth-first order, to the given lines.
+    fn collect_struct_element_text(
+        element: &PdfStructElement,
+        marked_content: &HashMap<i32, Vec<String>>,
+        lines: &mut Vec<String>,
+    ) {
+        let line = element
+            .marked_content_ids()
+            .iter()
+            .filter_map(|id| marked_content.get(id))
+            .flatten()
+            .map(|text| text.trim())
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if !line.is_empty() {
+            lines.push(line);
+        }
+
+        for child in element.children() {
+            Self::collect_struct_element_text(&child, marked_content, lines);
+        }
+    }
+
+    /// Returns the marked content ID assigned to the given page object, if any.
+    fn marked_content_id(&self, object: &PdfPageObject) -> Option<i32> {
+        object
+            .marks()
+            .iter()
+            .find_map(|mark| mark.marked_content_id())
+    }
+
+    /// Returns all the words on this [PdfPage], together with each word's bounding box,
+    /// font size, and font name. This is a convenience function equivalent to calling
+    /// `PdfPage::text()?.words()`; see the [PdfPageTextWord] documentation for details
+    /// of how word boundaries are determined.
+    ///
+    /// Enable the `serde` crate feature to serialize the returned words directly,
+    /// for instance to JSON.
+    #[inline]
+    pub fn text_words(&self) -> Result<Vec<PdfPageTextWord>, PdfiumError> {
+        Ok(self.text()?.words())
+    }
+
     /// Returns an immutable collection of the annotations that have been added to this [PdfPage].
     pub fn annotations(&self) -> &PdfPageAnnotations<'a> {
         &self.annotations
@@ -403,6 +650,78 @@ impl<'a> PdfPage<'a> {
         &mut self.annotations
     }
 
+    /// Returns the annotations of type [PdfPageAnnotationType::RichMedia] on this [PdfPage],
+    /// along with the multimedia resources, such as video, audio, or 3D content, embedded
+    /// in each annotation.
+    ///
+    /// Pdfium does not provide access to the contents of RichMedia annotations, so if this page
+    /// has any RichMedia annotations, this function serializes the containing document
+    /// in memory once and follows each annotation's `/Assets` name tree to the embedded
+    /// file streams. This may be slow for large documents; retain the returned value
+    /// rather than calling this function repeatedly. An annotation whose resources could
+    /// not be located, for instance because they are stored in a compressed object stream,
+    /// is returned with no assets.
+    ///
+    /// Asset data is returned as stored in the document. Use [PdfRichMediaAsset::filters()]
+    /// to determine which stream filters, if any, must be reversed before use.
+    pub fn rich_media_annotations(&self) -> Vec<PdfPageRichMediaAnnotation<'_>> {
+        // Pdfium's annotation indices are positions in the page's /Annots array, so each
+        // annotation is paired with the raw array entry at the same position. Annotations
+        // that Pdfium cannot load are skipped without affecting the indices of the others.
+
+        let annotations = (0..self.annotations().len())
+            .filter_map(|index| {
+                self.annotations()
+                    .get(index)
+                    .ok()
+                    .map(|annotation| (index, annotation))
+            })
+            .filter(|(_, annotation)| {
+                annotation.annotation_type() == PdfPageAnnotationType::RichMedia
+            })
+            .collect::<Vec<_>>();
+
+        if annotations.is_empty() {
+            return Vec::new();
+        }
+
+        let mut assets =
+            PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
+                .zip(get_pdfium_document_bytes(
+                    self.document_handle,
+                    self.bindings,
+                ))
+                .and_then(|(page_index, bytes)| {
+                    rich_media_assets(bytes.as_slice(), page_index as usize)
+                })
+                .unwrap_or_default();
+
+        annotations
+            .into_iter()
+            .map(|(index, annotation)| {
+                let assets = assets
+                    .get_mut(index)
+                    .and_then(|assets| assets.take())
+                    .unwrap_or_default();
+
+                PdfPageRichMediaAnnotation::new(
+                    annotation,
+                    assets
+                        .into_iter()
+                        .map(|asset| {
+                            PdfRichMediaAsset::new(
+                                asset.name,
+                                asset.mime_type,
+                                asset.filters,
+                                asset.data,
+                            )
+                        })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
     /// Returns an immutable collection of the bounding boxes defining the extents of this [PdfPage].
     #[inline]
     pub fn boundaries(&self) -> &PdfPageBoundaries<'a> {
@@ -461,9 +780,65 @@ impl<'a> PdfPage<'a> {
             .collect()
     }
 
+    /// Adds a text watermark containing the given text to this [PdfPage], centered on the page's
+    /// crop box (or, if the page has no crop box, its media box) and styled according to
+    /// the given [PdfWatermarkOptions]. The newly created text object is returned, wrapped
+    /// inside a generic [PdfPageObject] wrapper.
+    ///
+    /// If the watermark is positioned with [PdfWatermarkPosition::BelowContent], the watermark
+    /// is moved to the start of the page's drawing order using
+    /// [PdfPageObjects::set_object_index()], preserving the relative order of all existing
+    /// page objects.
+    pub fn add_watermark(
+        &mut self,
+        text: impl ToString,
+        options: &PdfWatermarkOptions,
+    ) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let visible = self
+            .boundaries()
+            .crop()
+            .or_else(|_| self.boundaries().media())
+            .map(|boundary| boundary.bounds)
+            .unwrap_or_else(|_| self.page_size());
+
+        let mut watermark = self.objects_mut().create_text_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            text,
+            options.font(),
+            options.font_size(),
+        )?;
+
+        watermark.set_fill_color(options.fill_color())?;
+
+        // Rotate the watermark around its own center, then move that center
+        // to the center of the visible area of the page. The text's bounds do not
+        // start at its origin, since glyphs can extend below the baseline.
+
+        let bounds = watermark.bounds()?.to_rect();
+
+        watermark.translate(
+            -(bounds.left() + bounds.width() / 2.0),
+            -(bounds.bottom() + bounds.height() / 2.0),
+        )?;
+        watermark.rotate_counter_clockwise_degrees(options.rotation_degrees())?;
+        watermark.translate(
+            visible.left() + visible.width() / 2.0,
+            visible.bottom() + visible.height() / 2.0,
+        )?;
+
+        if options.position() == PdfWatermarkPosition::BelowContent {
+            self.objects_mut().set_object_index(&watermark, 0)?;
+        }
+
+        Ok(watermark)
+    }
+
     /// Converts from a bitmap coordinate system, measured in [Pixels] and with constraints
     /// and dimensions determined by the given [PdfRenderConfig] object, to the equivalent
     /// position on this page, measured in [PdfPoints].
+    #[doc(alias = "device_to_page")]
+    #[doc(alias = "FPDF_DeviceToPage")]
     pub fn pixels_to_points(
         &self,
         x: Pixels,
@@ -477,10 +852,10 @@ impl<'a> PdfPage<'a> {
 
         if self.bindings.is_true(self.bindings.FPDF_DeviceToPage(
             self.page_handle,
-            settings.clipping.left as c_int,
-            settings.clipping.top as c_int,
-            (settings.clipping.right - settings.clipping.left) as c_int,
-            (settings.clipping.bottom - settings.clipping.top) as c_int,
+            settings.page_rect.left as c_int,
+            settings.page_rect.top as c_int,
+            (settings.page_rect.right - settings.page_rect.left) as c_int,
+            (settings.page_rect.bottom - settings.page_rect.top) as c_int,
             settings.rotate,
             x as c_int,
             y as c_int,
@@ -496,6 +871,8 @@ impl<'a> PdfPage<'a> {
     /// Converts from the page coordinate system, measured in [PdfPoints], to the equivalent position
     /// in a bitmap coordinate system measured in [Pixels] and with constraints and dimensions
     /// defined by the given [PdfRenderConfig] object.
+    #[doc(alias = "page_to_device")]
+    #[doc(alias = "FPDF_PageToDevice")]
     pub fn points_to_pixels(
         &self,
         x: PdfPoints,
@@ -509,10 +886,10 @@ impl<'a> PdfPage<'a> {
 
         if self.bindings.is_true(self.bindings.FPDF_PageToDevice(
             self.page_handle,
-            settings.clipping.left as c_int,
-            settings.clipping.top as c_int,
-            (settings.clipping.right - settings.clipping.left) as c_int,
-            (settings.clipping.bottom - settings.clipping.top) as c_int,
+            settings.page_rect.left as c_int,
+            settings.page_rect.top as c_int,
+            (settings.page_rect.right - settings.page_rect.left) as c_int,
+            (settings.page_rect.bottom - settings.page_rect.top) as c_int,
             settings.rotate,
             x.value.into(),
             y.value.into(),
@@ -525,6 +902,17 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns a [PdfScale] for measuring real-world distances and areas on this [PdfPage],
+    /// using the given drawing scale and this page's user unit. For a drawing at a scale
+    /// of 1:100, pass a drawing scale of `100.0`.
+    ///
+    /// The user unit is retrieved using [PdfPage::user_unit()]; any error reading the user unit
+    /// is returned.
+    #[inline]
+    pub fn measure(&self, drawing_scale: f32) -> Result<PdfScale, PdfiumError> {
+        Ok(PdfScale::new(drawing_scale).set_user_unit(self.user_unit()?))
+    }
+
     /// Renders this [PdfPage] into a [PdfBitmap] with the given pixel dimensions and page rotation.
     ///
     /// It is the responsibility of the caller to ensure the given pixel width and height
@@ -581,6 +969,157 @@ impl<'a> PdfPage<'a> {
         Ok(bitmap)
     }
 
+    /// Renders this [PdfPage] into a new [PdfBitmap] with the given pixel dimensions, applying
+    /// the given transformation matrix directly to the page during rendering. This allows
+    /// arbitrary affine transforms, such as mirroring or shearing, that cannot be expressed
+    /// using the rotation presets in [PdfRenderConfig].
+    ///
+    /// The matrix maps from device space at a resolution of 72 dpi, i.e. one pixel per
+    /// [PdfPoints], to the pixels of the new bitmap. In device space the origin is at the
+    /// top-left corner of the page and `y` values increase downwards, so the identity matrix
+    /// renders the page at its natural size into the top-left corner of the bitmap. For example,
+    /// to mirror a page horizontally into a bitmap the same size as the page, use the matrix
+    /// `PdfMatrix::new(-1.0, 0.0, 0.0, 1.0, page.width().value, 0.0)`.
+    ///
+    /// The given [PdfRenderConfig] supplies the pixel format, clear color, and rendering flags.
+    /// Its target dimensions, rotation settings, transformations, and clipping rectangle
+    /// are ignored. As with any transformed rendering, form data is not rendered; consider using
+    /// the [PdfPage::flatten()] function to flatten form data into the page before rendering.
+    pub fn render_with_matrix(
+        &self,
+        matrix: PdfMatrix,
+        width: Pixels,
+        height: Pixels,
+        config: &PdfRenderConfig,
+    ) -> Result<PdfBitmap<'_>, PdfiumError> {
+        if matrix.determinant() == 0.0 {
+            return Err(PdfiumError::InvalidTransformationMatrix);
+        }
+
+        let mut settings = config.apply_to_page(self);
+
+        settings.width = width;
+        settings.height = height;
+        settings.rotate = 0;
+        settings.do_render_form_data = false;
+        settings.form_field_highlight = None;
+        settings.matrix = matrix.as_pdfium();
+        settings.clipping = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: width as f32,
+            bottom: height as f32,
+        };
+
+        let mut bitmap = PdfBitmap::empty(
+            width,
+            height,
+            PdfBitmapFormat::from_pdfium(settings.format as u32)
+                .unwrap_or_else(|_| PdfBitmapFormat::default()),
+            self.bindings,
+        )?;
+
+        self.render_into_bitmap_with_settings(&mut bitmap, settings)?;
+
+        Ok(bitmap)
+    }
+
+    /// Renders this [PdfPage] into a new [PdfBitmap] using the given [PdfRenderConfig],
+    /// including only the given page objects. All other page objects are hidden during rendering.
+    ///
+    /// Pdfium provides no means of hiding individual page objects, so this function temporarily
+    /// detaches all other page objects from this [PdfPage] before rendering, then reattaches them
+    /// in their original order once rendering is complete. The content stream of this [PdfPage]
+    /// is not regenerated as a result. Any given objects that do not belong to this [PdfPage]
+    /// are ignored.
+    ///
+    /// If any page object cannot be detached, nothing is rendered, the objects already detached
+    /// are reattached, and an error is returned.
+    ///
+    /// Annotations and form data are rendered according to the given [PdfRenderConfig]. To render
+    /// the given page objects in isolation, disable both using the [PdfRenderConfig::render_annotations()]
+    /// and [PdfRenderConfig::render_form_data()] functions.
+    pub fn render_subset(
+        &mut self,
+        objects: &[PdfPageObject],
+        config: &PdfRenderConfig,
+    ) -> Result<PdfBitmap<'a>, PdfiumError> {
+        let visible = objects
+            .iter()
+            .map(|object| object.object_handle())
+            .collect::<Vec<_>>();
+
+        let all = (0..self.bindings.FPDFPage_CountObjects(self.page_handle))
+            .map(|index| self.bindings.FPDFPage_GetObject(self.page_handle, index))
+            .collect::<Vec<_>>();
+
+        // Detach every hidden object, keeping track of exactly which objects were detached
+        // so that only those objects are reattached afterwards.
+
+        let mut detached = Vec::new();
+
+        let mut result = Ok(());
+
+        for handle in all.iter().filter(|handle| !visible.contains(handle)) {
+            if self.bindings.is_true(
+                self.bindings
+                    .FPDFPage_RemoveObject(self.page_handle, *handle),
+            ) {
+                detached.push(*handle);
+            } else {
+                result = Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+
+                break;
+            }
+        }
+
+        let result = result.and_then(|_| {
+            let settings = config.apply_to_page(self);
+
+            let mut bitmap = PdfBitmap::empty(
+                settings.width as Pixels,
+                settings.height as Pixels,
+                PdfBitmapFormat::from_pdfium(settings.format as u32)
+                    .unwrap_or_else(|_| PdfBitmapFormat::default()),
+                self.bindings,
+            )?;
+
+            self.render_into_bitmap_with_settings(&mut bitmap, settings)?;
+
+            Ok(bitmap)
+        });
+
+        // Restore the original object order. Every object from the first detached object
+        // onwards needs to be re-appended in its original order: detached objects are simply
+        // reattached, while objects that remained attached are moved to the end of the page.
+
+        let first_detached = all
+            .iter()
+            .position(|handle| detached.contains(handle))
+            .unwrap_or(all.len());
+
+        let mut restored = Ok(());
+
+        for handle in all[first_detached..].iter() {
+            if detached.contains(handle) {
+                self.bindings
+                    .FPDFPage_InsertObject(self.page_handle, *handle);
+            } else if restored.is_ok() {
+                // If an attached object cannot be moved, the remaining detached objects
+                // must still be reattached, even though the original order is lost.
+
+                restored =
+                    PdfPageObjects::reorder_objects(self.page_handle, &[*handle], self.bindings);
+            }
+        }
+
+        let bitmap = result?;
+
+        restored.map(|_| bitmap)
+    }
+
     /// Renders this [PdfPage] into the given [PdfBitmap] using the given pixel dimensions
     /// and page rotation.
     ///
@@ -630,6 +1169,23 @@ impl<'a> PdfPage<'a> {
         bitmap: &mut PdfBitmap,
         settings: PdfPageRenderSettings,
     ) -> Result<(), PdfiumError> {
+        self.render_rows_into_bitmap(bitmap, &settings, 0, settings.height);
+
+        bitmap.set_byte_order_from_render_settings(&settings);
+
+        Ok(())
+    }
+
+    /// Renders the given number of rows of this [PdfPage], starting at the given row, into
+    /// the given [PdfBitmap] using the given [PdfRenderSettings]. Row 0 is the top row of the
+    /// full rendered page; the first rendered row is drawn at the top of the given bitmap.
+    fn render_rows_into_bitmap(
+        &self,
+        bitmap: &mut PdfBitmap,
+        settings: &PdfPageRenderSettings,
+        first_row: c_int,
+        row_count: c_int,
+    ) {
         let bitmap_handle = *bitmap.handle();
 
         if settings.do_clear_bitmap_before_rendering {
@@ -640,20 +1196,22 @@ impl<'a> PdfPage<'a> {
                 0,
                 0,
                 settings.width,
-                settings.height,
+                row_count,
                 settings.clear_color,
             );
         }
 
         if settings.do_render_form_data {
             // Render the PDF page into the bitmap buffer, ignoring any custom transformation matrix.
-            // (Custom transforms cannot be applied to the rendering of form fields.)
+            // (Custom transforms cannot be applied to the rendering of form fields.) The page
+            // is offset upwards so that the first requested row lands at the top of the bitmap;
+            // Pdfium discards anything drawn outside the bitmap.
 
             self.bindings.FPDF_RenderPageBitmap(
                 bitmap_handle,
                 self.page_handle,
                 0,
-                0,
+                -first_row,
                 settings.width,
                 settings.height,
                 settings.rotate,
@@ -681,7 +1239,7 @@ impl<'a> PdfPage<'a> {
                     bitmap_handle,
                     self.page_handle,
                     0,
-                    0,
+                    -first_row,
                     settings.width,
                     settings.height,
                     settings.rotate,
@@ -690,17 +1248,76 @@ impl<'a> PdfPage<'a> {
             }
         } else {
             // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
+            // The matrix and clipping rectangle are both translated upwards so that the first
+            // requested row lands at the top of the bitmap.
+
+            let offset = first_row as f32;
+
+            let mut matrix = settings.matrix;
+
+            matrix.f -= offset;
+
+            let mut clipping = settings.clipping;
+
+            clipping.top -= offset;
+            clipping.bottom -= offset;
 
             self.bindings.FPDF_RenderPageBitmapWithMatrix(
                 bitmap_handle,
                 self.page_handle,
-                &settings.matrix,
-                &settings.clipping,
+                &matrix,
+                &clipping,
                 settings.render_flags,
             );
         }
+    }
 
-        bitmap.set_byte_order_from_render_settings(&settings);
+    /// Renders this [PdfPage] in horizontal bands using pixel dimensions, page rotation settings,
+    /// and rendering options configured in the given [PdfRenderConfig], calling the given
+    /// callback once for each band, from top to bottom.
+    ///
+    /// The callback receives the index of the first row in the band, measured from the top of
+    /// the full rendered page, and the raw bitmap buffer holding the band. The buffer holds
+    /// every row in the band, one after another; each row occupies `stride` bytes, where
+    /// `stride` is the length of the buffer divided by the number of rows in the band.
+    /// Pixels are in the pixel format configured in the given [PdfRenderConfig], in the same
+    /// byte order as [PdfBitmap::as_raw_bytes()] would return for the full rendered page.
+    ///
+    /// Only a single band is held in memory at once; each band's buffer is freed before the
+    /// next band is rendered. This allows very large pages to be rendered at high resolution,
+    /// and piped directly to an encoder, without holding the entire rendered image in memory.
+    /// Use [PdfRenderConfig::set_render_band_height()] to set the height of each band;
+    /// by default, the page is rendered in a single band. Every band is rendered using exactly
+    /// the same page transformation as [PdfPage::render_with_config()], so the concatenated
+    /// bands match the output of that function.
+    pub fn render_to_callback(
+        &self,
+        config: &PdfRenderConfig,
+        mut f: impl FnMut(u32, &[u8]),
+    ) -> Result<(), PdfiumError> {
+        let settings = config.apply_to_page(self);
+
+        let format = PdfBitmapFormat::from_pdfium(settings.format as u32)
+            .unwrap_or_else(|_| PdfBitmapFormat::default());
+
+        let mut first_row = 0;
+
+        while first_row < settings.height {
+            let row_count = settings.band_height.min(settings.height - first_row);
+
+            let mut band = PdfBitmap::empty(
+                settings.width as Pixels,
+                row_count as Pixels,
+                format,
+                self.bindings,
+            )?;
+
+            self.render_rows_into_bitmap(&mut band, &settings, first_row, row_count);
+
+            band.with_pixels_mut(|buffer, _, _, _| f(first_row as u32, buffer));
+
+            first_row += row_count;
+        }
 
         Ok(())
     }
@@ -829,6 +1446,156 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Resizes this [PdfPage] to the given paper size, scaling and translating the existing
+    /// content of the page so that it fits the new page size according to the given
+    /// [PdfPageFitMode].
+    ///
+    /// The page's media box is replaced with a media box of the given size with its origin
+    /// at `(0, 0)`, and the page's crop box is reset to match. The source size is taken
+    /// from the page's existing media box; any rotation applied to the page is not
+    /// taken into account. Content is clipped to the new media box.
+    ///
+    /// Only the page's content is transformed. Annotations and form fields keep their
+    /// existing positions; consider flattening them into the page content first.
+    ///
+    /// Returns [PdfiumError::PageSizeInvalid] if the width or height of the page's existing
+    /// media box is zero or negative, since the content cannot then be scaled.
+    pub fn scale_to_fit(
+        &mut self,
+        target: PdfPagePaperSize,
+        mode: PdfPageFitMode,
+    ) -> Result<(), PdfiumError> {
+        let source = self.boundaries().media()?.bounds;
+
+        let target_width = target.width().value;
+
+        let target_height = target.height().value;
+
+        let source_width = source.width().value;
+
+        let source_height = source.height().value;
+
+        if source_width <= 0.0 || source_height <= 0.0 {
+            return Err(PdfiumError::PageSizeInvalid);
+        }
+
+        let horizontal_scale = target_width / source_width;
+
+        let vertical_scale = target_height / source_height;
+
+        let (horizontal_scale, vertical_scale) = match mode {
+            PdfPageFitMode::Stretch => (horizontal_scale, vertical_scale),
+            PdfPageFitMode::Contain => {
+                let scale = horizontal_scale.min(vertical_scale);
+
+                (scale, scale)
+            }
+            PdfPageFitMode::Cover => {
+                let scale = horizontal_scale.max(vertical_scale);
+
+                (scale, scale)
+            }
+        };
+
+        // Move the origin of the existing media box to (0, 0), scale the content,
+        // then center the scaled content within the new page size.
+
+        let matrix = PdfMatrix::new(
+            horizontal_scale,
+            0.0,
+            0.0,
+            vertical_scale,
+            (target_width - source_width * horizontal_scale) / 2.0
+                - source.left().value * horizontal_scale,
+            (target_height - source_height * vertical_scale) / 2.0
+                - source.bottom().value * vertical_scale,
+        );
+
+        let target_rect = PdfRect::new(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            target.height(),
+            target.width(),
+        );
+
+        self.apply_matrix_with_clip(matrix, target_rect)?;
+
+        self.boundaries_mut().set_media(target_rect)?;
+        self.boundaries_mut().set_crop(target_rect)
+    }
+
+    /// Returns the smallest rectangle that contains the transformed bounds of every
+    /// page object on this [PdfPage], as returned by [PdfPageObjects::bounding_box()],
+    /// limited to the page's media box.
+    ///
+    /// If the page contains no page objects with visible bounds inside the media box,
+    /// the full media box is returned.
+    pub fn content_bounds(&self) -> PdfRect {
+        let media = self
+            .boundaries()
+            .media()
+            .map(|boundary| boundary.bounds)
+            .unwrap_or_else(|_| self.page_size());
+
+        match self.objects().bounding_box() {
+            Ok(content) => {
+                let bottom = content.bottom().value.max(media.bottom().value);
+                let left = content.left().value.max(media.left().value);
+                let top = content.top().value.min(media.top().value);
+                let right = content.right().value.min(media.right().value);
+
+                if bottom < top && left < right {
+                    PdfRect::new_from_values(bottom, left, top, right)
+                } else {
+                    // All content lies outside the media box.
+
+                    media
+                }
+            }
+            Err(_) => media,
+        }
+    }
+
+    /// Sets the crop box of this [PdfPage] to the bounds of the page's content, as returned
+    /// by [PdfPage::content_bounds()], expanded on every side by the given margin.
+    /// The resulting crop box never extends beyond the page's media box.
+    ///
+    /// A blank page is cropped to its full media box.
+    pub fn autocrop(&mut self, margin: PdfPoints) -> Result<(), PdfiumError> {
+        let media = self.boundaries().media()?.bounds;
+
+        let content = self.content_bounds();
+
+        let crop = PdfRect::new(
+            (content.bottom() - margin).max(media.bottom()),
+            (content.left() - margin).max(media.left()),
+            (content.top() + margin).min(media.top()),
+            (content.right() + margin).min(media.right()),
+        );
+
+        self.boundaries_mut().set_crop(crop)
+    }
+
+    /// Converts this [PdfPage] into a Scalable Vector Graphics (SVG) document, returning
+    /// the document as a string. Text is emitted as SVG `<text>` elements and images are
+    /// embedded as base64-encoded data URIs. Use the [PdfPage::to_svg_with_config()] function
+    /// to emit text as vector outlines instead.
+    #[inline]
+    pub fn to_svg(&self) -> Result<String, PdfiumError> {
+        self.to_svg_with_config(&PdfSvgConfig::new())
+    }
+
+    /// Converts this [PdfPage] into a Scalable Vector Graphics (SVG) document using the given
+    /// [PdfSvgConfig], returning the document as a string.
+    ///
+    /// Path, text, and image objects are converted; shading objects and the contents of
+    /// form XObjects are not yet supported and are omitted. The page's rotation and
+    /// crop box are ignored, so the SVG document always covers the page's media box.
+    #[inline]
+    pub fn to_svg_with_config(&self, config: &PdfSvgConfig) -> Result<String, PdfiumError> {
+        page_to_svg(self, config)
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
@@ -899,41 +1666,189 @@ impl<'a> PdfPage<'a> {
         }
     }
 
-    /// Deletes this [PdfPage] from its containing `PdfPages` collection, consuming this [PdfPage].
-    pub fn delete(self) -> Result<(), PdfiumError> {
-        let index = PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
-            .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+    /// Replaces the transparent content on this [PdfPage] with an opaque image rendered at
+    /// the given resolution, expressed in dots per inch, producing a page that prints consistently
+    /// on output devices that handle transparency groups and blend modes poorly. Returns `true`
+    /// if any content was flattened, or `false` if no page object contains transparency.
+    ///
+    /// Only the region covered by page objects containing transparency is rasterized.
+    /// The rendered image includes every page object painted beneath the topmost transparent
+    /// object, so that blending against the backdrop is preserved; it takes the place of
+    /// that topmost object in the page's drawing order, and the transparent objects are removed.
+    /// Opaque objects painted above the topmost transparent object, and opaque content outside
+    /// the transparent region, remain as vector content.
+    ///
+    /// Annotations and form fields are not flattened.
+    #[cfg(feature = "image")]
+    pub fn flatten_transparency(&mut self, dpi: f32) -> Result<bool, PdfiumError> {
+        // Determine which objects contain transparency, and the region they cover.
 
-        self.bindings
-            .FPDFPage_Delete(self.document_handle, index as c_int);
+        let mut transparent = Vec::new();
 
-        PdfPageIndexCache::delete_pages_at_index(self.document_handle, index, 1);
+        let mut region: Option<(f32, f32, f32, f32)> = None;
 
-        Ok(())
-    }
+        for (index, object) in self.objects().iter().enumerate() {
+            if !object.has_transparency() {
+                continue;
+            }
 
-    /// Returns the strategy used by `pdfium-render` to regenerate the content of a [PdfPage].
-    ///
-    /// Updates to a [PdfPage] are not committed to the underlying `PdfDocument` until the page's
-    /// content is regenerated. If a page is reloaded or closed without regenerating the page's
-    /// content, all uncommitted changes will be lost.
-    ///
-    /// By default, `pdfium-render` will trigger content regeneration on any change to a [PdfPage];
-    /// this removes the possibility of data loss, and ensures changes can be read back from other
-    /// data structures as soon as they are made. However, if many changes are made to a page at once,
-    /// then regenerating the content after every change is inefficient; it is faster to stage
-    /// all changes first, then regenerate the page's content just once. In this case,
-    /// changing the content regeneration strategy for a [PdfPage] can improve performance,
-    /// but you must be careful not to forget to commit your changes before closing
-    /// or reloading the page.
-    #[inline]
-    pub fn content_regeneration_strategy(&self) -> PdfPageContentRegenerationStrategy {
-        self.regeneration_strategy
-    }
+            transparent.push(index);
 
-    /// Sets the strategy used by `pdfium-render` to regenerate the content of a [PdfPage].
-    ///
-    /// Updates to a [PdfPage] are not committed to the underlying `PdfDocument` until the page's
+            if let Ok(bounds) = object.bounds() {
+                let (bottom, left, top, right) = (
+                    bounds.bottom().value,
+                    bounds.left().value,
+                    bounds.top().value,
+                    bounds.right().value,
+                );
+
+                region = Some(match region {
+                    Some((b, l, t, r)) => (b.min(bottom), l.min(left), t.max(top), r.max(right)),
+                    None => (bottom, left, top, right),
+                });
+            }
+        }
+
+        let topmost = match transparent.last() {
+            Some(index) => *index,
+            None => return Ok(false),
+        };
+
+        let (bottom, left, top, right) = match region {
+            Some(region) => region,
+            None => return Ok(false),
+        };
+
+        // Render everything up to and including the topmost transparent object. The page's
+        // intrinsic rotation is temporarily removed so that bitmap pixels map directly onto
+        // the page's coordinate space.
+
+        let visible = self
+            .boundaries()
+            .crop()
+            .or_else(|_| self.boundaries().media())
+            .map(|boundary| boundary.bounds)
+            .unwrap_or_else(|_| self.page_size());
+
+        let rotation = self.rotation()?;
+
+        if rotation != PdfPageRenderRotation::None {
+            self.set_rotation(PdfPageRenderRotation::None);
+        }
+
+        let rendered = (0..=topmost)
+            .map(|index| self.objects().get(index))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|objects| {
+                self.render_subset(
+                    objects.as_slice(),
+                    &PdfRenderConfig::new()
+                        .set_target_dpi(dpi)
+                        .render_annotations(false)
+                        .render_form_data(false),
+                )
+                .map(|bitmap| (bitmap.width(), bitmap.height(), bitmap.as_image()))
+            });
+
+        if rotation != PdfPageRenderRotation::None {
+            self.set_rotation(rotation);
+        }
+
+        let (width, height, rendered) = rendered?;
+
+        let scale_x = width as f32 / visible.width().value;
+
+        let scale_y = height as f32 / visible.height().value;
+
+        // Convert the region into whole pixels, then convert the pixel boundaries back into
+        // page coordinates so that the image is placed exactly where it was rendered.
+
+        let x0 = ((left - visible.left().value) * scale_x).floor().max(0.0) as u32;
+
+        let x1 = ((right - visible.left().value) * scale_x)
+            .ceil()
+            .min(width as f32) as u32;
+
+        let y0 = ((visible.top().value - top) * scale_y).floor().max(0.0) as u32;
+
+        let y1 = ((visible.top().value - bottom) * scale_y)
+            .ceil()
+            .min(height as f32) as u32;
+
+        if x1 <= x0 || y1 <= y0 {
+            // The transparent content lies entirely outside the visible area of the page.
+
+            return Ok(false);
+        }
+
+        let image = DynamicImage::ImageRgb8(rendered.crop_imm(x0, y0, x1 - x0, y1 - y0).to_rgb8());
+
+        let image_object = self.objects_mut().create_image_object(
+            PdfPoints::new(visible.left().value + x0 as f32 / scale_x),
+            PdfPoints::new(visible.top().value - y1 as f32 / scale_y),
+            &image,
+            Some(PdfPoints::new((x1 - x0) as f32 / scale_x)),
+            Some(PdfPoints::new((y1 - y0) as f32 / scale_y)),
+        )?;
+
+        let image_object = self.objects_mut().remove_object(image_object)?;
+
+        // The image takes the place of the topmost transparent object. The remaining transparent
+        // objects all lie beneath it, so removing them in reverse order leaves the indices of
+        // those yet to be removed unchanged.
+
+        let replaced = self.objects().get(topmost)?;
+
+        self.objects_mut().replace_object(replaced, image_object)?;
+
+        for index in transparent.iter().rev().skip(1) {
+            let object = self.objects().get(*index)?;
+
+            self.objects_mut().remove_object(object)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Deletes this [PdfPage] from its containing `PdfPages` collection, consuming this [PdfPage].
+    pub fn delete(self) -> Result<(), PdfiumError> {
+        let index = PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
+            .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+
+        self.bindings
+            .FPDFPage_Delete(self.document_handle, index as c_int);
+
+        PdfPageIndexCache::delete_pages_at_index(self.document_handle, index, 1);
+
+        if let Some(dictionaries) = self.dictionaries.as_ref() {
+            dictionaries.lock().unwrap().invalidate();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the strategy used by `pdfium-render` to regenerate the content of a [PdfPage].
+    ///
+    /// Updates to a [PdfPage] are not committed to the underlying `PdfDocument` until the page's
+    /// content is regenerated. If a page is reloaded or closed without regenerating the page's
+    /// content, all uncommitted changes will be lost.
+    ///
+    /// By default, `pdfium-render` will trigger content regeneration on any change to a [PdfPage];
+    /// this removes the possibility of data loss, and ensures changes can be read back from other
+    /// data structures as soon as they are made. However, if many changes are made to a page at once,
+    /// then regenerating the content after every change is inefficient; it is faster to stage
+    /// all changes first, then regenerate the page's content just once. In this case,
+    /// changing the content regeneration strategy for a [PdfPage] can improve performance,
+    /// but you must be careful not to forget to commit your changes before closing
+    /// or reloading the page.
+    #[inline]
+    pub fn content_regeneration_strategy(&self) -> PdfPageContentRegenerationStrategy {
+        self.regeneration_strategy
+    }
+
+    /// Sets the strategy used by `pdfium-render` to regenerate the content of a [PdfPage].
+    ///
+    /// Updates to a [PdfPage] are not committed to the underlying `PdfDocument` until the page's
     /// content is regenerated. If a page is reloaded or closed without regenerating the page's
     /// content, all uncommitted changes will be lost.
     ///
@@ -1061,9 +1976,220 @@ impl<'a> Drop for PdfPage<'a> {
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-    use crate::utils::test::test_bind_to_pdfium;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
     use image_025::{GenericImageView, ImageFormat};
 
+    #[test]
+    fn test_text_in_reading_order_follows_struct_tree() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // A tagged two-column page whose content stream draws the right-hand column before
+        // the left-hand column. The structure tree places the left-hand column first.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /StructTreeRoot 5 0 R /MarkInfo << \
+            /Marked true >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents 4 0 R \
+            /StructParents 0 /Resources << /Font << /F1 << /Type /Font /Subtype /Type1 \
+            /BaseFont /Helvetica >> >> >> >>",
+            b"<< >>\nstream\n\
+            /P << /MCID 0 >> BDC BT /F1 12 Tf 320 700 Td (Right column) Tj ET EMC\n/P << \
+            /MCID 1 >> BDC BT /F1 12 Tf 72 700 Td (Left column) Tj ET EMC",
+            b"<< /Type /StructTreeRoot /K 6 0 R /ParentTree << /Nums [0 [8 0 R 7 0 R]] >> >>",
+            b"<< /Type /StructElem /S /Document /P 5 0 R /K [7 0 R 8 0 R] >>",
+            b"<< /Type /StructElem /S /P /P 6 0 R /Pg 3 0 R /K 1 >>",
+            b"<< /Type /StructElem /S /P /P 6 0 R /Pg 3 0 R /K 0 >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(
+            document.text_in_reading_order()?,
+            "Left column\nRight column"
+        );
+
+        // An untagged page falls back to Pdfium's own text ordering.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "Untagged",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        assert_eq!(page.text_in_reading_order()?, page.text()?.all());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_user_unit() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 400 300] /UserUnit 72 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+        ]);
+
+        let mut document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        {
+            let page = document.pages().get(0)?;
+
+            assert_eq!(page.user_unit()?, 72.0);
+
+            // A 400 x 300 unit page with a user unit of 72 measures 400 x 300 inches.
+
+            assert_eq!(page.width().value * page.user_unit()? / 72.0, 400.0);
+        }
+
+        let second = document.pages().get(1)?;
+
+        assert_eq!(second.user_unit()?, 1.0);
+
+        document.pages_mut().set_user_unit(1, 36.0)?;
+
+        // The unsaved value is visible through both the page and the page collection.
+
+        assert_eq!(document.pages().user_unit(1)?, 36.0);
+        assert_eq!(second.user_unit()?, 36.0);
+        assert!(document.pages_mut().set_user_unit(1, 0.0).is_err());
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(reloaded.pages().get(0)?.user_unit()?, 72.0);
+        assert_eq!(reloaded.pages().get(1)?.user_unit()?, 36.0);
+
+        // The new user unit follows its page when an earlier page is deleted.
+
+        document.pages().get(0)?.delete()?;
+
+        assert_eq!(document.pages().user_unit(0)?, 36.0);
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(reloaded.pages().len(), 1);
+        assert_eq!(reloaded.pages().get(0)?.user_unit()?, 36.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rich_media_annotations() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mp4: &[u8] = b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00mp42isom";
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R 5 0 R] >>",
+            b"<< /Type /Annot /Subtype /Text /Rect [0 0 10 10] >>",
+            b"<< /Type /Annot /Subtype /RichMedia /Rect [100 100 300 250] /RichMediaContent << \
+            /Assets << /Names [(clip.mp4) 6 0 R] >> >> >>",
+            b"<< /Type /Filespec /F (clip.mp4) /UF (clip.mp4) /EF << /F 7 0 R >> >>",
+            b"<< /Type /EmbeddedFile /Subtype /video#2Fmp4 >>\nstream\n\
+            \x00\x00\x00\x18ftypmp42\x00\x00\x00\x00mp42isom",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotations = page.rich_media_annotations();
+
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(
+            annotations[0].annotation().annotation_type(),
+            PdfPageAnnotationType::RichMedia
+        );
+
+        let assets = annotations[0].assets();
+
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].name(), "clip.mp4");
+        assert_eq!(assets[0].mime_type(), Some("video/mp4"));
+        assert!(!assets[0].is_encoded());
+        assert_eq!(assets[0].raw_bytes(), mp4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotation_inherited_from_page_tree() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The rotation and media box are set on the page tree node and inherited by
+        // the first and third pages. The second page overrides the inherited rotation.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R 4 0 R 5 0 R] /Count 3 /Rotate 90 /MediaBox \
+            [0 0 200 100] >>",
+            b"<< /Type /Page /Parent 2 0 R >>",
+            b"<< /Type /Page /Parent 2 0 R /Rotate 180 >>",
+            b"<< /Type /Page /Parent 2 0 R >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let rotations = document
+            .pages()
+            .iter()
+            .map(|page| page.rotation())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            rotations,
+            vec![
+                PdfPageRenderRotation::Degrees90,
+                PdfPageRenderRotation::Degrees180,
+                PdfPageRenderRotation::Degrees90,
+            ]
+        );
+
+        // Changing the rotation of the first page must not affect the third page,
+        // which continues to inherit its rotation from the page tree.
+
+        document
+            .pages()
+            .first()?
+            .set_rotation(PdfPageRenderRotation::None);
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let rotations = document
+            .pages()
+            .iter()
+            .map(|page| page.rotation())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            rotations,
+            vec![
+                PdfPageRenderRotation::None,
+                PdfPageRenderRotation::Degrees180,
+                PdfPageRenderRotation::Degrees90,
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_page_rendering_reusing_bitmap() -> Result<(), PdfiumError> {
         // Renders each page in the given test PDF file to a separate JPEG file
@@ -1094,6 +2220,73 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_watermark() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Existing content",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.add_watermark(
+            "CONFIDENTIAL",
+            &PdfWatermarkOptions::new(font)
+                .set_color(PdfColor::RED)
+                .set_position(PdfWatermarkPosition::BelowContent),
+        )?;
+
+        // The watermark should be the first object on the page, beneath the existing content.
+
+        assert_eq!(
+            page.objects()
+                .first()?
+                .as_text_object()
+                .map(|object| object.text()),
+            Some("CONFIDENTIAL".to_string())
+        );
+
+        assert!(page.text()?.all().contains("CONFIDENTIAL"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_watermark_centers_on_crop_box() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.boundaries_mut()
+            .set_crop(PdfRect::new_from_values(100.0, 50.0, 300.0, 250.0))?;
+
+        let watermark = page.add_watermark("DRAFT", &PdfWatermarkOptions::new(font))?;
+
+        let bounds = watermark.bounds()?.to_rect();
+
+        assert!(((bounds.left() + bounds.width() / 2.0).value - 150.0).abs() < 0.01);
+        assert!(((bounds.bottom() + bounds.height() / 2.0).value - 200.0).abs() < 0.01);
+
+        Ok(())
+    }
+
     #[test]
     fn test_rendered_image_dimension() -> Result<(), PdfiumError> {
         // Checks that downscaled dimensions are rounded correctly during page rendering.
@@ -1117,4 +2310,539 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rendered_image_dimension_at_target_dpi() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let size = PdfPagePaperSize::from_points(PdfPoints::new(612.0), PdfPoints::new(792.0));
+
+        document.pages_mut().create_page_at_end(size)?;
+
+        document.pages_mut().create_page_at_end(size.rotate())?;
+
+        let page = document.pages().get(0)?;
+
+        let settings = PdfRenderConfig::new()
+            .set_target_dpi(150.0)
+            .apply_to_page(&page);
+
+        assert_eq!((settings.width, settings.height), (1275, 1650));
+
+        let page = document.pages().get(1)?;
+
+        let settings = PdfRenderConfig::new()
+            .set_target_dpi(150.0)
+            .rotate_if_landscape(PdfPageRenderRotation::Degrees90, true)
+            .apply_to_page(&page);
+
+        assert_eq!((settings.width, settings.height), (1275, 1650));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_mirroring_matrix() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::from_points(
+                PdfPoints::new(200.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        // Fill the left quarter of the page with red, leaving the rest of the page blank.
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 100.0, 50.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let config = PdfRenderConfig::new();
+
+        let normal = page.render_with_matrix(PdfMatrix::IDENTITY, 200, 100, &config)?;
+
+        let mirrored = page.render_with_matrix(
+            PdfMatrix::new(-1.0, 0.0, 0.0, 1.0, 200.0, 0.0),
+            200,
+            100,
+            &config,
+        )?;
+
+        assert_eq!(normal.get_pixel(10, 50)?.red(), 255);
+        assert_eq!(normal.get_pixel(10, 50)?.green(), 0);
+        assert_eq!(mirrored.get_pixel(189, 50)?.green(), 0);
+        assert_eq!(mirrored.get_pixel(10, 50)?.green(), 255);
+
+        for x in [0, 25, 49, 50, 100, 150, 199] {
+            for y in [0, 50, 99] {
+                let expected = normal.get_pixel(199 - x, y)?;
+
+                let actual = mirrored.get_pixel(x, y)?;
+
+                assert_eq!(
+                    (actual.red(), actual.green(), actual.blue()),
+                    (expected.red(), expected.green(), expected.blue())
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_subset_hides_other_objects() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::from_points(
+                PdfPoints::new(200.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        // Fill the left half of the page with red and the right half with blue.
+
+        let red = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 100.0, 100.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 100.0, 100.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        let config = PdfRenderConfig::new()
+            .render_annotations(false)
+            .render_form_data(false);
+
+        let subset = page.render_subset(&[red], &config)?;
+
+        assert_eq!(subset.get_pixel(50, 50)?.red(), 255);
+        assert_eq!(subset.get_pixel(50, 50)?.green(), 0);
+        assert_eq!(subset.get_pixel(150, 50)?.red(), 255);
+        assert_eq!(subset.get_pixel(150, 50)?.green(), 255);
+        assert_eq!(subset.get_pixel(150, 50)?.blue(), 255);
+
+        // All objects should be restored in their original order after rendering.
+
+        assert_eq!(page.objects().len(), 2);
+        assert_eq!(page.objects().get(0)?.fill_color()?.red(), 255);
+        assert_eq!(page.objects().get(1)?.fill_color()?.blue(), 255);
+
+        let full = page.render_with_config(&config)?;
+
+        assert_eq!(full.get_pixel(150, 50)?.blue(), 255);
+        assert_eq!(full.get_pixel(150, 50)?.red(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scale_to_fit_contain_keeps_content_centered() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let target = PdfPagePaperSize::a4();
+
+        for (width, height) in [(200.0, 400.0), (400.0, 200.0)] {
+            let mut page =
+                document
+                    .pages_mut()
+                    .create_page_at_end(PdfPagePaperSize::from_points(
+                        PdfPoints::new(width),
+                        PdfPoints::new(height),
+                    ))?;
+
+            let mut object = page.objects_mut().create_text_object(
+                PdfPoints::ZERO,
+                PdfPoints::ZERO,
+                "Centered",
+                font,
+                PdfPoints::new(24.0),
+            )?;
+
+            let bounds = object.bounds()?;
+
+            object.translate(
+                PdfPoints::new(width / 2.0) - (bounds.left() + bounds.right()) / 2.0,
+                PdfPoints::new(height / 2.0) - (bounds.bottom() + bounds.top()) / 2.0,
+            )?;
+
+            page.scale_to_fit(target, PdfPageFitMode::Contain)?;
+
+            assert!((page.width() - target.width()).value.abs() < 0.01);
+            assert!((page.height() - target.height()).value.abs() < 0.01);
+
+            let bounds = page.objects().first()?.bounds()?;
+
+            let center_x = ((bounds.left() + bounds.right()) / 2.0).value;
+
+            let center_y = ((bounds.bottom() + bounds.top()) / 2.0).value;
+
+            assert!((center_x - target.width().value / 2.0).abs() < 1.0);
+            assert!((center_y - target.height().value / 2.0).abs() < 1.0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scale_to_fit_rejects_empty_media_box() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        page.boundaries_mut().set_media(PdfRect::new(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            PdfPoints::new(100.0),
+            PdfPoints::ZERO,
+        ))?;
+
+        assert!(matches!(
+            page.scale_to_fit(PdfPagePaperSize::a4(), PdfPageFitMode::Contain),
+            Err(PdfiumError::PageSizeInvalid)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_autocrop_shrinks_crop_box_to_content() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        // A blank page is cropped to its full media box.
+
+        let media = page.boundaries().media()?.bounds;
+
+        assert_eq!(page.content_bounds(), media);
+
+        page.autocrop(PdfPoints::new(10.0))?;
+
+        assert_eq!(page.boundaries().crop()?.bounds, media);
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(300.0, 200.0, 500.0, 400.0),
+            None,
+            None,
+            Some(PdfColor::BLACK),
+        )?;
+
+        let content = page.content_bounds();
+
+        assert!((content.bottom().value - 300.0).abs() < 0.5);
+        assert!((content.left().value - 200.0).abs() < 0.5);
+        assert!((content.top().value - 500.0).abs() < 0.5);
+        assert!((content.right().value - 400.0).abs() < 0.5);
+
+        page.autocrop(PdfPoints::new(10.0))?;
+
+        let crop = page.boundaries().crop()?.bounds;
+
+        assert!((crop.bottom().value - 290.0).abs() < 0.5);
+        assert!((crop.left().value - 190.0).abs() < 0.5);
+        assert!((crop.top().value - 510.0).abs() < 0.5);
+        assert!((crop.right().value - 410.0).abs() < 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_background_color() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 50.0, 100.0),
+            None,
+            None,
+            Some(PdfColor::BLACK),
+        )?;
+
+        let config = PdfRenderConfig::new()
+            .set_target_size(100, 100)
+            .set_background_color(PdfColor::RED);
+
+        let bitmap = page.render_with_config(&config)?;
+
+        // The background shows through the uncovered right half of the page...
+
+        let background = bitmap.get_pixel(75, 50)?;
+
+        assert_eq!(
+            (background.red(), background.green(), background.blue()),
+            (255, 0, 0)
+        );
+
+        // ... but not through the page content covering the left half.
+
+        let content = bitmap.get_pixel(25, 50)?;
+
+        assert_eq!((content.red(), content.green(), content.blue()), (0, 0, 0));
+
+        // A fully transparent background preserves the alpha channel.
+
+        let config = PdfRenderConfig::new()
+            .set_target_size(100, 100)
+            .set_background_color(PdfColor::new(0, 0, 0, 0));
+
+        let bitmap = page.render_with_config(&config)?;
+
+        assert_eq!(bitmap.get_pixel(75, 50)?.alpha(), 0);
+        assert_eq!(bitmap.get_pixel(25, 50)?.alpha(), 255);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_transparency_preserves_appearance() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(20.0, 20.0, 120.0, 120.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let mut multiply = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(70.0, 70.0, 170.0, 170.0),
+            None,
+            None,
+            Some(PdfColor::YELLOW),
+        )?;
+
+        multiply.set_blend_mode(PdfPageObjectBlendMode::Multiply)?;
+
+        // An opaque object outside the transparent region.
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(20.0, 150.0, 60.0, 190.0),
+            None,
+            None,
+            Some(PdfColor::BLACK),
+        )?;
+
+        assert!(page.has_transparency());
+
+        // 200 points at 144 DPI is 400 pixels.
+
+        let config = PdfRenderConfig::new().set_target_dpi(144.0);
+
+        let mut before = PdfBitmap::empty(400, 400, PdfBitmapFormat::default(), pdfium.bindings())?;
+
+        page.render_into_bitmap_with_config(&mut before, &config)?;
+
+        assert!(page.flatten_transparency(144.0)?);
+
+        let mut after = PdfBitmap::empty(400, 400, PdfBitmapFormat::default(), pdfium.bindings())?;
+
+        page.render_into_bitmap_with_config(&mut after, &config)?;
+
+        let (_, difference) = before.diff(&after, 16)?;
+
+        assert!(difference < 0.01);
+
+        assert!(!page.has_transparency());
+        assert_eq!(page.objects().len(), 3);
+        assert!(page.objects().get(0)?.as_path_object().is_some());
+        assert!(page.objects().get(1)?.as_image_object().is_some());
+        assert!(page.objects().get(2)?.as_path_object().is_some());
+
+        // A page without transparency is left untouched.
+
+        assert!(!page.flatten_transparency(144.0)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pixels_to_points_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let config = PdfRenderConfig::new()
+            .set_target_dpi(150.0)
+            .rotate(PdfPageRenderRotation::Degrees90, true);
+
+        for (x, y) in [(0, 0), (123, 456), (1000, 700)] {
+            let (page_x, page_y) = page.pixels_to_points(x, y, &config)?;
+
+            let (device_x, device_y) = page.points_to_pixels(page_x, page_y, &config)?;
+
+            assert!((device_x - x).abs() <= 1);
+            assert!((device_y - y).abs() <= 1);
+        }
+
+        assert_eq!(page.measure(100.0)?.user_unit(), 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_to_callback_matches_render_with_config() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(300.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(20.0, 20.0, 280.0, 180.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        // Check both the form data rendering path and the matrix rendering path, with a band
+        // height that does not evenly divide the rendered page height.
+
+        for config in [
+            PdfRenderConfig::new()
+                .set_target_dpi(100.0)
+                .rotate(PdfPageRenderRotation::Degrees90, true),
+            PdfRenderConfig::new()
+                .set_target_dpi(100.0)
+                .rotate(PdfPageRenderRotation::Degrees90, true)
+                .render_form_data(false),
+        ] {
+            let expected = page.render_with_config(&config)?.as_raw_bytes();
+
+            let mut rows = Vec::new();
+
+            let mut actual = Vec::new();
+
+            page.render_to_callback(&config.set_render_band_height(37), |row, band| {
+                rows.push(row);
+                actual.extend_from_slice(band);
+            })?;
+
+            assert_eq!(rows[0], 0);
+            assert_eq!(rows[1], 37);
+            assert!(rows.len() > 2);
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_clip_rect_matches_full_render() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(300.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(120.0, 20.0, 280.0, 180.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        let config = PdfRenderConfig::new()
+            .set_target_width(400)
+            .render_form_data(false);
+
+        let full = page.render_with_config(&config)?;
+
+        // Render only the top left quarter of the page.
+
+        let config = config.set_clip_rect(PdfRect::new_from_values(150.0, 0.0, 300.0, 100.0));
+
+        let region = page.render_with_config(&config)?;
+
+        assert_eq!((region.width(), region.height()), (200, 300));
+
+        let full_bytes = full.as_raw_bytes();
+
+        let region_bytes = region.as_raw_bytes();
+
+        let full_stride = full_bytes.len() / full.height() as usize;
+
+        let region_stride = region_bytes.len() / region.height() as usize;
+
+        for row in 0..300 {
+            assert_eq!(
+                &region_bytes[row * region_stride..(row + 1) * region_stride],
+                &full_bytes[row * full_stride..row * full_stride + region_stride]
+            );
+        }
+
+        assert_eq!(
+            page.points_to_pixels(PdfPoints::new(50.0), PdfPoints::new(225.0), &config)?,
+            (100, 150)
+        );
+
+        // Re-enabling form data rendering must not disable the region.
+
+        let region = page.render_with_config(&config.render_form_data(true))?;
+
+        assert_eq!((region.width(), region.height()), (200, 300));
+
+        assert_eq!(region.as_raw_bytes(), region_bytes);
+
+        Ok(())
+    }
 }
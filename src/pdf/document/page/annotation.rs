@@ -22,7 +22,10 @@ pub mod xfa_widget;
 
 use crate::bindgen::{
     FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_CARET, FPDF_ANNOT_CIRCLE,
-    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
+    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_FLAG_INVISIBLE,
+    FPDF_ANNOT_FLAG_LOCKED, FPDF_ANNOT_FLAG_NOROTATE, FPDF_ANNOT_FLAG_NOVIEW,
+    FPDF_ANNOT_FLAG_NOZOOM, FPDF_ANNOT_FLAG_PRINT, FPDF_ANNOT_FLAG_READONLY,
+    FPDF_ANNOT_FLAG_TOGGLENOVIEW, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
     FPDF_ANNOT_LINE, FPDF_ANNOT_LINK, FPDF_ANNOT_MOVIE, FPDF_ANNOT_POLYGON, FPDF_ANNOT_POLYLINE,
     FPDF_ANNOT_POPUP, FPDF_ANNOT_PRINTERMARK, FPDF_ANNOT_REDACT, FPDF_ANNOT_RICHMEDIA,
     FPDF_ANNOT_SCREEN, FPDF_ANNOT_SOUND, FPDF_ANNOT_SQUARE, FPDF_ANNOT_SQUIGGLY, FPDF_ANNOT_STAMP,
@@ -55,11 +58,106 @@ use crate::pdf::document::page::annotation::xfa_widget::PdfPageXfaWidgetAnnotati
 use crate::pdf::document::page::field::PdfFormField;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
+use bitflags::bitflags;
 use chrono::prelude::*;
 
 #[cfg(doc)]
 use crate::pdf::document::page::PdfPage;
 
+bitflags! {
+    /// The flags set on a single [PdfPageAnnotation], as defined in table 8.16 of the
+    /// PDF Reference, version 1.7, on page 608. These flags control whether, and how,
+    /// the annotation is displayed on screen and when printed.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct PdfAnnotationFlags: u32 {
+        /// Do not display the annotation if it is not one of the standard annotation types.
+        const INVISIBLE = FPDF_ANNOT_FLAG_INVISIBLE;
+
+        /// Do not display or print the annotation, or allow it to interact with the user.
+        const HIDDEN = FPDF_ANNOT_FLAG_HIDDEN;
+
+        /// Print the annotation when the page is printed.
+        const PRINT = FPDF_ANNOT_FLAG_PRINT;
+
+        /// Do not scale the annotation's appearance to match the magnification of the page.
+        const NO_ZOOM = FPDF_ANNOT_FLAG_NOZOOM;
+
+        /// Do not rotate the annotation's appearance to match the rotation of the page.
+        const NO_ROTATE = FPDF_ANNOT_FLAG_NOROTATE;
+
+        /// Do not display the annotation on screen or allow it to interact with the user.
+        /// The annotation may still be printed, depending on the [PdfAnnotationFlags::PRINT] flag.
+        const NO_VIEW = FPDF_ANNOT_FLAG_NOVIEW;
+
+        /// Do not allow the annotation to interact with the user.
+        const READ_ONLY = FPDF_ANNOT_FLAG_READONLY;
+
+        /// Do not allow the annotation to be deleted, or its properties to be modified.
+        const LOCKED = FPDF_ANNOT_FLAG_LOCKED;
+
+        /// Invert the interpretation of the [PdfAnnotationFlags::NO_VIEW] flag for certain events.
+        const TOGGLE_NO_VIEW = FPDF_ANNOT_FLAG_TOGGLENOVIEW;
+    }
+}
+
+impl PdfAnnotationFlags {
+    /// Returns `true` if an annotation with these flags should be included when printing,
+    /// i.e. if the [PdfAnnotationFlags::PRINT] flag is set and the
+    /// [PdfAnnotationFlags::HIDDEN] flag is not.
+    #[inline]
+    pub fn is_printable(&self) -> bool {
+        self.contains(PdfAnnotationFlags::PRINT) && !self.is_hidden()
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::INVISIBLE] flag is set.
+    #[inline]
+    pub fn is_invisible(&self) -> bool {
+        self.contains(PdfAnnotationFlags::INVISIBLE)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::HIDDEN] flag is set.
+    #[inline]
+    pub fn is_hidden(&self) -> bool {
+        self.contains(PdfAnnotationFlags::HIDDEN)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::NO_ZOOM] flag is set.
+    #[inline]
+    pub fn is_no_zoom(&self) -> bool {
+        self.contains(PdfAnnotationFlags::NO_ZOOM)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::NO_ROTATE] flag is set.
+    #[inline]
+    pub fn is_no_rotate(&self) -> bool {
+        self.contains(PdfAnnotationFlags::NO_ROTATE)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::NO_VIEW] flag is set.
+    #[inline]
+    pub fn is_no_view(&self) -> bool {
+        self.contains(PdfAnnotationFlags::NO_VIEW)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::READ_ONLY] flag is set.
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.contains(PdfAnnotationFlags::READ_ONLY)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::LOCKED] flag is set.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.contains(PdfAnnotationFlags::LOCKED)
+    }
+
+    /// Returns `true` if the [PdfAnnotationFlags::TOGGLE_NO_VIEW] flag is set.
+    #[inline]
+    pub fn is_toggle_no_view(&self) -> bool {
+        self.contains(PdfAnnotationFlags::TOGGLE_NO_VIEW)
+    }
+}
+
 /// The type of a single [PdfPageAnnotation], as defined in table 8.20 of the PDF Reference,
 /// version 1.7, on page 615.
 ///
@@ -925,9 +1023,15 @@ pub trait PdfPageAnnotationCommon {
     /// form for providing accessibility to users with disabilities or for other purposes.
     fn set_contents(&mut self, contents: &str) -> Result<(), PdfiumError>;
 
-    /// Returns the name of the creator of this [PdfPageAnnotation], if any.
+    /// Returns the name of the creator of this [PdfPageAnnotation], if any. For markup
+    /// annotations such as reviewer comments, this is the author of the annotation,
+    /// stored in the `/T` entry of the annotation dictionary.
     fn creator(&self) -> Option<String>;
 
+    /// Sets the name of the creator of this [PdfPageAnnotation]. For markup annotations
+    /// such as reviewer comments, this is the author of the annotation.
+    fn set_creator(&mut self, creator: &str) -> Result<(), PdfiumError>;
+
     /// Returns the date and time when this [PdfPageAnnotation] was originally created, if any.
     fn creation_date(&self) -> Option<String>;
 
@@ -952,6 +1056,38 @@ pub trait PdfPageAnnotationCommon {
     /// Sets the color of any stroked paths in this [PdfPageAnnotation].
     fn set_stroke_color(&mut self, stroke_color: PdfColor) -> Result<(), PdfiumError>;
 
+    /// Returns the border or stroke color stored in the `C` entry of this [PdfPageAnnotation]'s
+    /// dictionary, if any.
+    ///
+    /// Unlike [PdfPageAnnotationCommon::stroke_color()], this function reads only
+    /// the annotation dictionary. It returns `None` if the annotation has no color entry,
+    /// or if the annotation is using appearance streams.
+    fn color(&self) -> Option<PdfColor>;
+
+    /// Sets the border or stroke color stored in the `C` entry of this [PdfPageAnnotation]'s
+    /// dictionary. Pdfium will not change the color of an annotation that is using
+    /// appearance streams.
+    fn set_color(&mut self, color: PdfColor) -> Result<(), PdfiumError>;
+
+    /// Returns the interior or fill color stored in the `IC` entry of this [PdfPageAnnotation]'s
+    /// dictionary, if any.
+    ///
+    /// Unlike [PdfPageAnnotationCommon::fill_color()], this function reads only
+    /// the annotation dictionary. It returns `None` if the annotation has no interior color entry,
+    /// or if the annotation is using appearance streams.
+    fn interior_color(&self) -> Option<PdfColor>;
+
+    /// Sets the interior or fill color stored in the `IC` entry of this [PdfPageAnnotation]'s
+    /// dictionary. Pdfium will not change the color of an annotation that is using
+    /// appearance streams.
+    fn set_interior_color(&mut self, color: PdfColor) -> Result<(), PdfiumError>;
+
+    /// Returns the [PdfAnnotationFlags] set on this [PdfPageAnnotation].
+    fn flags(&self) -> PdfAnnotationFlags;
+
+    /// Replaces the [PdfAnnotationFlags] set on this [PdfPageAnnotation] with the given flags.
+    fn set_flags(&mut self, flags: PdfAnnotationFlags) -> Result<(), PdfiumError>;
+
     /// Returns an immutable collection of all the page objects in this [PdfPageAnnotation].
     ///
     /// Page objects can be retrieved from any type of [PdfPageAnnotation], but Pdfium currently
@@ -1044,6 +1180,11 @@ where
         self.creator_impl()
     }
 
+    #[inline]
+    fn set_creator(&mut self, creator: &str) -> Result<(), PdfiumError> {
+        self.set_creator_impl(creator)
+    }
+
     #[inline]
     fn creation_date(&self) -> Option<String> {
         self.creation_date_impl()
@@ -1084,6 +1225,36 @@ where
         self.set_stroke_color_impl(stroke_color)
     }
 
+    #[inline]
+    fn color(&self) -> Option<PdfColor> {
+        self.color_impl()
+    }
+
+    #[inline]
+    fn set_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_color_impl(color)
+    }
+
+    #[inline]
+    fn interior_color(&self) -> Option<PdfColor> {
+        self.interior_color_impl()
+    }
+
+    #[inline]
+    fn set_interior_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_interior_color_impl(color)
+    }
+
+    #[inline]
+    fn flags(&self) -> PdfAnnotationFlags {
+        self.flags_impl()
+    }
+
+    #[inline]
+    fn set_flags(&mut self, flags: PdfAnnotationFlags) -> Result<(), PdfiumError> {
+        self.set_flags_impl(flags)
+    }
+
     #[inline]
     fn objects(&self) -> &PdfPageAnnotationObjects {
         self.objects_impl()
@@ -3,9 +3,208 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::appearance_mode::PdfAppearanceMode;
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::fonts::PdfFontBuiltin;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use std::fmt::Write;
+
+#[cfg(doc)]
+use crate::pdf::document::page::annotations::PdfPageAnnotations;
+
+/// The horizontal alignment of the text in a [PdfPageFreeTextAnnotation].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PdfFreeTextAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Configures the font, font size, color, and alignment of the text in a
+/// [PdfPageFreeTextAnnotation] created by the [PdfPageAnnotations::create_free_text()] function.
+#[derive(Copy, Clone, Debug)]
+pub struct PdfFreeTextOptions {
+    font: PdfFontBuiltin,
+    font_size: PdfPoints,
+    color: PdfColor,
+    alignment: PdfFreeTextAlignment,
+}
+
+impl PdfFreeTextOptions {
+    /// Creates a new [PdfFreeTextOptions] object with all settings initialized with their
+    /// default values: 12 point black Helvetica, aligned to the left.
+    #[inline]
+    pub fn new() -> Self {
+        PdfFreeTextOptions {
+            font: PdfFontBuiltin::Helvetica,
+            font_size: PdfPoints::new(12.0),
+            color: PdfColor::BLACK,
+            alignment: PdfFreeTextAlignment::Left,
+        }
+    }
+
+    /// Sets the built-in font used to display the text.
+    #[inline]
+    pub fn set_font(mut self, font: PdfFontBuiltin) -> Self {
+        self.font = font;
+
+        self
+    }
+
+    /// Sets the font size used to display the text.
+    #[inline]
+    pub fn set_font_size(mut self, font_size: PdfPoints) -> Self {
+        self.font_size = font_size;
+
+        self
+    }
+
+    /// Sets the color used to display the text.
+    #[inline]
+    pub fn set_color(mut self, color: PdfColor) -> Self {
+        self.color = color;
+
+        self
+    }
+
+    /// Sets the horizontal alignment of the text within the bounds of the annotation.
+    #[inline]
+    pub fn set_alignment(mut self, alignment: PdfFreeTextAlignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Returns the built-in font used to display the text.
+    #[inline]
+    pub fn font(&self) -> PdfFontBuiltin {
+        self.font
+    }
+
+    /// Returns the font size used to display the text.
+    #[inline]
+    pub fn font_size(&self) -> PdfPoints {
+        self.font_size
+    }
+
+    /// Returns the color used to display the text.
+    #[inline]
+    pub fn color(&self) -> PdfColor {
+        self.color
+    }
+
+    /// Returns the horizontal alignment of the text within the bounds of the annotation.
+    #[inline]
+    pub fn alignment(&self) -> PdfFreeTextAlignment {
+        self.alignment
+    }
+
+    /// Returns the default appearance string for these options, suitable for use as the
+    /// `/DA` entry of a free text annotation dictionary.
+    pub(crate) fn default_appearance(&self) -> String {
+        format!(
+            "/{} {} Tf {}",
+            self.font.to_pdf_font_name(),
+            self.font_size.value,
+            color_operator(self.color),
+        )
+    }
+
+    /// Returns an appearance stream that draws the given text inside the given bounds
+    /// using these options. Each line of the text is measured using the given closure
+    /// so that it can be aligned.
+    pub(crate) fn appearance_stream(
+        &self,
+        bounds: PdfRect,
+        text: &str,
+        measure: impl Fn(&str) -> f32,
+    ) -> String {
+        let font_size = self.font_size.value;
+
+        let leading = font_size * 1.2;
+
+        let mut stream = format!(
+            "q {} {} {} {} re W n BT {} ",
+            bounds.left().value,
+            bounds.bottom().value,
+            bounds.width().value,
+            bounds.height().value,
+            self.default_appearance(),
+        );
+
+        let mut baseline = bounds.top().value - font_size;
+
+        for line in text.lines() {
+            let x = match self.alignment {
+                PdfFreeTextAlignment::Left => bounds.left().value,
+                PdfFreeTextAlignment::Center => {
+                    bounds.left().value + (bounds.width().value - measure(line)) / 2.0
+                }
+                PdfFreeTextAlignment::Right => bounds.right().value - measure(line),
+            };
+
+            // Writing to a String cannot fail.
+
+            let _ = write!(
+                stream,
+                "1 0 0 1 {} {} Tm ({}) Tj ",
+                x,
+                baseline,
+                escape_string(line)
+            );
+
+            baseline -= leading;
+        }
+
+        stream.push_str("ET Q");
+
+        stream
+    }
+}
+
+impl Default for PdfFreeTextOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfFreeTextOptions::new()
+    }
+}
+
+/// Returns the content stream operator that sets the given color as the fill color.
+fn color_operator(color: PdfColor) -> String {
+    format!(
+        "{} {} {} rg",
+        color.red() as f32 / 255.0,
+        color.green() as f32 / 255.0,
+        color.blue() as f32 / 255.0,
+    )
+}
+
+/// Escapes the given text for use as a literal string in a content stream. Characters
+/// outside the Latin-1 range cannot be represented and are replaced with `?`.
+fn escape_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                result.push('\\');
+                result.push(c);
+            }
+            ' '..='~' => result.push(c),
+            '\u{a0}'..='\u{ff}' => {
+                let _ = write!(result, "\\{:03o}", c as u32);
+            }
+            _ => result.push('?'),
+        }
+    }
+
+    result
+}
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::FreeText`.
 pub struct PdfPageFreeTextAnnotation<'a> {
@@ -37,6 +236,21 @@ impl<'a> PdfPageFreeTextAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Sets the default appearance string, the `/DA` entry, of this annotation.
+    #[inline]
+    pub(crate) fn set_default_appearance(&mut self, appearance: &str) -> Result<(), PdfiumError> {
+        self.set_string_value("DA", appearance)
+    }
+
+    /// Replaces the normal appearance stream of this annotation with the given content stream.
+    pub(crate) fn set_normal_appearance(&mut self, content: &str) -> Result<(), PdfiumError> {
+        self.bindings.to_result(self.bindings.FPDFAnnot_SetAP_str(
+            self.handle,
+            PdfAppearanceMode::Normal as i32,
+            content,
+        ))
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
@@ -70,3 +284,29 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_free_text_appearance_stream() {
+        let options = PdfFreeTextOptions::new()
+            .set_font_size(PdfPoints::new(10.0))
+            .set_color(PdfColor::RED)
+            .set_alignment(PdfFreeTextAlignment::Center);
+
+        assert_eq!(options.default_appearance(), "/Helvetica 10 Tf 1 0 0 rg");
+
+        let bounds = PdfRect::new_from_values(0.0, 100.0, 50.0, 300.0);
+
+        let stream =
+            options.appearance_stream(bounds, "a(b)\nc", |line| line.chars().count() as f32 * 10.0);
+
+        assert_eq!(
+            stream,
+            "q 100 0 200 50 re W n BT /Helvetica 10 Tf 1 0 0 rg \
+            1 0 0 1 180 40 Tm (a\\(b\\)) Tj 1 0 0 1 195 28 Tm (c) Tj ET Q"
+        );
+    }
+}
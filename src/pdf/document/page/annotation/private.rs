@@ -9,22 +9,24 @@ pub(crate) mod internal {
 
     use crate::bindgen::{
         FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
-        FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_OBJECT_STRING,
-        FPDF_PAGEOBJECT, FPDF_WCHAR, FS_RECTF,
+        FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDFANNOT_COLORTYPE,
+        FPDF_ANNOTATION, FPDF_OBJECT_STRING, FPDF_PAGEOBJECT, FPDF_WCHAR, FS_RECTF,
     };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::{PdfiumError, PdfiumInternalError};
     use crate::pdf::color::PdfColor;
     use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
     use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
-    use crate::pdf::document::page::annotation::{PdfPageAnnotationCommon, PdfPageAnnotationType};
+    use crate::pdf::document::page::annotation::{
+        PdfAnnotationFlags, PdfPageAnnotationCommon, PdfPageAnnotationType,
+    };
     use crate::pdf::points::PdfPoints;
     use crate::pdf::rect::PdfRect;
     use crate::utils::dates::date_time_to_pdf_string;
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
     use chrono::{DateTime, Utc};
-    use std::os::raw::c_uint;
+    use std::os::raw::{c_int, c_uint};
 
     /// Internal crate-specific functionality common to all [PdfPageAnnotation] objects.
     pub trait PdfPageAnnotationPrivate<'a>: PdfPageAnnotationCommon {
@@ -230,7 +232,7 @@ pub(crate) mod internal {
 
         /// Internal implementation of [PdfPageAnnotationCommon::set_creator()].
         #[inline]
-        fn set_creator(&mut self, creator: &str) -> Result<(), PdfiumError> {
+        fn set_creator_impl(&mut self, creator: &str) -> Result<(), PdfiumError> {
             self.set_string_value("T", creator)
         }
 
@@ -440,6 +442,105 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::color()].
+        #[inline]
+        fn color_impl(&self) -> Option<PdfColor> {
+            self.annotation_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_color()].
+        #[inline]
+        fn set_color_impl(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+            self.set_annotation_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, color)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::interior_color()].
+        #[inline]
+        fn interior_color_impl(&self) -> Option<PdfColor> {
+            self.annotation_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_interior_color()].
+        #[inline]
+        fn set_interior_color_impl(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+            self.set_annotation_color_impl(
+                FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor,
+                color,
+            )
+        }
+
+        /// Returns the color of the given type stored in this annotation's dictionary, if any.
+        /// Unlike [PdfPageAnnotationPrivate::fill_color_impl()] and
+        /// [PdfPageAnnotationPrivate::stroke_color_impl()], this function does not fall back
+        /// to page object colors if the annotation is using appearance streams.
+        fn annotation_color_impl(&self, color_type: FPDFANNOT_COLORTYPE) -> Option<PdfColor> {
+            let mut r: c_uint = 0;
+
+            let mut g: c_uint = 0;
+
+            let mut b: c_uint = 0;
+
+            let mut a: c_uint = 0;
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_GetColor(
+                self.handle(),
+                color_type,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )) {
+                Some(PdfColor::new(r as u8, g as u8, b as u8, a as u8))
+            } else {
+                None
+            }
+        }
+
+        /// Sets the color of the given type in this annotation's dictionary.
+        fn set_annotation_color_impl(
+            &mut self,
+            color_type: FPDFANNOT_COLORTYPE,
+            color: PdfColor,
+        ) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetColor(
+                self.handle(),
+                color_type,
+                color.red() as c_uint,
+                color.green() as c_uint,
+                color.blue() as c_uint,
+                color.alpha() as c_uint,
+            )) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::flags()].
+        #[inline]
+        fn flags_impl(&self) -> PdfAnnotationFlags {
+            PdfAnnotationFlags::from_bits_truncate(
+                self.bindings().FPDFAnnot_GetFlags(self.handle()) as u32,
+            )
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_flags()].
+        #[inline]
+        fn set_flags_impl(&mut self, flags: PdfAnnotationFlags) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(
+                self.bindings()
+                    .FPDFAnnot_SetFlags(self.handle(), flags.bits() as c_int),
+            ) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::objects()].
         fn objects_impl(&self) -> &PdfPageAnnotationObjects;
 
@@ -39,6 +39,11 @@ impl<'a> PdfPageStampAnnotation<'a> {
     }
 
     /// Returns a mutable collection of all the page objects in this [PdfPageStampAnnotation].
+    ///
+    /// Page objects added to this collection become part of the annotation's appearance
+    /// stream, allowing custom stamps to be composed from any combination of image, text,
+    /// and path objects. Objects are positioned in page coordinates, so they should be placed
+    /// within the annotation's bounds in order to be visible when the annotation is rendered.
     #[inline]
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
@@ -76,3 +81,65 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStampAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_stamp_annotation_renders_appended_image_object() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Rendering a blank page with a red clear color gives us a solid red image
+        // to use as the stamp's logo.
+
+        let logo = pdfium
+            .create_new_pdf()?
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?
+            .render_with_config(
+                &PdfRenderConfig::new()
+                    .set_target_size(20, 20)
+                    .set_clear_color(PdfColor::RED),
+            )?
+            .as_image();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let mut object = PdfPageImageObject::new_with_size(
+            &document,
+            &logo,
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+        )?;
+
+        object.translate(PdfPoints::new(100.0), PdfPoints::new(100.0))?;
+
+        let mut stamp = page.annotations_mut().create_stamp_annotation()?;
+
+        stamp.set_bounds(PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0))?;
+
+        stamp.objects_mut().add_image_object(object)?;
+
+        assert_eq!(stamp.objects().len(), 1);
+
+        // The centre of the stamp should now be rendered in red. Page coordinates have their
+        // origin at the bottom left of the page; bitmap coordinates have their origin at the top left.
+
+        let rendered = page
+            .render_with_config(&PdfRenderConfig::new().scale_page_by_factor(1.0))?
+            .as_image()
+            .to_rgba8();
+
+        let pixel = rendered.get_pixel(150, rendered.height() - 150);
+
+        assert_eq!((pixel[0], pixel[1], pixel[2]), (255, 0, 0));
+
+        Ok(())
+    }
+}
@@ -5,7 +5,9 @@ use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE}
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
-use crate::pdf::document::page::annotation::free_text::PdfPageFreeTextAnnotation;
+use crate::pdf::document::page::annotation::free_text::{
+    PdfFreeTextOptions, PdfPageFreeTextAnnotation,
+};
 use crate::pdf::document::page::annotation::highlight::PdfPageHighlightAnnotation;
 use crate::pdf::document::page::annotation::ink::PdfPageInkAnnotation;
 use crate::pdf::document::page::annotation::link::PdfPageLinkAnnotation;
@@ -22,7 +24,9 @@ use crate::pdf::document::page::annotation::{
 };
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::document::page::{PdfPage, PdfPageContentRegenerationStrategy, PdfPageIndexCache};
+use crate::pdf::font::PdfFont;
 use crate::pdf::quad_points::PdfQuadPoints;
+use crate::pdf::rect::PdfRect;
 use chrono::prelude::*;
 use std::ops::Range;
 use std::os::raw::c_int;
@@ -144,6 +148,15 @@ impl<'a> PdfPageAnnotations<'a> {
         PdfPageAnnotationsIterator::new(self)
     }
 
+    /// Returns an iterator over the annotations in this [PdfPageAnnotations] collection that
+    /// should be included when printing, i.e. those annotations whose flags include
+    /// `PdfAnnotationFlags::PRINT` but not `PdfAnnotationFlags::HIDDEN`.
+    #[inline]
+    pub fn printable(&self) -> impl Iterator<Item = PdfPageAnnotation<'_>> + '_ {
+        self.iter()
+            .filter(|annotation| annotation.flags().is_printable())
+    }
+
     /// Creates a new annotation of the given [PdfPageAnnotationType] by passing the result of calling
     /// `FPDFPage_CreateAnnot()` to an annotation constructor function.
     ///
@@ -224,6 +237,58 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
+    /// Creates a new [PdfPageFreeTextAnnotation] displaying the given text inside the given
+    /// bounds in this [PdfPageAnnotations] collection, styled using the given [PdfFreeTextOptions].
+    /// Returns the newly created annotation.
+    ///
+    /// The annotation's default appearance string is set from the given options, and a normal
+    /// appearance stream is generated so that the text is visible when the page is rendered.
+    /// Pdfium provides no means of writing numeric entries to an annotation dictionary,
+    /// so the requested alignment is applied when generating the appearance stream rather than
+    /// being recorded in the annotation's `/Q` entry. Pdfium is also unable to attach
+    /// font resources to the generated appearance stream; the text is rendered by Pdfium
+    /// in Helvetica if the requested built-in font is not available in the page's resources.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_free_text(
+        &mut self,
+        bounds: PdfRect,
+        text: &str,
+        options: PdfFreeTextOptions,
+    ) -> Result<PdfPageFreeTextAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_free_text_annotation(text)?;
+
+        annotation.set_bounds(bounds)?;
+        annotation.set_default_appearance(&options.default_appearance())?;
+
+        let font = self
+            .bindings()
+            .FPDFText_LoadStandardFont(self.document_handle(), options.font().to_pdf_font_name());
+
+        if font.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        // The font was loaded by FPDFText_LoadStandardFont(), so it will be closed
+        // when the PdfFont is dropped.
+
+        let font = PdfFont::from_pdfium(font, self.bindings(), Some(options.font()), true);
+
+        let appearance = options.appearance_stream(bounds, text, |line| {
+            font.measure_text(line, options.font_size())
+                .map(|width| width.value)
+                .unwrap_or(0.0)
+        });
+
+        annotation.set_normal_appearance(&appearance)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPageHighlightAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -274,6 +339,31 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
+    /// Creates a new [PdfPageLinkAnnotation] with the given URI in this [PdfPageAnnotations]
+    /// collection, positioning it so that it covers the given [PdfRect]. Clicking anywhere
+    /// inside the given bounds in a conforming PDF viewer will open the given URI.
+    /// Returns the newly created annotation.
+    ///
+    /// Pdfium does not currently provide any means of creating a link annotation with a
+    /// destination inside the current document; only URI actions are supported.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_link_annotation_in_rect(
+        &mut self,
+        bounds: PdfRect,
+        uri: &str,
+    ) -> Result<PdfPageLinkAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_link_annotation(uri)?;
+
+        // The link will not be clickable if it is not positioned.
+
+        annotation.set_bounds(bounds)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPagePopupAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -631,3 +721,214 @@ impl<'a> Iterator for PdfPageAnnotationsIterator<'a> {
         next.ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::dates::date_time_to_pdf_string;
+    use crate::utils::test::test_bind_to_pdfium;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_create_link_annotation_in_rect() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let bounds = PdfRect::new_from_values(20.0, 50.0, 40.0, 250.0);
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?
+            .annotations_mut()
+            .create_link_annotation_in_rect(bounds, "https://example.com/")?;
+
+        // Save and reload the document, then check that the link survived the round trip.
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        assert_eq!(page.links().len(), 1);
+
+        let link = page
+            .links()
+            .link_at_point(PdfPoints::new(100.0), PdfPoints::new(30.0))
+            .expect("link at point");
+
+        let uri = link
+            .action()
+            .and_then(|action| action.as_uri_action().map(|action| action.uri()));
+
+        assert_eq!(uri.transpose()?, Some("https://example.com/".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_printable_excludes_hidden_annotations() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let annotations = page.annotations_mut();
+
+        annotations
+            .create_square_annotation()?
+            .set_flags(PdfAnnotationFlags::PRINT)?;
+
+        annotations
+            .create_square_annotation()?
+            .set_flags(PdfAnnotationFlags::PRINT | PdfAnnotationFlags::HIDDEN)?;
+
+        annotations
+            .create_square_annotation()?
+            .set_flags(PdfAnnotationFlags::empty())?;
+
+        assert_eq!(page.annotations().len(), 3);
+
+        let printable = page.annotations().printable().collect::<Vec<_>>();
+
+        assert_eq!(printable.len(), 1);
+        assert_eq!(printable[0].flags(), PdfAnnotationFlags::PRINT);
+        assert!(page
+            .annotations()
+            .iter()
+            .any(|annotation| annotation.flags().is_hidden()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_square_annotation_color_and_interior_color() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        assert!(annotation.interior_color().is_none());
+
+        annotation.set_color(PdfColor::RED)?;
+        annotation.set_interior_color(PdfColor::BLUE)?;
+
+        let color = annotation.color().expect("color");
+
+        assert_eq!(color.red(), PdfColor::RED.red());
+        assert_eq!(color.green(), PdfColor::RED.green());
+        assert_eq!(color.blue(), PdfColor::RED.blue());
+
+        let interior_color = annotation.interior_color().expect("interior color");
+
+        assert_eq!(interior_color.red(), PdfColor::BLUE.red());
+        assert_eq!(interior_color.green(), PdfColor::BLUE.green());
+        assert_eq!(interior_color.blue(), PdfColor::BLUE.blue());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_free_text() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        let bounds = PdfRect::new_from_values(20.0, 20.0, 80.0, 180.0);
+
+        page.annotations_mut().create_free_text(
+            bounds,
+            "Note",
+            PdfFreeTextOptions::new()
+                .set_font_size(PdfPoints::new(14.0))
+                .set_color(PdfColor::RED)
+                .set_alignment(PdfFreeTextAlignment::Center),
+        )?;
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_size(200, 100)
+                .render_annotations(true),
+        )?;
+
+        let image = bitmap.as_image().into_rgb8();
+
+        // Collect the columns containing red pixels. All of them must fall within the bounds
+        // of the annotation, and they must be centered horizontally within those bounds.
+
+        let red_columns = image
+            .enumerate_pixels()
+            .filter(|(_, _, pixel)| pixel[0] > 200 && pixel[1] < 100 && pixel[2] < 100)
+            .map(|(x, _, _)| x)
+            .collect::<Vec<_>>();
+
+        assert!(!red_columns.is_empty());
+
+        let left = *red_columns.iter().min().unwrap();
+
+        let right = *red_columns.iter().max().unwrap();
+
+        assert!(left >= 20 && right <= 180);
+        assert!(((left + right) as i32 / 2 - 100).abs() <= 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_contents_and_creator_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let date = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+
+        let mut annotation = page.annotations_mut().create_text_annotation("")?;
+
+        annotation.set_contents("Please check this figure.")?;
+        annotation.set_creator("A. Reviewer")?;
+        annotation.set_modification_date(date)?;
+
+        // Save and reload the document, then check that the note text and author survived
+        // the round trip.
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().get(0)?;
+
+        assert_eq!(
+            annotation.contents(),
+            Some("Please check this figure.".to_string())
+        );
+        assert_eq!(annotation.creator(), Some("A. Reviewer".to_string()));
+        assert_eq!(
+            annotation.modification_date(),
+            Some(date_time_to_pdf_string(date))
+        );
+
+        Ok(())
+    }
+}
@@ -14,9 +14,11 @@ pub mod text;
 pub mod unknown;
 
 use crate::bindgen::{
-    FPDF_ANNOTATION, FPDF_FORMFIELD_CHECKBOX, FPDF_FORMFIELD_COMBOBOX, FPDF_FORMFIELD_LISTBOX,
-    FPDF_FORMFIELD_PUSHBUTTON, FPDF_FORMFIELD_RADIOBUTTON, FPDF_FORMFIELD_SIGNATURE,
-    FPDF_FORMFIELD_TEXTFIELD, FPDF_FORMFIELD_UNKNOWN, FPDF_FORMHANDLE,
+    FPDF_ANNOTATION, FPDF_ANNOT_AACTION_CALCULATE, FPDF_ANNOT_AACTION_FORMAT,
+    FPDF_ANNOT_AACTION_KEY_STROKE, FPDF_ANNOT_AACTION_VALIDATE, FPDF_FORMFIELD_CHECKBOX,
+    FPDF_FORMFIELD_COMBOBOX, FPDF_FORMFIELD_LISTBOX, FPDF_FORMFIELD_PUSHBUTTON,
+    FPDF_FORMFIELD_RADIOBUTTON, FPDF_FORMFIELD_SIGNATURE, FPDF_FORMFIELD_TEXTFIELD,
+    FPDF_FORMFIELD_UNKNOWN, FPDF_FORMHANDLE, FPDF_PAGE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
@@ -84,6 +86,38 @@ impl PdfFormFieldType {
     }
 }
 
+/// An event on a single interactive form field in a [PdfForm] that can trigger
+/// a JavaScript action, as defined in table 8.45 of the PDF Reference, version 1.7, on page 649.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfFormFieldTrigger {
+    /// The user types a keystroke into a text field or combo box, or modifies the selection
+    /// in a scrollable list box. The action can check the keystroke for validity and reject
+    /// or modify it.
+    Keystroke,
+
+    /// The field is about to be formatted in order to display its current value.
+    /// The action can modify the field's value before it is displayed.
+    Format,
+
+    /// The field's value has changed. The action can check the new value for validity.
+    Validate,
+
+    /// The value of another field has changed, so this field's value should be recalculated.
+    Calculate,
+}
+
+impl PdfFormFieldTrigger {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> u32 {
+        match self {
+            PdfFormFieldTrigger::Keystroke => FPDF_ANNOT_AACTION_KEY_STROKE,
+            PdfFormFieldTrigger::Format => FPDF_ANNOT_AACTION_FORMAT,
+            PdfFormFieldTrigger::Validate => FPDF_ANNOT_AACTION_VALIDATE,
+            PdfFormFieldTrigger::Calculate => FPDF_ANNOT_AACTION_CALCULATE,
+        }
+    }
+}
+
 /// A single interactive form field in a [PdfForm].
 pub enum PdfFormField<'a> {
     PushButton(PdfFormPushButtonField<'a>),
@@ -99,6 +133,7 @@ pub enum PdfFormField<'a> {
 impl<'a> PdfFormField<'a> {
     pub(crate) fn from_pdfium(
         form_handle: FPDF_FORMHANDLE,
+        page_handle: FPDF_PAGE,
         annotation_handle: FPDF_ANNOTATION,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Option<Self> {
@@ -131,6 +166,7 @@ impl<'a> PdfFormField<'a> {
             )),
             PdfFormFieldType::Text => PdfFormField::Text(PdfFormTextField::from_pdfium(
                 form_handle,
+                page_handle,
                 annotation_handle,
                 bindings,
             )),
@@ -295,6 +331,10 @@ pub trait PdfFormFieldCommon {
     /// Returns the value currently set for the given appearance mode for this [PdfFormField],
     /// if any.
     fn appearance_mode_value(&self, appearance_mode: PdfAppearanceMode) -> Option<String>;
+
+    /// Returns the JavaScript that runs when the given [PdfFormFieldTrigger] event occurs
+    /// on this [PdfFormField], if any.
+    fn action_script(&self, trigger: PdfFormFieldTrigger) -> Option<String>;
 }
 
 // Blanket implementation for all PdfFormFieldCommon types.
@@ -317,6 +357,11 @@ where
     fn appearance_mode_value(&self, appearance_mode: PdfAppearanceMode) -> Option<String> {
         self.appearance_mode_value_impl(appearance_mode)
     }
+
+    #[inline]
+    fn action_script(&self, trigger: PdfFormFieldTrigger) -> Option<String> {
+        self.action_script_impl(trigger)
+    }
 }
 
 impl<'a> PdfFormFieldPrivate<'a> for PdfFormField<'a> {
@@ -16,7 +16,7 @@ pub(crate) mod internal {
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::PdfiumError;
     use crate::pdf::appearance_mode::PdfAppearanceMode;
-    use crate::pdf::document::page::field::PdfFormFieldCommon;
+    use crate::pdf::document::page::field::{PdfFormFieldCommon, PdfFormFieldTrigger};
     use crate::utils::dates::date_time_to_pdf_string;
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
@@ -87,6 +87,41 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfFormFieldCommon::action_script()].
+        fn action_script_impl(&self, trigger: PdfFormFieldTrigger) -> Option<String> {
+            // Retrieving the script from Pdfium is a two-step operation, as for the field name.
+            // Pdfium returns a length of 2, the size of an empty null-terminated string, if no
+            // script is defined for the given event.
+
+            let buffer_length = self.bindings().FPDFAnnot_GetFormAdditionalActionJavaScript(
+                *self.form_handle(),
+                *self.annotation_handle(),
+                trigger.as_pdfium() as c_int,
+                std::ptr::null_mut(),
+                0,
+            );
+
+            if buffer_length <= 2 {
+                // No script is defined for the given event.
+
+                None
+            } else {
+                let mut buffer = create_byte_buffer(buffer_length as usize);
+
+                let result = self.bindings().FPDFAnnot_GetFormAdditionalActionJavaScript(
+                    *self.form_handle(),
+                    *self.annotation_handle(),
+                    trigger.as_pdfium() as c_int,
+                    buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+                    buffer_length,
+                );
+
+                debug_assert_eq!(result, buffer_length);
+
+                get_string_from_pdfium_utf16le_bytes(buffer)
+            }
+        }
+
         /// Internal implementation of `value()` function shared by value-carrying form field widgets
         /// such as text fields. Not exposed directly by [PdfFormFieldCommon].
         fn value_impl(&self) -> Option<String> {
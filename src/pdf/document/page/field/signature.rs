@@ -36,6 +36,13 @@ impl<'a> PdfFormSignatureField<'a> {
     pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
+
+    /// Returns `true` if this [PdfFormSignatureField] contains a signature value.
+    #[inline]
+    pub fn is_signed(&self) -> bool {
+        self.bindings
+            .is_true(self.bindings.FPDFAnnot_HasKey(self.annotation_handle, "V"))
+    }
 }
 
 impl<'a> PdfFormFieldPrivate<'a> for PdfFormSignatureField<'a> {
@@ -1,10 +1,11 @@
 //! Defines the [PdfFormTextField] struct, exposing functionality related to a single
 //! form field of type [PdfFormFieldType::Text].
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_FORMHANDLE};
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_FORMHANDLE, FPDF_PAGE, FPDF_WIDESTRING};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
+use crate::utils::utf16le::get_pdfium_utf16le_bytes_from_str;
 
 #[cfg(doc)]
 use {
@@ -22,6 +23,7 @@ use {
 /// [PdfForm::field_values()] function.
 pub struct PdfFormTextField<'a> {
     form_handle: FPDF_FORMHANDLE,
+    page_handle: FPDF_PAGE,
     annotation_handle: FPDF_ANNOTATION,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
@@ -30,11 +32,13 @@ impl<'a> PdfFormTextField<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         form_handle: FPDF_FORMHANDLE,
+        page_handle: FPDF_PAGE,
         annotation_handle: FPDF_ANNOTATION,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfFormTextField {
             form_handle,
+            page_handle,
             annotation_handle,
             bindings,
         }
@@ -53,9 +57,48 @@ impl<'a> PdfFormTextField<'a> {
     }
 
     /// Sets the value of this [PdfFormTextField] object.
-    #[inline]
+    ///
+    /// The value is entered into the field using Pdfium's form fill environment, as if it
+    /// had been typed by a user, so that the field's appearance stream is regenerated
+    /// to display the new value. This ensures the new value is visible when the page
+    /// is subsequently rendered or flattened. If the value cannot be entered this way,
+    /// for instance because the field is read-only, the field's value is set directly and
+    /// its appearance stream is removed, in which case the new value may not be visible
+    /// after the page is flattened.
     pub fn set_value(&mut self, value: &str) -> Result<(), PdfiumError> {
-        self.set_value_impl(value)
+        if self.set_value_with_form_fill(value) {
+            Ok(())
+        } else {
+            self.set_value_impl(value)
+        }
+    }
+
+    /// Focuses this [PdfFormTextField], replaces its text with the given value, and then
+    /// removes focus from the field so that Pdfium commits the new value and regenerates
+    /// the field's appearance stream. Returns `true` if the field's value was updated.
+    fn set_value_with_form_fill(&mut self, value: &str) -> bool {
+        let bindings = self.bindings();
+
+        if !bindings
+            .is_true(bindings.FORM_SetFocusedAnnot(self.form_handle, self.annotation_handle))
+        {
+            return false;
+        }
+
+        // FORM_SelectAllText() fails if the field is empty, in which case there is
+        // nothing to replace, so we can safely ignore its return value.
+
+        bindings.FORM_SelectAllText(self.form_handle, self.page_handle);
+
+        bindings.FORM_ReplaceSelection(
+            self.form_handle,
+            self.page_handle,
+            get_pdfium_utf16le_bytes_from_str(value).as_ptr() as FPDF_WIDESTRING,
+        );
+
+        bindings.FORM_ForceToKillFocus(self.form_handle);
+
+        self.value_impl().unwrap_or_default() == value
     }
 }
 
@@ -75,3 +118,50 @@ impl<'a> PdfFormFieldPrivate<'a> for PdfFormTextField<'a> {
         self.bindings
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_set_value_is_visible_after_flatten() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /AcroForm << /Fields [4 0 R] /DR << /Font << \
+            /Helv 5 0 R >> >> /DA (/Helv 12 Tf 0 g) >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R] >>",
+            b"<< /Type /Annot /Subtype /Widget /FT /Tx /T (greeting) /V (Goodbye) /Rect \
+            [100 700 300 720] /F 4 /P 3 0 R /DA (/Helv 12 Tf 0 g) >>",
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let mut page = document.pages().first()?;
+
+        {
+            let mut annotation = page.annotations_mut().first()?;
+
+            let field = annotation
+                .as_form_field_mut()
+                .and_then(|field| field.as_text_field_mut())
+                .unwrap();
+
+            field.set_value("Hello")?;
+
+            assert_eq!(field.value().as_deref(), Some("Hello"));
+        }
+
+        page.flatten()?;
+
+        let text = page.text()?.all();
+
+        assert!(text.contains("Hello"));
+        assert!(!text.contains("Goodbye"));
+
+        Ok(())
+    }
+}
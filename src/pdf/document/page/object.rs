@@ -2,6 +2,7 @@
 
 pub(crate) mod group;
 pub(crate) mod image;
+pub(crate) mod mark;
 pub(crate) mod ownership;
 pub(crate) mod path;
 pub(crate) mod private; // Keep private so that the PdfPageObjectPrivate trait is not exposed.
@@ -17,26 +18,29 @@ use crate::bindgen::{
     FPDF_PAGEOBJ_TEXT, FPDF_PAGEOBJ_UNKNOWN,
 };
 use crate::bindings::PdfiumLibraryBindings;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::object::image::PdfPageImageObject;
+use crate::pdf::document::page::object::mark::PdfPageObjectMark;
 use crate::pdf::document::page::object::path::PdfPagePathObject;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::shading::PdfPageShadingObject;
 use crate::pdf::document::page::object::text::PdfPageTextObject;
 use crate::pdf::document::page::object::unsupported::PdfPageUnsupportedObject;
 use crate::pdf::document::page::object::x_object_form::PdfPageXObjectFormObject;
+use crate::pdf::document::page::objects::common::PdfPageObjectIndex;
 use crate::pdf::document::page::objects::PdfPageObjects;
 use crate::pdf::document::page::PdfPageObjectOwnership;
 use crate::pdf::document::PdfDocument;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
+use crate::pdf::path::clip_path::PdfClipPath;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::quad_points::PdfQuadPoints;
 use crate::pdf::rect::PdfRect;
 use crate::{create_transform_getters, create_transform_setters};
 use std::convert::TryInto;
-use std::os::raw::{c_int, c_uint};
+use std::os::raw::{c_int, c_uint, c_ulong};
 
 #[cfg(doc)]
 use crate::pdf::document::page::PdfPage;
@@ -540,6 +544,27 @@ pub trait PdfPageObjectCommon<'a> {
     /// any glyphs, use the [PdfPageTextObject::descent()] function.
     fn bounds(&self) -> Result<PdfQuadPoints, PdfiumError>;
 
+    /// Returns the zero-based position of this [PdfPageObject] in the drawing order of its
+    /// containing `PdfPage`. Objects with higher positions are painted later, on top of
+    /// objects with lower positions.
+    ///
+    /// Returns `None` if this object is not attached directly to a page.
+    fn z_order(&self) -> Option<PdfPageObjectIndex>;
+
+    /// Returns the content marks attached to this [PdfPageObject], in the order in which
+    /// they are nested in the page's content stream.
+    fn marks(&self) -> Vec<PdfPageObjectMark<'_>>;
+
+    /// Attaches a new content mark with the given name, or tag, such as `P` or `Span`,
+    /// to this [PdfPageObject], returning the new [PdfPageObjectMark] so that its parameters
+    /// can be set. To connect the marked content to a structure element in a tagged PDF
+    /// document, set the mark's `MCID` parameter using the
+    /// [PdfPageObjectMark::set_marked_content_id()] function.
+    ///
+    /// Parameters can only be set once this [PdfPageObject] is attached to a document,
+    /// for instance by adding it to a page.
+    fn add_mark(&mut self, name: &str) -> Result<PdfPageObjectMark<'_>, PdfiumError>;
+
     /// Returns the width of this [PdfPageObject].
     #[inline]
     fn width(&self) -> Result<PdfPoints, PdfiumError> {
@@ -569,6 +594,13 @@ pub trait PdfPageObjectCommon<'a> {
             .unwrap_or(false)
     }
 
+    /// Returns the [PdfClipPath] applied to this [PdfPageObject], if any.
+    ///
+    /// The clip path remains valid until the page containing this [PdfPageObject] is closed.
+    /// Page objects that are not attached to a page, and page objects that are not clipped,
+    /// have no clip path.
+    fn clip_path(&self) -> Option<PdfClipPath<'_>>;
+
     /// Transforms this [PdfPageObject] by applying the transformation matrix read from the given [PdfPageObject].
     ///
     /// Any translation, rotation, scaling, or skewing transformations currently applied to the
@@ -595,6 +627,40 @@ pub trait PdfPageObjectCommon<'a> {
     /// the object's stroke mode must be set in order for strokes to actually be visible.
     fn set_stroke_color(&mut self, stroke_color: PdfColor) -> Result<(), PdfiumError>;
 
+    /// Returns the opacity of any filled paths in this [PdfPageObject], expressed as a value
+    /// between 0.0 (completely transparent) and 1.0 (completely opaque).
+    fn fill_opacity(&self) -> Result<f32, PdfiumError> {
+        Ok(self.fill_color()?.alpha() as f32 / 255.0)
+    }
+
+    /// Sets the opacity of any filled paths in this [PdfPageObject] to the given value,
+    /// leaving the red, green, and blue components of the current fill color unchanged.
+    ///
+    /// The opacity should be a value between 0.0 (completely transparent) and 1.0
+    /// (completely opaque). Values outside this range will be clamped.
+    fn set_fill_opacity(&mut self, opacity: f32) -> Result<(), PdfiumError> {
+        let fill_color = self.fill_color()?;
+
+        self.set_fill_color(fill_color.with_alpha(opacity_to_alpha(opacity)))
+    }
+
+    /// Returns the opacity of any stroked paths in this [PdfPageObject], expressed as a value
+    /// between 0.0 (completely transparent) and 1.0 (completely opaque).
+    fn stroke_opacity(&self) -> Result<f32, PdfiumError> {
+        Ok(self.stroke_color()?.alpha() as f32 / 255.0)
+    }
+
+    /// Sets the opacity of any stroked paths in this [PdfPageObject] to the given value,
+    /// leaving the red, green, and blue components of the current stroke color unchanged.
+    ///
+    /// The opacity should be a value between 0.0 (completely transparent) and 1.0
+    /// (completely opaque). Values outside this range will be clamped.
+    fn set_stroke_opacity(&mut self, opacity: f32) -> Result<(), PdfiumError> {
+        let stroke_color = self.stroke_color()?;
+
+        self.set_stroke_color(stroke_color.with_alpha(opacity_to_alpha(opacity)))
+    }
+
     /// Returns the width of any stroked lines in this [PdfPageObject].
     fn stroke_width(&self) -> Result<PdfPoints, PdfiumError>;
 
@@ -738,6 +804,13 @@ pub trait PdfPageObjectCommon<'a> {
         -> Result<PdfPageObject<'b>, PdfiumError>;
 }
 
+/// Converts the given opacity value, clamped to the range 0.0 - 1.0, into an 8-bit
+/// alpha channel value.
+#[inline]
+fn opacity_to_alpha(opacity: f32) -> u8 {
+    (opacity.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
 // Blanket implementation for all PdfPageObject types.
 
 impl<'a, T> PdfPageObjectCommon<'a> for T
@@ -754,6 +827,79 @@ where
         self.bounds_impl()
     }
 
+    fn z_order(&self) -> Option<PdfPageObjectIndex> {
+        match self.ownership() {
+            PdfPageObjectOwnership::Page(ownership) => {
+                let page_handle = ownership.page_handle();
+
+                (0..self.bindings().FPDFPage_CountObjects(page_handle))
+                    .find(|index| {
+                        self.bindings().FPDFPage_GetObject(page_handle, *index)
+                            == self.object_handle()
+                    })
+                    .map(|index| index as PdfPageObjectIndex)
+            }
+            _ => None,
+        }
+    }
+
+    fn marks(&self) -> Vec<PdfPageObjectMark<'_>> {
+        let handle = self.object_handle();
+
+        (0..self.bindings().FPDFPageObj_CountMarks(handle).max(0))
+            .filter_map(|index| {
+                let mark = self
+                    .bindings()
+                    .FPDFPageObj_GetMark(handle, index as c_ulong);
+
+                if mark.is_null() {
+                    None
+                } else {
+                    Some(PdfPageObjectMark::from_pdfium(
+                        mark,
+                        handle,
+                        *self.ownership(),
+                        self.bindings(),
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn add_mark(&mut self, name: &str) -> Result<PdfPageObjectMark<'_>, PdfiumError> {
+        let handle = self.object_handle();
+
+        let mark = self.bindings().FPDFPageObj_AddMark(handle, name);
+
+        if mark.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        self.regenerate_content_after_mutation()?;
+
+        Ok(PdfPageObjectMark::from_pdfium(
+            mark,
+            handle,
+            *self.ownership(),
+            self.bindings(),
+        ))
+    }
+
+    #[inline]
+    fn clip_path(&self) -> Option<PdfClipPath<'_>> {
+        let handle = self
+            .bindings()
+            .FPDFPageObj_GetClipPath(self.object_handle());
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfClipPath::from_pdfium(handle, self.bindings()))
+        }
+    }
+
     #[inline]
     fn transform_from(&mut self, other: &PdfPageObject) -> Result<(), PdfiumError> {
         self.reset_matrix_impl(other.matrix()?)
@@ -1228,6 +1374,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_set_opacity_retains_color_components() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            Some(PdfColor::TEAL),
+            Some(PdfPoints::new(2.0)),
+            Some(PdfColor::CRIMSON),
+        )?;
+
+        object.set_fill_opacity(0.5)?;
+        object.set_stroke_opacity(1.5)?;
+
+        let fill_color = object.fill_color()?;
+
+        assert_eq!(fill_color.red(), PdfColor::CRIMSON.red());
+        assert_eq!(fill_color.green(), PdfColor::CRIMSON.green());
+        assert_eq!(fill_color.blue(), PdfColor::CRIMSON.blue());
+        assert_eq!(fill_color.alpha(), 128);
+        assert!((object.fill_opacity()? - 0.5).abs() < 0.01);
+
+        let stroke_color = object.stroke_color()?;
+
+        assert_eq!(stroke_color.red(), PdfColor::TEAL.red());
+        assert_eq!(stroke_color.green(), PdfColor::TEAL.green());
+        assert_eq!(stroke_color.blue(), PdfColor::TEAL.blue());
+        assert_eq!(object.stroke_opacity()?, 1.0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_transform_captured_in_content_regeneration() -> Result<(), PdfiumError> {
         // The purpose of the test is to confirm that object transformations are correctly
@@ -1281,4 +1465,60 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_add_mark_with_marked_content_id() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().times_roman();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Tagged text",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        assert!(object.marks().is_empty());
+
+        {
+            let mut mark = object.add_mark("Span")?;
+
+            mark.set_marked_content_id(7)?;
+            mark.set_string_param("Lang", "en-GB")?;
+        }
+
+        let marks = object.marks();
+
+        assert_eq!(marks.len(), 1);
+        assert_eq!(marks[0].name(), "Span");
+        assert_eq!(marks[0].params_len(), 2);
+        assert_eq!(marks[0].int_param("MCID"), Some(7));
+        assert_eq!(marks[0].marked_content_id(), Some(7));
+        assert_eq!(marks[0].string_param("Lang"), Some("en-GB".to_string()));
+        assert_eq!(marks[0].int_param("Missing"), None);
+
+        // The mark should survive a round trip through the page's content stream.
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = reloaded.pages().first()?;
+
+        let object = page.objects().get(0)?;
+
+        let marks = object.marks();
+
+        assert_eq!(marks.len(), 1);
+        assert_eq!(marks[0].name(), "Span");
+        assert_eq!(marks[0].marked_content_id(), Some(7));
+
+        Ok(())
+    }
 }
@@ -11,14 +11,16 @@ use crate::pdf::bitmap::Pixels;
 use crate::pdf::color_space::PdfColorSpace;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectOwnership};
+use crate::pdf::document::page::PdfPageFitMode;
 use crate::pdf::document::PdfDocument;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
 use crate::utils::mem::create_byte_buffer;
 use crate::{create_transform_getters, create_transform_setters};
 use std::convert::TryInto;
 use std::ops::{Range, RangeInclusive};
-use std::os::raw::{c_int, c_void};
+use std::os::raw::{c_int, c_uint, c_void};
 
 #[cfg(any(feature = "image_latest", feature = "image_025"))]
 use {
@@ -27,6 +29,12 @@ use {
     image_025::{DynamicImage, EncodableLayout, GrayImage, RgbaImage},
 };
 
+#[cfg(all(
+    any(feature = "image_latest", feature = "image_025"),
+    not(target_arch = "wasm32")
+))]
+use image_025::{codecs::jpeg::JpegEncoder, imageops::FilterType};
+
 #[cfg(feature = "image_024")]
 use {
     crate::pdf::bitmap::PdfBitmapFormat,
@@ -34,6 +42,9 @@ use {
     image_024::{DynamicImage, EncodableLayout, GrayImage, RgbaImage},
 };
 
+#[cfg(all(feature = "image_024", not(target_arch = "wasm32")))]
+use image_024::{codecs::jpeg::JpegEncoder, imageops::FilterType};
+
 #[cfg(feature = "image_023")]
 use {
     crate::pdf::bitmap::PdfBitmapFormat,
@@ -41,6 +52,16 @@ use {
     image_023::{DynamicImage, EncodableLayout, GenericImageView, GrayImage, RgbaImage},
 };
 
+#[cfg(all(feature = "image_023", not(target_arch = "wasm32")))]
+use image_023::{codecs::jpeg::JpegEncoder, imageops::FilterType};
+
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+use {
+    crate::pdf::document::raw::image_streams,
+    crate::utils::files::{get_pdfium_document_bytes, get_pdfium_file_accessor_from_reader},
+    std::io::Cursor,
+};
+
 /// A single `PdfPageObject` of type `PdfPageObjectType::Image`. The page object defines a single
 /// bitmapped image.
 ///
@@ -585,6 +606,159 @@ impl<'a> PdfPageImageObject<'a> {
         self.set_bitmap(&bitmap)
     }
 
+    /// Applies the given `Image::DynamicImage` to this [PdfPageImageObject], encoding it
+    /// as a JPEG image with the given quality, from 1 (smallest) to 100 (best). Any alpha channel
+    /// in the given image is discarded.
+    ///
+    /// Unlike [PdfPageImageObject::set_image()], which stores the image data uncompressed,
+    /// the image data is stored using `DCTDecode` compression.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    /// It is not available when compiling to WASM.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn set_image_as_jpeg(
+        &mut self,
+        image: &DynamicImage,
+        quality: u8,
+    ) -> Result<(), PdfiumError> {
+        let mut jpeg = Vec::new();
+
+        JpegEncoder::new_with_quality(&mut jpeg, quality.clamp(1, 100))
+            .encode_image(&image.to_rgb8())
+            .map_err(|_| PdfiumError::ImageError)?;
+
+        let mut reader = get_pdfium_file_accessor_from_reader(Cursor::new(jpeg));
+
+        if self
+            .bindings
+            .is_true(self.bindings().FPDFImageObj_LoadJpegFileInline(
+                std::ptr::null_mut::<FPDF_PAGE>(),
+                0,
+                self.object_handle(),
+                reader.as_fpdf_file_access_mut_ptr(),
+            ))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Resamples the image assigned to this [PdfPageImageObject] so that its effective
+    /// resolution on the page, taking into account the object's transformation matrix,
+    /// does not exceed the given resolution in dots per inch. The resampled image is
+    /// re-embedded as a JPEG image with the given quality, from 1 (smallest) to 100 (best).
+    ///
+    /// Returns `true` if the image was resampled, or `false` if its effective resolution
+    /// already did not exceed the given resolution or the image cannot be resampled safely.
+    ///
+    /// Since the resampled image is opaque and uses the `DeviceRGB` or `DeviceGray` color space,
+    /// only images in those color spaces that are not drawn through a mask are resampled.
+    /// Pdfium does not report whether an image is masked, so the containing document is
+    /// serialized in memory and the image is located in the serialized data by comparing
+    /// stream data. Images that cannot be located, such as inline images and images in
+    /// encrypted documents, are assumed to be masked and are not resampled.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    /// It is not available when compiling to WASM.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn downsample_to_dpi(
+        &mut self,
+        target_dpi: f32,
+        jpeg_quality: u8,
+    ) -> Result<bool, PdfiumError> {
+        if !self.exceeds_dpi(target_dpi)? {
+            return Ok(false);
+        }
+
+        let bytes = self
+            .ownership()
+            .document_handle()
+            .and_then(|document| get_pdfium_document_bytes(document, self.bindings()));
+
+        let images = bytes.as_deref().map(image_streams).unwrap_or_default();
+
+        self.downsample_to_dpi_with_image_streams(target_dpi, jpeg_quality, images.as_slice())
+    }
+
+    /// Internal implementation of [PdfPageImageObject::downsample_to_dpi()], using the given
+    /// image XObject stream data and mask flags, as returned by `image_streams()`, to determine
+    /// whether the image is masked. This allows a caller resampling many images to serialize
+    /// the containing document only once.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub(crate) fn downsample_to_dpi_with_image_streams(
+        &mut self,
+        target_dpi: f32,
+        jpeg_quality: u8,
+        images: &[(&[u8], bool)],
+    ) -> Result<bool, PdfiumError> {
+        if !self.exceeds_dpi(target_dpi)?
+            || !matches!(
+                self.color_space()?,
+                PdfColorSpace::DeviceRGB | PdfColorSpace::DeviceGray
+            )
+        {
+            return Ok(false);
+        }
+
+        let is_masked = match self.get_image_data_raw() {
+            Some(data) => {
+                let mut matching = images
+                    .iter()
+                    .filter(|(stream, _)| *stream == data.as_slice())
+                    .map(|(_, is_masked)| *is_masked)
+                    .peekable();
+
+                matching.peek().is_none() || matching.any(|is_masked| is_masked)
+            }
+            None => true,
+        };
+
+        if is_masked {
+            return Ok(false);
+        }
+
+        let horizontal_dpi = self.horizontal_dpi()?;
+
+        let vertical_dpi = self.vertical_dpi()?;
+
+        let image = self.get_raw_image()?;
+
+        let scale = |pixels: u32, dpi: f32| {
+            if dpi <= target_dpi {
+                pixels
+            } else {
+                ((pixels as f32 * target_dpi / dpi).round() as u32).max(1)
+            }
+        };
+
+        let resampled = image.resize_exact(
+            scale(image.width(), horizontal_dpi),
+            scale(image.height(), vertical_dpi),
+            FilterType::Triangle,
+        );
+
+        self.set_image_as_jpeg(&resampled, jpeg_quality)?;
+
+        Ok(true)
+    }
+
+    /// Returns `true` if the effective resolution of the image assigned to this
+    /// [PdfPageImageObject] exceeds the given resolution in dots per inch.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    fn exceeds_dpi(&self, target_dpi: f32) -> Result<bool, PdfiumError> {
+        let horizontal_dpi = self.horizontal_dpi()?;
+
+        let vertical_dpi = self.vertical_dpi()?;
+
+        Ok(target_dpi > 0.0
+            && horizontal_dpi > 0.0
+            && vertical_dpi > 0.0
+            && (horizontal_dpi > target_dpi || vertical_dpi > target_dpi))
+    }
+
     /// Applies the byte data in the given [PdfBitmap] to this [PdfPageImageObject].
     pub fn set_bitmap(&mut self, bitmap: &PdfBitmap) -> Result<(), PdfiumError> {
         if self
@@ -675,12 +849,203 @@ impl<'a> PdfPageImageObject<'a> {
             .and_then(|metadata| PdfColorSpace::from_pdfium(metadata.colorspace as u32))
     }
 
+    /// Returns the width and height, in pixels, of the image assigned to this [PdfPageImageObject].
+    pub fn pixel_size(&self) -> Result<(u32, u32), PdfiumError> {
+        let mut width: c_uint = 0;
+
+        let mut height: c_uint = 0;
+
+        if self
+            .bindings()
+            .is_true(self.bindings().FPDFImageObj_GetImagePixelSize(
+                self.object_handle(),
+                &mut width,
+                &mut height,
+            ))
+        {
+            Ok((width, height))
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Replaces the transformation matrix of this [PdfPageImageObject] so that the image
+    /// is positioned within the given rectangle, scaled according to the given [PdfPageFitMode]:
+    ///
+    /// * [PdfPageFitMode::Stretch] scales the image to exactly fill the rectangle, ignoring
+    ///   the aspect ratio of the image.
+    /// * [PdfPageFitMode::Contain] scales the image to the largest size that fits entirely
+    ///   within the rectangle while preserving its aspect ratio, centering it in the rectangle.
+    /// * [PdfPageFitMode::Cover] scales the image to the smallest size that covers the entire
+    ///   rectangle while preserving its aspect ratio, centering it in the rectangle. Parts of
+    ///   the image will extend beyond the rectangle; no clipping is applied.
+    ///
+    /// Any existing transformation applied to this [PdfPageImageObject], including rotation
+    /// and skew, is discarded.
+    pub fn fit_into_rect(
+        &mut self,
+        rect: PdfRect,
+        mode: PdfPageFitMode,
+    ) -> Result<(), PdfiumError> {
+        let (pixel_width, pixel_height) = self.pixel_size()?;
+
+        if pixel_width == 0 || pixel_height == 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let target_width = rect.width().value;
+
+        let target_height = rect.height().value;
+
+        let (width, height) = match mode {
+            PdfPageFitMode::Stretch => (target_width, target_height),
+            PdfPageFitMode::Contain | PdfPageFitMode::Cover => {
+                let horizontal_scale = target_width / pixel_width as f32;
+
+                let vertical_scale = target_height / pixel_height as f32;
+
+                let scale = if mode == PdfPageFitMode::Contain {
+                    horizontal_scale.min(vertical_scale)
+                } else {
+                    horizontal_scale.max(vertical_scale)
+                };
+
+                (pixel_width as f32 * scale, pixel_height as f32 * scale)
+            }
+        };
+
+        // An image is drawn into the unit square, so the matrix scales the unit square
+        // to the target size and then translates it to the center of the rectangle.
+
+        self.reset_matrix_impl(PdfMatrix::new(
+            width,
+            0.0,
+            0.0,
+            height,
+            rect.left().value + (target_width - width) / 2.0,
+            rect.bottom().value + (target_height - height) / 2.0,
+        ))
+    }
+
     /// Returns the collection of image filters currently applied to this [PdfPageImageObject].
     #[inline]
     pub fn filters(&self) -> PdfPageImageObjectFilters {
         PdfPageImageObjectFilters::new(self)
     }
 
+    /// Returns the names of all the image filters currently applied to this [PdfPageImageObject],
+    /// in the order in which they are applied, for example `["FlateDecode", "DCTDecode"]`.
+    ///
+    /// This is a convenience function equivalent to collecting the names of the filters
+    /// returned by [PdfPageImageObject::filters()].
+    pub fn filter_names(&self) -> Vec<String> {
+        self.filters()
+            .iter()
+            .map(|filter| filter.name().to_owned())
+            .collect()
+    }
+
+    /// Returns the still-encoded image data of this [PdfPageImageObject], together with the
+    /// [PdfImageCompression] needed to decode it, allowing the image to be extracted or
+    /// repackaged without being decompressed and re-encoded.
+    ///
+    /// If more than one filter is applied to the image, the compression of the final filter
+    /// is returned. Any filters applied before it, such as `ASCII85Decode`, are removed from
+    /// the returned data, so the data is always encoded using only the returned compression.
+    ///
+    /// Returns `None` if no filters are applied to the image, or if the final filter is not
+    /// one of the filters represented by [PdfImageCompression].
+    pub fn compressed_stream(&self) -> Option<(PdfImageCompression, Vec<u8>)> {
+        let filters = self.filters();
+
+        let compression = filters
+            .get(filters.len().checked_sub(1)?)
+            .ok()
+            .and_then(|filter| PdfImageCompression::from_filter_name(filter.name()))?;
+
+        if filters.len() == 1 {
+            // The raw stream data is encoded only by the image filter itself.
+
+            self.get_image_data_raw().map(|data| (compression, data))
+        } else if compression == PdfImageCompression::Flate {
+            // Pdfium decodes all non-image filters when retrieving decoded data, so there is
+            // no way of retrieving data encoded only by a final FlateDecode filter.
+
+            None
+        } else {
+            // Pdfium stops decoding at the final image filter when retrieving decoded data,
+            // leaving the data encoded only by that filter.
+
+            self.get_image_data_decoded()
+                .map(|data| (compression, data))
+        }
+    }
+
+    /// Returns the raw, undecoded stream data of the image assigned to this [PdfPageImageObject].
+    fn get_image_data_raw(&self) -> Option<Vec<u8>> {
+        // Retrieving the image data from Pdfium is a two-step operation. First, we call
+        // FPDFImageObj_GetImageDataRaw() with a null buffer; this will retrieve the length of
+        // the image data in bytes. If the length is zero, then there is no image data.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFImageObj_GetImageDataRaw() again with a pointer to the buffer;
+        // this will write the image data into the buffer.
+
+        let buffer_length = self.bindings().FPDFImageObj_GetImageDataRaw(
+            self.object_handle(),
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFImageObj_GetImageDataRaw(
+            self.object_handle(),
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        Some(buffer)
+    }
+
+    /// Returns the stream data of the image assigned to this [PdfPageImageObject], with all
+    /// filters other than any final image filter decoded.
+    fn get_image_data_decoded(&self) -> Option<Vec<u8>> {
+        // As for get_image_data_raw(), retrieving the data is a two-step operation.
+
+        let buffer_length = self.bindings().FPDFImageObj_GetImageDataDecoded(
+            self.object_handle(),
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFImageObj_GetImageDataDecoded(
+            self.object_handle(),
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        Some(buffer)
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
@@ -751,6 +1116,55 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageImageObject<'a> {
     }
 }
 
+/// The compression used to encode the image data returned by
+/// [PdfPageImageObject::compressed_stream()].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfImageCompression {
+    /// CCITT Group 3 or Group 4 facsimile encoding of monochrome image data, as used by
+    /// the `CCITTFaxDecode` filter.
+    CcittFax,
+
+    /// JBIG2 encoding of monochrome image data, as used by the `JBIG2Decode` filter.
+    /// Any global data segments referenced by the image's `JBIG2Globals` decode parameter
+    /// are not included in the encoded data.
+    Jbig2,
+
+    /// JPEG baseline encoding, as used by the `DCTDecode` filter.
+    Dct,
+
+    /// JPEG 2000 encoding, as used by the `JPXDecode` filter.
+    Jpx,
+
+    /// zlib/deflate encoding, as used by the `FlateDecode` filter. Any predictor specified
+    /// in the image's decode parameters is not reversed.
+    Flate,
+}
+
+impl PdfImageCompression {
+    /// Returns the [PdfImageCompression] matching the given PDF filter name, if any.
+    pub fn from_filter_name(name: &str) -> Option<Self> {
+        match name {
+            "CCITTFaxDecode" | "CCF" => Some(PdfImageCompression::CcittFax),
+            "JBIG2Decode" => Some(PdfImageCompression::Jbig2),
+            "DCTDecode" | "DCT" => Some(PdfImageCompression::Dct),
+            "JPXDecode" => Some(PdfImageCompression::Jpx),
+            "FlateDecode" | "Fl" => Some(PdfImageCompression::Flate),
+            _ => None,
+        }
+    }
+
+    /// Returns the PDF filter name corresponding to this [PdfImageCompression].
+    pub fn filter_name(&self) -> &'static str {
+        match self {
+            PdfImageCompression::CcittFax => "CCITTFaxDecode",
+            PdfImageCompression::Jbig2 => "JBIG2Decode",
+            PdfImageCompression::Dct => "DCTDecode",
+            PdfImageCompression::Jpx => "JPXDecode",
+            PdfImageCompression::Flate => "FlateDecode",
+        }
+    }
+}
+
 /// The zero-based index of a single [PdfPageImageObjectFilter] inside its containing
 /// [PdfPageImageObjectFilters] collection.
 pub type PdfPageImageObjectFilterIndex = usize;
@@ -903,7 +1317,7 @@ impl<'a> Iterator for PdfPageImageObjectFiltersIterator<'a> {
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-    use crate::utils::test::test_bind_to_pdfium;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
 
     #[test]
     fn test_page_image_object_retains_format() -> Result<(), PdfiumError> {
@@ -968,6 +1382,142 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fit_into_rect_contain_preserves_aspect_ratio() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let image = pdfium
+            .load_pdf_from_file("./test/path-test.pdf", None)?
+            .pages()
+            .get(0)?
+            .render_with_config(&PdfRenderConfig::new().set_target_width(300))?
+            .as_image();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_image_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            &image,
+            None,
+            None,
+        )?;
+
+        let slot = PdfRect::new_from_values(100.0, 50.0, 300.0, 450.0);
+
+        object
+            .as_image_object_mut()
+            .unwrap()
+            .fit_into_rect(slot, PdfPageFitMode::Contain)?;
+
+        let bounds = object.bounds()?;
+
+        let expected_height = 200.0;
+
+        let expected_width = expected_height * image.width() as f32 / image.height() as f32;
+
+        assert!(expected_width < 400.0);
+        assert!((bounds.height().value - expected_height).abs() < 0.5);
+        assert!((bounds.width().value - expected_width).abs() < 0.5);
+
+        // The image should be centered horizontally within the slot.
+
+        let left_margin = bounds.left().value - slot.left().value;
+
+        let right_margin = slot.right().value - (bounds.left().value + bounds.width().value);
+
+        assert!((left_margin - right_margin).abs() < 0.5);
+        assert!((bounds.bottom().value - slot.bottom().value).abs() < 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_stream() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // An 8 x 8 pixel all-white CCITT Group 4 image, stored once directly and once
+        // wrapped in an additional ASCIIHexDecode filter.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] /Contents 4 0 R /Resources << \
+            /XObject << /Im0 5 0 R /Im1 6 0 R >> >> >>",
+            b"<< >>\nstream\n\
+            q 8 0 0 8 0 0 cm /Im0 Do Q q 8 0 0 8 20 0 cm /Im1 Do Q",
+            b"<< /Type /XObject /Subtype /Image /Width 8 /Height 8 /ColorSpace /DeviceGray \
+            /BitsPerComponent 1 /Filter /CCITTFaxDecode /DecodeParms << /K -1 /Columns 8 \
+            /Rows 8 >> >>\nstream\n\
+            \xff\x00\x10\x01",
+            b"<< /Type /XObject /Subtype /Image /Width 8 /Height 8 /ColorSpace /DeviceGray \
+            /BitsPerComponent 1 /Filter [/ASCIIHexDecode /CCITTFaxDecode] /DecodeParms [null << \
+            /K -1 /Columns 8 /Rows 8 >>] >>\nstream\n\
+            FF001001>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let images = page
+            .objects()
+            .iter()
+            .filter_map(|object| {
+                object
+                    .as_image_object()
+                    .and_then(|image| image.compressed_stream())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(images.len(), 2);
+
+        for (compression, data) in images {
+            assert_eq!(compression, PdfImageCompression::CcittFax);
+            assert_eq!(data, vec![0xff, 0x00, 0x10, 0x01]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_names() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // An image stream with a double filter. Only the image dictionary is inspected,
+        // so the stream data itself need not be decodable.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 100 100] /Contents 4 0 R /Resources << \
+            /XObject << /Im0 5 0 R >> >> >>",
+            b"<< >>\nstream\n\
+            q 8 0 0 8 0 0 cm /Im0 Do Q",
+            b"<< /Type /XObject /Subtype /Image /Width 8 /Height 8 /ColorSpace /DeviceGray \
+            /BitsPerComponent 8 /Filter [/FlateDecode /DCTDecode] >>\nstream\n\
+            \x00\x00\x00\x00",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let filter_names = page
+            .objects()
+            .iter()
+            .find_map(|object| object.as_image_object().map(|image| image.filter_names()))
+            .expect("image object");
+
+        assert_eq!(filter_names, vec!["FlateDecode", "DCTDecode"]);
+
+        Ok(())
+    }
+
     fn compare_equality_of_byte_arrays(a: &[u8], b: &[u8]) -> bool {
         if a.len() != b.len() {
             return false;
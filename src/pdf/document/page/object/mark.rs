@@ -0,0 +1,239 @@
+//! Defines the [PdfPageObjectMark] struct, exposing functionality related to a single
+//! content mark attached to a `PdfPageObject`.
+
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::page::object::private::internal::regenerate_content_for_ownership;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong};
+
+#[cfg(doc)]
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+
+/// A single content mark attached to a [PdfPageObject].
+///
+/// Content marks identify sections of a page's content stream that belong together, for
+/// instance the content belonging to a single structure element in a tagged PDF document.
+/// Each mark has a name, or tag, such as `P` or `Span`, and an optional dictionary of
+/// parameters. A mark with an integer `MCID` parameter connects the marked content to the
+/// structure element that refers to that marked content identifier.
+///
+/// Use the [PdfPageObjectCommon::marks()] function to retrieve the marks attached to a page
+/// object, and the [PdfPageObjectCommon::add_mark()] function to attach a new mark.
+pub struct PdfPageObjectMark<'a> {
+    handle: FPDF_PAGEOBJECTMARK,
+    object_handle: FPDF_PAGEOBJECT,
+    ownership: PdfPageObjectOwnership,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMark<'a> {
+    /// The parameter key conventionally used to store the marked content identifier of a mark.
+    pub const MARKED_CONTENT_ID_KEY: &'static str = "MCID";
+
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_PAGEOBJECTMARK,
+        object_handle: FPDF_PAGEOBJECT,
+        ownership: PdfPageObjectOwnership,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMark {
+            handle,
+            object_handle,
+            ownership,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMark].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name, or tag, of this [PdfPageObjectMark], such as `P` or `Span`.
+    pub fn name(&self) -> String {
+        // Retrieving the name from Pdfium is a two-step operation. First, we call
+        // FPDFPageObjMark_GetName() with a null buffer; this will retrieve the length of
+        // the name in bytes. If the length is zero, then there is no name.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFPageObjMark_GetName() again with a pointer to the buffer;
+        // this will write the name into the buffer in UTF16-LE format.
+
+        let mut buffer_length: c_ulong = 0;
+
+        if !self.bindings.is_true(self.bindings.FPDFPageObjMark_GetName(
+            self.handle,
+            std::ptr::null_mut(),
+            0,
+            &mut buffer_length,
+        )) || buffer_length == 0
+        {
+            return String::new();
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        if self.bindings.is_true(self.bindings.FPDFPageObjMark_GetName(
+            self.handle,
+            buffer.as_mut_ptr() as *mut _,
+            buffer_length,
+            &mut buffer_length,
+        )) {
+            get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns the number of parameters in the parameter dictionary of this [PdfPageObjectMark].
+    #[inline]
+    pub fn params_len(&self) -> usize {
+        self.bindings
+            .FPDFPageObjMark_CountParams(self.handle)
+            .max(0) as usize
+    }
+
+    /// Returns the value of the integer parameter with the given key, if this
+    /// [PdfPageObjectMark] contains a numeric parameter with that key.
+    pub fn int_param(&self, key: &str) -> Option<i32> {
+        let mut value: c_int = 0;
+
+        if self
+            .bindings
+            .is_true(
+                self.bindings
+                    .FPDFPageObjMark_GetParamIntValue(self.handle, key, &mut value),
+            )
+        {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the string parameter with the given key, if this
+    /// [PdfPageObjectMark] contains a string parameter with that key.
+    pub fn string_param(&self, key: &str) -> Option<String> {
+        let mut buffer_length: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamStringValue(
+                self.handle,
+                key,
+                std::ptr::null_mut(),
+                0,
+                &mut buffer_length,
+            ))
+        {
+            return None;
+        }
+
+        if buffer_length == 0 {
+            return Some(String::new());
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamStringValue(
+                self.handle,
+                key,
+                buffer.as_mut_ptr() as *mut _,
+                buffer_length,
+                &mut buffer_length,
+            ))
+        {
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the marked content identifier stored in the `MCID` parameter of this
+    /// [PdfPageObjectMark], if any.
+    #[inline]
+    pub fn marked_content_id(&self) -> Option<i32> {
+        self.int_param(Self::MARKED_CONTENT_ID_KEY)
+    }
+
+    /// Sets the parameter with the given key in the parameter dictionary of this
+    /// [PdfPageObjectMark] to the given integer value, replacing any existing value.
+    ///
+    /// The page object containing this mark must be attached to a document, for instance
+    /// by adding it to a page, before any parameters can be set.
+    pub fn set_int_param(&mut self, key: &str, value: i32) -> Result<(), PdfiumError> {
+        let document_handle = self.document_handle()?;
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_SetIntParam(
+                document_handle,
+                self.object_handle,
+                self.handle,
+                key,
+                value,
+            ))
+        {
+            regenerate_content_for_ownership(&self.ownership, self.bindings)
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Sets the parameter with the given key in the parameter dictionary of this
+    /// [PdfPageObjectMark] to the given string value, replacing any existing value.
+    ///
+    /// The page object containing this mark must be attached to a document, for instance
+    /// by adding it to a page, before any parameters can be set.
+    pub fn set_string_param(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        let document_handle = self.document_handle()?;
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_SetStringParam(
+                document_handle,
+                self.object_handle,
+                self.handle,
+                key,
+                value,
+            ))
+        {
+            regenerate_content_for_ownership(&self.ownership, self.bindings)
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Sets the `MCID` parameter of this [PdfPageObjectMark] to the given marked content
+    /// identifier, connecting the marked content to the structure element that refers to it.
+    #[inline]
+    pub fn set_marked_content_id(&mut self, id: i32) -> Result<(), PdfiumError> {
+        self.set_int_param(Self::MARKED_CONTENT_ID_KEY, id)
+    }
+
+    /// Returns the handle of the document containing the page object holding this mark.
+    fn document_handle(&self) -> Result<FPDF_DOCUMENT, PdfiumError> {
+        match self.ownership {
+            PdfPageObjectOwnership::Page(ownership) => Ok(ownership.document_handle()),
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                Ok(ownership.document_handle())
+            }
+            PdfPageObjectOwnership::UnattachedAnnotation(ownership) => {
+                Ok(ownership.document_handle())
+            }
+            PdfPageObjectOwnership::Unowned => Err(PdfiumError::OwnershipNotAttachedToDocument),
+        }
+    }
+}
@@ -109,6 +109,22 @@ impl PdfPageObjectOwnership {
         })
     }
 
+    /// Returns the `FPDF_DOCUMENT` handle of the document containing the object container
+    /// that owns the [PdfPageObject] holding this [PdfObjectOwnership] instance, if any.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn document_handle(&self) -> Option<FPDF_DOCUMENT> {
+        match self {
+            PdfPageObjectOwnership::Unowned => None,
+            PdfPageObjectOwnership::Page(ownership) => Some(ownership.document_handle()),
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                Some(ownership.document_handle())
+            }
+            PdfPageObjectOwnership::UnattachedAnnotation(ownership) => {
+                Some(ownership.document_handle())
+            }
+        }
+    }
+
     /// Returns `true` if the memory allocated to the [PdfPageObject] holding
     /// this [PdfObjectOwnership] instance is owned by an object container attached to
     /// either a [PdfPage] or a [PdfAnnotation].
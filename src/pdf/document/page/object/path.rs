@@ -143,6 +143,11 @@ impl Default for PdfPathFillMode {
 ///
 /// The detached path object can later be attached to a page by calling the
 /// `PdfPageObjects::add_path_object()` function.
+///
+/// The fill and stroke settings of an existing path object can be read back using the
+/// [PdfPagePathObject::fill_mode()] and [PdfPagePathObject::is_stroked()] functions,
+/// together with the `fill_color()`, `stroke_color()`, and `stroke_width()` functions
+/// provided by the `PdfPageObjectCommon` trait.
 pub struct PdfPagePathObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
     ownership: PdfPageObjectOwnership,
@@ -1064,3 +1069,105 @@ impl<'a> PdfPathSegments<'a> for PdfPagePathObjectSegments<'a> {
         PdfPathSegmentsIterator::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_path_fill_and_stroke_settings_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            Some(PdfColor::RED),
+            Some(PdfPoints::new(3.0)),
+            Some(PdfColor::BLUE),
+        )?;
+
+        object
+            .as_path_object_mut()
+            .unwrap()
+            .set_fill_and_stroke_mode(PdfPathFillMode::EvenOdd, true)?;
+
+        drop(page);
+
+        // Save and reload the document, then check that the path's settings were preserved.
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page.objects().first()?;
+
+        let path = object.as_path_object().unwrap();
+
+        assert_eq!(path.fill_mode()?, PdfPathFillMode::EvenOdd);
+        assert!(path.is_stroked()?);
+
+        let fill = path.fill_color()?;
+
+        assert_eq!((fill.red(), fill.green(), fill.blue()), (0, 0, 255));
+
+        let stroke = path.stroke_color()?;
+
+        assert_eq!((stroke.red(), stroke.green(), stroke.blue()), (255, 0, 0));
+        assert_eq!(path.stroke_width()?, PdfPoints::new(3.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_closed_sub_path_reports_close_flag() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut path = PdfPagePathObject::new(
+            &document,
+            PdfPoints::new(10.0),
+            PdfPoints::new(10.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(1.0)),
+            None,
+        )?;
+
+        path.line_to(PdfPoints::new(50.0), PdfPoints::new(10.0))?;
+        path.line_to(PdfPoints::new(30.0), PdfPoints::new(40.0))?;
+        path.close_path()?;
+
+        let segments = path.segments();
+
+        let segments = segments.iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            segments
+                .iter()
+                .map(|segment| segment.segment_type())
+                .collect::<Vec<_>>(),
+            vec![
+                PdfPathSegmentType::MoveTo,
+                PdfPathSegmentType::LineTo,
+                PdfPathSegmentType::LineTo,
+            ]
+        );
+        assert!(!segments[0].is_close());
+        assert!(!segments[1].is_close());
+        assert!(segments[2].is_close());
+        assert_eq!(
+            segments[2].point(),
+            (PdfPoints::new(30.0), PdfPoints::new(40.0))
+        );
+
+        Ok(())
+    }
+}
@@ -325,38 +325,7 @@ pub(crate) mod internal {
         /// within the page objects container. The page's content regeneration strategy is
         /// taken into account.
         fn regenerate_content_after_mutation(&self) -> Result<(), PdfiumError> {
-            let (document_handle, page_handle) = match self.ownership() {
-                PdfPageObjectOwnership::Page(ownership) => (
-                    Some(ownership.document_handle()),
-                    Some(ownership.page_handle()),
-                ),
-                PdfPageObjectOwnership::AttachedAnnotation(ownership) => (
-                    Some(ownership.document_handle()),
-                    Some(ownership.page_handle()),
-                ),
-                _ => (None, None),
-            };
-
-            if let (Some(document_handle), Some(page_handle)) = (document_handle, page_handle) {
-                if let Some(content_regeneration_strategy) =
-                    PdfPageIndexCache::get_content_regeneration_strategy_for_page(
-                        document_handle,
-                        page_handle,
-                    )
-                {
-                    if content_regeneration_strategy
-                        == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
-                    {
-                        PdfPage::regenerate_content_immut_for_handle(page_handle, self.bindings())
-                    } else {
-                        Ok(())
-                    }
-                } else {
-                    Err(PdfiumError::SourcePageIndexNotInCache)
-                }
-            } else {
-                Ok(())
-            }
+            regenerate_content_for_ownership(self.ownership(), self.bindings())
         }
 
         /// Returns `true` if this [PdfPageObject] can be successfully cloned by calling its
@@ -371,6 +340,47 @@ pub(crate) mod internal {
             bindings: &'b dyn PdfiumLibraryBindings,
         ) -> Result<PdfPageObject<'b>, PdfiumError>;
     }
+
+    /// Regenerates the content of the page containing a page object with the given ownership,
+    /// if the page's content regeneration strategy is
+    /// [PdfPageContentRegenerationStrategy::AutomaticOnEveryChange].
+    pub(crate) fn regenerate_content_for_ownership(
+        ownership: &PdfPageObjectOwnership,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(), PdfiumError> {
+        let (document_handle, page_handle) = match ownership {
+            PdfPageObjectOwnership::Page(ownership) => (
+                Some(ownership.document_handle()),
+                Some(ownership.page_handle()),
+            ),
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => (
+                Some(ownership.document_handle()),
+                Some(ownership.page_handle()),
+            ),
+            _ => (None, None),
+        };
+
+        if let (Some(document_handle), Some(page_handle)) = (document_handle, page_handle) {
+            if let Some(content_regeneration_strategy) =
+                PdfPageIndexCache::get_content_regeneration_strategy_for_page(
+                    document_handle,
+                    page_handle,
+                )
+            {
+                if content_regeneration_strategy
+                    == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
+                {
+                    PdfPage::regenerate_content_immut_for_handle(page_handle, bindings)
+                } else {
+                    Ok(())
+                }
+            } else {
+                Err(PdfiumError::SourcePageIndexNotInCache)
+            }
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
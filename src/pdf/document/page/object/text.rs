@@ -14,6 +14,7 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::color::PdfColor;
 use crate::pdf::document::fonts::ToPdfFontToken;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{
@@ -122,6 +123,30 @@ impl PdfPageTextRenderMode {
             }
         }
     }
+
+    /// Returns `true` if text drawn using this rendering mode has its glyph outlines filled.
+    #[inline]
+    pub fn is_filled(&self) -> bool {
+        matches!(
+            self,
+            PdfPageTextRenderMode::FilledUnstroked
+                | PdfPageTextRenderMode::FilledThenStroked
+                | PdfPageTextRenderMode::FilledUnstrokedClipping
+                | PdfPageTextRenderMode::FilledThenStrokedClipping
+        )
+    }
+
+    /// Returns `true` if text drawn using this rendering mode has its glyph outlines stroked.
+    #[inline]
+    pub fn is_stroked(&self) -> bool {
+        matches!(
+            self,
+            PdfPageTextRenderMode::StrokedUnfilled
+                | PdfPageTextRenderMode::FilledThenStroked
+                | PdfPageTextRenderMode::StrokedUnfilledClipping
+                | PdfPageTextRenderMode::FilledThenStrokedClipping
+        )
+    }
 }
 
 /// A single `PdfPageObject` of type `PdfPageObjectType::Text`. The page object defines a single
@@ -253,6 +278,24 @@ impl<'a> PdfPageTextObject<'a> {
         }
     }
 
+    /// Returns the text matrix of this [PdfPageTextObject], using character retrieval
+    /// functionality provided by the given [PdfPageText] object. The text matrix maps the
+    /// text space in which glyphs are laid out to the user space of the containing page.
+    ///
+    /// Combine the text matrix with the positions of individual characters, available from
+    /// [PdfPageTextObject::chars()], to determine where each glyph lands on the page.
+    ///
+    /// Returns [PdfiumError::NoCharsInPageObject] if this text object contains no characters.
+    pub fn text_matrix(&self, text: &'a PdfPageText<'a>) -> Result<PdfMatrix, PdfiumError> {
+        let chars = self.chars(text)?;
+
+        if chars.is_empty() {
+            return Err(PdfiumError::NoCharsInPageObject);
+        }
+
+        chars.get(chars.first_char_index())?.text_matrix()
+    }
+
     /// Returns the [PdfFont] used to render the text contained within this [PdfPageTextObject].
     pub fn font(&self) -> PdfFont {
         PdfFont::from_pdfium(
@@ -313,6 +356,8 @@ impl<'a> PdfPageTextObject<'a> {
                 if buffer_length == 0 {
                     // There is no text.
 
+                    self.bindings.FPDFText_ClosePage(text_handle);
+
                     return String::new();
                 }
 
@@ -381,6 +426,37 @@ impl<'a> PdfPageTextObject<'a> {
         }
     }
 
+    /// Sets the fill color, stroke color, and stroke width of the text contained within this
+    /// [PdfPageTextObject], and switches the text rendering mode so that the text is both
+    /// filled and then stroked. This produces outlined text, with the stroke drawn over the
+    /// edges of each filled glyph.
+    ///
+    /// Any clipping behaviour of the current text rendering mode is preserved. Note that
+    /// the [PdfPageObjectCommon::set_stroke_color()] and [PdfPageObjectCommon::set_stroke_width()]
+    /// functions do not change the text rendering mode, so strokes set using those functions
+    /// will not be visible unless a stroked text rendering mode is also set using the
+    /// [PdfPageTextObject::set_render_mode()] function.
+    pub fn set_fill_and_stroke(
+        &mut self,
+        fill_color: PdfColor,
+        stroke_color: PdfColor,
+        stroke_width: PdfPoints,
+    ) -> Result<(), PdfiumError> {
+        self.set_fill_color(fill_color)?;
+        self.set_stroke_color(stroke_color)?;
+        self.set_stroke_width(stroke_width)?;
+
+        self.set_render_mode(match self.render_mode() {
+            PdfPageTextRenderMode::FilledUnstrokedClipping
+            | PdfPageTextRenderMode::StrokedUnfilledClipping
+            | PdfPageTextRenderMode::FilledThenStrokedClipping
+            | PdfPageTextRenderMode::InvisibleClipping => {
+                PdfPageTextRenderMode::FilledThenStrokedClipping
+            }
+            _ => PdfPageTextRenderMode::FilledThenStroked,
+        })
+    }
+
     /// Returns a collection of the characters contained within this [PdfPageTextObject],
     /// using character retrieval functionality provided by the given [PdfPageText] object.
     #[inline]
@@ -490,3 +566,122 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageTextObject<'a> {
         Ok(PdfPageObject::Text(copy))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_text_matrix_reflects_translation() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object =
+            PdfPageTextObject::new(&document, "Translated", font, PdfPoints::new(12.0))?;
+
+        object.translate(PdfPoints::new(100.0), PdfPoints::new(200.0))?;
+
+        let object = page.objects_mut().add_text_object(object)?;
+
+        let text = page.text()?;
+
+        let matrix = object.as_text_object().unwrap().text_matrix(&text)?;
+
+        assert_eq!(matrix.a(), 1.0);
+        assert_eq!(matrix.d(), 1.0);
+        assert_eq!(matrix.e(), 100.0);
+        assert_eq!(matrix.f(), 200.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let detached = PdfPageTextObject::new(&document, "Detached", font, PdfPoints::new(12.0))?;
+
+        // Text retrieval requires the text object to be attached to a page.
+
+        assert_eq!(detached.text(), "");
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Hello, world!",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        assert_eq!(object.as_text_object().unwrap().text(), "Hello, world!");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_outlined_text_renders_fill_and_stroke() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica_bold();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::from_points(
+                PdfPoints::new(400.0),
+                PdfPoints::new(300.0),
+            ))?;
+
+        let mut object = PdfPageTextObject::new(&document, "H", font, PdfPoints::new(240.0))?;
+
+        object.set_fill_and_stroke(PdfColor::RED, PdfColor::BLUE, PdfPoints::new(8.0))?;
+
+        assert_eq!(
+            object.render_mode(),
+            PdfPageTextRenderMode::FilledThenStroked
+        );
+
+        object.translate(PdfPoints::new(100.0), PdfPoints::new(50.0))?;
+
+        page.objects_mut().add_text_object(object)?;
+
+        let bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_width(400))?;
+
+        let mut interior = 0;
+        let mut edge = 0;
+
+        for y in 0..bitmap.height() {
+            for x in 0..bitmap.width() {
+                let pixel = bitmap.get_pixel(x, y)?;
+
+                if pixel.red() > 200 && pixel.green() < 50 && pixel.blue() < 50 {
+                    interior += 1;
+                } else if pixel.blue() > 200 && pixel.red() < 50 && pixel.green() < 50 {
+                    edge += 1;
+                }
+            }
+        }
+
+        assert!(interior > 0);
+        assert!(edge > 0);
+
+        Ok(())
+    }
+}
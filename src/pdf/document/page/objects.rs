@@ -4,17 +4,19 @@
 pub mod common;
 pub(crate) mod private; // Keep private so that the PdfPageObjectsPrivate trait is not exposed.
 
-use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE, FPDF_PAGEOBJECT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::page::object::group::PdfPageGroupObject;
 use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
-use crate::pdf::document::page::object::PdfPageObject;
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::document::page::objects::common::{
     PdfPageObjectIndex, PdfPageObjectsCommon, PdfPageObjectsIterator,
 };
 use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
 use std::os::raw::c_int;
 
 /// The page objects contained within a single `PdfPage`.
@@ -82,6 +84,191 @@ impl<'a> PdfPageObjects<'a> {
     pub fn create_empty_group(&self) -> PdfPageGroupObject<'a> {
         PdfPageGroupObject::from_pdfium(self.document_handle(), self.page_handle(), self.bindings())
     }
+
+    /// Returns the topmost [PdfPageObject] in this [PdfPageObjects] collection whose bounds
+    /// contain the given point, or `None` if no page object covers the point.
+    ///
+    /// Page objects are tested in reverse drawing order, so where objects overlap, the object
+    /// painted last (and therefore visible on top) is returned. The test uses the transformed
+    /// bounds returned by [PdfPageObjectCommon::bounds()], so rotated and skewed objects
+    /// are hit-tested against their actual on-page quadrilateral rather than an axis-aligned box.
+    pub fn object_at_point(&self, x: PdfPoints, y: PdfPoints) -> Option<PdfPageObject<'a>> {
+        (0..self.len()).rev().find_map(|index| {
+            self.get(index).ok().filter(|object| {
+                object
+                    .bounds()
+                    .map(|bounds| bounds.contains(x, y))
+                    .unwrap_or(false)
+            })
+        })
+    }
+
+    /// Returns the smallest [PdfRect] that encloses every [PdfPageObject] in this
+    /// [PdfPageObjects] collection.
+    ///
+    /// Each object contributes the transformed bounds returned by
+    /// [PdfPageObjectCommon::bounds()], so the result takes into account each object's
+    /// transformation matrix. Objects whose bounds cannot be retrieved, or whose bounds
+    /// have zero area, are skipped. Returns [PdfiumError::NoPageObjectsInCollection] if
+    /// no object in this collection has bounds with a non-zero area.
+    pub fn bounding_box(&self) -> Result<PdfRect, PdfiumError> {
+        self.iter()
+            .filter_map(|object| object.bounds().ok().map(|bounds| bounds.to_rect()))
+            .filter(|bounds| bounds.width().value > 0.0 && bounds.height().value > 0.0)
+            .reduce(|result, bounds| result.union(&bounds))
+            .ok_or(PdfiumError::NoPageObjectsInCollection)
+    }
+
+    /// Replaces the given existing [PdfPageObject] in this [PdfPageObjects] collection with
+    /// the given new [PdfPageObject], returning the new object once it has been added to the page.
+    ///
+    /// The new object takes the position of the replaced object in the page's content stream,
+    /// so it will be painted above and below the same page objects as the object it replaces.
+    /// This makes it easy to, for instance, swap a placeholder image for a final image without
+    /// disturbing any content layered on top of the placeholder. The replaced object is removed
+    /// from the page and its memory freed; to keep the replaced object, remove it using the
+    /// [PdfPageObjectsCommon::remove_object()] function and add the new object separately.
+    ///
+    /// Returns [PdfiumError::OwnershipNotAttachedToPage] if the object to be replaced is not
+    /// contained in this [PdfPageObjects] collection.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn replace_object(
+        &mut self,
+        old: PdfPageObject<'a>,
+        new: PdfPageObject<'a>,
+    ) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let index = (0..self.len())
+            .find(|index| {
+                self.bindings
+                    .FPDFPage_GetObject(self.page_handle, *index as c_int)
+                    == old.object_handle()
+            })
+            .ok_or(PdfiumError::OwnershipNotAttachedToPage)?;
+
+        let new = self.add_object(new)?;
+
+        self.remove_object(old)?;
+
+        // Move every page object that followed the replaced object back on top of
+        // the new object, which Pdfium appended to the end of the page's content stream.
+
+        let following = (index..self.len() - 1)
+            .map(|index| {
+                self.bindings
+                    .FPDFPage_GetObject(self.page_handle, index as c_int)
+            })
+            .collect::<Vec<_>>();
+
+        Self::reorder_objects(self.page_handle, following.as_slice(), self.bindings)?;
+
+        new.regenerate_content_after_mutation()?;
+
+        Ok(new)
+    }
+
+    /// Moves the given [PdfPageObject] to the given zero-based position in the drawing order
+    /// of this [PdfPageObjects] collection. Objects with higher positions are painted later,
+    /// on top of objects with lower positions. The relative order of all other page objects
+    /// is unchanged.
+    ///
+    /// Returns [PdfiumError::OwnershipNotAttachedToPage] if the object is not contained in
+    /// this [PdfPageObjects] collection, or [PdfiumError::PageObjectIndexOutOfBounds] if
+    /// the given position is not a valid index into this collection.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn set_object_index(
+        &mut self,
+        object: &PdfPageObject<'a>,
+        index: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError> {
+        let mut handles = (0..self.len())
+            .map(|index| {
+                self.bindings
+                    .FPDFPage_GetObject(self.page_handle, index as c_int)
+            })
+            .collect::<Vec<_>>();
+
+        let current = handles
+            .iter()
+            .position(|handle| *handle == object.object_handle())
+            .ok_or(PdfiumError::OwnershipNotAttachedToPage)?;
+
+        if index >= handles.len() {
+            return Err(PdfiumError::PageObjectIndexOutOfBounds);
+        }
+
+        if index == current {
+            return Ok(());
+        }
+
+        let handle = handles.remove(current);
+
+        handles.insert(index, handle);
+
+        // Re-append every page object from the first changed position onwards,
+        // in their new order.
+
+        Self::reorder_objects(
+            self.page_handle,
+            &handles[index.min(current)..],
+            self.bindings,
+        )?;
+
+        object.regenerate_content_after_mutation()
+    }
+
+    /// Moves each of the given page objects, in the given order, to the end of the drawing
+    /// order of the page with the given handle.
+    ///
+    /// Pdfium always appends newly inserted page objects to the end of the page's content
+    /// stream, so the only means of reordering page objects is to remove and re-insert them.
+    /// Pdfium searches for each object to remove from the start of the page's object list,
+    /// so moving objects starting from the front of the drawing order, as
+    /// [PdfPageObjects::set_object_index()] does when moving an object to the start, takes time
+    /// proportional to the number of objects moved.
+    /// Each object is only re-inserted once it has been successfully removed; if an object
+    /// cannot be removed, it and all objects following it in the given list are left
+    /// in place and an error is returned.
+    pub(crate) fn reorder_objects(
+        page_handle: FPDF_PAGE,
+        handles: &[FPDF_PAGEOBJECT],
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(), PdfiumError> {
+        for handle in handles {
+            if !bindings.is_true(bindings.FPDFPage_RemoveObject(page_handle, *handle)) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+
+            bindings.FPDFPage_InsertObject(page_handle, *handle);
+        }
+
+        Ok(())
+    }
+
+    /// Moves the given [PdfPageObject] to the end of the drawing order of this
+    /// [PdfPageObjects] collection, so that it is painted on top of all other page objects.
+    ///
+    /// See the [PdfPageObjects::set_object_index()] function for details.
+    #[inline]
+    pub fn move_to_front(&mut self, object: &PdfPageObject<'a>) -> Result<(), PdfiumError> {
+        self.set_object_index(object, self.len().saturating_sub(1))
+    }
+
+    /// Moves the given [PdfPageObject] to the start of the drawing order of this
+    /// [PdfPageObjects] collection, so that it is painted beneath all other page objects.
+    ///
+    /// See the [PdfPageObjects::set_object_index()] function for details.
+    #[inline]
+    pub fn move_to_back(&mut self, object: &PdfPageObject<'a>) -> Result<(), PdfiumError> {
+        self.set_object_index(object, 0)
+    }
 }
 
 impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
@@ -143,3 +330,289 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
         object.remove_object_from_page().map(|_| object)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    fn object_texts(page: &PdfPage) -> Vec<String> {
+        page.objects()
+            .iter()
+            .filter_map(|object| object.as_text_object().map(|object| object.text()))
+            .collect()
+    }
+
+    #[test]
+    fn test_replace_object_preserves_position() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for text in ["Below", "Placeholder", "Above"] {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+                text,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let placeholder = page.objects().get(1)?;
+
+        let replacement = PdfPageTextObject::new(&document, "Final", font, PdfPoints::new(12.0))?;
+
+        let replaced = page
+            .objects_mut()
+            .replace_object(placeholder, PdfPageObject::from(replacement))?;
+
+        assert_eq!(replaced.as_text_object().unwrap().text(), "Final");
+        assert_eq!(object_texts(&page), vec!["Below", "Final", "Above"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_object_at_point_returns_topmost_object() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // Two overlapping rectangles; the second is drawn on top of the first.
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 300.0, 300.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(200.0, 200.0, 400.0, 400.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        let fill_blue = |object: PdfPageObject| object.fill_color().map(|color| color.blue());
+
+        let objects = page.objects();
+
+        // Inside both rectangles: the topmost (blue) rectangle wins.
+
+        let hit = objects.object_at_point(PdfPoints::new(250.0), PdfPoints::new(250.0));
+
+        assert_eq!(hit.map(fill_blue).transpose()?, Some(255));
+
+        // Inside only the first rectangle.
+
+        let hit = objects.object_at_point(PdfPoints::new(150.0), PdfPoints::new(150.0));
+
+        assert_eq!(hit.map(fill_blue).transpose()?, Some(0));
+
+        // Outside both rectangles.
+
+        assert!(objects
+            .object_at_point(PdfPoints::new(50.0), PdfPoints::new(50.0))
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bounding_box() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert!(matches!(
+            page.objects().bounding_box(),
+            Err(PdfiumError::NoPageObjectsInCollection)
+        ));
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        // The second rectangle is translated; its transformed bounds must be used.
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        object.translate(PdfPoints::new(200.0), PdfPoints::new(300.0))?;
+
+        let bounds = page.objects().bounding_box()?;
+
+        assert_eq!(bounds.bottom().value.round(), 100.0);
+        assert_eq!(bounds.left().value.round(), 100.0);
+        assert_eq!(bounds.top().value.round(), 500.0);
+        assert_eq!(bounds.right().value.round(), 400.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_removed_object_is_not_saved() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for (index, text) in ["Keep", "Discard"].iter().enumerate() {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0 + index as f32 * 50.0),
+                text,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let removed = page.objects_mut().remove_object_at_index(1)?;
+
+        assert_eq!(removed.as_text_object().unwrap().text(), "Discard");
+        assert_eq!(page.objects().len(), 1);
+
+        drop(page);
+
+        let bytes = document.save_to_bytes()?;
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = reloaded.pages().first()?;
+
+        let text = page.text()?.all();
+
+        assert!(text.contains("Keep"));
+        assert!(!text.contains("Discard"));
+        assert_eq!(object_texts(&page), vec!["Keep"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_to_front_changes_draw_order() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // Two overlapping rectangles; the second is drawn on top of the first.
+
+        let red = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 300.0, 300.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let blue = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(200.0, 200.0, 400.0, 400.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        assert_eq!(red.z_order(), Some(0));
+        assert_eq!(blue.z_order(), Some(1));
+
+        // Render at one pixel per point, and sample the pixel in the overlapping region.
+
+        let overlap_color = |page: &PdfPage| -> Result<[u8; 4], PdfiumError> {
+            let image = page
+                .render_with_config(&PdfRenderConfig::new().set_target_width(595))?
+                .as_image()
+                .into_rgba8();
+
+            let y = image.height() - 250;
+
+            Ok(image.get_pixel(250, y).0)
+        };
+
+        assert_eq!(overlap_color(&page)?, [0, 0, 255, 255]);
+
+        page.objects_mut().move_to_front(&red)?;
+
+        assert_eq!(red.z_order(), Some(1));
+        assert_eq!(blue.z_order(), Some(0));
+        assert_eq!(overlap_color(&page)?, [255, 0, 0, 255]);
+
+        page.objects_mut().move_to_back(&red)?;
+
+        assert_eq!(red.z_order(), Some(0));
+        assert_eq!(overlap_color(&page)?, [0, 0, 255, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_path_object_rect_is_closed_and_filled() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        let object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(25.0, 25.0, 75.0, 75.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(1.0)),
+            Some(PdfColor::RED),
+        )?;
+
+        // Three explicit line segments; the fourth side is drawn by closing the sub-path.
+
+        let segments = object.as_path_object().unwrap().segments();
+
+        let lines = segments
+            .iter()
+            .filter(|segment| segment.segment_type() == PdfPathSegmentType::LineTo)
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].is_close());
+
+        let bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_size(100, 100))?;
+
+        let center = bitmap.get_pixel(50, 50)?;
+
+        assert_eq!((center.red(), center.green(), center.blue()), (255, 0, 0));
+
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ use crate::pdf::document::page::PdfPageOrientation::{Landscape, Portrait};
 use crate::pdf::document::page::{PdfPage, PdfPageOrientation, PdfPageRenderRotation};
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
 use std::os::raw::c_int;
 
 #[cfg(doc)]
@@ -87,6 +88,8 @@ pub struct PdfRenderConfig {
     form_field_highlight: Option<Vec<(PdfFormFieldType, PdfColor)>>,
     transformation_matrix: PdfMatrix,
     clip_rect: Option<(Pixels, Pixels, Pixels, Pixels)>,
+    clip_page_rect: Option<PdfRect>,
+    render_band_height: Option<Pixels>,
 
     // The fields below set Pdfium's page rendering flags. Coverage for the
     // FPDF_DEBUG_INFO and FPDF_NO_CATCH flags is omitted since they are obsolete.
@@ -125,6 +128,8 @@ impl PdfRenderConfig {
             form_field_highlight: None,
             transformation_matrix: PdfMatrix::IDENTITY,
             clip_rect: None,
+            clip_page_rect: None,
+            render_band_height: None,
             do_set_flag_render_annotations: true,
             do_set_flag_use_lcd_text_rendering: false,
             do_set_flag_no_native_text: false,
@@ -251,6 +256,25 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Converts the width and height of each [PdfPage] from points to pixels so that the page
+    /// is rendered at the given physical resolution, expressed in dots per inch. Since there are
+    /// 72 points in an inch, a US Letter page measuring 612 x 792 points rendered at 150 DPI
+    /// will produce a bitmap measuring 1275 x 1650 pixels.
+    ///
+    /// The pixel dimensions are calculated separately for each page at render time, so the same
+    /// [PdfRenderConfig] will render pages of differing sizes at a consistent physical resolution.
+    /// Any rotation applied by [PdfRenderConfig::rotate()], [PdfRenderConfig::rotate_if_portrait()],
+    /// or [PdfRenderConfig::rotate_if_landscape()] is applied after the page has been scaled.
+    ///
+    /// This is equivalent to calling [PdfRenderConfig::scale_page_by_factor()] with a scale factor
+    /// of `dpi / 72.0`, and overrides any previous call to that function,
+    /// [PdfRenderConfig::scale_page_width_by_factor()], or
+    /// [PdfRenderConfig::scale_page_height_by_factor()].
+    #[inline]
+    pub fn set_target_dpi(self, dpi: f32) -> Self {
+        self.scale_page_by_factor(dpi / 72.0)
+    }
+
     /// Specifies that the final pixel width of the [PdfPage] will not exceed the given maximum.
     #[inline]
     pub fn set_maximum_width(mut self, width: Pixels) -> Self {
@@ -379,12 +403,29 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Fills the destination bitmap with the given background color before rendering the
+    /// [PdfPage]. Any areas of the page that are not covered by page content will show
+    /// this color in the rendered output.
+    ///
+    /// This is a convenience function equivalent to calling both
+    /// [PdfRenderConfig::clear_before_rendering()] with `true` and
+    /// [PdfRenderConfig::set_clear_color()] with the given color. A fully transparent color,
+    /// i.e. one with an alpha value of zero, leaves the alpha channel of uncovered areas at zero
+    /// when rendering to a bitmap format that includes an alpha channel, allowing the
+    /// rendered page to be composited later.
+    #[inline]
+    pub fn set_background_color(self, color: PdfColor) -> Self {
+        self.clear_before_rendering(true).set_clear_color(color)
+    }
+
     /// Controls whether form data widgets and user-supplied form data should be included
     /// during rendering of the [PdfPage]. The default is `true`.
     ///
     /// Pdfium's rendering pipeline supports _either_ rendering with form data _or_ rendering with
     /// a custom transformation matrix, but not both at the same time. Applying any transformation
     /// automatically sets this value to `false`, disabling rendering of form data.
+    /// Form data is never rendered when a region of the page has been set using
+    /// [PdfRenderConfig::set_clip_rect()], regardless of this value.
     #[inline]
     pub fn render_form_data(mut self, do_render: bool) -> Self {
         self.do_render_form_data = do_render;
@@ -652,6 +693,56 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Renders only the given region of the page, measured in page coordinates, into a bitmap
+    /// sized to fit that region. This avoids rasterizing parts of the page that are not needed;
+    /// for instance, a viewer that supports panning and zooming can render just the portion
+    /// of the page visible in its viewport.
+    ///
+    /// The region is scaled and rotated exactly as the full page would be, so settings such as
+    /// [PdfRenderConfig::set_target_width()], [PdfRenderConfig::set_maximum_height()], and
+    /// [PdfRenderConfig::rotate_if_landscape()] continue to apply to the full page.
+    /// The dimensions of the rendered bitmap are those of the given region within the scaled
+    /// and rotated page; for example, with a target width of 1000 pixels, a region covering the
+    /// left half of the page will be rendered into a bitmap 500 pixels wide. The region is
+    /// located on the page before any custom transformation set on this [PdfRenderConfig] is
+    /// applied. The [PdfPage::points_to_pixels()] and [PdfPage::pixels_to_points()] functions
+    /// take the region into account.
+    ///
+    /// Unlike [PdfRenderConfig::clip()], which limits rendering to a pixel region of
+    /// a bitmap sized to the full page, this setting changes the size of the rendered bitmap.
+    /// The two settings can be combined: if both are set, the pixel coordinates given to
+    /// [PdfRenderConfig::clip()] are measured within the bitmap sized to this region, and
+    /// only the intersection of the two is rendered.
+    ///
+    /// Rendering a region of the page uses the same rendering pipeline as clipping, so
+    /// setting a region automatically disables rendering of form data. If you must render
+    /// form data within a region, consider using the [PdfPage::flatten()] function to flatten
+    /// the form elements and form data into the containing page. Form data is never rendered
+    /// while a region is set, even if [PdfRenderConfig::render_form_data()] is
+    /// subsequently called.
+    #[inline]
+    pub fn set_clip_rect(mut self, rect: PdfRect) -> Self {
+        self.clip_page_rect = Some(rect);
+        self.do_render_form_data = false;
+
+        self
+    }
+
+    /// Sets the height, in pixels, of each horizontal band rendered by the
+    /// [PdfPage::render_to_callback()] function. Rendering a page in bands bounds the memory
+    /// needed to render very large pages, since only a single band is held in memory at once.
+    /// The last band of a page is shorter if the page's pixel height is not a multiple of
+    /// the band height.
+    ///
+    /// By default, the page is rendered in a single band covering its full height.
+    /// This setting has no effect on any other rendering function.
+    #[inline]
+    pub fn set_render_band_height(mut self, height: Pixels) -> Self {
+        self.render_band_height = Some(height);
+
+        self
+    }
+
     /// Computes the pixel dimensions and rotation settings for the given [PdfPage]
     /// based on the configuration of this [PdfRenderConfig].
     #[inline]
@@ -822,7 +913,12 @@ impl PdfRenderConfig {
         // is disabled, then the scaled output width and height and any user-specified
         // 90-degree rotation need to be applied to the transformation matrix now.
 
-        let transformation_matrix = if !self.do_render_form_data {
+        // Rendering a region of the page always requires the transformation matrix, even if
+        // rendering of form data was re-enabled after the region was set.
+
+        let do_render_form_data = self.do_render_form_data && self.clip_page_rect.is_none();
+
+        let transformation_matrix = if !do_render_form_data {
             let result = if target_rotation != PdfPageRenderRotation::None {
                 // Translate the origin to the center of the page before rotating.
 
@@ -847,6 +943,100 @@ impl PdfRenderConfig {
             Ok(self.transformation_matrix)
         };
 
+        let mut matrix = transformation_matrix
+            .unwrap_or(PdfMatrix::IDENTITY)
+            .as_pdfium();
+
+        let mut clipping = if let Some((left, top, right, bottom)) = self.clip_rect {
+            FS_RECTF {
+                left: left as f32,
+                top: top as f32,
+                right: right as f32,
+                bottom: bottom as f32,
+            }
+        } else {
+            FS_RECTF {
+                left: 0.0,
+                top: 0.0,
+                right: output_width as f32,
+                bottom: output_height as f32,
+            }
+        };
+
+        let mut page_rect = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: output_width as f32,
+            bottom: output_height as f32,
+        };
+
+        let (output_width, output_height) = match self.clip_page_rect {
+            Some(rect) => {
+                // Locate the region within the full page bitmap, then shift the rendering
+                // transformation so that the region's top left corner lies at the origin
+                // of a bitmap sized to the region.
+
+                let corners = [
+                    (rect.left(), rect.top()),
+                    (rect.right(), rect.top()),
+                    (rect.left(), rect.bottom()),
+                    (rect.right(), rect.bottom()),
+                ]
+                .iter()
+                .map(|(x, y)| {
+                    let mut device_x: c_int = 0;
+                    let mut device_y: c_int = 0;
+
+                    page.bindings().FPDF_PageToDevice(
+                        page.page_handle(),
+                        0,
+                        0,
+                        output_width,
+                        output_height,
+                        target_rotation.as_pdfium(),
+                        x.value.into(),
+                        y.value.into(),
+                        &mut device_x,
+                        &mut device_y,
+                    );
+
+                    (device_x, device_y)
+                })
+                .collect::<Vec<_>>();
+
+                let left = corners.iter().map(|(x, _)| *x).min().unwrap_or(0);
+                let top = corners.iter().map(|(_, y)| *y).min().unwrap_or(0);
+                let right = corners.iter().map(|(x, _)| *x).max().unwrap_or(0);
+                let bottom = corners.iter().map(|(_, y)| *y).max().unwrap_or(0);
+
+                let width = (right - left).max(1);
+                let height = (bottom - top).max(1);
+
+                matrix.e -= left as f32;
+                matrix.f -= top as f32;
+
+                // Any pixel clipping rectangle set by clip() is measured within the
+                // region's bitmap, so intersect the two.
+
+                clipping = FS_RECTF {
+                    left: clipping.left.max(0.0),
+                    top: clipping.top.max(0.0),
+                    right: clipping.right.min(width as f32),
+                    bottom: clipping.bottom.min(height as f32),
+                };
+
+                page_rect = FS_RECTF {
+                    left: -left as f32,
+                    top: -top as f32,
+                    right: (output_width - left) as f32,
+                    bottom: (output_height - top) as f32,
+                };
+
+                (width, height)
+            }
+            None => (output_width, output_height),
+        };
+
         PdfPageRenderSettings {
             width: output_width,
             height: output_height,
@@ -854,10 +1044,8 @@ impl PdfRenderConfig {
             rotate: target_rotation.as_pdfium(),
             do_clear_bitmap_before_rendering: self.do_clear_bitmap_before_rendering,
             clear_color: self.clear_color.as_pdfium_color(),
-            do_render_form_data: self.do_render_form_data,
-            form_field_highlight: if !self.do_render_form_data
-                || self.form_field_highlight.is_none()
-            {
+            do_render_form_data,
+            form_field_highlight: if !do_render_form_data || self.form_field_highlight.is_none() {
                 None
             } else {
                 Some(
@@ -874,26 +1062,15 @@ impl PdfRenderConfig {
                         .collect::<Vec<_>>(),
                 )
             },
-            matrix: transformation_matrix
-                .unwrap_or(PdfMatrix::IDENTITY)
-                .as_pdfium(),
-            clipping: if let Some((left, top, right, bottom)) = self.clip_rect {
-                FS_RECTF {
-                    left: left as f32,
-                    top: top as f32,
-                    right: right as f32,
-                    bottom: bottom as f32,
-                }
-            } else {
-                FS_RECTF {
-                    left: 0.0,
-                    top: 0.0,
-                    right: output_width as f32,
-                    bottom: output_height as f32,
-                }
-            },
+            matrix,
+            clipping,
+            page_rect,
             render_flags: render_flags as c_int,
             is_reversed_byte_order_flag_set: self.do_set_flag_reverse_byte_order,
+            band_height: match self.render_band_height {
+                Some(band_height) => band_height.clamp(1, output_height.max(1)),
+                None => output_height,
+            },
         }
     }
 }
@@ -919,6 +1096,10 @@ pub(crate) struct PdfPageRenderSettings {
     pub(crate) form_field_highlight: Option<Vec<(c_int, (FPDF_DWORD, u8))>>,
     pub(crate) matrix: FS_MATRIX,
     pub(crate) clipping: FS_RECTF,
+    // The rectangle, in bitmap pixels, covered by the full page. This can extend beyond
+    // the bitmap when only a region of the page is rendered.
+    pub(crate) page_rect: FS_RECTF,
     pub(crate) render_flags: c_int,
     pub(crate) is_reversed_byte_order_flag_set: bool,
+    pub(crate) band_height: c_int,
 }
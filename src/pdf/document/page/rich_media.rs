@@ -0,0 +1,112 @@
+//! Defines the [PdfPageRichMediaAnnotation] struct, exposing the embedded multimedia
+//! resources of a single RichMedia annotation, and the [PdfRichMediaAsset] struct,
+//! representing a single embedded resource.
+
+use crate::pdf::document::page::annotation::PdfPageAnnotation;
+
+#[cfg(doc)]
+use crate::pdf::document::page::{annotation::PdfPageAnnotationType, PdfPage};
+
+/// A single annotation of type [PdfPageAnnotationType::RichMedia], along with the
+/// multimedia resources embedded in the annotation's `/RichMediaContent` dictionary.
+///
+/// RichMedia annotations are typically used to embed video, audio, Flash, or 3D content
+/// in a page. Pdfium does not provide access to the contents of RichMedia annotations,
+/// so the embedded resources are located by inspecting the serialized document.
+///
+/// Use the [PdfPage::rich_media_annotations()] function to retrieve the RichMedia
+/// annotations on a page.
+pub struct PdfPageRichMediaAnnotation<'a> {
+    annotation: PdfPageAnnotation<'a>,
+    assets: Vec<PdfRichMediaAsset>,
+}
+
+impl<'a> PdfPageRichMediaAnnotation<'a> {
+    #[inline]
+    pub(crate) fn new(annotation: PdfPageAnnotation<'a>, assets: Vec<PdfRichMediaAsset>) -> Self {
+        PdfPageRichMediaAnnotation { annotation, assets }
+    }
+
+    /// Returns the underlying [PdfPageAnnotation] for this RichMedia annotation.
+    #[inline]
+    pub fn annotation(&self) -> &PdfPageAnnotation<'a> {
+        &self.annotation
+    }
+
+    /// Returns the multimedia resources embedded in this RichMedia annotation, in the order
+    /// in which they appear in the annotation's `/Assets` name tree.
+    #[inline]
+    pub fn assets(&self) -> &[PdfRichMediaAsset] {
+        self.assets.as_slice()
+    }
+}
+
+/// A single multimedia resource embedded in a [PdfPageRichMediaAnnotation].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfRichMediaAsset {
+    name: String,
+    mime_type: Option<String>,
+    filters: Vec<String>,
+    bytes: Vec<u8>,
+}
+
+impl PdfRichMediaAsset {
+    #[inline]
+    pub(crate) fn new(
+        name: String,
+        mime_type: Option<String>,
+        filters: Vec<String>,
+        bytes: Vec<u8>,
+    ) -> Self {
+        PdfRichMediaAsset {
+            name,
+            mime_type,
+            filters,
+            bytes,
+        }
+    }
+
+    /// Returns the name of this asset, as given in the annotation's `/Assets` name tree.
+    /// This is usually the original file name of the resource, such as `clip.mp4`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the MIME type of this asset, such as `video/mp4`, taken from the `/Subtype`
+    /// entry of the embedded file stream. Returns `None` if the embedded file stream
+    /// does not specify a MIME type.
+    #[inline]
+    pub fn mime_type(&self) -> Option<&str> {
+        self.mime_type.as_deref()
+    }
+
+    /// Returns the names of the stream filters, such as `FlateDecode`, applied to the
+    /// embedded file stream containing this asset, in the order in which they must be
+    /// reversed to recover the original resource from [PdfRichMediaAsset::raw_bytes()].
+    #[inline]
+    pub fn filters(&self) -> &[String] {
+        self.filters.as_slice()
+    }
+
+    /// Returns `true` if the embedded file stream containing this asset has one or more
+    /// stream filters applied to it, in which case the value returned by
+    /// [PdfRichMediaAsset::raw_bytes()] must be decoded by the caller before use.
+    #[inline]
+    pub fn is_encoded(&self) -> bool {
+        !self.filters.is_empty()
+    }
+
+    /// Returns the data of the embedded file stream containing this asset, exactly as it is
+    /// stored in the document.
+    ///
+    /// Stream filters are not reversed. Media formats such as MP4 are already compressed,
+    /// so they are usually stored without any filters, in which case the returned bytes are
+    /// the original media file. Otherwise, the caller must reverse each filter returned by
+    /// [PdfRichMediaAsset::filters()], for instance by inflating `FlateDecode` data,
+    /// before use.
+    #[inline]
+    pub fn raw_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
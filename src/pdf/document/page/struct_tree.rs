@@ -0,0 +1,880 @@
+//! Defines the [PdfPageStructTree] struct, exposing functionality related to the
+//! logical structure tree of a single `PdfPage` in a tagged PDF document.
+
+use crate::bindgen::{
+    FPDF_DOCUMENT, FPDF_OBJECT_BOOLEAN, FPDF_OBJECT_NAME, FPDF_OBJECT_NUMBER, FPDF_OBJECT_STRING,
+    FPDF_STRUCTELEMENT, FPDF_STRUCTELEMENT_ATTR, FPDF_STRUCTTREE,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::pages::PdfPageDictionaryState;
+use crate::pdf::document::raw::struct_element_object_number;
+use crate::utils::files::get_pdfium_document_bytes;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::collections::HashMap;
+use std::os::raw::{c_int, c_ulong, c_void};
+use std::sync::{Arc, Mutex};
+
+#[cfg(any(
+    feature = "pdfium_future",
+    feature = "pdfium_6721",
+    feature = "pdfium_6666",
+    feature = "pdfium_6611",
+    feature = "pdfium_6569",
+    feature = "pdfium_6555",
+    feature = "pdfium_6490",
+))]
+use crate::bindgen::{FPDF_OBJECT_ARRAY, FPDF_STRUCTELEMENT_ATTR_VALUE};
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// The logical structure tree of a single [PdfPage] in a tagged PDF document.
+///
+/// A tagged PDF document describes the logical structure of its content, such as headings,
+/// paragraphs, tables, and table cells, using a tree of [PdfStructElement] objects.
+/// Only the parts of the document's structure tree that refer to content on the containing
+/// [PdfPage] are included.
+///
+/// Use the [PdfPage::struct_tree()] function to retrieve the structure tree for a page.
+pub struct PdfPageStructTree<'a> {
+    handle: FPDF_STRUCTTREE,
+    document_handle: FPDF_DOCUMENT,
+    dictionaries: Option<Arc<Mutex<PdfPageDictionaryState>>>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageStructTree<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTTREE,
+        document_handle: FPDF_DOCUMENT,
+        dictionaries: Option<Arc<Mutex<PdfPageDictionaryState>>>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageStructTree {
+            handle,
+            document_handle,
+            dictionaries,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageStructTree].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of top-level [PdfStructElement] objects in this [PdfPageStructTree].
+    pub fn len(&self) -> usize {
+        self.bindings
+            .FPDF_StructTree_CountChildren(self.handle)
+            .max(0) as usize
+    }
+
+    /// Returns `true` if this [PdfPageStructTree] contains no structure elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the top-level [PdfStructElement] objects in this [PdfPageStructTree].
+    pub fn elements(&self) -> Vec<PdfStructElement<'_>> {
+        (0..self.len())
+            .filter_map(|index| {
+                let handle = self
+                    .bindings
+                    .FPDF_StructTree_GetChildAtIndex(self.handle, index as c_int);
+
+                if handle.is_null() {
+                    None
+                } else {
+                    Some(PdfStructElement::from_pdfium(
+                        handle,
+                        self.handle,
+                        self.document_handle,
+                        self.dictionaries.clone(),
+                        self.bindings,
+                    ))
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a> Drop for PdfPageStructTree<'a> {
+    /// Closes the [PdfPageStructTree], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_StructTree_Close(self.handle);
+    }
+}
+
+/// A single element in a [PdfPageStructTree], such as a paragraph, a table, or a table cell.
+///
+/// A [PdfStructElement] is only valid for as long as the [PdfPageStructTree] it was
+/// retrieved from.
+pub struct PdfStructElement<'a> {
+    handle: FPDF_STRUCTELEMENT,
+    tree_handle: FPDF_STRUCTTREE,
+    document_handle: FPDF_DOCUMENT,
+    dictionaries: Option<Arc<Mutex<PdfPageDictionaryState>>>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElement<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTELEMENT,
+        tree_handle: FPDF_STRUCTTREE,
+        document_handle: FPDF_DOCUMENT,
+        dictionaries: Option<Arc<Mutex<PdfPageDictionaryState>>>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElement {
+            handle,
+            tree_handle,
+            document_handle,
+            dictionaries,
+            bindings,
+        }
+    }
+
+    /// Creates a [PdfStructElement] for the given handle, belonging to the same
+    /// structure tree as this [PdfStructElement].
+    #[inline]
+    fn related(&self, handle: FPDF_STRUCTELEMENT) -> PdfStructElement<'a> {
+        PdfStructElement::from_pdfium(
+            handle,
+            self.tree_handle,
+            self.document_handle,
+            self.dictionaries.clone(),
+            self.bindings,
+        )
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructElement].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the structure type of this [PdfStructElement], such as `Table`, `TR`, or `TH`.
+    pub fn element_type(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetType(handle, buffer, length)
+        })
+    }
+
+    /// Returns the title of this [PdfStructElement], if any.
+    pub fn title(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetTitle(handle, buffer, length)
+        })
+    }
+
+    /// Returns the alternate description of this [PdfStructElement], if any.
+    pub fn alt_text(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetAltText(handle, buffer, length)
+        })
+    }
+
+    /// Returns the replacement text of this [PdfStructElement], if any.
+    pub fn actual_text(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetActualText(handle, buffer, length)
+        })
+    }
+
+    /// Returns the language of this [PdfStructElement] as an IETF BCP 47 language code, if any.
+    ///
+    /// A language set using [PdfStructElement::set_lang()] that has not yet been saved
+    /// is returned in preference to the language stored in the document.
+    pub fn lang(&self) -> Option<String> {
+        if let Some(lang) = self.unsaved_lang() {
+            return Some(lang);
+        }
+
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetLang(handle, buffer, length)
+        })
+    }
+
+    /// Sets the language of this [PdfStructElement], for instance to mark a quotation in a
+    /// language other than that of the surrounding text. The language should be an IETF BCP 47
+    /// language code such as `fr-CA`; it is stored exactly as given, without validation.
+    ///
+    /// Pdfium does not support writing structure element dictionaries directly, so the change
+    /// is recorded by the containing `PdfDocument` and written to the element's dictionary the
+    /// next time the document is saved. The new language is immediately returned by
+    /// [PdfStructElement::lang()].
+    ///
+    /// The element's dictionary is located by serializing the document in memory and walking
+    /// the structure tree. Returns [PdfiumError::StructElementLangNotWritable] if the dictionary
+    /// could not be located, for instance because it is a direct object nested inside its parent
+    /// or is stored in a compressed object stream, or if this element was not retrieved from
+    /// a page belonging to a `PdfDocument`.
+    pub fn set_lang(&mut self, lang: &str) -> Result<(), PdfiumError> {
+        let dictionaries = self
+            .dictionaries
+            .as_ref()
+            .ok_or(PdfiumError::StructElementLangNotWritable)?;
+
+        let number = self
+            .object_number()
+            .ok_or(PdfiumError::StructElementLangNotWritable)?;
+
+        dictionaries
+            .lock()
+            .unwrap()
+            .struct_element_langs
+            .insert(number, lang.to_owned());
+
+        Ok(())
+    }
+
+    /// Returns the language set for this [PdfStructElement] using [PdfStructElement::set_lang()]
+    /// that has not yet been saved, if any.
+    fn unsaved_lang(&self) -> Option<String> {
+        let dictionaries = self.dictionaries.as_ref()?;
+
+        if dictionaries.lock().unwrap().struct_element_langs.is_empty() {
+            // Avoid locating the element's dictionary if no languages have been set.
+
+            return None;
+        }
+
+        let number = self.object_number()?;
+
+        dictionaries
+            .lock()
+            .unwrap()
+            .struct_element_langs
+            .get(&number)
+            .cloned()
+    }
+
+    /// Returns the object number of the dictionary of this [PdfStructElement], locating it
+    /// in the serialized document the first time this function is called for this element.
+    /// Located object numbers are shared by every element retrieved from pages in the same
+    /// `PdfPages` collection.
+    fn object_number(&self) -> Option<u32> {
+        let dictionaries = self.dictionaries.as_ref()?;
+
+        let path = self.path()?;
+
+        let cached = dictionaries
+            .lock()
+            .unwrap()
+            .struct_element_object_numbers
+            .get(&path)
+            .copied();
+
+        if cached.is_some() {
+            return cached;
+        }
+
+        // The lock is not held while the document is serialized.
+
+        let number = struct_element_object_number(
+            get_pdfium_document_bytes(self.document_handle, self.bindings)?.as_slice(),
+            path.as_slice(),
+        )?;
+
+        dictionaries
+            .lock()
+            .unwrap()
+            .struct_element_object_numbers
+            .insert(path, number);
+
+        Some(number)
+    }
+
+    /// Returns the kid index of this [PdfStructElement] and each of its ancestors, starting
+    /// from the top-level element. Pdfium counts every entry in an element's `/K` entry when
+    /// indexing its children, including marked content and object references, so each index
+    /// is also the position of the element in its parent's `/K` entry.
+    fn path(&self) -> Option<Vec<usize>> {
+        let mut path = Vec::new();
+
+        let mut handle = self.handle;
+
+        loop {
+            let parent = self.bindings.FPDF_StructElement_GetParent(handle);
+
+            let index = if parent.is_null() {
+                (0..self
+                    .bindings
+                    .FPDF_StructTree_CountChildren(self.tree_handle)
+                    .max(0))
+                    .position(|index| {
+                        self.bindings
+                            .FPDF_StructTree_GetChildAtIndex(self.tree_handle, index)
+                            == handle
+                    })?
+            } else {
+                (0..self
+                    .bindings
+                    .FPDF_StructElement_CountChildren(parent)
+                    .max(0))
+                    .position(|index| {
+                        self.bindings
+                            .FPDF_StructElement_GetChildAtIndex(parent, index)
+                            == handle
+                    })?
+            };
+
+            path.push(index);
+
+            if parent.is_null() {
+                path.reverse();
+
+                return Some(path);
+            }
+
+            handle = parent;
+        }
+    }
+
+    /// Returns the child [PdfStructElement] objects of this [PdfStructElement].
+    /// Marked content and object references are not included.
+    pub fn children(&self) -> Vec<PdfStructElement<'a>> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_CountChildren(self.handle)
+            .max(0);
+
+        (0..count)
+            .filter_map(|index| {
+                let handle = self
+                    .bindings
+                    .FPDF_StructElement_GetChildAtIndex(self.handle, index);
+
+                if handle.is_null() {
+                    None
+                } else {
+                    Some(self.related(handle))
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the marked content IDs of the page content directly belonging to this
+    /// [PdfStructElement], in the order in which they are listed in the element.
+    /// Marked content belonging to child elements is not included.
+    pub fn marked_content_ids(&self) -> Vec<i32> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_GetMarkedContentIdCount(self.handle)
+            .max(0);
+
+        (0..count)
+            .map(|index| {
+                self.bindings
+                    .FPDF_StructElement_GetMarkedContentIdAtIndex(self.handle, index)
+            })
+            .filter(|id| *id >= 0)
+            .collect()
+    }
+
+    /// Returns the parent [PdfStructElement] of this [PdfStructElement], or `None` if this
+    /// is a top-level element.
+    pub fn parent(&self) -> Option<PdfStructElement<'a>> {
+        let handle = self.bindings.FPDF_StructElement_GetParent(self.handle);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(self.related(handle))
+        }
+    }
+
+    /// Returns the attributes of this [PdfStructElement], such as the `Scope`, `ColSpan`,
+    /// and `RowSpan` table attributes or the `BBox` and `Placement` layout attributes,
+    /// keyed by attribute name.
+    ///
+    /// Attributes from all the element's attribute objects are merged into a single map.
+    /// The `O` entry naming the owner of each attribute object is not included.
+    /// If more than one attribute object defines the same attribute, the value in the
+    /// last attribute object is returned.
+    ///
+    /// Boolean, number, string, and name attributes are returned for all Pdfium versions.
+    /// Array attributes, such as `BBox`, are only returned when using Pdfium 6490 or later;
+    /// attributes of any other type are not included.
+    pub fn attributes(&self) -> HashMap<String, PdfStructElementAttributeValue> {
+        let mut result = HashMap::new();
+
+        let count = self
+            .bindings
+            .FPDF_StructElement_GetAttributeCount(self.handle)
+            .max(0);
+
+        for index in 0..count {
+            let attribute = self
+                .bindings
+                .FPDF_StructElement_GetAttributeAtIndex(self.handle, index);
+
+            if attribute.is_null() {
+                continue;
+            }
+
+            let len = self
+                .bindings
+                .FPDF_StructElement_Attr_GetCount(attribute)
+                .max(0);
+
+            for index in 0..len {
+                if let Some(name) = self.get_attribute_name(attribute, index) {
+                    if name == "O" {
+                        continue;
+                    }
+
+                    if let Some(value) = self.get_attribute_value(attribute, &name) {
+                        result.insert(name, value);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the name of the attribute at the given index in the given attribute object.
+    fn get_attribute_name(
+        &self,
+        attribute: FPDF_STRUCTELEMENT_ATTR,
+        index: c_int,
+    ) -> Option<String> {
+        let mut length: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDF_StructElement_Attr_GetName(
+                attribute,
+                index,
+                std::ptr::null_mut(),
+                0,
+                &mut length,
+            ))
+            || length == 0
+        {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(length as usize);
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDF_StructElement_Attr_GetName(
+                attribute,
+                index,
+                buffer.as_mut_ptr() as *mut c_void,
+                length,
+                &mut length,
+            ))
+        {
+            return None;
+        }
+
+        // Unlike most text handling in Pdfium, attribute names are returned as
+        // null-terminated byte strings.
+
+        String::from_utf8(buffer)
+            .ok()
+            .map(|name| name.trim_end_matches(char::from(0)).to_owned())
+            .filter(|name| !name.is_empty())
+    }
+
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    /// Returns the value of the attribute with the given name in the given attribute object.
+    fn get_attribute_value(
+        &self,
+        attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+    ) -> Option<PdfStructElementAttributeValue> {
+        let value = self
+            .bindings
+            .FPDF_StructElement_Attr_GetValue(attribute, name);
+
+        if value.is_null() {
+            None
+        } else {
+            self.get_attribute_value_from_handle(value)
+        }
+    }
+
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn get_attribute_value_from_handle(
+        &self,
+        value: FPDF_STRUCTELEMENT_ATTR_VALUE,
+    ) -> Option<PdfStructElementAttributeValue> {
+        match self.bindings.FPDF_StructElement_Attr_GetType(value) as u32 {
+            FPDF_OBJECT_BOOLEAN => {
+                let mut result = 0;
+
+                if self.bindings.is_true(
+                    self.bindings
+                        .FPDF_StructElement_Attr_GetBooleanValue(value, &mut result),
+                ) {
+                    Some(PdfStructElementAttributeValue::Boolean(
+                        self.bindings.is_true(result),
+                    ))
+                } else {
+                    None
+                }
+            }
+            FPDF_OBJECT_NUMBER => {
+                let mut result = 0.0;
+
+                if self.bindings.is_true(
+                    self.bindings
+                        .FPDF_StructElement_Attr_GetNumberValue(value, &mut result),
+                ) {
+                    Some(PdfStructElementAttributeValue::Number(result))
+                } else {
+                    None
+                }
+            }
+            object_type @ (FPDF_OBJECT_STRING | FPDF_OBJECT_NAME) => self
+                .get_attribute_string(|buffer, length, out_length| {
+                    self.bindings
+                        .FPDF_StructElement_Attr_GetStringValue(value, buffer, length, out_length)
+                })
+                .map(|result| {
+                    if object_type == FPDF_OBJECT_NAME {
+                        PdfStructElementAttributeValue::Name(result)
+                    } else {
+                        PdfStructElementAttributeValue::String(result)
+                    }
+                }),
+            FPDF_OBJECT_ARRAY => {
+                let count = self
+                    .bindings
+                    .FPDF_StructElement_Attr_CountChildren(value)
+                    .max(0);
+
+                Some(PdfStructElementAttributeValue::Array(
+                    (0..count)
+                        .filter_map(|index| {
+                            let child = self
+                                .bindings
+                                .FPDF_StructElement_Attr_GetChildAtIndex(value, index);
+
+                            if child.is_null() {
+                                None
+                            } else {
+                                self.get_attribute_value_from_handle(child)
+                            }
+                        })
+                        .collect(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    #[cfg(any(
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    /// Returns the value of the attribute with the given name in the given attribute object.
+    fn get_attribute_value(
+        &self,
+        attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+    ) -> Option<PdfStructElementAttributeValue> {
+        match self
+            .bindings
+            .FPDF_StructElement_Attr_GetType(attribute, name) as u32
+        {
+            FPDF_OBJECT_BOOLEAN => {
+                let mut result = 0;
+
+                if self
+                    .bindings
+                    .is_true(self.bindings.FPDF_StructElement_Attr_GetBooleanValue(
+                        attribute,
+                        name,
+                        &mut result,
+                    ))
+                {
+                    Some(PdfStructElementAttributeValue::Boolean(
+                        self.bindings.is_true(result),
+                    ))
+                } else {
+                    None
+                }
+            }
+            FPDF_OBJECT_NUMBER => {
+                let mut result = 0.0;
+
+                if self
+                    .bindings
+                    .is_true(self.bindings.FPDF_StructElement_Attr_GetNumberValue(
+                        attribute,
+                        name,
+                        &mut result,
+                    ))
+                {
+                    Some(PdfStructElementAttributeValue::Number(result))
+                } else {
+                    None
+                }
+            }
+            object_type @ (FPDF_OBJECT_STRING | FPDF_OBJECT_NAME) => self
+                .get_attribute_string(|buffer, length, out_length| {
+                    self.bindings.FPDF_StructElement_Attr_GetStringValue(
+                        attribute, name, buffer, length, out_length,
+                    )
+                })
+                .map(|result| {
+                    if object_type == FPDF_OBJECT_NAME {
+                        PdfStructElementAttributeValue::Name(result)
+                    } else {
+                        PdfStructElementAttributeValue::String(result)
+                    }
+                }),
+            _ => None,
+        }
+    }
+
+    /// Retrieves a UTF-16LE attribute string value from Pdfium using the given function.
+    fn get_attribute_string<F>(&self, f: F) -> Option<String>
+    where
+        F: Fn(*mut c_void, c_ulong, *mut c_ulong) -> i32,
+    {
+        let mut length: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(f(std::ptr::null_mut(), 0, &mut length))
+            || length == 0
+        {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(length as usize);
+
+        if !self
+            .bindings
+            .is_true(f(buffer.as_mut_ptr() as *mut c_void, length, &mut length))
+        {
+            return None;
+        }
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+
+    /// Retrieves a UTF-16LE string property of this [PdfStructElement] from Pdfium
+    /// using the given function.
+    fn get_string<F>(&self, f: F) -> Option<String>
+    where
+        F: Fn(FPDF_STRUCTELEMENT, *mut c_void, c_ulong) -> c_ulong,
+    {
+        // Retrieving the string from Pdfium is a two-step operation. First, we call
+        // the function with a null buffer; this will retrieve the length of the string
+        // in bytes. If the length is zero, then the string is not present.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given length
+        // and call the function again with a pointer to the buffer; this will write
+        // the string into the buffer in UTF-16LE format.
+
+        let buffer_length = f(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = f(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
+
+/// The value of a single attribute of a [PdfStructElement].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PdfStructElementAttributeValue {
+    Boolean(bool),
+    Number(f32),
+    String(String),
+
+    /// A PDF name, such as the `Column` value of a table header cell's `Scope` attribute.
+    Name(String),
+
+    /// An array of values, such as the four numbers of a `BBox` attribute.
+    Array(Vec<PdfStructElementAttributeValue>),
+}
+
+impl PdfStructElementAttributeValue {
+    /// Returns the numeric value of this [PdfStructElementAttributeValue], if it is a number.
+    #[inline]
+    pub fn as_number(&self) -> Option<f32> {
+        match self {
+            PdfStructElementAttributeValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the text value of this [PdfStructElementAttributeValue], if it is a string
+    /// or a name.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PdfStructElementAttributeValue::String(value)
+            | PdfStructElementAttributeValue::Name(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_table_header_cell_attributes() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /StructTreeRoot 5 0 R /MarkInfo << \
+            /Marked true >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents 4 0 R \
+            /StructParents 0 /Resources << >> >>",
+            b"<< >>\nstream\n\
+            /TH << /MCID 0 >> BDC EMC",
+            b"<< /Type /StructTreeRoot /K 6 0 R /ParentTree << /Nums [0 [8 0 R]] >> >>",
+            b"<< /Type /StructElem /S /Table /P 5 0 R /K 7 0 R >>",
+            b"<< /Type /StructElem /S /TR /P 6 0 R /K 8 0 R >>",
+            b"<< /Type /StructElem /S /TH /P 7 0 R /Pg 3 0 R /K 0 /A << /O /Table /Scope /Column \
+            /ColSpan 2 >> >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let tree = page.struct_tree().unwrap();
+
+        let table = tree.elements().into_iter().next().unwrap();
+
+        assert_eq!(table.element_type().as_deref(), Some("Table"));
+
+        let header = table.children()[0].children().into_iter().next().unwrap();
+
+        assert_eq!(header.element_type().as_deref(), Some("TH"));
+
+        let attributes = header.attributes();
+
+        assert_eq!(
+            attributes.get("Scope"),
+            Some(&PdfStructElementAttributeValue::Name("Column".to_owned()))
+        );
+        assert_eq!(
+            attributes
+                .get("ColSpan")
+                .and_then(|value| value.as_number()),
+            Some(2.0)
+        );
+        assert!(!attributes.contains_key("O"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_lang_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /StructTreeRoot 5 0 R /MarkInfo << \
+            /Marked true >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents 4 0 R \
+            /StructParents 0 /Resources << >> >>",
+            b"<< >>\nstream\n\
+            /P << /MCID 0 >> BDC EMC /Quote << /MCID 1 >> BDC EMC",
+            b"<< /Type /StructTreeRoot /K 6 0 R /ParentTree << /Nums [0 [6 0 R 7 0 R]] >> >>",
+            b"<< /Type /StructElem /S /P /P 5 0 R /Pg 3 0 R /K [0 7 0 R] /Lang (en-GB) >>",
+            b"<< /Type /StructElem /S /Quote /P 6 0 R /Pg 3 0 R /K 1 >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        {
+            let page = document.pages().first()?;
+
+            let tree = page.struct_tree().unwrap();
+
+            let paragraph = tree.elements().into_iter().next().unwrap();
+
+            let mut quote = paragraph.children().into_iter().next().unwrap();
+
+            assert_eq!(quote.element_type().as_deref(), Some("Quote"));
+            assert_eq!(quote.lang(), None);
+
+            // Language tags are stored as given, without validation.
+
+            quote.set_lang("x-klingon-tlh")?;
+
+            assert_eq!(quote.lang().as_deref(), Some("x-klingon-tlh"));
+            assert_eq!(paragraph.lang().as_deref(), Some("en-GB"));
+        }
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = reloaded.pages().first()?;
+
+        let tree = page.struct_tree().unwrap();
+
+        let paragraph = tree.elements().into_iter().next().unwrap();
+
+        let quote = paragraph.children().into_iter().next().unwrap();
+
+        assert_eq!(quote.lang().as_deref(), Some("x-klingon-tlh"));
+        assert_eq!(paragraph.lang().as_deref(), Some("en-GB"));
+
+        Ok(())
+    }
+}
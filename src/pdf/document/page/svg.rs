@@ -0,0 +1,502 @@
+//! Defines the [PdfSvgConfig] struct, a builder-based approach to configuring the conversion
+//! of a [PdfPage] into a Scalable Vector Graphics (SVG) document.
+
+use crate::error::PdfiumError;
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::page::object::image::{PdfImageCompression, PdfPageImageObject};
+use crate::pdf::document::page::object::path::{PdfPagePathObject, PdfPathFillMode};
+use crate::pdf::document::page::object::text::{PdfPageTextObject, PdfPageTextRenderMode};
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::font::glyph::PdfFontGlyph;
+use crate::pdf::matrix::PdfMatrix;
+use crate::pdf::path::segment::PdfPathSegmentType;
+use crate::pdf::path::segments::PdfPathSegments;
+use std::fmt::Write;
+
+#[cfg(feature = "image")]
+use std::io::Cursor;
+
+#[cfg(any(feature = "image_latest", feature = "image_025"))]
+use image_025::ImageFormat;
+
+#[cfg(feature = "image_024")]
+use image_024::ImageFormat;
+
+#[cfg(feature = "image_023")]
+use image_023::ImageFormat;
+
+/// Configures the conversion of a [PdfPage] into a Scalable Vector Graphics (SVG) document
+/// by the [PdfPage::to_svg_with_config()] function.
+///
+/// By default, text is emitted as SVG `<text>` elements and images are embedded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PdfSvgConfig {
+    text_as_outlines: bool,
+    embed_images: bool,
+}
+
+impl PdfSvgConfig {
+    /// Creates a new [PdfSvgConfig] object with all settings initialized with their default values.
+    #[inline]
+    pub fn new() -> Self {
+        PdfSvgConfig {
+            text_as_outlines: false,
+            embed_images: true,
+        }
+    }
+
+    /// Controls whether text is emitted as vector outlines rather than as SVG `<text>` elements.
+    ///
+    /// Text emitted as `<text>` elements remains selectable and searchable, but is displayed
+    /// using whichever font the SVG viewer substitutes for the font named in the document,
+    /// so its appearance may not match the page. Text emitted as outlines uses the shapes
+    /// of the glyphs in the document's own fonts, so it always appears as it does on the page.
+    #[inline]
+    pub fn render_text_as_outlines(mut self, do_render: bool) -> Self {
+        self.text_as_outlines = do_render;
+
+        self
+    }
+
+    /// Controls whether images are embedded in the SVG document as base64-encoded data URIs.
+    ///
+    /// JPEG images are embedded using their original compressed data. Other images are
+    /// re-encoded as PNG images, which requires the `image` crate feature; without it,
+    /// such images are omitted.
+    #[inline]
+    pub fn embed_images(mut self, do_embed: bool) -> Self {
+        self.embed_images = do_embed;
+
+        self
+    }
+}
+
+impl Default for PdfSvgConfig {
+    #[inline]
+    fn default() -> Self {
+        PdfSvgConfig::new()
+    }
+}
+
+/// Converts the given [PdfPage] into an SVG document using the given [PdfSvgConfig].
+pub(crate) fn page_to_svg(page: &PdfPage, config: &PdfSvgConfig) -> Result<String, PdfiumError> {
+    let media = page
+        .boundaries()
+        .media()
+        .map(|boundary| boundary.bounds)
+        .unwrap_or_else(|_| page.page_size());
+
+    let mut svg = String::new();
+
+    let _ = write!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" \
+        width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">",
+        width = number(media.width().value),
+        height = number(media.height().value),
+    );
+
+    // PDF page coordinates place the origin at the bottom left of the page, with the y axis
+    // pointing upwards; SVG places the origin at the top left, with the y axis pointing downwards.
+
+    let _ = write!(
+        svg,
+        "<g transform=\"matrix(1 0 0 -1 {} {})\">",
+        number(-media.left().value),
+        number(media.top().value),
+    );
+
+    let text = if config.text_as_outlines {
+        Some(page.text()?)
+    } else {
+        None
+    };
+
+    for object in page.objects().iter() {
+        match &object {
+            PdfPageObject::Path(path) => write_path(&mut svg, path)?,
+            PdfPageObject::Text(text_object) => match text.as_ref() {
+                Some(text) => write_text_outlines(&mut svg, text_object, text)?,
+                None => write_text(&mut svg, text_object)?,
+            },
+            PdfPageObject::Image(image) if config.embed_images => write_image(&mut svg, image)?,
+            _ => {}
+        }
+    }
+
+    svg.push_str("</g></svg>");
+
+    Ok(svg)
+}
+
+fn write_path(svg: &mut String, path: &PdfPagePathObject) -> Result<(), PdfiumError> {
+    let matrix = path.matrix()?;
+
+    let data = path_data(&path.segments().transform(matrix));
+
+    if data.is_empty() {
+        return Ok(());
+    }
+
+    let fill = match path.fill_mode()? {
+        PdfPathFillMode::None => String::from("fill=\"none\""),
+        PdfPathFillMode::EvenOdd => format!(
+            "{} fill-rule=\"evenodd\"",
+            paint("fill", &path.fill_color()?)
+        ),
+        PdfPathFillMode::Winding => paint("fill", &path.fill_color()?),
+    };
+
+    let _ = write!(svg, "<path d=\"{}\" {}", data, fill);
+
+    if path.is_stroked()? {
+        // The path's points have already been transformed into page space, so the stroke
+        // width must be scaled to match.
+
+        let scale = matrix.determinant().abs().sqrt();
+
+        let _ = write!(
+            svg,
+            " {} stroke-width=\"{}\"",
+            paint("stroke", &path.stroke_color()?),
+            number(path.stroke_width()?.value * scale),
+        );
+    }
+
+    svg.push_str("/>");
+
+    Ok(())
+}
+
+fn write_text(svg: &mut String, object: &PdfPageTextObject) -> Result<(), PdfiumError> {
+    if object.render_mode() == PdfPageTextRenderMode::Invisible {
+        return Ok(());
+    }
+
+    let content = object.text();
+
+    if content.trim().is_empty() {
+        return Ok(());
+    }
+
+    let matrix = object.matrix()?;
+
+    // The additional vertical flip undoes the flip applied to the page as a whole,
+    // so that glyphs are drawn upright.
+
+    let _ = write!(
+        svg,
+        "<text transform=\"{} scale(1 -1)\" font-family=\"{}\" font-size=\"{}\" {} \
+        xml:space=\"preserve\">{}</text>",
+        transform(&matrix),
+        escape(object.font().family().as_str()),
+        number(object.unscaled_font_size().value),
+        paint("fill", &object.fill_color()?),
+        escape(content.as_str()),
+    );
+
+    Ok(())
+}
+
+fn write_text_outlines(
+    svg: &mut String,
+    object: &PdfPageTextObject,
+    text: &PdfPageText,
+) -> Result<(), PdfiumError> {
+    if object.render_mode() == PdfPageTextRenderMode::Invisible {
+        return Ok(());
+    }
+
+    let matrix = object.matrix()?;
+
+    let font = object.font();
+
+    let size = object.unscaled_font_size();
+
+    let mut data = String::new();
+
+    for char in text.chars_for_object(object)?.iter() {
+        let unicode = char.unicode_value();
+
+        if unicode > u16::MAX as u32 || char.unicode_char().map_or(true, char::is_whitespace) {
+            continue;
+        }
+
+        let (x, y) = char.origin()?;
+
+        // Glyph outlines are given in glyph space at the requested font size; the text matrix
+        // scales and rotates them, and each glyph is positioned at its character's origin.
+
+        let glyph_matrix = PdfMatrix::new(
+            matrix.a(),
+            matrix.b(),
+            matrix.c(),
+            matrix.d(),
+            x.value,
+            y.value,
+        );
+
+        let glyph = PdfFontGlyph::from_pdfium(font.handle(), unicode as u16, font.bindings());
+
+        if let Ok(segments) = glyph.segments_at_font_size(size) {
+            data.push_str(path_data_with_matrix(&segments, Some(glyph_matrix)).as_str());
+        }
+    }
+
+    if !data.is_empty() {
+        let _ = write!(
+            svg,
+            "<path d=\"{}\" {}/>",
+            data.trim_end(),
+            paint("fill", &object.fill_color()?),
+        );
+    }
+
+    Ok(())
+}
+
+fn write_image(svg: &mut String, image: &PdfPageImageObject) -> Result<(), PdfiumError> {
+    let (mime_type, data) = match image.compressed_stream() {
+        Some((PdfImageCompression::Dct, data)) => ("image/jpeg", data),
+        _ => match encode_png(image) {
+            Some(data) => ("image/png", data),
+            None => return Ok(()),
+        },
+    };
+
+    // The image object's matrix maps the unit square onto the page. Image rows are stored
+    // from top to bottom, so the image is flipped within the unit square.
+
+    let _ = write!(
+        svg,
+        "<image x=\"0\" y=\"0\" width=\"1\" height=\"1\" preserveAspectRatio=\"none\" \
+        transform=\"{} matrix(1 0 0 -1 0 1)\" href=\"data:{};base64,{}\"/>",
+        transform(&image.matrix()?),
+        mime_type,
+        base64(data.as_slice()),
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+fn encode_png(image: &PdfPageImageObject) -> Option<Vec<u8>> {
+    let mut cursor = Cursor::new(Vec::new());
+
+    image
+        .get_raw_image()
+        .ok()?
+        .write_to(&mut cursor, ImageFormat::Png)
+        .ok()?;
+
+    Some(cursor.into_inner())
+}
+
+#[cfg(not(feature = "image"))]
+#[inline]
+fn encode_png(_: &PdfPageImageObject) -> Option<Vec<u8>> {
+    None
+}
+
+/// Returns SVG path data for the given path segments.
+#[inline]
+fn path_data<'a>(segments: &impl PdfPathSegments<'a>) -> String {
+    path_data_with_matrix(segments, None)
+}
+
+/// Returns SVG path data for the given path segments, transforming each point
+/// by the given matrix if one is provided.
+fn path_data_with_matrix<'a>(
+    segments: &impl PdfPathSegments<'a>,
+    matrix: Option<PdfMatrix>,
+) -> String {
+    let mut data = String::new();
+
+    // Pdfium reports each cubic Bézier curve as three consecutive segments: the two
+    // control points followed by the end point.
+
+    let mut curve = Vec::with_capacity(3);
+
+    for index in segments.as_range() {
+        let segment = match segments.get(index) {
+            Ok(segment) => segment,
+            Err(_) => continue,
+        };
+
+        let (x, y) = segment.point();
+
+        let (x, y) = match matrix.as_ref() {
+            Some(matrix) => matrix.apply_to_points(x, y),
+            None => (x, y),
+        };
+
+        let point = format!("{} {}", number(x.value), number(y.value));
+
+        match segment.segment_type() {
+            PdfPathSegmentType::MoveTo => {
+                let _ = write!(data, "M{} ", point);
+            }
+            PdfPathSegmentType::LineTo => {
+                let _ = write!(data, "L{} ", point);
+            }
+            PdfPathSegmentType::BezierTo => {
+                curve.push(point);
+
+                if curve.len() == 3 {
+                    let _ = write!(data, "C{} ", curve.join(" "));
+
+                    curve.clear();
+                }
+            }
+            PdfPathSegmentType::Unknown => {}
+        }
+
+        if segment.is_close() {
+            data.push_str("Z ");
+        }
+    }
+
+    data.trim_end().to_owned()
+}
+
+/// Returns an SVG `transform` attribute value equivalent to the given matrix.
+#[inline]
+fn transform(matrix: &PdfMatrix) -> String {
+    format!(
+        "matrix({} {} {} {} {} {})",
+        number(matrix.a()),
+        number(matrix.b()),
+        number(matrix.c()),
+        number(matrix.d()),
+        number(matrix.e()),
+        number(matrix.f()),
+    )
+}
+
+/// Returns SVG attributes that paint either the fill or the stroke of an element
+/// with the given color.
+fn paint(attribute: &str, color: &PdfColor) -> String {
+    if color.alpha() == 255 {
+        format!("{}=\"#{}\"", attribute, color.to_hex())
+    } else {
+        format!(
+            "{}=\"#{}\" {}-opacity=\"{}\"",
+            attribute,
+            color.to_hex(),
+            attribute,
+            number(color.alpha() as f32 / 255.0)
+        )
+    }
+}
+
+/// Formats the given value with at most three decimal places, omitting trailing zeros.
+fn number(value: f32) -> String {
+    let result = format!("{:.3}", value);
+
+    let result = result.trim_end_matches('0').trim_end_matches('.');
+
+    match result {
+        "-0" | "" => String::from("0"),
+        _ => result.to_owned(),
+    }
+}
+
+/// Escapes the given text for inclusion in SVG element content or attribute values.
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            c if c < ' ' && c != '\t' && c != '\n' && c != '\r' => {}
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Encodes the given bytes using the standard base64 alphabet, with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut result = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let value = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+
+        for position in 0..4 {
+            if position <= chunk.len() {
+                result.push(ALPHABET[(value >> (18 - 6 * position) & 0x3F) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base64, number};
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_svg_helpers() {
+        assert_eq!(base64(b"Man"), "TWFu");
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"M"), "TQ==");
+        assert_eq!(number(612.0), "612");
+        assert_eq!(number(0.5), "0.5");
+        assert_eq!(number(-0.0001), "0");
+    }
+
+    #[test]
+    fn test_rectangle_page_to_svg() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(10.0, 20.0, 50.0, 120.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let svg = page.to_svg()?;
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("viewBox=\"0 0 200 100\""));
+
+        let path = &svg[svg.find("<path d=\"").unwrap()..];
+
+        let path = &path[..path.find("/>").unwrap()];
+
+        assert!(path.starts_with("<path d=\"M20 10 L"));
+        assert!(path.contains("L120 50"));
+        assert!(path.contains("Z\" fill=\"#FF0000\""));
+        assert!(!path.contains("stroke"));
+        assert!(svg.ends_with("</g></svg>"));
+
+        Ok(())
+    }
+}
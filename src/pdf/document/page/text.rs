@@ -2,12 +2,15 @@
 //! collection of Unicode characters visible on a single [PdfPage].
 
 pub mod char;
+pub mod char_info;
 pub mod chars;
+pub mod paragraph;
 pub mod search;
 pub mod segment;
 pub mod segments;
+pub mod word;
 
-use crate::bindgen::{FPDF_TEXTPAGE, FPDF_WCHAR, FPDF_WIDESTRING};
+use crate::bindgen::{FPDF_TEXTPAGE, FPDF_WCHAR, FPDF_WIDESTRING, FS_RECTF};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::pdf::document::page::annotation::PdfPageAnnotation;
@@ -16,9 +19,17 @@ use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::text::PdfPageTextObject;
 use crate::pdf::document::page::object::PdfPageObjectCommon;
 use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::text::char::PdfPageTextChar;
+use crate::pdf::document::page::text::char_info::PdfCharInfo;
 use crate::pdf::document::page::text::chars::{PdfPageTextCharIndex, PdfPageTextChars};
-use crate::pdf::document::page::text::search::{PdfPageTextSearch, PdfSearchOptions};
+use crate::pdf::document::page::text::paragraph::{
+    paragraphs_from_text, PdfPageTextParagraph, PdfPageTextParagraphOptions,
+};
+use crate::pdf::document::page::text::search::{
+    PdfPageTextSearch, PdfSearchDirection, PdfSearchOptions, PdfSearchResult,
+};
 use crate::pdf::document::page::text::segments::PdfPageTextSegments;
+use crate::pdf::document::page::text::word::{words_from_text, PdfPageTextWord};
 use crate::pdf::document::page::{PdfPage, PdfPageContentRegenerationStrategy, PdfPageIndexCache};
 use crate::pdf::document::pages::PdfPageIndex;
 use crate::pdf::points::PdfPoints;
@@ -29,9 +40,12 @@ use crate::utils::utf16le::{
 };
 use bytemuck::cast_slice;
 use std::fmt::{Display, Formatter};
-use std::os::raw::{c_double, c_int};
+use std::os::raw::{c_double, c_int, c_ushort};
 use std::ptr::null_mut;
 
+#[cfg(feature = "regex")]
+use regex::Regex;
+
 /// The collection of Unicode characters visible on a single [PdfPage].
 ///
 /// Use the [PdfPageText::all()] function to easily return all characters in the containing
@@ -45,6 +59,13 @@ use std::ptr::null_mut;
 /// which they are read by a user) may not necessarily match.
 ///
 /// [PdfPageText] implements both the [ToString] and the [Display] traits.
+///
+/// Each [PdfPageText] holds an open Pdfium text page handle for as long as it is in scope;
+/// the handle is closed when the [PdfPageText] is dropped. Loading a text page is relatively
+/// expensive, so callers running many queries against the same page should retrieve a single
+/// [PdfPageText] using [PdfPage::text()] and reuse it for every query, rather than retrieving
+/// a new [PdfPageText] for each query.
+#[doc(alias = "PdfTextPage")]
 pub struct PdfPageText<'a> {
     text_page_handle: FPDF_TEXTPAGE,
     page: &'a PdfPage<'a>,
@@ -108,6 +129,25 @@ impl<'a> PdfPageText<'a> {
         PdfPageTextSegments::new(self, start as i32, count as i32, self.bindings())
     }
 
+    /// Returns all the words in the containing [PdfPage], in the order in which their
+    /// characters are defined in the PDF file. Each [PdfPageTextWord] carries its own text,
+    /// bounding box, font size, and font name.
+    ///
+    /// A word is a run of non-whitespace characters sharing the same baseline. See the
+    /// [PdfPageTextWord] documentation for details of how word boundaries are determined.
+    #[inline]
+    pub fn words(&self) -> Vec<PdfPageTextWord> {
+        words_from_text(self)
+    }
+
+    /// Returns all the text in the containing [PdfPage], grouped into paragraphs using the
+    /// given [PdfPageTextParagraphOptions]. See the [PdfPageTextParagraph] documentation
+    /// for details of how paragraph boundaries are determined.
+    #[inline]
+    pub fn paragraphs(&self, options: &PdfPageTextParagraphOptions) -> Vec<PdfPageTextParagraph> {
+        paragraphs_from_text(self, options)
+    }
+
     /// Returns a collection of all the `PdfPageTextChar` characters in the containing [PdfPage].
     #[inline]
     pub fn chars(&self) -> PdfPageTextChars {
@@ -121,6 +161,113 @@ impl<'a> PdfPageText<'a> {
         )
     }
 
+    /// Returns an iterator over a [PdfCharInfo] snapshot of every character in the containing
+    /// [PdfPage], giving the Unicode value, origin, bounds, and font size of each character.
+    ///
+    /// Retrieving the same properties through [PdfPageText::chars()] takes four calls into Pdfium
+    /// per character. This function retrieves the Unicode values of all characters in a single
+    /// call, reducing the cost to three calls per character. The saving is most noticeable when
+    /// compiling to WASM, where each call into Pdfium involves copying data across the boundary
+    /// of Pdfium's WASM heap. All values are retrieved when this function is called, so the
+    /// returned iterator makes no further calls into Pdfium.
+    pub fn chars_fast(&self) -> impl Iterator<Item = PdfCharInfo> {
+        let count = self.len();
+
+        if count <= 0 {
+            return Vec::new().into_iter();
+        }
+
+        let unicode = self.unicode_chars(count);
+
+        let mut result = Vec::with_capacity(count as usize);
+
+        for (index, unicode) in unicode.into_iter().enumerate() {
+            let mut x = 0.0;
+
+            let mut y = 0.0;
+
+            let origin = if self.bindings.is_true(self.bindings.FPDFText_GetCharOrigin(
+                self.text_page_handle,
+                index as c_int,
+                &mut x,
+                &mut y,
+            )) {
+                (PdfPoints::new(x as f32), PdfPoints::new(y as f32))
+            } else {
+                (PdfPoints::ZERO, PdfPoints::ZERO)
+            };
+
+            let mut bounds = FS_RECTF {
+                left: 0.0,
+                top: 0.0,
+                right: 0.0,
+                bottom: 0.0,
+            };
+
+            let bounds = PdfRect::from_pdfium_as_result(
+                self.bindings.FPDFText_GetLooseCharBox(
+                    self.text_page_handle,
+                    index as c_int,
+                    &mut bounds,
+                ),
+                bounds,
+                self.bindings,
+            )
+            .unwrap_or(PdfRect::ZERO);
+
+            let font_size = PdfPoints::new(
+                self.bindings
+                    .FPDFText_GetFontSize(self.text_page_handle, index as c_int)
+                    as f32,
+            );
+
+            result.push(PdfCharInfo {
+                index: index as PdfPageTextCharIndex,
+                unicode,
+                origin,
+                bounds,
+                font_size,
+            });
+        }
+
+        result.into_iter()
+    }
+
+    /// Returns the Unicode values of the first `count` characters in this [PdfPageText].
+    fn unicode_chars(&self, count: i32) -> Vec<char> {
+        // FPDFText_GetText() writes one UTF-16 code unit per character, plus a trailing null.
+        // Characters outside the Basic Multilingual Plane are written as surrogate pairs,
+        // misaligning code units and character indices; in that case we fall back to
+        // retrieving each character individually.
+
+        let mut buffer = create_sized_buffer::<c_ushort>(count as usize + 1);
+
+        let written =
+            self.bindings
+                .FPDFText_GetText(self.text_page_handle, 0, count, buffer.as_mut_ptr());
+
+        if written == count + 1
+            && buffer[..count as usize]
+                .iter()
+                .all(|unit| !(0xD800..=0xDFFF).contains(unit))
+        {
+            buffer[..count as usize]
+                .iter()
+                .map(|unit| char::from_u32(*unit as u32).unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect()
+        } else {
+            (0..count)
+                .map(|index| {
+                    char::from_u32(
+                        self.bindings
+                            .FPDFText_GetUnicode(self.text_page_handle, index),
+                    )
+                    .unwrap_or(char::REPLACEMENT_CHARACTER)
+                })
+                .collect()
+        }
+    }
+
     /// Returns a collection of all the `PdfPageTextChar` characters in the given [PdfPageTextObject].
     ///
     /// The return result will be empty if the given [PdfPageTextObject] is not attached to the
@@ -268,6 +415,78 @@ impl<'a> PdfPageText<'a> {
         }
     }
 
+    /// Returns an iterator over every `PdfPageTextChar` character in the containing [PdfPage]
+    /// whose loose bounding box lies at least partially within the given [PdfRect], expanded
+    /// on every side by the given tolerance. Characters are yielded in reading order.
+    ///
+    /// Unlike [PdfPageText::chars_inside_rect()], which returns the contiguous run of characters
+    /// between the characters nearest the left and right edges of a rectangle, this function
+    /// tests every character on the page individually, so it suits rectangles spanning
+    /// several lines or columns of text, such as a text selection.
+    pub fn chars_in_rect(
+        &self,
+        rect: &PdfRect,
+        tolerance: PdfPoints,
+    ) -> impl Iterator<Item = PdfPageTextChar<'_>> + '_ {
+        let rect = PdfRect::new(
+            rect.bottom() - tolerance,
+            rect.left() - tolerance,
+            rect.top() + tolerance,
+            rect.right() + tolerance,
+        );
+
+        (0..self.len()).filter_map(move |index| {
+            let mut bounds = FS_RECTF {
+                left: 0.0,
+                top: 0.0,
+                right: 0.0,
+                bottom: 0.0,
+            };
+
+            PdfRect::from_pdfium_as_result(
+                self.bindings
+                    .FPDFText_GetLooseCharBox(self.text_page_handle, index, &mut bounds),
+                bounds,
+                self.bindings,
+            )
+            .ok()
+            .filter(|bounds| bounds.does_overlap(&rect))
+            .map(|_| {
+                PdfPageTextChar::from_pdfium(
+                    self.page.document_handle(),
+                    self.page.page_handle(),
+                    self.text_page_handle,
+                    index,
+                    self.bindings,
+                )
+            })
+        })
+    }
+
+    /// Returns the index of the character nearest to the given x and y positions on the
+    /// containing [PdfPage], or `None` if no character lies within the given tolerance
+    /// of the given positions. The same tolerance is applied both horizontally and vertically.
+    ///
+    /// This is useful for hit-testing a mouse click when implementing text selection;
+    /// the [PdfPageText::segments_subset()] function can then retrieve the bounding
+    /// rectangles of the characters between two such indices.
+    #[inline]
+    pub fn char_index_at_point(
+        &self,
+        x: PdfPoints,
+        y: PdfPoints,
+        tolerance: PdfPoints,
+    ) -> Option<PdfPageTextCharIndex> {
+        Self::get_char_index_near_point(
+            self.text_page_handle(),
+            x,
+            tolerance,
+            y,
+            tolerance,
+            self.bindings(),
+        )
+    }
+
     /// Returns the character near to the given x and y positions on the containing [PdfPage],
     /// if any. The returned character will be no further from the given positions than the given
     /// tolerance values.
@@ -421,6 +640,84 @@ impl<'a> PdfPageText<'a> {
         self.search_from(text, options, 0)
     }
 
+    /// Returns every match of the given search string in the containing [PdfPage], in the
+    /// order in which the matching characters are defined in the PDF file.
+    ///
+    /// This is a convenience function that starts a new search from the first character
+    /// on the page and steps through all results. An empty or whitespace-only search string
+    /// never matches anything, so an empty list is returned. If a limit set in the given
+    /// [PdfSearchOptions] is reached, the matches found up to that point are returned.
+    pub fn find_all(&self, needle: &str, options: &PdfSearchOptions) -> Vec<PdfSearchResult> {
+        let mut results = Vec::new();
+
+        if needle.trim().is_empty() {
+            return results;
+        }
+
+        let search = self.search(needle, options);
+
+        while let Some(Ok((start_index, count))) =
+            search.next_match(PdfSearchDirection::SearchForward)
+        {
+            let rects = self
+                .segments_subset(start_index, count)
+                .iter()
+                .map(|segment| segment.bounds())
+                .collect();
+
+            results.push(PdfSearchResult::new(start_index, count, rects));
+        }
+
+        results
+    }
+
+    /// Returns every non-empty match of the given regular expression in the containing
+    /// [PdfPage], in the order in which the matching characters are defined in the PDF file.
+    ///
+    /// The expression is matched against the same text returned by [PdfPageText::all()],
+    /// including any line breaks and spaces generated by Pdfium. Byte offsets of each match
+    /// are mapped back to Pdfium character indices, so matches containing multi-byte
+    /// UTF-8 characters report the correct [PdfSearchResult::start_index()] and
+    /// [PdfSearchResult::len()].
+    #[cfg(feature = "regex")]
+    pub fn search_regex(&self, pattern: &Regex) -> Vec<PdfSearchResult> {
+        let chars = self.unicode_chars(self.len());
+
+        // Record the byte offset at which each character starts, plus a final entry marking
+        // the end of the string, so that every match boundary maps to a character index.
+
+        let mut text = String::with_capacity(chars.len());
+
+        let mut byte_offsets = Vec::with_capacity(chars.len() + 1);
+
+        for c in chars {
+            byte_offsets.push(text.len());
+            text.push(c);
+        }
+
+        byte_offsets.push(text.len());
+
+        pattern
+            .find_iter(text.as_str())
+            .filter(|found| !found.as_str().is_empty())
+            .filter_map(|found| {
+                let start_index = byte_offsets.binary_search(&found.start()).ok()?;
+
+                let end_index = byte_offsets.binary_search(&found.end()).ok()?;
+
+                let count = end_index - start_index;
+
+                let rects = self
+                    .segments_subset(start_index, count)
+                    .iter()
+                    .map(|segment| segment.bounds())
+                    .collect();
+
+                Some(PdfSearchResult::new(start_index, count, rects))
+            })
+            .collect()
+    }
+
     /// Starts a search for the given test string from the given character position,
     /// returning a new [PdfPageTextSearch] object that can be used to step through
     /// the search results.
@@ -438,6 +735,7 @@ impl<'a> PdfPageText<'a> {
                 index as c_int,
             ),
             self,
+            options,
             self.bindings(),
         )
     }
@@ -522,6 +820,573 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_loose_bounds_differ_from_tight_bounds() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            "ag",
+            font,
+            PdfPoints::new(48.0),
+        )?;
+
+        let page_text = page.text()?;
+
+        let chars = page_text.chars();
+
+        let a = chars.get(0)?;
+
+        let g = chars.get(1)?;
+
+        assert_eq!(a.unicode_char(), Some('a'));
+        assert_eq!(g.unicode_char(), Some('g'));
+
+        let baseline = g.origin_y()?.value;
+
+        // The tight bounds of a character with a descender extend below the baseline;
+        // the tight bounds of a character without a descender do not.
+
+        assert!(g.tight_bounds()?.bottom().value < baseline);
+        assert!(a.tight_bounds()?.bottom().value >= baseline - 1.0);
+
+        // The loose bounds span the full font ascent and descent, so they are the same
+        // height for both characters and differ from the tight bounds of each.
+
+        assert_ne!(g.loose_bounds()?.bottom(), g.tight_bounds()?.bottom());
+        assert_ne!(g.loose_bounds()?.top(), g.tight_bounds()?.top());
+        assert_eq!(a.loose_bounds()?.bottom(), g.loose_bounds()?.bottom());
+        assert_eq!(a.loose_bounds()?.top(), g.loose_bounds()?.top());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_index_at_point() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            "Click",
+            font,
+            PdfPoints::new(48.0),
+        )?;
+
+        let page_text = page.text()?;
+
+        let bounds = page_text.chars().get(2)?.loose_bounds()?;
+
+        let (x, y) = (
+            (bounds.left() + bounds.right()) / 2.0,
+            (bounds.bottom() + bounds.top()) / 2.0,
+        );
+
+        assert_eq!(
+            page_text.char_index_at_point(x, y, PdfPoints::ZERO),
+            Some(2)
+        );
+        assert_eq!(
+            page_text.char_index_at_point(
+                PdfPoints::new(500.0),
+                PdfPoints::new(700.0),
+                PdfPoints::new(5.0)
+            ),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chars_fast_matches_chars() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let page_text = page.text()?;
+
+        let chars = page_text.chars();
+
+        let infos = page_text.chars_fast().collect::<Vec<_>>();
+
+        assert!(!infos.is_empty());
+        assert_eq!(infos.len(), chars.len());
+
+        for (info, char) in infos.iter().zip(chars.iter()) {
+            assert_eq!(info.index, char.index());
+            assert_eq!(Some(info.unicode), char.unicode_char());
+            assert_eq!(info.origin, char.origin()?);
+            assert_eq!(info.bounds, char.loose_bounds()?);
+            assert_eq!(info.font_size, char.unscaled_font_size());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_words() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "Hello world",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        // A large horizontal gap with no separating whitespace should also split words.
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(300.0),
+            PdfPoints::new(500.0),
+            "again",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(400.0),
+            "Goodbye",
+            font,
+            PdfPoints::new(24.0),
+        )?;
+
+        let words = page.text_words()?;
+
+        assert_eq!(
+            words.iter().map(|word| word.text()).collect::<Vec<_>>(),
+            vec!["Hello", "world", "again", "Goodbye"]
+        );
+
+        assert!(words[0].bounds().right() < words[1].bounds().left());
+        assert!(words[0].bounds().contains_y(PdfPoints::new(500.0)));
+        assert_eq!(words[0].font_size(), PdfPoints::new(12.0));
+        assert_eq!(words[3].font_size(), PdfPoints::new(24.0));
+        assert!(words[3].font_name().contains("Helvetica"));
+        assert!(words.iter().all(|word| word.page_index() == Some(0)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_paragraphs() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        // Two paragraphs of two lines each, separated by a blank line.
+
+        for (text, y) in [
+            ("The first paragraph", 700.0),
+            ("has two lines.", 686.0),
+            ("The second paragraph", 658.0),
+            ("also has two lines.", 644.0),
+        ] {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(72.0),
+                PdfPoints::new(y),
+                text,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let paragraphs = page
+            .text()?
+            .paragraphs(&PdfPageTextParagraphOptions::default());
+
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(paragraphs[0].text(), "The first paragraph has two lines.");
+        assert_eq!(paragraphs[0].line_count(), 2);
+        assert_eq!(
+            paragraphs[1].text(),
+            "The second paragraph also has two lines."
+        );
+        assert!(paragraphs[0].bounds().bottom() > paragraphs[1].bounds().top());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_all() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "The cat sat on the mat with the hat",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        let results = text.find_all("the", &PdfSearchOptions::new());
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.start_index())
+                .collect::<Vec<_>>(),
+            vec![0, 15, 28]
+        );
+        assert!(results.iter().all(|result| result.len() == 3));
+        assert!(results.iter().all(|result| !result.rects().is_empty()));
+
+        assert_eq!(
+            text.find_all("the", &PdfSearchOptions::new().match_case(true))
+                .len(),
+            2
+        );
+
+        // Empty and whitespace-only search strings must terminate without matching.
+
+        assert!(text.find_all("", &PdfSearchOptions::new()).is_empty());
+        assert!(text.find_all("   ", &PdfSearchOptions::new()).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chars_in_rect() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "First",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(400.0),
+            "Second",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        // A rectangle around both lines yields the characters of both, in reading order.
+
+        let rect = PdfRect::new_from_values(390.0, 90.0, 520.0, 300.0);
+
+        let chars = text
+            .chars_in_rect(&rect, PdfPoints::ZERO)
+            .filter_map(|char| char.unicode_char())
+            .filter(|char| !char.is_whitespace())
+            .collect::<String>();
+
+        assert_eq!(chars, "FirstSecond");
+
+        // A rectangle ending just short of the first line matches it only with a tolerance.
+
+        let rect = PdfRect::new_from_values(440.0, 90.0, 495.0, 300.0);
+
+        assert_eq!(text.chars_in_rect(&rect, PdfPoints::ZERO).count(), 0);
+        assert!(text.chars_in_rect(&rect, PdfPoints::new(10.0)).count() >= 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_max_results() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "The cat sat on the mat with the hat",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        let options = PdfSearchOptions::new().set_max_results(2);
+
+        let search = text.search("the", &options);
+
+        let results = search
+            .try_iter(PdfSearchDirection::SearchForward)
+            .collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(matches!(results[2], Err(PdfiumError::SearchLimitReached)));
+        assert!(search.is_limit_reached());
+        assert!(search.find_next().is_none());
+
+        assert_eq!(text.find_all("the", &options).len(), 2);
+
+        // A search that runs out of matches before reaching its limit ends normally.
+
+        let search = text.search("the", &PdfSearchOptions::new().set_max_results(5));
+
+        assert_eq!(search.iter(PdfSearchDirection::SearchForward).count(), 3);
+        assert!(!search.is_limit_reached());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_search_regex() -> Result<(), PdfiumError> {
+        use regex::Regex;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "Café filename file profile",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        // The multi-byte "é" occupies two bytes in UTF-8 but only one character index,
+        // so the single whole-word match starts at character index 14, not byte offset 15.
+
+        let results = text.search_regex(&Regex::new(r"\bfile\b").unwrap());
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].start_index(), 14);
+        assert_eq!(results[0].len(), 4);
+        assert!(!results[0].rects().is_empty());
+
+        let results = text.search_regex(&Regex::new(r"file").unwrap());
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.start_index())
+                .collect::<Vec<_>>(),
+            vec![5, 14, 22]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_reuses_handle_across_searches() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "The cat sat on the mat with the hat",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text_page = page.text()?;
+
+        let handle = text_page.text_page_handle();
+
+        for _ in 0..100 {
+            for (needle, expected) in [("the", 3), ("at", 4), ("dog", 0)] {
+                assert_eq!(
+                    text_page.find_all(needle, &PdfSearchOptions::new()).len(),
+                    expected
+                );
+            }
+
+            assert_eq!(text_page.chars().len() as i32, text_page.len());
+            assert!(!text_page.inside_rect(page.page_size()).is_empty());
+        }
+
+        assert_eq!(text_page.text_page_handle(), handle);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_chars() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(500.0),
+            "Hello",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(400.0),
+            "World",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        let text_chars = text.chars();
+
+        let chars = text_chars.iter().collect::<Vec<_>>();
+
+        // Pdfium inserts a line break between the two text objects.
+
+        assert!(chars.iter().any(|char| char.is_generated()));
+        assert!(chars
+            .iter()
+            .filter(|char| char.is_generated())
+            .all(|char| char.unicode_char().map_or(false, char::is_whitespace)));
+        assert!(chars.iter().all(|char| !char.is_hyphen()));
+
+        let content = chars
+            .iter()
+            .filter(|char| !char.is_generated())
+            .filter_map(|char| char.unicode_char())
+            .collect::<String>();
+
+        assert_eq!(content, "HelloWorld");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_fill_colors() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut heading = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Heading",
+            font,
+            PdfPoints::new(24.0),
+        )?;
+
+        heading.set_fill_color(PdfColor::RED)?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(650.0),
+            "Body",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let page_text = page.text()?;
+
+        let chars = page_text.chars();
+
+        let heading = chars.get(0)?;
+
+        let body = chars
+            .iter()
+            .find(|char| char.unicode_char() == Some('B'))
+            .unwrap();
+
+        assert_eq!(heading.visible_fill_color().unwrap().to_hex(), "FF0000");
+        assert_eq!(body.visible_fill_color().unwrap().to_hex(), "000000");
+
+        // Text is filled but not stroked by default.
+
+        assert!(heading.visible_stroke_color().is_none());
+        assert!(body.visible_stroke_color().is_none());
+
+        Ok(())
+    }
+
     fn test_one_overlapping_text_object_results(
         object: &PdfPageObject,
         page_text: &PdfPageText,
@@ -8,6 +8,7 @@ use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::object::text::PdfPageTextRenderMode;
 use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
 use crate::pdf::font::{FpdfFontDescriptorFlags, PdfFontWeight};
+use crate::pdf::matrix::PdfMatrix;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
 use crate::utils::mem::create_byte_buffer;
@@ -463,6 +464,50 @@ impl<'a> PdfPageTextChar<'a> {
         }
     }
 
+    /// Returns the fill color applied to this character, or `None` if the character's
+    /// text rendering mode means its glyph outlines are not filled when rendered,
+    /// for instance if the character is stroked but not filled, or is invisible.
+    ///
+    /// Pdfium reports only an RGB approximation of the fill color. Characters filled using
+    /// a pattern or shading rather than a solid color cannot be distinguished from solid
+    /// colors: Pdfium reports shading fills as opaque white, and colored tiling pattern
+    /// fills as mid-gray. Use [PdfPageTextChar::fill_color()] to retrieve the fill color
+    /// regardless of the character's text rendering mode.
+    pub fn visible_fill_color(&self) -> Option<PdfColor> {
+        if self.is_painted(PdfPageTextRenderMode::is_filled) {
+            self.fill_color().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the stroke color applied to this character, or `None` if the character's
+    /// text rendering mode means its glyph outlines are not stroked when rendered.
+    /// Most text is filled but not stroked, so this function usually returns `None`.
+    ///
+    /// The same limitations regarding patterns and shadings described for
+    /// [PdfPageTextChar::visible_fill_color()] apply. Use [PdfPageTextChar::stroke_color()]
+    /// to retrieve the stroke color regardless of the character's text rendering mode.
+    pub fn visible_stroke_color(&self) -> Option<PdfColor> {
+        if self.is_painted(PdfPageTextRenderMode::is_stroked) {
+            self.stroke_color().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the text rendering mode of this character satisfies the given
+    /// predicate. If the text rendering mode cannot be determined, the default mode of
+    /// [PdfPageTextRenderMode::FilledUnstroked] is assumed.
+    fn is_painted(&self, predicate: fn(&PdfPageTextRenderMode) -> bool) -> bool {
+        match self.render_mode() {
+            Ok(PdfPageTextRenderMode::Unknown) | Err(_) => {
+                predicate(&PdfPageTextRenderMode::FilledUnstroked)
+            }
+            Ok(mode) => predicate(&mode),
+        }
+    }
+
     /// Returns the rotation angle of this character, expressed in degrees.
     #[inline]
     pub fn angle_degrees(&self) -> Result<f32, PdfiumError> {
@@ -484,7 +529,9 @@ impl<'a> PdfPageTextChar<'a> {
     }
 
     /// Returns a precise bounding box for this character, taking the character's specific
-    /// shape into account.
+    /// shape into account. The bounding box is fitted to the ink of the glyph, so (for example)
+    /// the bounding box of a lowercase `g` extends below the font baseline while the bounding
+    /// box of a lowercase `a` does not.
     ///
     /// To return a loose bounding box that contains the entire glyph bounds, use the
     /// [PdfPageTextChar::loose_bounds] function.
@@ -519,6 +566,10 @@ impl<'a> PdfPageTextChar<'a> {
     }
 
     /// Returns a loose bounding box for this character, containing the entire glyph bounds.
+    /// The loose bounding box spans the full ascent and descent of the character's font,
+    /// and the full advance width of the glyph, irrespective of the character's specific shape.
+    /// Loose bounding boxes for adjacent characters on the same line will therefore share
+    /// the same top and bottom edges, making them a good fit for drawing text highlights.
     ///
     /// To return a tight bounding box that takes this character's specific shape into
     /// account, use the [PdfPageTextChar::tight_bounds] function.
@@ -563,6 +614,14 @@ impl<'a> PdfPageTextChar<'a> {
         }
     }
 
+    /// Returns the text matrix that Pdfium applied when laying out this character. The text
+    /// matrix maps the text space of the text object containing this character to the
+    /// user space of the containing page.
+    #[inline]
+    pub fn text_matrix(&self) -> Result<PdfMatrix, PdfiumError> {
+        self.matrix().map(PdfMatrix::from_pdfium)
+    }
+
     /// Returns the current horizontal and vertical translation of the origin of this character.
     #[inline]
     pub fn get_translation(&self) -> (PdfPoints, PdfPoints) {
@@ -739,6 +798,66 @@ impl<'a> PdfPageTextChar<'a> {
         self.origin().map(|result| result.1)
     }
 
+    /// Returns `true` if this character was generated by Pdfium during text extraction
+    /// rather than being present in the page content, for example a space or line break
+    /// inserted between two text objects.
+    ///
+    /// Returns `false` if Pdfium reports an error retrieving this information.
+    #[inline]
+    pub fn is_generated(&self) -> bool {
+        self.bindings()
+            .FPDFText_IsGenerated(self.text_page_handle, self.index)
+            == 1
+    }
+
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+    ))]
+    /// Returns `true` if Pdfium identifies this character as a hyphen, such as a hyphen
+    /// breaking a word across two lines.
+    ///
+    /// Returns `false` if Pdfium reports an error retrieving this information.
+    ///
+    /// When this crate is compiled using the `pdfium_5961` feature, this function instead
+    /// compares the character's Unicode value against known hyphen characters, and so may
+    /// return different results for the same character.
+    #[inline]
+    pub fn is_hyphen(&self) -> bool {
+        self.bindings()
+            .FPDFText_IsHyphen(self.text_page_handle, self.index)
+            == 1
+    }
+
+    #[cfg(feature = "pdfium_5961")]
+    /// Returns `true` if this character is a hyphen.
+    ///
+    /// Pdfium versions before 6015 cannot identify hyphens directly, so this function
+    /// instead returns `true` if the Unicode value of this character is a hyphen-minus,
+    /// a soft hyphen, or a hyphen. Unlike later Pdfium versions, which only identify
+    /// hyphens that Pdfium judges to break a word across two lines, this matches every
+    /// such character, including a hyphen-minus used as a minus sign or within a
+    /// hyphenated compound word.
+    #[inline]
+    pub fn is_hyphen(&self) -> bool {
+        matches!(self.unicode_char(), Some('-' | '\u{ad}' | '\u{2010}'))
+    }
+
     /// Returns `true` if the glyph shape of this character descends below the font baseline.
     #[inline]
     pub fn has_descender(&self) -> bool {
@@ -0,0 +1,38 @@
+//! Defines the [PdfCharInfo] struct, a plain snapshot of the most commonly used properties
+//! of a single character in a `PdfPageText` collection.
+
+use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+
+#[cfg(doc)]
+use {
+    crate::pdf::document::page::text::char::PdfPageTextChar,
+    crate::pdf::document::page::text::PdfPageText,
+};
+
+/// A plain snapshot of the Unicode value, origin, bounds, and font size of a single character,
+/// as returned by the [PdfPageText::chars_fast()] function.
+///
+/// Unlike a [PdfPageTextChar], which retrieves each property from Pdfium on demand,
+/// all the values in a [PdfCharInfo] are retrieved up front. Reading them involves
+/// no further calls into Pdfium.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfCharInfo {
+    /// The index of this character in the containing [PdfPageText] collection.
+    pub index: PdfPageTextCharIndex,
+
+    /// The Unicode value of this character. Characters with no valid Unicode value
+    /// are reported as [char::REPLACEMENT_CHARACTER].
+    pub unicode: char,
+
+    /// The origin of this character, relative to the containing page.
+    pub origin: (PdfPoints, PdfPoints),
+
+    /// The loose bounds of this character, as returned by [PdfPageTextChar::loose_bounds()].
+    pub bounds: PdfRect,
+
+    /// The font size applied to this character, ignoring any vertical scaling,
+    /// as returned by [PdfPageTextChar::unscaled_font_size()].
+    pub font_size: PdfPoints,
+}
@@ -0,0 +1,411 @@
+//! Defines the [PdfPageTextParagraph] struct, a paragraph of text extracted from a `PdfPageText`
+//! collection together with its bounding box, and the [PdfPageTextParagraphOptions] struct
+//! that controls how lines of text are grouped into paragraphs.
+
+use crate::pdf::document::page::text::word::PdfPageTextWord;
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::rect::PdfRect;
+use std::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// The maximum distance, expressed as a fraction of the font size, by which the first line
+/// of a paragraph can be indented relative to the second line. A line further to the right
+/// than this, such as a centered heading, is not treated as an indented first line.
+const MAXIMUM_INDENTATION: f32 = 4.0;
+
+/// Controls how lines of text are grouped into paragraphs by the [PdfPageText::paragraphs()]
+/// function. All distances are expressed as fractions of the font size of the line being
+/// considered, so the same options work equally well for body text and headings.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfPageTextParagraphOptions {
+    line_spacing_threshold: f32,
+    indentation_threshold: f32,
+    alignment_tolerance: f32,
+}
+
+impl PdfPageTextParagraphOptions {
+    /// Creates a new [PdfPageTextParagraphOptions] object with all settings initialized with
+    /// their default values.
+    #[inline]
+    pub fn new() -> Self {
+        PdfPageTextParagraphOptions {
+            line_spacing_threshold: 1.3,
+            indentation_threshold: 1.0,
+            alignment_tolerance: 0.5,
+        }
+    }
+
+    /// Sets the distance between the baselines of two adjacent lines, expressed as a multiple
+    /// of the median baseline distance on the page, above which the lines are considered to
+    /// belong to different paragraphs. Default: 1.3.
+    #[inline]
+    pub fn set_line_spacing_threshold(mut self, threshold: f32) -> Self {
+        self.line_spacing_threshold = threshold;
+
+        self
+    }
+
+    /// Sets the distance, expressed as a fraction of the font size, by which a line must be
+    /// indented relative to the left edge of the current paragraph for the line to be considered
+    /// the indented first line of a new paragraph. Default: 1.0.
+    #[inline]
+    pub fn set_indentation_threshold(mut self, threshold: f32) -> Self {
+        self.indentation_threshold = threshold;
+
+        self
+    }
+
+    /// Sets the tolerance, expressed as a fraction of the font size, within which the left
+    /// edges, right edges, or centers of two adjacent lines are considered to be aligned.
+    /// Adjacent lines sharing none of these alignments are considered to belong to different
+    /// paragraphs. Default: 0.5.
+    #[inline]
+    pub fn set_alignment_tolerance(mut self, tolerance: f32) -> Self {
+        self.alignment_tolerance = tolerance;
+
+        self
+    }
+
+    /// Returns the line spacing threshold used when grouping lines into paragraphs.
+    #[inline]
+    pub fn line_spacing_threshold(&self) -> f32 {
+        self.line_spacing_threshold
+    }
+
+    /// Returns the indentation threshold used when grouping lines into paragraphs.
+    #[inline]
+    pub fn indentation_threshold(&self) -> f32 {
+        self.indentation_threshold
+    }
+
+    /// Returns the alignment tolerance used when grouping lines into paragraphs.
+    #[inline]
+    pub fn alignment_tolerance(&self) -> f32 {
+        self.alignment_tolerance
+    }
+}
+
+impl Default for PdfPageTextParagraphOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfPageTextParagraphOptions::new()
+    }
+}
+
+/// A single paragraph of text extracted from a [PdfPageText] collection, together with
+/// its bounding box.
+///
+/// Paragraphs are assembled from the [PdfPageTextWord] objects on a page. Words sharing
+/// a baseline are first grouped into lines; adjacent lines are then grouped into paragraphs,
+/// starting a new paragraph whenever the spacing between two lines is noticeably larger than
+/// usual, whenever a line is indented relative to the lines before it, or whenever a line
+/// shares neither a left edge, a right edge, nor a center with the line before it.
+/// The thresholds for each of these rules can be adjusted using [PdfPageTextParagraphOptions].
+///
+/// Like [PdfPageTextWord], a [PdfPageTextParagraph] holds no reference to the [PdfPage] it was
+/// extracted from. When the `serde` crate feature is enabled, [PdfPageTextParagraph] implements
+/// `serde::Serialize`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PdfPageTextParagraph {
+    text: String,
+    bounds: PdfRect,
+    line_count: usize,
+}
+
+impl PdfPageTextParagraph {
+    /// Returns the text of this [PdfPageTextParagraph]. Words are separated by single spaces,
+    /// and lines are joined with a single space.
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the bounding box of this [PdfPageTextParagraph], the union of the bounding
+    /// boxes of every word in the paragraph.
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the number of lines of text in this [PdfPageTextParagraph].
+    #[inline]
+    pub fn line_count(&self) -> usize {
+        self.line_count
+    }
+}
+
+/// A single line of words, used while grouping words into paragraphs.
+#[derive(Debug, Clone)]
+struct PdfPageTextLine {
+    text: String,
+    bounds: PdfRect,
+    font_size: f32,
+}
+
+impl PdfPageTextLine {
+    fn new(word: &PdfPageTextWord) -> Self {
+        PdfPageTextLine {
+            text: word.text().to_owned(),
+            bounds: word.bounds(),
+            font_size: word.font_size().value,
+        }
+    }
+
+    /// Returns `true` if the given word continues this line, i.e. if it lies to the right of
+    /// the line and its vertical midpoint lies within the vertical extent of the line.
+    fn is_continued_by(&self, word: &PdfPageTextWord) -> bool {
+        let bounds = word.bounds();
+
+        let middle = (bounds.bottom().value + bounds.top().value) / 2.0;
+
+        middle >= self.bounds.bottom().value
+            && middle <= self.bounds.top().value
+            && bounds.left().value >= self.bounds.left().value
+    }
+
+    fn push(&mut self, word: &PdfPageTextWord) {
+        self.text.push(' ');
+        self.text.push_str(word.text());
+        self.bounds = self.bounds.union(&word.bounds());
+        self.font_size = self.font_size.max(word.font_size().value);
+    }
+
+    #[inline]
+    fn left(&self) -> f32 {
+        self.bounds.left().value
+    }
+
+    #[inline]
+    fn right(&self) -> f32 {
+        self.bounds.right().value
+    }
+
+    #[inline]
+    fn center(&self) -> f32 {
+        (self.left() + self.right()) / 2.0
+    }
+
+    #[inline]
+    fn baseline(&self) -> f32 {
+        self.bounds.bottom().value
+    }
+}
+
+/// Splits the words in the given [PdfPageText] collection into paragraphs.
+pub(crate) fn paragraphs_from_text(
+    text: &PdfPageText,
+    options: &PdfPageTextParagraphOptions,
+) -> Vec<PdfPageTextParagraph> {
+    paragraphs_from_lines(lines_from_words(&text.words()), options)
+}
+
+/// Groups the given words into lines.
+fn lines_from_words(words: &[PdfPageTextWord]) -> Vec<PdfPageTextLine> {
+    let mut lines: Vec<PdfPageTextLine> = Vec::new();
+
+    for word in words {
+        match lines.last_mut() {
+            Some(line) if line.is_continued_by(word) => line.push(word),
+            _ => lines.push(PdfPageTextLine::new(word)),
+        }
+    }
+
+    lines
+}
+
+/// Groups the given lines into paragraphs.
+fn paragraphs_from_lines(
+    lines: Vec<PdfPageTextLine>,
+    options: &PdfPageTextParagraphOptions,
+) -> Vec<PdfPageTextParagraph> {
+    let median_spacing = median_line_spacing(&lines);
+
+    let mut paragraphs: Vec<Vec<PdfPageTextLine>> = Vec::new();
+
+    for line in lines {
+        let is_new_paragraph = match paragraphs.last() {
+            Some(paragraph) => is_paragraph_break(paragraph, &line, median_spacing, options),
+            None => true,
+        };
+
+        if is_new_paragraph {
+            paragraphs.push(vec![line]);
+        } else if let Some(paragraph) = paragraphs.last_mut() {
+            paragraph.push(line);
+        }
+    }
+
+    paragraphs
+        .into_iter()
+        .map(|lines| PdfPageTextParagraph {
+            text: lines
+                .iter()
+                .map(|line| line.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+            bounds: lines
+                .iter()
+                .skip(1)
+                .fold(lines[0].bounds, |bounds, line| bounds.union(&line.bounds)),
+            line_count: lines.len(),
+        })
+        .collect()
+}
+
+/// Returns `true` if the given line should begin a new paragraph rather than continue
+/// the given paragraph.
+fn is_paragraph_break(
+    paragraph: &[PdfPageTextLine],
+    line: &PdfPageTextLine,
+    median_spacing: Option<f32>,
+    options: &PdfPageTextParagraphOptions,
+) -> bool {
+    let previous = match paragraph.last() {
+        Some(previous) => previous,
+        None => return true,
+    };
+
+    let spacing = previous.baseline() - line.baseline();
+
+    if spacing <= 0.0 {
+        // The line is level with or above the previous line, for instance
+        // at the top of a new column.
+
+        return true;
+    }
+
+    if let Some(median) = median_spacing {
+        if spacing > median * options.line_spacing_threshold {
+            return true;
+        }
+    }
+
+    let font_size = line.font_size.max(previous.font_size);
+
+    let paragraph_left = paragraph
+        .iter()
+        .map(|line| line.left())
+        .fold(f32::INFINITY, f32::min);
+
+    if line.left() - paragraph_left > font_size * options.indentation_threshold {
+        return true;
+    }
+
+    let tolerance = font_size * options.alignment_tolerance;
+
+    let is_aligned = (line.left() - previous.left()).abs() <= tolerance
+        || (line.right() - previous.right()).abs() <= tolerance
+        || (line.center() - previous.center()).abs() <= tolerance;
+
+    // The second line of a paragraph with an indented first line shares no alignment
+    // with the first line if the paragraph is not justified.
+
+    let follows_indented_first_line = paragraph.len() == 1
+        && line.left() < previous.left()
+        && previous.left() - line.left() <= font_size * MAXIMUM_INDENTATION;
+
+    !is_aligned && !follows_indented_first_line
+}
+
+/// Returns the median distance between the baselines of adjacent lines that lie one
+/// beneath the other, or `None` if there are no such lines.
+fn median_line_spacing(lines: &[PdfPageTextLine]) -> Option<f32> {
+    let mut spacings = lines
+        .windows(2)
+        .map(|pair| pair[0].baseline() - pair[1].baseline())
+        .filter(|spacing| *spacing > 0.0)
+        .collect::<Vec<_>>();
+
+    if spacings.is_empty() {
+        return None;
+    }
+
+    spacings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    Some(spacings[spacings.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str, baseline: f32, left: f32, right: f32) -> PdfPageTextLine {
+        PdfPageTextLine {
+            text: text.to_owned(),
+            bounds: PdfRect::new_from_values(baseline, left, baseline + 12.0, right),
+            font_size: 12.0,
+        }
+    }
+
+    #[test]
+    fn test_paragraphs_from_lines() {
+        let options = PdfPageTextParagraphOptions::new();
+
+        // Two left-aligned paragraphs separated by a blank line.
+
+        let paragraphs = paragraphs_from_lines(
+            vec![
+                line("one", 700.0, 72.0, 500.0),
+                line("two", 686.0, 72.0, 480.0),
+                line("three", 672.0, 72.0, 300.0),
+                line("four", 644.0, 72.0, 500.0),
+                line("five", 630.0, 72.0, 200.0),
+            ],
+            &options,
+        );
+
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(paragraphs[0].text(), "one two three");
+        assert_eq!(paragraphs[0].line_count(), 3);
+        assert_eq!(paragraphs[1].text(), "four five");
+        assert_eq!(
+            paragraphs[0].bounds(),
+            PdfRect::new_from_values(672.0, 72.0, 712.0, 500.0)
+        );
+
+        // Paragraphs with indented first lines and no additional spacing.
+
+        let paragraphs = paragraphs_from_lines(
+            vec![
+                line("one", 700.0, 96.0, 500.0),
+                line("two", 686.0, 72.0, 480.0),
+                line("three", 672.0, 96.0, 500.0),
+                line("four", 658.0, 72.0, 300.0),
+            ],
+            &options,
+        );
+
+        assert_eq!(
+            paragraphs
+                .iter()
+                .map(|paragraph| paragraph.text())
+                .collect::<Vec<_>>(),
+            vec!["one two", "three four"]
+        );
+
+        // A centered heading above a left-aligned paragraph.
+
+        let paragraphs = paragraphs_from_lines(
+            vec![
+                line("heading", 700.0, 250.0, 350.0),
+                line("one", 686.0, 72.0, 500.0),
+                line("two", 672.0, 72.0, 400.0),
+            ],
+            &options,
+        );
+
+        assert_eq!(
+            paragraphs
+                .iter()
+                .map(|paragraph| paragraph.text())
+                .collect::<Vec<_>>(),
+            vec!["heading", "one two"]
+        );
+    }
+}
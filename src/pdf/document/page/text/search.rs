@@ -3,10 +3,17 @@
 
 use crate::bindgen::{FPDF_MATCHCASE, FPDF_MATCHWHOLEWORD, FPDF_SCHHANDLE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
 use crate::pdf::document::page::text::segments::PdfPageTextSegments;
 use crate::pdf::document::page::text::PdfPageText;
-use std::os::raw::c_ulong;
+use crate::pdf::rect::PdfRect;
+use std::cell::Cell;
+use std::os::raw::{c_int, c_ulong};
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 #[cfg(doc)]
 use crate::pdf::document::page::PdfPage;
@@ -16,6 +23,8 @@ use crate::pdf::document::page::PdfPage;
 pub struct PdfSearchOptions {
     match_case: bool,
     match_whole_word: bool,
+    max_results: Option<usize>,
+    match_timeout: Option<Duration>,
 }
 
 impl PdfSearchOptions {
@@ -24,6 +33,8 @@ impl PdfSearchOptions {
         PdfSearchOptions {
             match_case: false,
             match_whole_word: false,
+            max_results: None,
+            match_timeout: None,
         }
     }
 
@@ -43,6 +54,42 @@ impl PdfSearchOptions {
         self
     }
 
+    /// Limits the number of results a [PdfPageTextSearch] will yield. Once the limit is reached,
+    /// the search ends; the [PdfPageTextSearch::try_iter()] function reports this by yielding
+    /// a final `Err(PdfiumError::SearchLimitReached)` value. By default, the number of results
+    /// is not limited.
+    pub fn set_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+
+        self
+    }
+
+    /// Limits the time a [PdfPageTextSearch] will spend yielding results, measured from the
+    /// first request for a result. Once the timeout has elapsed, the search ends;
+    /// the [PdfPageTextSearch::try_iter()] function reports this by yielding a final
+    /// `Err(PdfiumError::SearchLimitReached)` value. By default, no timeout is applied.
+    ///
+    /// The elapsed time is checked periodically rather than before every result, so a search
+    /// may run slightly past its timeout. Timeouts are not supported when compiling to WASM,
+    /// where this setting is ignored.
+    pub fn set_match_timeout(mut self, timeout: Duration) -> Self {
+        self.match_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Returns the maximum number of results a [PdfPageTextSearch] will yield, if any.
+    #[inline]
+    pub fn max_results(&self) -> Option<usize> {
+        self.max_results
+    }
+
+    /// Returns the time limit applied to a [PdfPageTextSearch], if any.
+    #[inline]
+    pub fn match_timeout(&self) -> Option<Duration> {
+        self.match_timeout
+    }
+
     pub(crate) fn as_pdfium(&self) -> c_ulong {
         let mut flag = 0;
 
@@ -71,11 +118,79 @@ pub enum PdfSearchDirection {
     SearchBackward,
 }
 
+/// A single match returned by the [PdfPageText::find_all()] function.
+///
+/// Unlike the [PdfPageTextSegments] yielded by a [PdfPageTextSearch] object, a [PdfSearchResult]
+/// holds no reference to the [PdfPageText] it was found in, so it can be retained after
+/// the page's text has been dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfSearchResult {
+    start_index: PdfPageTextCharIndex,
+    len: PdfPageTextCharIndex,
+    rects: Vec<PdfRect>,
+}
+
+impl PdfSearchResult {
+    #[inline]
+    pub(crate) fn new(
+        start_index: PdfPageTextCharIndex,
+        len: PdfPageTextCharIndex,
+        rects: Vec<PdfRect>,
+    ) -> Self {
+        PdfSearchResult {
+            start_index,
+            len,
+            rects,
+        }
+    }
+
+    /// Returns the index of the first character in this match.
+    #[inline]
+    pub fn start_index(&self) -> PdfPageTextCharIndex {
+        self.start_index
+    }
+
+    /// Returns the number of characters in this match.
+    #[inline]
+    pub fn len(&self) -> PdfPageTextCharIndex {
+        self.len
+    }
+
+    /// Returns `true` if this match contains no characters.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bounding boxes of the text segments covered by this match. A match that
+    /// spans more than one line of text, or more than one text object, will have more
+    /// than one bounding box.
+    #[inline]
+    pub fn rects(&self) -> &[PdfRect] {
+        self.rects.as_slice()
+    }
+}
+
+/// The number of results a [PdfPageTextSearch] yields between checks of its match timeout.
+const MATCH_TIMEOUT_CHECK_INTERVAL: usize = 16;
+
 /// Yields the results of searching for a given string within the collection of Unicode characters
 /// visible on a single [PdfPage].
+///
+/// A search ends when Pdfium finds no further matches, when Pdfium fails to advance through
+/// the page, or when a limit set by [PdfSearchOptions::set_max_results()] or
+/// [PdfSearchOptions::set_match_timeout()] is reached.
 pub struct PdfPageTextSearch<'a> {
     search_handle: FPDF_SCHHANDLE,
     text_page: &'a PdfPageText<'a>,
+    max_results: Option<usize>,
+    match_timeout: Option<Duration>,
+    result_count: Cell<usize>,
+    last_result: Cell<Option<(PdfSearchDirection, c_int)>>,
+    is_finished: Cell<bool>,
+    is_limit_reached: Cell<bool>,
+    #[cfg(not(target_arch = "wasm32"))]
+    started: Cell<Option<Instant>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -83,11 +198,20 @@ impl<'a> PdfPageTextSearch<'a> {
     pub(crate) fn from_pdfium(
         search_handle: FPDF_SCHHANDLE,
         text_page: &'a PdfPageText<'a>,
+        options: &PdfSearchOptions,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageTextSearch {
             search_handle,
             text_page,
+            max_results: options.max_results(),
+            match_timeout: options.match_timeout(),
+            result_count: Cell::new(0),
+            last_result: Cell::new(None),
+            is_finished: Cell::new(false),
+            is_limit_reached: Cell::new(false),
+            #[cfg(not(target_arch = "wasm32"))]
+            started: Cell::new(None),
             bindings,
         }
     }
@@ -120,26 +244,101 @@ impl<'a> PdfPageTextSearch<'a> {
 
     /// Returns the next search result yielded by this [PdfPageTextSearch] object
     /// in the given direction.
+    ///
+    /// Returns `None` if the search has ended, including when a limit set in the
+    /// [PdfSearchOptions] used to create this [PdfPageTextSearch] has been reached.
+    /// Use the [PdfPageTextSearch::try_next_result()] function to distinguish between
+    /// the two cases.
+    #[inline]
     pub fn get_next_result(&self, direction: PdfSearchDirection) -> Option<PdfPageTextSegments> {
+        self.try_next_result(direction)?.ok()
+    }
+
+    /// Returns the next search result yielded by this [PdfPageTextSearch] object
+    /// in the given direction.
+    ///
+    /// Returns `Some(Err(PdfiumError::SearchLimitReached))` exactly once if a limit set in the
+    /// [PdfSearchOptions] used to create this [PdfPageTextSearch] ends the search, and `None`
+    /// once the search has ended.
+    pub fn try_next_result(
+        &self,
+        direction: PdfSearchDirection,
+    ) -> Option<Result<PdfPageTextSegments<'_>, PdfiumError>> {
+        self.next_match(direction).map(|result| {
+            result.map(|(start_index, count)| self.text_page.segments_subset(start_index, count))
+        })
+    }
+
+    /// Advances this search to its next match in the given direction, returning the index
+    /// of the first character in the match and the number of characters it contains.
+    pub(crate) fn next_match(
+        &self,
+        direction: PdfSearchDirection,
+    ) -> Option<Result<(PdfPageTextCharIndex, PdfPageTextCharIndex), PdfiumError>> {
+        if self.is_finished.get() {
+            return None;
+        }
+
+        if self.has_timed_out() {
+            return Some(self.end_at_limit());
+        }
+
         let has_next = if direction == PdfSearchDirection::SearchForward {
             self.bindings().FPDFText_FindNext(self.search_handle()) != 0
         } else {
             self.bindings().FPDFText_FindPrev(self.search_handle()) != 0
         };
 
-        if has_next {
-            let start_index = self
-                .bindings()
-                .FPDFText_GetSchResultIndex(self.search_handle());
-            let count = self.bindings().FPDFText_GetSchCount(self.search_handle());
+        if !has_next {
+            return None;
+        }
 
-            return Some(self.text_page.segments_subset(
-                start_index as PdfPageTextCharIndex,
-                count as PdfPageTextCharIndex,
-            ));
-        } else {
-            None
+        let start_index = self
+            .bindings()
+            .FPDFText_GetSchResultIndex(self.search_handle());
+
+        let count = self.bindings().FPDFText_GetSchCount(self.search_handle());
+
+        let has_advanced = match self.last_result.get() {
+            Some((last_direction, last_start_index)) if last_direction == direction => {
+                match direction {
+                    PdfSearchDirection::SearchForward => start_index > last_start_index,
+                    PdfSearchDirection::SearchBackward => start_index < last_start_index,
+                }
+            }
+            _ => true,
+        };
+
+        if !has_advanced || start_index < 0 || count <= 0 {
+            // Pdfium has failed to make progress through the page; end the search
+            // rather than looping forever.
+
+            self.is_finished.set(true);
+
+            return None;
         }
+
+        if self
+            .max_results
+            .map_or(false, |max_results| self.result_count.get() >= max_results)
+        {
+            return Some(self.end_at_limit());
+        }
+
+        self.last_result.set(Some((direction, start_index)));
+        self.result_count.set(self.result_count.get() + 1);
+
+        Some(Ok((
+            start_index as PdfPageTextCharIndex,
+            count as PdfPageTextCharIndex,
+        )))
+    }
+
+    /// Returns `true` if this search ended because a limit set in the [PdfSearchOptions]
+    /// used to create it was reached.
+    #[inline]
+    pub fn is_limit_reached(&self) -> bool {
+        self.is_limit_reached.get()
     }
 
     /// Returns an iterator over all search results yielded by this [PdfPageTextSearch]
@@ -148,6 +347,50 @@ impl<'a> PdfPageTextSearch<'a> {
     pub fn iter(&self, direction: PdfSearchDirection) -> PdfPageTextSearchIterator {
         PdfPageTextSearchIterator::new(self, direction)
     }
+
+    /// Returns an iterator over all search results yielded by this [PdfPageTextSearch]
+    /// object in the given direction. If a limit set in the [PdfSearchOptions] used to create
+    /// this [PdfPageTextSearch] ends the search, the iterator yields a final
+    /// `Err(PdfiumError::SearchLimitReached)` value.
+    #[inline]
+    pub fn try_iter(&self, direction: PdfSearchDirection) -> PdfPageTextSearchTryIterator<'_> {
+        PdfPageTextSearchTryIterator::new(self, direction)
+    }
+
+    fn end_at_limit<T>(&self) -> Result<T, PdfiumError> {
+        self.is_finished.set(true);
+        self.is_limit_reached.set(true);
+
+        Err(PdfiumError::SearchLimitReached)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn has_timed_out(&self) -> bool {
+        let timeout = match self.match_timeout {
+            Some(timeout) => timeout,
+            None => return false,
+        };
+
+        match self.started.get() {
+            None => {
+                self.started.set(Some(Instant::now()));
+
+                false
+            }
+            Some(started) => {
+                self.result_count.get() % MATCH_TIMEOUT_CHECK_INTERVAL == 0
+                    && started.elapsed() >= timeout
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[inline]
+    fn has_timed_out(&self) -> bool {
+        // std::time::Instant is not available when compiling to WASM.
+
+        false
+    }
 }
 
 impl<'a> Drop for PdfPageTextSearch<'a> {
@@ -177,3 +420,25 @@ impl<'a> Iterator for PdfPageTextSearchIterator<'a> {
         self.search.get_next_result(self.direction)
     }
 }
+
+/// An iterator over all the [PdfPageTextSegments] search results yielded by a [PdfPageTextSearch]
+/// object, yielding a final `Err(PdfiumError::SearchLimitReached)` value if a limit set in the
+/// search's [PdfSearchOptions] ends the search.
+pub struct PdfPageTextSearchTryIterator<'a> {
+    search: &'a PdfPageTextSearch<'a>,
+    direction: PdfSearchDirection,
+}
+
+impl<'a> PdfPageTextSearchTryIterator<'a> {
+    pub(crate) fn new(search: &'a PdfPageTextSearch<'a>, direction: PdfSearchDirection) -> Self {
+        PdfPageTextSearchTryIterator { search, direction }
+    }
+}
+
+impl<'a> Iterator for PdfPageTextSearchTryIterator<'a> {
+    type Item = Result<PdfPageTextSegments<'a>, PdfiumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.search.try_next_result(self.direction)
+    }
+}
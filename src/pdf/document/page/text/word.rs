@@ -0,0 +1,189 @@
+//! Defines the [PdfPageTextWord] struct, a single word of text extracted from a `PdfPageText`
+//! collection together with its position and font settings.
+
+use crate::pdf::document::page::index_cache::PdfPageIndexCache;
+use crate::pdf::document::page::text::char::PdfPageTextChar;
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// The horizontal gap between two adjacent characters on the same baseline, expressed
+/// as a fraction of the font size, above which the characters are considered to belong
+/// to different words even if no whitespace character separates them.
+///
+/// A value of 0.25 corresponds to roughly the width of a space character in most fonts.
+pub const PDF_PAGE_TEXT_WORD_GAP_THRESHOLD: f32 = 0.25;
+
+/// The vertical distance between the baselines of two adjacent characters, expressed
+/// as a fraction of the font size, above which the characters are considered to be
+/// on different lines.
+const BASELINE_TOLERANCE: f32 = 0.2;
+
+/// A single word of text extracted from a [PdfPageText] collection, together with its
+/// bounding box and font settings.
+///
+/// A word is a run of non-whitespace characters sharing the same baseline. Words are
+/// separated by whitespace characters, by a change of baseline, by a horizontal gap
+/// between adjacent characters larger than [PDF_PAGE_TEXT_WORD_GAP_THRESHOLD] multiplied
+/// by the font size, or by a character that starts to the left of the character before it,
+/// as happens when the text flow jumps back to an earlier position on the line.
+///
+/// Unlike most other objects in this crate, a [PdfPageTextWord] holds no reference to the
+/// [PdfPage] it was extracted from, so it can outlive both the page and its containing document.
+/// When the `serde` crate feature is enabled, [PdfPageTextWord] implements `serde::Serialize`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PdfPageTextWord {
+    page_index: Option<PdfPageIndex>,
+    text: String,
+    bounds: PdfRect,
+    font_size: PdfPoints,
+    font_name: String,
+}
+
+impl PdfPageTextWord {
+    /// Returns the zero-based index of the [PdfPage] this [PdfPageTextWord] was extracted from.
+    /// Returns `None` if the index of the page was not known when the word was extracted,
+    /// which can only happen if the page has since been removed from its document.
+    #[inline]
+    pub fn page_index(&self) -> Option<PdfPageIndex> {
+        self.page_index
+    }
+
+    /// Returns the text of this [PdfPageTextWord].
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the bounding box of this [PdfPageTextWord]. The bounding box is the union
+    /// of the loose bounds of every character in the word, so it spans the full ascent
+    /// and descent of the word's font.
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the effective font size of the first character in this [PdfPageTextWord],
+    /// taking any vertical scaling into account.
+    #[inline]
+    pub fn font_size(&self) -> PdfPoints {
+        self.font_size
+    }
+
+    /// Returns the name of the font applied to the first character in this [PdfPageTextWord].
+    #[inline]
+    pub fn font_name(&self) -> &str {
+        self.font_name.as_str()
+    }
+}
+
+/// Accumulates adjacent characters into a single [PdfPageTextWord].
+struct PdfPageTextWordBuilder {
+    word: PdfPageTextWord,
+    baseline: f32,
+    last_left: f32,
+}
+
+impl PdfPageTextWordBuilder {
+    fn new(
+        page_index: Option<PdfPageIndex>,
+        char: &PdfPageTextChar,
+        c: char,
+        bounds: PdfRect,
+        baseline: f32,
+    ) -> Self {
+        PdfPageTextWordBuilder {
+            word: PdfPageTextWord {
+                page_index,
+                text: c.to_string(),
+                bounds,
+                font_size: char.scaled_font_size(),
+                font_name: char.font_name(),
+            },
+            baseline,
+            last_left: bounds.left().value,
+        }
+    }
+
+    /// Returns `true` if a character with the given bounds and baseline continues this word.
+    fn is_continued_by(&self, bounds: &PdfRect, baseline: f32) -> bool {
+        let font_size = self.word.font_size.value;
+
+        let gap = bounds.left().value - self.word.bounds.right().value;
+
+        // Adjacent characters may overlap slightly because of kerning, giving a small negative
+        // gap, but a character that starts to the left of the previous character means the
+        // text flow has moved backwards, so it always begins a new word.
+
+        (baseline - self.baseline).abs() <= font_size * BASELINE_TOLERANCE
+            && bounds.left().value >= self.last_left
+            && gap <= font_size * PDF_PAGE_TEXT_WORD_GAP_THRESHOLD
+    }
+
+    fn push(&mut self, c: char, bounds: &PdfRect) {
+        self.word.text.push(c);
+
+        self.last_left = bounds.left().value;
+
+        self.word.bounds = self.word.bounds.union(bounds);
+    }
+}
+
+/// Splits the characters in the given [PdfPageText] collection into words.
+pub(crate) fn words_from_text(text: &PdfPageText) -> Vec<PdfPageTextWord> {
+    let page_index =
+        PdfPageIndexCache::get_index_for_page(text.page.document_handle(), text.page.page_handle());
+
+    let mut words = Vec::new();
+
+    let mut current: Option<PdfPageTextWordBuilder> = None;
+
+    for char in text.chars().iter() {
+        let c = match char.unicode_char() {
+            Some(c) if !c.is_whitespace() && !c.is_control() => c,
+            _ => {
+                // Whitespace and unrepresentable characters always end the current word.
+
+                if let Some(builder) = current.take() {
+                    words.push(builder.word);
+                }
+
+                continue;
+            }
+        };
+
+        let (bounds, baseline) = match (char.loose_bounds(), char.origin_y()) {
+            (Ok(bounds), Ok(baseline)) => (bounds, baseline.value),
+            _ => continue,
+        };
+
+        match current.as_mut() {
+            Some(builder) if builder.is_continued_by(&bounds, baseline) => {
+                builder.push(c, &bounds);
+            }
+            _ => {
+                if let Some(builder) = current.take() {
+                    words.push(builder.word);
+                }
+
+                current = Some(PdfPageTextWordBuilder::new(
+                    page_index, &char, c, bounds, baseline,
+                ));
+            }
+        }
+    }
+
+    if let Some(builder) = current {
+        words.push(builder.word);
+    }
+
+    words
+}
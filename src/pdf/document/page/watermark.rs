@@ -0,0 +1,127 @@
+//! Defines the [PdfWatermarkOptions] struct, a builder-based approach to configuring
+//! the text watermarks created by the [PdfPage::add_watermark()] function.
+
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::fonts::{PdfFontToken, ToPdfFontToken};
+use crate::pdf::points::PdfPoints;
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// The position of a text watermark relative to the existing content on a [PdfPage].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfWatermarkPosition {
+    /// The watermark will be painted on top of all existing page content.
+    AboveContent,
+
+    /// The watermark will be painted underneath all existing page content.
+    BelowContent,
+}
+
+/// Configures the font, size, color, opacity, rotation, and position of a text watermark
+/// created by the [PdfPage::add_watermark()] function. The watermark will always be
+/// centered on the page.
+#[derive(Debug, Copy, Clone)]
+pub struct PdfWatermarkOptions {
+    font: PdfFontToken,
+    font_size: PdfPoints,
+    color: PdfColor,
+    opacity: f32,
+    rotation_degrees: f32,
+    position: PdfWatermarkPosition,
+}
+
+impl PdfWatermarkOptions {
+    /// Creates a new [PdfWatermarkOptions] object using the given font. All other settings
+    /// are initialized with their default values: a font size of 72 points, a 50% grey color
+    /// at 50% opacity, a counter-clockwise rotation of 45 degrees, and a position above all
+    /// existing page content.
+    pub fn new(font: impl ToPdfFontToken) -> Self {
+        PdfWatermarkOptions {
+            font: font.token(),
+            font_size: PdfPoints::new(72.0),
+            color: PdfColor::GREY_50,
+            opacity: 0.5,
+            rotation_degrees: 45.0,
+            position: PdfWatermarkPosition::AboveContent,
+        }
+    }
+
+    /// Sets the font used to render the watermark text.
+    #[inline]
+    pub fn set_font(mut self, font: impl ToPdfFontToken) -> Self {
+        self.font = font.token();
+
+        self
+    }
+
+    /// Sets the font size used to render the watermark text.
+    #[inline]
+    pub fn set_font_size(mut self, font_size: PdfPoints) -> Self {
+        self.font_size = font_size;
+
+        self
+    }
+
+    /// Sets the color used to fill the watermark text. Any alpha channel value in the given
+    /// color is ignored; use the [PdfWatermarkOptions::set_opacity()] function to control
+    /// the transparency of the watermark.
+    #[inline]
+    pub fn set_color(mut self, color: PdfColor) -> Self {
+        self.color = color;
+
+        self
+    }
+
+    /// Sets the opacity of the watermark text, expressed as a value between 0.0 (completely
+    /// transparent) and 1.0 (completely opaque). Values outside this range will be clamped.
+    #[inline]
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+
+        self
+    }
+
+    /// Sets the counter-clockwise rotation of the watermark text, in degrees.
+    #[inline]
+    pub fn set_rotation_degrees(mut self, degrees: f32) -> Self {
+        self.rotation_degrees = degrees;
+
+        self
+    }
+
+    /// Sets whether the watermark should be painted above or below existing page content.
+    #[inline]
+    pub fn set_position(mut self, position: PdfWatermarkPosition) -> Self {
+        self.position = position;
+
+        self
+    }
+
+    #[inline]
+    pub(crate) fn font(&self) -> PdfFontToken {
+        self.font
+    }
+
+    #[inline]
+    pub(crate) fn font_size(&self) -> PdfPoints {
+        self.font_size
+    }
+
+    /// Returns the fill color of the watermark text, with the configured opacity applied
+    /// as the color's alpha channel.
+    #[inline]
+    pub(crate) fn fill_color(&self) -> PdfColor {
+        self.color.with_alpha((self.opacity * 255.0).round() as u8)
+    }
+
+    #[inline]
+    pub(crate) fn rotation_degrees(&self) -> f32 {
+        self.rotation_degrees
+    }
+
+    #[inline]
+    pub(crate) fn position(&self) -> PdfWatermarkPosition {
+        self.position
+    }
+}
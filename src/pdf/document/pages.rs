@@ -8,17 +8,41 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
 use crate::pdf::document::page::index_cache::PdfPageIndexCache;
 use crate::pdf::document::page::object::group::PdfPageGroupObject;
+use crate::pdf::document::page::render_config::PdfRenderConfig;
 use crate::pdf::document::page::size::PdfPagePaperSize;
-use crate::pdf::document::page::PdfPage;
+use crate::pdf::document::page::watermark::PdfWatermarkOptions;
+use crate::pdf::document::page::{PdfPage, PdfPageRenderRotation};
+use crate::pdf::document::raw::{
+    append_page_user_units, append_struct_element_langs, page_user_units,
+};
 use crate::pdf::document::PdfDocument;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
+use crate::utils::files::get_pdfium_document_bytes;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::collections::BTreeMap;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::{c_double, c_int, c_void};
+use std::sync::{Arc, Mutex};
+
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+use {std::sync::mpsc::sync_channel, std::thread};
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "image_latest", feature = "image_025")
+))]
+use image_025::DynamicImage;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image_024"))]
+use image_024::DynamicImage;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image_023"))]
+use image_023::DynamicImage;
 
 /// The zero-based index of a single [PdfPage] inside its containing [PdfPages] collection.
 pub type PdfPageIndex = u16;
@@ -71,11 +95,83 @@ impl PdfPageMode {
     }
 }
 
+/// Page and structure element dictionary entries that Pdfium can neither read nor write,
+/// held by a [PdfPages] collection and shared with every [PdfPage] retrieved from it.
+#[derive(Default)]
+pub(crate) struct PdfPageDictionaryState {
+    // The object number and saved `/UserUnit` entry of each page dictionary, in page order,
+    // read from a single serialization of the document. Pdfium preserves object numbers,
+    // so the entries only need to be read again when the number of pages changes or
+    // a page is deleted.
+    saved_user_units: Option<Vec<(u32, f32)>>,
+
+    // Keyed by the object number of the page dictionary, which, unlike the page index,
+    // does not change when pages are inserted, deleted, or moved.
+    user_units: BTreeMap<u32, f32>,
+
+    // Keyed by the object number of the structure element dictionary.
+    pub(crate) struct_element_langs: BTreeMap<u32, String>,
+
+    // The object number of each structure element dictionary located so far, keyed by the
+    // element's kid index path from the root of the structure tree. Pages can be inserted
+    // and deleted without changing the structure tree, so these are never read again.
+    pub(crate) struct_element_object_numbers: BTreeMap<Vec<usize>, u32>,
+}
+
+impl PdfPageDictionaryState {
+    /// Returns the object number of the dictionary of the page at the given index in the
+    /// given document, along with the `/UserUnit` entry saved in that dictionary.
+    fn saved_user_unit(
+        &mut self,
+        document_handle: FPDF_DOCUMENT,
+        index: PdfPageIndex,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(u32, f32), PdfiumError> {
+        let len = bindings.FPDF_GetPageCount(document_handle).max(0) as usize;
+
+        if self.saved_user_units.as_ref().map(|pages| pages.len()) != Some(len) {
+            self.saved_user_units = Some(
+                get_pdfium_document_bytes(document_handle, bindings)
+                    .and_then(|bytes| page_user_units(bytes.as_slice()))
+                    .filter(|pages| pages.len() == len)
+                    .ok_or(PdfiumError::PageUserUnitNotReadable)?,
+            );
+        }
+
+        self.saved_user_units
+            .as_ref()
+            .and_then(|pages| pages.get(index as usize))
+            .copied()
+            .ok_or(PdfiumError::PageIndexOutOfBounds)
+    }
+
+    /// Returns the `/UserUnit` scale factor of the page at the given index in the given
+    /// document, taking into account any value that has been set but not yet saved.
+    pub(crate) fn user_unit(
+        &mut self,
+        document_handle: FPDF_DOCUMENT,
+        index: PdfPageIndex,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<f32, PdfiumError> {
+        let (number, saved) = self.saved_user_unit(document_handle, index, bindings)?;
+
+        Ok(self.user_units.get(&number).copied().unwrap_or(saved))
+    }
+
+    /// Discards the saved page dictionary entries read from the document, so that they are
+    /// read again the next time they are needed. Called whenever a page is deleted.
+    #[inline]
+    pub(crate) fn invalidate(&mut self) {
+        self.saved_user_units = None;
+    }
+}
+
 /// The collection of [PdfPage] objects inside a [PdfDocument].
 pub struct PdfPages<'a> {
     document_handle: FPDF_DOCUMENT,
     form_handle: Option<FPDF_FORMHANDLE>,
     bindings: &'a dyn PdfiumLibraryBindings,
+    dictionaries: Arc<Mutex<PdfPageDictionaryState>>,
 }
 
 impl<'a> PdfPages<'a> {
@@ -89,6 +185,7 @@ impl<'a> PdfPages<'a> {
             document_handle,
             form_handle,
             bindings,
+            dictionaries: Arc::new(Mutex::new(PdfPageDictionaryState::default())),
         }
     }
 
@@ -126,6 +223,9 @@ impl<'a> PdfPages<'a> {
     }
 
     /// Returns a single [PdfPage] from this [PdfPages] collection.
+    ///
+    /// Returns [PdfiumError::PageLoadFailed] if Pdfium is unable to load the page at the
+    /// given index, for instance because the page's dictionary is corrupt.
     pub fn get(&self, index: PdfPageIndex) -> Result<PdfPage<'a>, PdfiumError> {
         if index >= self.len() {
             return Err(PdfiumError::PageIndexOutOfBounds);
@@ -135,6 +235,10 @@ impl<'a> PdfPages<'a> {
             .bindings
             .FPDF_LoadPage(self.document_handle, index as c_int);
 
+        if page_handle.is_null() {
+            return Err(PdfiumError::PageLoadFailed { index });
+        }
+
         let result = self.pdfium_page_handle_to_result(index, page_handle);
 
         if let Ok(page) = result.as_ref() {
@@ -149,9 +253,99 @@ impl<'a> PdfPages<'a> {
         result
     }
 
+    /// Returns the `/UserUnit` scale factor of the [PdfPage] at the given index, taking into
+    /// account any value set using [PdfPages::set_user_unit()] that has not yet been saved.
+    /// See [PdfPage::user_unit()] for more information.
+    ///
+    /// The user units of all pages are read together the first time this function or
+    /// [PdfPage::user_unit()] is called, and are read again only after pages are added
+    /// or deleted, so retrieving the user unit of every page in turn is inexpensive.
+    pub fn user_unit(&self, index: PdfPageIndex) -> Result<f32, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        self.dictionaries
+            .lock()
+            .unwrap()
+            .user_unit(self.document_handle, index, self.bindings)
+    }
+
+    /// Sets the `/UserUnit` scale factor of the [PdfPage] at the given index. A user unit of
+    /// `72.0` means that each default unit measures one inch rather than one point, allowing
+    /// pages larger than the 14,400 point limit imposed by the PDF specification on page
+    /// dimensions expressed in default units.
+    ///
+    /// Pdfium does not support writing the `/UserUnit` entry directly, so the change is
+    /// recorded by this [PdfPages] collection and written to the page's dictionary
+    /// the next time the containing `PdfDocument` is saved. The change follows the page if
+    /// pages are later inserted, deleted, or moved; it is discarded if the page is deleted.
+    /// The new value is immediately returned by both [PdfPages::user_unit()] and
+    /// [PdfPage::user_unit()].
+    ///
+    /// Returns [PdfiumError::PageUserUnitNotReadable] if the page dictionary could not be
+    /// located, for instance because it is stored in a compressed object stream.
+    pub fn set_user_unit(
+        &mut self,
+        index: PdfPageIndex,
+        user_unit: f32,
+    ) -> Result<(), PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        if !user_unit.is_finite() || user_unit <= 0.0 {
+            return Err(PdfiumError::PageUserUnitInvalid);
+        }
+
+        let mut dictionaries = self.dictionaries.lock().unwrap();
+
+        let (number, _) =
+            dictionaries.saved_user_unit(self.document_handle, index, self.bindings)?;
+
+        dictionaries.user_units.insert(number, user_unit);
+
+        Ok(())
+    }
+
+    /// Returns `true` if any page user units have been set using [PdfPages::set_user_unit()],
+    /// or any structure element languages have been set using `PdfStructElement::set_lang()`.
+    #[inline]
+    pub(crate) fn is_modified(&self) -> bool {
+        let dictionaries = self.dictionaries.lock().unwrap();
+
+        !dictionaries.user_units.is_empty() || !dictionaries.struct_element_langs.is_empty()
+    }
+
+    /// Writes the page user units set using [PdfPages::set_user_unit()] and the structure
+    /// element languages set using `PdfStructElement::set_lang()` to the given document data,
+    /// as saved by Pdfium, as incremental updates to the affected dictionaries.
+    pub(crate) fn apply_to_saved_bytes(&self, bytes: Vec<u8>) -> Result<Vec<u8>, PdfiumError> {
+        let dictionaries = self.dictionaries.lock().unwrap();
+
+        let mut bytes = bytes;
+
+        if !dictionaries.user_units.is_empty() {
+            bytes = append_page_user_units(bytes.as_slice(), &dictionaries.user_units)
+                .ok_or(PdfiumError::PageUserUnitNotWritable)?;
+        }
+
+        if !dictionaries.struct_element_langs.is_empty() {
+            bytes =
+                append_struct_element_langs(bytes.as_slice(), &dictionaries.struct_element_langs)
+                    .ok_or(PdfiumError::StructElementLangNotWritable)?;
+        }
+
+        Ok(bytes)
+    }
+
     /// Returns the size of a single [PdfPage] without loading it into memory.
     /// This is considerably faster than loading the page first via [PdfPages::get()] and then
     /// retrieving the page size using [PdfPage::page_size()].
+    ///
+    /// The size is read from the document's page tree using `FPDF_GetPageSizeByIndexF()`;
+    /// the page's content is never parsed, so this function is suitable for laying out
+    /// many pages before deciding which of them to load.
     pub fn page_size(&self, index: PdfPageIndex) -> Result<PdfRect, PdfiumError> {
         if index >= self.len() {
             return Err(PdfiumError::PageIndexOutOfBounds);
@@ -236,12 +430,20 @@ impl<'a> PdfPages<'a> {
     }
 
     /// Creates a new, empty [PdfPage] with the given [PdfPagePaperSize] and inserts it
-    /// into this [PdfPages] collection at the given page index.
+    /// into this [PdfPages] collection at the given page index, shuffling down all pages
+    /// at or after that index.
+    ///
+    /// Returns [PdfiumError::PageIndexOutOfBounds] if the given index is greater than
+    /// the number of pages in this [PdfPages] collection.
     pub fn create_page_at_index(
         &mut self,
         size: PdfPagePaperSize,
         index: PdfPageIndex,
     ) -> Result<PdfPage<'a>, PdfiumError> {
+        if index > self.len() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
         let result = self.pdfium_page_handle_to_result(
             index,
             self.bindings.FPDFPage_New(
@@ -265,6 +467,25 @@ impl<'a> PdfPages<'a> {
         result
     }
 
+    /// Creates a new, empty [PdfPage] with the given [PdfPagePaperSize] and [PdfPageRenderRotation]
+    /// and inserts it into this [PdfPages] collection at the given page index, shuffling down
+    /// all pages at or after that index.
+    ///
+    /// Returns [PdfiumError::PageIndexOutOfBounds] if the given index is greater than
+    /// the number of pages in this [PdfPages] collection.
+    pub fn create_rotated_page_at_index(
+        &mut self,
+        size: PdfPagePaperSize,
+        rotation: PdfPageRenderRotation,
+        index: PdfPageIndex,
+    ) -> Result<PdfPage<'a>, PdfiumError> {
+        let mut page = self.create_page_at_index(size, index)?;
+
+        page.set_rotation(rotation);
+
+        Ok(page)
+    }
+
     // TODO: AJRC - 5/2/23 - remove deprecated PdfPages::delete_page_range() function in 0.9.0
     // as part of tracking issue: https://github.com/ajrcarey/pdfium-render/issues/36
     // TODO: AJRC - 5/2/23 - if PdfDocument::pages() returned a &PdfPages reference (rather than an
@@ -288,6 +509,8 @@ impl<'a> PdfPages<'a> {
 
         PdfPageIndexCache::delete_pages_at_index(self.document_handle, index, 1);
 
+        self.dictionaries.lock().unwrap().invalidate();
+
         Ok(())
     }
 
@@ -407,6 +630,7 @@ impl<'a> PdfPages<'a> {
 
     /// Copies one or more pages with the given range of indices from one raw document handle
     /// to another, inserting the pages sequentially starting at the given destination page index.
+    #[inline]
     pub(crate) fn copy_page_range_between_documents(
         source: FPDF_DOCUMENT,
         source_page_range: RangeInclusive<PdfPageIndex>,
@@ -414,14 +638,31 @@ impl<'a> PdfPages<'a> {
         destination_page_index: PdfPageIndex,
         bindings: &dyn PdfiumLibraryBindings,
     ) -> Result<(), PdfiumError> {
-        let no_of_pages_to_import = source_page_range.len() as PdfPageIndex;
+        Self::copy_page_indices_between_documents(
+            source,
+            source_page_range.collect::<Vec<_>>().as_slice(),
+            destination,
+            destination_page_index,
+            bindings,
+        )
+    }
 
+    /// Copies the pages with the given indices from one raw document handle to another,
+    /// inserting the pages sequentially starting at the given destination page index.
+    pub(crate) fn copy_page_indices_between_documents(
+        source: FPDF_DOCUMENT,
+        source_page_indices: &[PdfPageIndex],
+        destination: FPDF_DOCUMENT,
+        destination_page_index: PdfPageIndex,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(), PdfiumError> {
         if bindings.is_true(
             bindings.FPDF_ImportPagesByIndex_vec(
                 destination,
                 source,
-                source_page_range
-                    .map(|index| index as c_int)
+                source_page_indices
+                    .iter()
+                    .map(|index| *index as c_int)
                     .collect::<Vec<_>>(),
                 destination_page_index as c_int,
             ),
@@ -429,7 +670,7 @@ impl<'a> PdfPages<'a> {
             PdfPageIndexCache::insert_pages_at_index(
                 destination,
                 destination_page_index,
-                no_of_pages_to_import,
+                source_page_indices.len() as PdfPageIndex,
             );
 
             Ok(())
@@ -440,12 +681,58 @@ impl<'a> PdfPages<'a> {
         }
     }
 
+    /// Copies the pages with the given indices from the given source [PdfDocument],
+    /// inserting them sequentially starting at the given destination page index in this
+    /// [PdfPages] collection. Pages are copied in the order given, so the same source page
+    /// can be copied more than once, and pages can be reordered as they are copied.
+    /// An empty list of indices copies every page in the source document.
+    ///
+    /// Returns [PdfiumError::PageIndexOutOfBounds] if any of the given source page indices
+    /// lies outside the source document, or if the destination page index lies beyond the end
+    /// of this [PdfPages] collection. In either case, no pages are copied.
+    pub fn import_from(
+        &mut self,
+        source: &PdfDocument,
+        source_page_indices: &[PdfPageIndex],
+        destination_page_index: PdfPageIndex,
+    ) -> Result<(), PdfiumError> {
+        let source_page_count = source.pages().len();
+
+        if destination_page_index > self.len()
+            || source_page_indices
+                .iter()
+                .any(|index| *index >= source_page_count)
+        {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        if source_page_indices.is_empty() {
+            if source_page_count == 0 {
+                return Ok(());
+            }
+
+            self.copy_page_range_from_document(
+                source,
+                source.pages().as_range_inclusive(),
+                destination_page_index,
+            )
+        } else {
+            Self::copy_page_indices_between_documents(
+                source.handle(),
+                source_page_indices,
+                self.document_handle,
+                destination_page_index,
+                self.bindings(),
+            )
+        }
+    }
+
     /// Copies all pages in the given source [PdfDocument], appending them sequentially
     /// to the end of this [PdfPages] collection.
     ///
     /// For finer control over which pages are imported, and where they should be inserted,
     /// use one of the [PdfPages::copy_page_from_document()], [PdfPages::copy_pages_from_document()],
-    ///  or [PdfPages::copy_page_range_from_document()] functions.
+    /// [PdfPages::copy_page_range_from_document()], or [PdfPages::import_from()] functions.
     #[inline]
     pub fn append(&mut self, document: &PdfDocument) -> Result<(), PdfiumError> {
         self.copy_page_range_from_document(
@@ -489,6 +776,43 @@ impl<'a> PdfPages<'a> {
         }
     }
 
+    /// Returns the label of the page at the given index, if any.
+    pub(crate) fn label(&self, index: PdfPageIndex) -> Option<String> {
+        // Retrieving the label text from Pdfium is a two-step operation. First, we call
+        // FPDF_GetPageLabel() with a null buffer; this will retrieve the length of
+        // the label text in bytes. If the length is zero, then there is no such tag.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDF_GetPageLabel() again with a pointer to the buffer;
+        // this will write the label text to the buffer in UTF16LE format.
+
+        let buffer_length = self.bindings.FPDF_GetPageLabel(
+            self.document_handle,
+            index as c_int,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            // The label is not present.
+
+            None
+        } else {
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings.FPDF_GetPageLabel(
+                self.document_handle,
+                index as c_int,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer_length,
+            );
+
+            debug_assert_eq!(result, buffer_length);
+
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        }
+    }
+
     /// Returns a [PdfPage] from the given `FPDF_PAGE` handle, if possible.
     pub(crate) fn pdfium_page_handle_to_result(
         &self,
@@ -508,41 +832,7 @@ impl<'a> PdfPages<'a> {
             // (Pdfium does not currently include an FPDF_SetPageLabel() function, so the label
             // _will_ be an immutable property of the PdfPage for its entire lifetime.)
 
-            let label = {
-                // Retrieving the label text from Pdfium is a two-step operation. First, we call
-                // FPDF_GetPageLabel() with a null buffer; this will retrieve the length of
-                // the label text in bytes. If the length is zero, then there is no such tag.
-
-                // If the length is non-zero, then we reserve a byte buffer of the given
-                // length and call FPDF_GetPageLabel() again with a pointer to the buffer;
-                // this will write the label text to the buffer in UTF16LE format.
-
-                let buffer_length = self.bindings.FPDF_GetPageLabel(
-                    self.document_handle,
-                    index as c_int,
-                    std::ptr::null_mut(),
-                    0,
-                );
-
-                if buffer_length == 0 {
-                    // The label is not present.
-
-                    None
-                } else {
-                    let mut buffer = create_byte_buffer(buffer_length as usize);
-
-                    let result = self.bindings.FPDF_GetPageLabel(
-                        self.document_handle,
-                        index as c_int,
-                        buffer.as_mut_ptr() as *mut c_void,
-                        buffer_length,
-                    );
-
-                    debug_assert_eq!(result, buffer_length);
-
-                    get_string_from_pdfium_utf16le_bytes(buffer)
-                }
-            };
+            let label = self.label(index);
 
             Ok(PdfPage::from_pdfium(
                 self.document_handle,
@@ -550,7 +840,8 @@ impl<'a> PdfPages<'a> {
                 self.form_handle,
                 label,
                 self.bindings,
-            ))
+            )
+            .with_dictionary_state(Arc::clone(&self.dictionaries)))
         }
     }
 
@@ -626,14 +917,227 @@ impl<'a> PdfPages<'a> {
         Ok(())
     }
 
+    /// Adds a text watermark containing the given text to every [PdfPage] in this [PdfPages]
+    /// collection, styled according to the given [PdfWatermarkOptions].
+    ///
+    /// For more information, see the [PdfPage::add_watermark()] function.
+    pub fn add_watermark_to_all(
+        &mut self,
+        text: impl ToString,
+        options: &PdfWatermarkOptions,
+    ) -> Result<(), PdfiumError> {
+        let text = text.to_string();
+
+        for mut page in self.iter() {
+            page.add_watermark(&text, options)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders every [PdfPage] in this [PdfPages] collection using the given [PdfRenderConfig],
+    /// passing each rendered page image to the given callback on one of `num_threads`
+    /// worker threads. The values returned by the callback are collected and returned
+    /// in page order, irrespective of the order in which the worker threads complete.
+    ///
+    /// Pdfium itself is not thread safe, so all rendering takes place sequentially on
+    /// the calling thread; only the callback runs in parallel. This function is therefore
+    /// most useful when the callback performs expensive work, such as encoding each page
+    /// image to JPEG or PNG, that can overlap with the rendering of subsequent pages.
+    /// Rendering is throttled so that no more than twice `num_threads` rendered images
+    /// wait to be processed at any one time. A `num_threads` value of zero is treated as one.
+    ///
+    /// The callback must not call any Pdfium functions. If the callback panics, the panic
+    /// is propagated to the calling thread once the remaining worker threads have finished
+    /// processing the pages already rendered. Rendering continues while at least one worker
+    /// thread remains, and stops early once every worker thread has panicked.
+    ///
+    /// Rendering stops at the first page that cannot be loaded or rendered, and that error
+    /// is returned once the worker threads have finished processing the pages already rendered.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    /// It is not available when compiling to WASM.
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn render_all_parallel<F, R>(
+        &self,
+        config: &PdfRenderConfig,
+        num_threads: usize,
+        callback: F,
+    ) -> Result<Vec<R>, PdfiumError>
+    where
+        F: Fn(PdfPageIndex, DynamicImage) -> R + Send + Sync + 'static,
+        R: Send + 'static,
+    {
+        let num_threads = num_threads.max(1);
+
+        let (sender, receiver) = sync_channel::<(PdfPageIndex, DynamicImage)>(num_threads * 2);
+
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let callback = Arc::new(callback);
+
+        let workers = (0..num_threads)
+            .map(|_| {
+                let receiver = receiver.clone();
+
+                let callback = callback.clone();
+
+                thread::spawn(move || {
+                    let mut results = Vec::new();
+
+                    loop {
+                        // Release the lock on the receiver before invoking the callback,
+                        // so that other workers can pick up the next page in the meantime.
+
+                        let next = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(),
+                            Err(_) => break,
+                        };
+
+                        match next {
+                            Ok((index, image)) => results.push((index, callback(index, image))),
+                            Err(_) => break, // The sender has been dropped; all pages are rendered.
+                        }
+                    }
+
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Only the workers may keep the receiver alive, so that sending fails rather than
+        // blocking forever once every worker has exited.
+
+        drop(receiver);
+
+        let mut render_result = Ok(());
+
+        for (index, page) in self.try_iter().enumerate() {
+            let image = match page.and_then(|page| {
+                page.render_with_config(config)
+                    .map(|bitmap| bitmap.as_image())
+            }) {
+                Ok(image) => image,
+                Err(err) => {
+                    render_result = Err(err);
+
+                    break;
+                }
+            };
+
+            if sender.send((index as PdfPageIndex, image)).is_err() {
+                // All workers have exited and dropped the receiver, which can only happen
+                // if they have panicked.
+
+                break;
+            }
+        }
+
+        drop(sender);
+
+        let mut results = Vec::with_capacity(self.len() as usize);
+
+        for worker in workers {
+            match worker.join() {
+                Ok(worker_results) => results.extend(worker_results),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+
+        render_result?;
+
+        results.sort_by_key(|(index, _)| *index);
+
+        Ok(results.into_iter().map(|(_, result)| result).collect())
+    }
+
+    /// Renders every [PdfPage] in this [PdfPages] collection using the given [PdfRenderConfig],
+    /// passing each rendered page to the given callback along with its page index.
+    ///
+    /// Rather than allocating a new [PdfBitmap] for each page, a single bitmap is allocated
+    /// and reused for every page of the same pixel dimensions, reducing allocator churn when
+    /// rendering large numbers of pages. The bitmap is cleared before each page is rendered,
+    /// irrespective of the clearing setting in the given [PdfRenderConfig]. If a page renders
+    /// to different pixel dimensions than the previous page, a new bitmap of the correct size
+    /// is allocated in place of the old one, so documents with pages of differing sizes are
+    /// rendered correctly, but benefit less from buffer reuse. To guarantee a single allocation,
+    /// use a [PdfRenderConfig] that sets both a fixed target width and a fixed target height.
+    ///
+    /// The bitmap is overwritten when the next page is rendered, so the callback must copy
+    /// or encode any data it wishes to keep before returning. Rendering stops at the first
+    /// error returned either by Pdfium or by the callback, and that error is returned.
+    /// A page that cannot be loaded is reported as a [PdfiumError::PageLoadFailed] error.
+    pub fn render_each_reusing_buffer<F>(
+        &self,
+        config: &PdfRenderConfig,
+        mut callback: F,
+    ) -> Result<(), PdfiumError>
+    where
+        F: FnMut(PdfPageIndex, &PdfBitmap) -> Result<(), PdfiumError>,
+    {
+        let mut bitmap: Option<PdfBitmap> = None;
+
+        for (index, page) in self.try_iter().enumerate() {
+            let page = page?;
+
+            let mut settings = config.apply_to_page(&page);
+
+            settings.do_clear_bitmap_before_rendering = true;
+
+            let bitmap = match bitmap.take() {
+                Some(existing)
+                    if existing.width() == settings.width as Pixels
+                        && existing.height() == settings.height as Pixels =>
+                {
+                    bitmap.insert(existing)
+                }
+                _ => bitmap.insert(PdfBitmap::empty(
+                    settings.width as Pixels,
+                    settings.height as Pixels,
+                    PdfBitmapFormat::from_pdfium(settings.format as u32)
+                        .unwrap_or_else(|_| PdfBitmapFormat::default()),
+                    self.bindings,
+                )?),
+            };
+
+            page.render_into_bitmap_with_settings(bitmap, settings)?;
+
+            callback(index as PdfPageIndex, bitmap)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns an iterator over all the pages in this [PdfPages] collection.
+    ///
+    /// Pages are loaded lazily: each [PdfPage] is only loaded when the iterator yields it,
+    /// and is closed again as soon as the yielded [PdfPage] is dropped. Iterator adapters
+    /// that return early, such as `find()` or `take()`, will not load any pages beyond
+    /// the last page they consume.
     #[inline]
     pub fn iter(&self) -> PdfPagesIterator {
         PdfPagesIterator::new(self)
     }
+
+    /// Returns an iterator over the results of loading each page in this [PdfPages] collection.
+    ///
+    /// Unlike [PdfPages::iter()], which stops at the first page that cannot be loaded,
+    /// this iterator yields a [PdfiumError::PageLoadFailed] error for each page that fails
+    /// to load and then continues with the next page. This allows batch processing of partially
+    /// corrupt documents to skip bad pages without abandoning the remainder of the document.
+    #[inline]
+    pub fn try_iter(&self) -> PdfPagesTryIterator<'_> {
+        PdfPagesTryIterator::new(self)
+    }
 }
 
 /// An iterator over all the [PdfPage] objects in a [PdfPages] collection.
+///
+/// Each [PdfPage] is loaded only when it is yielded by the iterator, and is closed when
+/// the yielded [PdfPage] is dropped.
+///
+/// Iteration ends at the first page that cannot be loaded. Use [PdfPages::try_iter()]
+/// to continue past pages that fail to load.
 pub struct PdfPagesIterator<'a> {
     pages: &'a PdfPages<'a>,
     next_index: PdfPageIndex,
@@ -653,18 +1157,176 @@ impl<'a> Iterator for PdfPagesIterator<'a> {
     type Item = PdfPage<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Only the page at the current index is loaded. No other page handles are retrieved.
+
         let next = self.pages.get(self.next_index);
 
         self.next_index += 1;
 
         next.ok()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Retrieving the page count does not require loading any pages. Iteration ends early
+        // if a page fails to load, so only the upper bound is known.
+
+        let remaining = self.pages.len().saturating_sub(self.next_index) as usize;
+
+        (0, Some(remaining))
+    }
+}
+
+/// An iterator over the results of loading each [PdfPage] in a [PdfPages] collection.
+///
+/// Each [PdfPage] is loaded only when it is yielded by the iterator, and is closed when
+/// the yielded [PdfPage] is dropped. Pages that cannot be loaded are yielded as
+/// [PdfiumError::PageLoadFailed] errors.
+pub struct PdfPagesTryIterator<'a> {
+    pages: &'a PdfPages<'a>,
+    next_index: PdfPageIndex,
+}
+
+impl<'a> PdfPagesTryIterator<'a> {
+    #[inline]
+    pub(crate) fn new(pages: &'a PdfPages<'a>) -> Self {
+        PdfPagesTryIterator {
+            pages,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPagesTryIterator<'a> {
+    type Item = Result<PdfPage<'a>, PdfiumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.pages.len() {
+            return None;
+        }
+
+        let next = self.pages.get(self.next_index);
+
+        self.next_index += 1;
+
+        Some(next)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.pages.len().saturating_sub(self.next_index) as usize;
+
+        (remaining, Some(remaining))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-    use crate::utils::test::test_bind_to_pdfium;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_create_page_at_index() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for width in [100.0, 200.0, 300.0] {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::new_custom(
+                    PdfPoints::new(width),
+                    PdfPoints::new(500.0),
+                ))?;
+        }
+
+        document.pages_mut().create_rotated_page_at_index(
+            PdfPagePaperSize::new_custom(PdfPoints::new(400.0), PdfPoints::new(500.0)),
+            PdfPageRenderRotation::Degrees90,
+            1,
+        )?;
+
+        assert_eq!(document.pages().len(), 4);
+
+        let widths = document
+            .pages()
+            .iter()
+            .map(|page| page.page_size().width().value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(widths, vec![100.0, 400.0, 200.0, 300.0]);
+        assert_eq!(
+            document.pages().get(1)?.rotation()?,
+            PdfPageRenderRotation::Degrees90
+        );
+
+        assert!(matches!(
+            document
+                .pages_mut()
+                .create_page_at_index(PdfPagePaperSize::a4(), 5),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_from() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut source = pdfium.create_new_pdf()?;
+
+        for width in [100.0, 200.0, 300.0] {
+            source
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::new_custom(
+                    PdfPoints::new(width),
+                    PdfPoints::new(500.0),
+                ))?;
+        }
+
+        let mut destination = pdfium.create_new_pdf()?;
+
+        for _ in 0..2 {
+            destination
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::new_custom(
+                    PdfPoints::new(50.0),
+                    PdfPoints::new(500.0),
+                ))?;
+        }
+
+        destination.pages_mut().import_from(&source, &[2, 0], 1)?;
+
+        let widths = |document: &PdfDocument| {
+            document
+                .pages()
+                .iter()
+                .map(|page| page.page_size().width().value)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(widths(&destination), vec![50.0, 300.0, 100.0, 50.0]);
+
+        destination.merge_pages_from(&source, &[])?;
+
+        assert_eq!(
+            widths(&destination),
+            vec![50.0, 300.0, 100.0, 50.0, 100.0, 200.0, 300.0]
+        );
+
+        assert!(matches!(
+            destination.pages_mut().import_from(&source, &[0, 3], 0),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+        assert!(matches!(
+            destination.pages_mut().import_from(&source, &[0], 8),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+        assert_eq!(destination.pages().len(), 7);
+
+        Ok(())
+    }
 
     #[test]
     fn test_page_size() -> Result<(), PdfiumError> {
@@ -706,6 +1368,209 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_page_size_matches_loaded_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/page-sizes-test.pdf", None)?;
+
+        for index in document.pages().as_range() {
+            assert_eq!(
+                document.pages().page_size(index)?,
+                document.pages().get(index)?.page_size()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_each_reusing_buffer_matches_render_with_config() -> Result<(), PdfiumError> {
+        // Pages in this sample file have differing sizes, so the shared bitmap must be
+        // reallocated as necessary while still producing the same output as rendering
+        // each page individually.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/page-sizes-test.pdf", None)?;
+
+        let config = PdfRenderConfig::new().set_target_width(300);
+
+        let mut rendered = 0;
+
+        document
+            .pages()
+            .render_each_reusing_buffer(&config, |index, bitmap| {
+                let page = document.pages().get(index)?;
+
+                let expected = page.render_with_config(&config)?;
+
+                assert_eq!(bitmap.width(), expected.width());
+                assert_eq!(bitmap.height(), expected.height());
+                assert_eq!(bitmap.as_raw_bytes(), expected.as_raw_bytes());
+
+                rendered += 1;
+
+                Ok(())
+            })?;
+
+        assert_eq!(rendered, document.pages().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iterator_loads_pages_lazily() -> Result<(), PdfiumError> {
+        // Finding a matching page early in a large document should not load any later pages.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for _ in 0..500 {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        document
+            .pages_mut()
+            .get(1)?
+            .set_rotation(PdfPageRenderRotation::Degrees90);
+
+        let mut iterator = document.pages().iter();
+
+        assert_eq!(iterator.size_hint(), (0, Some(500)));
+
+        let mut inspected = 0;
+
+        let found = iterator.find(|page| {
+            inspected += 1;
+
+            matches!(page.rotation(), Ok(PdfPageRenderRotation::Degrees90))
+        });
+
+        assert!(found.is_some());
+        assert_eq!(inspected, 2);
+        assert_eq!(iterator.size_hint(), (0, Some(498)));
+
+        // The iterator should resume with the page following the one that was found.
+
+        let next = iterator.next().unwrap();
+
+        assert_eq!(next.rotation()?, PdfPageRenderRotation::None);
+        assert_eq!(iterator.size_hint(), (0, Some(497)));
+        assert_eq!(iterator.count(), 497);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_all_parallel_is_deterministic() -> Result<(), PdfiumError> {
+        // Images processed on worker threads should be returned in page order and should
+        // be identical to images rendered sequentially.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let config = PdfRenderConfig::new().set_target_width(200);
+
+        let sequential = document
+            .pages()
+            .iter()
+            .map(|page| {
+                page.render_with_config(&config)
+                    .map(|bitmap| bitmap.as_image().as_bytes().to_vec())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for num_threads in [1, 4] {
+            let parallel =
+                document
+                    .pages()
+                    .render_all_parallel(&config, num_threads, |index, image| {
+                        (index, image.as_bytes().to_vec())
+                    })?;
+
+            assert_eq!(parallel.len(), sequential.len());
+
+            for (expected_index, (index, bytes)) in parallel.iter().enumerate() {
+                assert_eq!(*index as usize, expected_index);
+                assert_eq!(bytes, &sequential[expected_index]);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_all_parallel_propagates_callback_panic() -> Result<(), PdfiumError> {
+        // A panicking callback must not leave the calling thread blocked on a full channel
+        // once every worker thread has exited.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for _ in 0..6 {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        let config = PdfRenderConfig::new().set_target_width(50);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            document
+                .pages()
+                .render_all_parallel(&config, 1, |index, _| {
+                    if index == 0 {
+                        panic!("callback panicked on page {}", index);
+                    }
+                })
+        }));
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_iter_reports_pages_that_fail_to_load() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The page tree claims three pages, but one of its kids references a missing object.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R 9 0 R 4 0 R] /Count 3 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let results = document.pages().try_iter().collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 3);
+
+        let failed = results
+            .iter()
+            .filter(|result| matches!(result, Err(PdfiumError::PageLoadFailed { .. })))
+            .count();
+
+        assert_eq!(failed, 1);
+
+        for page in results.iter().filter_map(|result| result.as_ref().ok()) {
+            assert!(page.is_renderable());
+        }
+
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 2);
+
+        Ok(())
+    }
+
     const fn expected_page_0_size() -> PdfRect {
         PdfRect::new_from_values(0.0, 0.0, 841.8897, 595.3039)
     }
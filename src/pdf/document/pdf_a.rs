@@ -0,0 +1,179 @@
+//! Defines the [PdfAIssue] enum, describing a single way in which a `PdfDocument` fails
+//! a key requirement of the PDF/A archival standard.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::permissions::PdfSecurityHandlerRevision;
+use crate::pdf::document::raw::{catalog_contains_key, catalog_metadata_stream};
+use crate::pdf::document::security_policy::{PdfSecurityPolicy, PdfSecurityPolicyViolation};
+use crate::pdf::document::{collect_non_embedded_font_names, PdfDocument};
+
+/// A single way in which a [PdfDocument] fails a key requirement of the PDF/A family of
+/// archival standards, ISO 19005. Use the [PdfDocument::pdf_a_issues()] function to check
+/// a document for these issues.
+///
+/// The checks cover common causes of PDF/A validation failures; they are not a substitute
+/// for a full PDF/A validator. A document that reports no issues may still fail validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfAIssue {
+    /// The font with the given name is used by text in the document but is not embedded.
+    /// PDF/A requires every font used to render text to be embedded, including the
+    /// 14 standard PDF fonts.
+    FontNotEmbedded { name: String },
+
+    /// The document is encrypted. PDF/A forbids encryption.
+    Encrypted,
+
+    /// The document contains document-level JavaScript, or a form field with a JavaScript
+    /// action. PDF/A forbids JavaScript.
+    JavaScript { page_index: Option<PdfPageIndex> },
+
+    /// The document catalog has no `/OutputIntents` entry. PDF/A requires an output intent
+    /// describing the intended output device whenever device-dependent color spaces such
+    /// as `DeviceRGB` or `DeviceCMYK` are used.
+    ///
+    /// This is the only color check performed. The color spaces used by the document's content
+    /// are not inspected, so any document without an output intent reports this issue, even if
+    /// it uses only device-independent color; conversely, the contents of an output intent
+    /// are not validated, so a document with an unsuitable output intent reports no issue.
+    MissingOutputIntent,
+
+    /// The document catalog has no `/Metadata` entry referencing an XMP metadata stream.
+    /// PDF/A requires XMP metadata.
+    MissingXmpMetadata,
+
+    /// The document's XMP metadata does not contain a `pdfaid:part` PDF/A identification
+    /// property, so the document does not declare which part of the PDF/A standard it
+    /// conforms to. Compressed metadata streams cannot be inspected, so they also report
+    /// this issue; PDF/A recommends that metadata streams are left uncompressed.
+    MissingPdfAIdentification,
+}
+
+/// Returns every key PDF/A requirement that the given [PdfDocument] fails.
+pub(crate) fn pdf_a_issues(document: &PdfDocument) -> Result<Vec<PdfAIssue>, PdfiumError> {
+    let mut issues = Vec::new();
+
+    let mut names = Vec::new();
+
+    for page in document.pages().iter() {
+        for object in page.objects().iter() {
+            collect_non_embedded_font_names(&object, &mut names);
+        }
+    }
+
+    issues.extend(
+        names
+            .into_iter()
+            .map(|name| PdfAIssue::FontNotEmbedded { name }),
+    );
+
+    if !matches!(
+        document.permissions().security_handler_revision(),
+        Ok(PdfSecurityHandlerRevision::Unprotected)
+    ) {
+        issues.push(PdfAIssue::Encrypted);
+    }
+
+    issues.extend(
+        document
+            .security_policy_violations(&PdfSecurityPolicy::permissive().allow_javascript(false))
+            .into_iter()
+            .filter_map(|violation| match violation {
+                PdfSecurityPolicyViolation::JavaScript { page_index } => {
+                    Some(PdfAIssue::JavaScript { page_index })
+                }
+                _ => None,
+            }),
+    );
+
+    // Pdfium does not provide access to output intents or XMP metadata, so the
+    // remaining checks inspect the serialized document catalog.
+
+    let bytes = document.save_to_bytes()?;
+
+    if catalog_contains_key(bytes.as_slice(), b"/OutputIntents") != Some(true) {
+        issues.push(PdfAIssue::MissingOutputIntent);
+    }
+
+    match catalog_metadata_stream(bytes.as_slice()) {
+        None => issues.push(PdfAIssue::MissingXmpMetadata),
+        Some((filters, data)) => {
+            // Compressed metadata streams cannot be inspected, so the identification
+            // property can only be found in uncompressed streams.
+
+            if !filters.is_empty() || !contains(data.as_slice(), b"pdfaid:part") {
+                issues.push(PdfAIssue::MissingPdfAIdentification);
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+#[inline]
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_pdf_a_issues() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /Metadata 6 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Resources << /Font << \
+            /F1 5 0 R >> >> /Contents 4 0 R >>",
+            b"<< >>\nstream\n\
+            BT /F1 12 Tf 72 720 Td (Archive) Tj ET",
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+            b"<< /Type /Metadata /Subtype /XML >>\nstream\n\
+            <rdf:Description rdf:about=\"\" xmlns:pdfaid=\"http://www.aiim.org/pdfa/ns/id/\" \
+            pdfaid:part=\"2\"/>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(
+            document.pdf_a_issues()?,
+            vec![
+                PdfAIssue::FontNotEmbedded {
+                    name: "Helvetica".to_string()
+                },
+                PdfAIssue::MissingOutputIntent,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_xmp_metadata_reports_missing_identification() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /Metadata 3 0 R /OutputIntents [4 0 R] >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+            b"<< /Type /Metadata /Subtype /XML /Filter /FlateDecode >>\nstream\n\
+            x\x9c\x03\x00\x00\x00\x00\x01",
+            b"<< /Type /OutputIntent /S /GTS_PDFA1 /OutputConditionIdentifier (sRGB) >>",
+        ]);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(
+            document.pdf_a_issues()?,
+            vec![PdfAIssue::MissingPdfAIdentification]
+        );
+
+        Ok(())
+    }
+}
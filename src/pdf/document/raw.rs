@@ -0,0 +1,3265 @@
+//! Functions that read and update the raw bytes of a serialized document.
+//!
+//! Pdfium does not expose many parts of the document structure, such as the document catalog,
+//! page dictionary entries it does not interpret, and the contents of some annotation types.
+//! The functions in this module work around this by scanning the serialized document for
+//! indirect object definitions and following references between them.
+//!
+//! The scan can only see objects written directly into the document data. Objects stored
+//! inside compressed object streams, which are indexed by a cross-reference stream rather than
+//! a classic cross-reference table, are invisible to it. Pdfium always writes a classic
+//! cross-reference table when saving a document in full, so data returned by
+//! `PdfDocument::save_to_bytes()` can be inspected; other data, such as the original file
+//! passed to Pdfium, may not be. Every function that locates the document trailer returns
+//! `None` if the last cross-reference section is a cross-reference stream, or if the trailer
+//! refers to one using a `/XRefStm` entry, rather than returning an incomplete result.
+
+pub(crate) mod outline;
+
+use crate::utils::dates::pdf_string_to_date_time;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+/// Attempts to rebuild the cross-reference table of the given document data by scanning
+/// the document for indirect object definitions of the form `<number> <generation> obj`.
+///
+/// A new cross-reference section and trailer are appended to a copy of the given data,
+/// so that the original byte offsets of all objects remain valid. Returns `None` if no
+/// indirect objects could be located, if no document catalog could be located, or if the
+/// document appears to be encrypted.
+///
+/// Object numbers of 1048576 or above, which Pdfium will not load, are ignored.
+///
+/// This is a best-effort recovery strategy. Objects stored inside compressed object streams
+/// cannot be located by rescanning, so documents that rely on them may remain unreadable.
+pub(crate) fn rebuild_cross_reference_table(bytes: &[u8]) -> Option<Vec<u8>> {
+    if find_last(bytes, b"/Encrypt").is_some() {
+        // Rebuilding the trailer would discard the document's encryption dictionary.
+
+        return None;
+    }
+
+    let mut objects = scan_object_offsets(bytes);
+
+    // Pdfium refuses to load objects with numbers at or above this bound, so there is
+    // no point writing cross-reference entries for them.
+
+    objects.retain(|number, _| *number < MAXIMUM_OBJECT_NUMBER);
+
+    if objects.is_empty() {
+        return None;
+    }
+
+    let root = find_reference_after_last(bytes, b"/Root")
+        .filter(|(number, _)| objects.contains_key(number))
+        .or_else(|| find_catalog(bytes, &objects))?;
+
+    let info = find_reference_after_last(bytes, b"/Info")
+        .filter(|(number, _)| objects.contains_key(number));
+
+    let size = objects.keys().last()?.checked_add(1)?;
+
+    let mut result = Vec::with_capacity(bytes.len() + (objects.len() + 10) * 20);
+
+    result.extend_from_slice(bytes);
+
+    if !bytes.ends_with(b"\n") && !bytes.ends_with(b"\r") {
+        result.push(b'\n');
+    }
+
+    let xref_offset = result.len();
+
+    result.extend_from_slice(b"xref\n");
+
+    // Entries are only written for objects that were located, grouped into subsections
+    // of consecutive object numbers, so the size of the table is proportional to the
+    // number of objects rather than to the largest object number. Object 0 is always
+    // the head of the free list.
+
+    let numbers = std::iter::once(0)
+        .chain(objects.keys().copied().filter(|number| *number > 0))
+        .collect::<Vec<_>>();
+
+    let mut start = 0;
+
+    while start < numbers.len() {
+        let mut end = start + 1;
+
+        while end < numbers.len() && numbers[end] == numbers[end - 1] + 1 {
+            end += 1;
+        }
+
+        result.extend_from_slice(format!("{} {}\n", numbers[start], end - start).as_bytes());
+
+        // Each cross-reference entry must be exactly 20 bytes long, including the two-byte
+        // end-of-line marker.
+
+        for number in &numbers[start..end] {
+            match objects.get(number) {
+                Some((generation, offset)) if *number > 0 => result.extend_from_slice(
+                    format!("{:010} {:05} n\r\n", offset, generation).as_bytes(),
+                ),
+                _ => result.extend_from_slice(b"0000000000 65535 f\r\n"),
+            }
+        }
+
+        start = end;
+    }
+
+    result.extend_from_slice(
+        format!("trailer\n<< /Size {} /Root {} {} R", size, root.0, root.1).as_bytes(),
+    );
+
+    if let Some((number, generation)) = info {
+        result.extend_from_slice(format!(" /Info {} {} R", number, generation).as_bytes());
+    }
+
+    result.extend_from_slice(format!(" >>\nstartxref\n{}\n%%EOF\n", xref_offset).as_bytes());
+
+    Some(result)
+}
+
+/// The largest object number, exclusive, that Pdfium will load from a cross-reference table.
+const MAXIMUM_OBJECT_NUMBER: u32 = 1_048_576;
+
+/// An embedded file stream located by the [rich_media_assets()] function.
+pub(crate) struct RawEmbeddedFile {
+    /// The key of the file in the name tree that references it.
+    pub(crate) name: String,
+
+    /// The `/Subtype` entry of the embedded file stream, decoded into a MIME type.
+    pub(crate) mime_type: Option<String>,
+
+    /// The names of the stream filters applied to the data.
+    pub(crate) filters: Vec<String>,
+
+    /// The stream data, as stored in the document.
+    pub(crate) data: Vec<u8>,
+}
+
+/// The entries of a file specification located by the [embedded_file_entries()] function.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RawEmbeddedFileEntry {
+    /// The `/Subtype` entry of the embedded file stream, decoded into a MIME type.
+    pub(crate) mime_type: Option<String>,
+
+    /// The `/AFRelationship` entry of the file specification.
+    pub(crate) relationship: Option<String>,
+}
+
+/// The entries of a usage rights signature read by the [usage_rights()] function.
+pub(crate) struct RawUsageRights {
+    /// The `/Name` entry of the signature dictionary.
+    pub(crate) name: Option<String>,
+
+    /// The `/Msg` entry of the transform parameters dictionary.
+    pub(crate) message: Option<String>,
+
+    /// The `/Document` entry of the transform parameters dictionary.
+    pub(crate) document: Vec<String>,
+
+    /// The `/Form` entry of the transform parameters dictionary.
+    pub(crate) form: Vec<String>,
+
+    /// The `/Annots` entry of the transform parameters dictionary.
+    pub(crate) annotations: Vec<String>,
+
+    /// The `/Signature` entry of the transform parameters dictionary.
+    pub(crate) signature: Vec<String>,
+
+    /// The `/EF` entry of the transform parameters dictionary.
+    pub(crate) embedded_files: Vec<String>,
+
+    /// The `/P` entry of the transform parameters dictionary.
+    pub(crate) is_restrictive: bool,
+}
+
+/// Returns `true` if the document catalog in the given document data contains the given key.
+/// Returns `None` if the catalog could not be located.
+pub(crate) fn catalog_contains_key(bytes: &[u8], key: &[u8]) -> Option<bool> {
+    let objects = find_object_offsets(bytes);
+
+    Some(find_key(catalog(bytes, &objects)?, key).is_some())
+}
+
+/// Returns the `/Subtype` and `/AFRelationship` entries of every file specification dictionary
+/// in the document's `/EmbeddedFiles` name tree, in the same order as Pdfium's
+/// `FPDFDoc_GetAttachment()` function. Returns `None` if the name tree could not be located.
+pub(crate) fn embedded_file_entries(bytes: &[u8]) -> Option<Vec<RawEmbeddedFileEntry>> {
+    let objects = find_object_offsets(bytes);
+
+    Some(
+        embedded_file_specifications(bytes, &objects)?
+            .into_iter()
+            .map(|specification| RawEmbeddedFileEntry {
+                mime_type: specification.and_then(|specification| {
+                    embedded_file_subtype(bytes, &objects, specification)
+                }),
+                relationship: specification.and_then(|specification| {
+                    parse_name_at(specification, find_key(specification, b"/AFRelationship")?)
+                }),
+            })
+            .collect(),
+    )
+}
+
+/// Returns the `/Subtype` entry of the embedded file stream belonging to the given file
+/// specification dictionary, decoded from a PDF name into a MIME type such as `text/xml`.
+fn embedded_file_subtype(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    specification: &[u8],
+) -> Option<String> {
+    let embedded_files = dictionary_after_key(bytes, objects, specification, b"/EF")?;
+
+    let (number, _) = find_reference_after_key(embedded_files, b"/F")?;
+
+    let stream = dictionary_part(object_definition(bytes, objects, number)?);
+
+    parse_name_at(stream, find_key(stream, b"/Subtype")?)
+}
+
+/// Returns each file specification dictionary in the `/EmbeddedFiles` name tree of the
+/// document catalog, counting entries in the same order as Pdfium's `FPDFDoc_GetAttachment()`
+/// function. Values that are not dictionaries are returned as `None`, so that each entry
+/// keeps its position.
+fn embedded_file_specifications<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+) -> Option<Vec<Option<&'a [u8]>>> {
+    let names = dictionary_after_key(bytes, objects, catalog(bytes, objects)?, b"/Names")?;
+
+    let root = dictionary_after_key(bytes, objects, names, b"/EmbeddedFiles")?;
+
+    let mut specifications = Vec::new();
+
+    let mut visited = Vec::new();
+
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if find_key(node, b"/Names").is_some() {
+            // As in Pdfium, the /Kids of a node with a /Names array are ignored.
+
+            let entries = array_dictionaries(bytes, objects, node, b"/Names")?;
+
+            // Entries alternate between keys and values; Pdfium counts each pair once.
+
+            specifications.extend(entries.chunks_exact(2).map(|pair| pair[1]));
+        } else if let Some(kids) =
+            find_key(node, b"/Kids").and_then(|position| parse_reference_array(node, position))
+        {
+            // Push in reverse so that kids are visited in order.
+
+            for (number, _) in kids.into_iter().rev() {
+                if visited.contains(&number) {
+                    // A malformed name tree containing a cycle.
+
+                    continue;
+                }
+
+                visited.push(number);
+
+                if let Some(kid) = object_definition(bytes, objects, number) {
+                    stack.push(dictionary_part(kid));
+                }
+            }
+        }
+    }
+
+    Some(specifications)
+}
+
+/// Returns the given object body up to, but not including, any `stream` keyword,
+/// so that stream data is not mistaken for dictionary entries.
+fn dictionary_part(definition: &[u8]) -> &[u8] {
+    match find_key(definition, b"stream") {
+        Some(end) => &definition[..end - b"stream".len()],
+        None => definition,
+    }
+}
+
+/// Returns the decoded text string that is the value of the given key in the given
+/// dictionary. Returns `None` if the dictionary has no such key, or if its value is not a string.
+fn find_string_after_key(bytes: &[u8], key: &[u8]) -> Option<String> {
+    parse_string_at(bytes, find_key(bytes, key)?).map(|value| decode_text_string(value.as_slice()))
+}
+
+/// Parses the literal string `(...)` or hexadecimal string `<...>` at the given position,
+/// skipping any leading whitespace, and returns the bytes it contains.
+fn parse_string_at(bytes: &[u8], position: usize) -> Option<Vec<u8>> {
+    parse_string_and_end_at(bytes, position).map(|(string, _)| string)
+}
+
+/// Parses the literal string `(...)` or hexadecimal string `<...>` at the given position,
+/// skipping any leading whitespace. Returns the bytes it contains, along with the position
+/// immediately after the string.
+fn parse_string_and_end_at(bytes: &[u8], position: usize) -> Option<(Vec<u8>, usize)> {
+    let mut position = skip_whitespace_forwards(bytes, position);
+
+    let mut result = Vec::new();
+
+    match bytes.get(position)? {
+        b'(' => {
+            let mut depth = 1;
+
+            loop {
+                position += 1;
+
+                match *bytes.get(position)? {
+                    b'\\' => {
+                        position += 1;
+
+                        match *bytes.get(position)? {
+                            b'n' => result.push(b'\n'),
+                            b'r' => result.push(b'\r'),
+                            b't' => result.push(b'\t'),
+                            b'b' => result.push(b'\x08'),
+                            b'f' => result.push(b'\x0C'),
+                            b'\r' => {
+                                // A line continuation; the end-of-line marker is ignored.
+
+                                if bytes.get(position + 1) == Some(&b'\n') {
+                                    position += 1;
+                                }
+                            }
+                            b'\n' => {}
+                            digit @ b'0'..=b'7' => {
+                                let mut value = u32::from(digit - b'0');
+
+                                for _ in 0..2 {
+                                    match bytes.get(position + 1) {
+                                        Some(digit @ b'0'..=b'7') => {
+                                            value = value * 8 + u32::from(digit - b'0');
+                                            position += 1;
+                                        }
+                                        _ => break,
+                                    }
+                                }
+
+                                result.push(value as u8);
+                            }
+                            other => result.push(other),
+                        }
+                    }
+                    b'(' => {
+                        depth += 1;
+                        result.push(b'(');
+                    }
+                    b')' => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Some((result, position + 1));
+                        }
+
+                        result.push(b')');
+                    }
+                    other => result.push(other),
+                }
+            }
+        }
+        b'<' if bytes.get(position + 1) != Some(&b'<') => {
+            let mut digits = Vec::new();
+
+            loop {
+                position += 1;
+
+                match *bytes.get(position)? {
+                    b'>' => break,
+                    byte if is_whitespace(byte) => {}
+                    byte => digits.push((byte as char).to_digit(16)? as u8),
+                }
+            }
+
+            if digits.len() % 2 == 1 {
+                // A missing final digit is assumed to be zero.
+
+                digits.push(0);
+            }
+
+            result.extend(digits.chunks(2).map(|pair| pair[0] * 16 + pair[1]));
+
+            Some((result, position + 1))
+        }
+        _ => None,
+    }
+}
+
+/// Decodes the given text string, which is either UTF-16BE or UTF-8 with a leading
+/// byte order mark, or otherwise in PDFDocEncoding. Characters in PDFDocEncoding
+/// are treated as Latin-1, which agrees with PDFDocEncoding for all printable ASCII characters.
+fn decode_text_string(bytes: &[u8]) -> String {
+    if let Some(units) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        char::decode_utf16(
+            units
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]])),
+        )
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+    } else if let Some(bytes) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        String::from_utf8_lossy(bytes).into_owned()
+    } else {
+        bytes.iter().map(|byte| *byte as char).collect()
+    }
+}
+
+/// Parses the name object at the given position, skipping any leading whitespace, and
+/// returns it without its leading `/`. Any `#xx` hexadecimal escapes are decoded.
+fn parse_name_at(bytes: &[u8], position: usize) -> Option<String> {
+    let mut position = skip_whitespace_forwards(bytes, position);
+
+    if bytes.get(position) != Some(&b'/') {
+        return None;
+    }
+
+    let mut result = Vec::new();
+
+    loop {
+        position += 1;
+
+        match bytes.get(position) {
+            Some(byte) if is_whitespace(*byte) || is_delimiter(*byte) => break,
+            Some(b'#') => {
+                let value = std::str::from_utf8(bytes.get(position + 1..position + 3)?).ok()?;
+
+                result.push(u8::from_str_radix(value, 16).ok()?);
+
+                position += 2;
+            }
+            Some(byte) => result.push(*byte),
+            None => break,
+        }
+    }
+
+    Some(String::from_utf8_lossy(result.as_slice()).into_owned())
+}
+
+/// Appends an incremental update to the given document data that sets the given key and value
+/// pairs in the document's information dictionary. Keys should be given without a leading `/`.
+/// Values are encoded as UTF-16BE text strings, so any Unicode value can be stored. All other
+/// entries in an existing information dictionary are retained.
+///
+/// If the document catalog references an XMP metadata stream that is stored without any
+/// stream filters, the XMP properties corresponding to the given keys, such as `dc:title`
+/// for `Title`, are updated as well; properties not already present in the XMP packet
+/// are not added. Compressed XMP metadata streams are left untouched.
+///
+/// The existing document data is left untouched, so the original byte offsets of all objects
+/// remain valid. Returns `None` if the last trailer in the document could not be located,
+/// if the document appears to be encrypted, or if the trailer references an existing
+/// information dictionary that could not be located or parsed.
+pub(crate) fn append_information_dictionary(
+    bytes: &[u8],
+    entries: &[(&str, &str)],
+) -> Option<Vec<u8>> {
+    let objects = find_object_offsets(bytes);
+
+    let trailer = last_trailer(bytes, &objects)?;
+
+    if trailer.is_encrypted {
+        // Strings in the information dictionary of an encrypted document must themselves
+        // be encrypted.
+
+        return None;
+    }
+
+    let mut dictionary = String::from("<<");
+
+    if let Some((number, _)) = trailer.info {
+        // Carry over every existing entry that is not being replaced. Text strings are
+        // re-encoded, since they may not be valid UTF-8 as stored.
+
+        let existing = dictionary_part(object_definition(bytes, &objects, number)?);
+
+        for (key, value) in dictionary_entries(existing)? {
+            if entries
+                .iter()
+                .any(|(replaced, _)| replaced.as_bytes() == key)
+            {
+                continue;
+            }
+
+            let value = match value.first() {
+                Some(b'(') | Some(b'<') if !value.starts_with(b"<<") => {
+                    encode_text_string(&decode_text_string(&parse_string_at(value, 0)?))
+                }
+                _ => std::str::from_utf8(value).ok()?.to_owned(),
+            };
+
+            dictionary.push_str(&format!(" /{} {}", std::str::from_utf8(key).ok()?, value));
+        }
+    }
+
+    for (key, value) in entries {
+        dictionary.push_str(&format!(" /{} {}", key, encode_text_string(value)));
+    }
+
+    dictionary.push_str(" >>");
+
+    let (number, generation, trailer_entries) = match trailer.info {
+        Some((number, generation)) => (number, generation, trailer.entries()),
+        None => {
+            let number = trailer.size;
+
+            (
+                number,
+                0,
+                format!(
+                    "/Root {} {} R /Info {} 0 R",
+                    trailer.root.0, trailer.root.1, number
+                ),
+            )
+        }
+    };
+
+    let mut updated = vec![(number, generation, dictionary)];
+
+    if let Some(metadata) = updated_metadata_stream(bytes, &objects, entries) {
+        updated.push(metadata);
+    }
+
+    let size = if trailer.info.is_some() {
+        trailer.size
+    } else {
+        trailer.size.checked_add(1)?
+    };
+
+    append_incremental_update(bytes, &trailer, updated, size, &trailer_entries)
+}
+
+/// The XMP property corresponding to each information dictionary key.
+const XMP_PROPERTIES: [(&str, &str); 8] = [
+    ("Title", "dc:title"),
+    ("Author", "dc:creator"),
+    ("Subject", "dc:description"),
+    ("Keywords", "pdf:Keywords"),
+    ("Creator", "xmp:CreatorTool"),
+    ("Producer", "pdf:Producer"),
+    ("CreationDate", "xmp:CreateDate"),
+    ("ModDate", "xmp:ModifyDate"),
+];
+
+/// Returns the object number, generation number, and new body of the XMP metadata stream
+/// referenced by the document catalog, with the XMP properties corresponding to the given
+/// information dictionary entries updated. Returns `None` if the stream could not be located,
+/// has stream filters applied, or contains none of the corresponding properties.
+fn updated_metadata_stream(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    entries: &[(&str, &str)],
+) -> Option<(u32, u16, String)> {
+    let (number, generation) = find_reference_after_key(catalog(bytes, objects)?, b"/Metadata")?;
+
+    let definition = object_definition(bytes, objects, number)?;
+
+    let dictionary = dictionary_part(definition);
+
+    if !stream_filters(dictionary).is_empty() {
+        return None;
+    }
+
+    let xmp = std::str::from_utf8(stream_data(bytes, objects, definition)?).ok()?;
+
+    let mut updated = xmp.to_owned();
+
+    for (key, value) in entries {
+        let property = match XMP_PROPERTIES.iter().find(|(name, _)| name == key) {
+            Some((_, property)) => property,
+            None => continue,
+        };
+
+        let value = if matches!(*key, "CreationDate" | "ModDate") {
+            // XMP dates use ISO 8601 rather than the PDF date format.
+
+            match pdf_string_to_date_time(value) {
+                Some(date) => date.to_rfc3339(),
+                None => continue,
+            }
+        } else {
+            value.to_string()
+        };
+
+        if let Some(replaced) = replace_xmp_property(&updated, property, &escape_xml(&value)) {
+            updated = replaced;
+        }
+    }
+
+    if updated == xmp {
+        return None;
+    }
+
+    Some((
+        number,
+        generation,
+        format!(
+            "{}\nstream\n{}\nendstream",
+            set_entry(dictionary, "Length", updated.len())?,
+            updated
+        ),
+    ))
+}
+
+/// Returns a copy of the given XMP packet with the value of the given property replaced by
+/// the given XML-escaped value. The property may be written either as an element or as an
+/// attribute; for array properties such as `dc:title`, only the first item is replaced.
+/// Returns `None` if the property is not present.
+fn replace_xmp_property(xmp: &str, property: &str, value: &str) -> Option<String> {
+    let open = format!("<{}", property);
+
+    let mut search = 0;
+
+    while let Some(position) = xmp[search..].find(&open) {
+        let start = search + position;
+
+        search = start + open.len();
+
+        if !matches!(xmp[search..].chars().next(), Some(c) if c == '>' || c.is_whitespace()) {
+            // A different property sharing the same prefix.
+
+            continue;
+        }
+
+        let content_start = search + xmp[search..].find('>')? + 1;
+
+        if xmp[..content_start].ends_with("/>") {
+            // An empty element, whose value may be given as an rdf:resource attribute.
+
+            continue;
+        }
+
+        let content_end = content_start + xmp[content_start..].find(&format!("</{}>", property))?;
+
+        let content = &xmp[content_start..content_end];
+
+        let (start, end) = match content.find("<rdf:li") {
+            Some(item) => {
+                let start = item + content[item..].find('>')? + 1;
+
+                (start, start + content[start..].find("</rdf:li>")?)
+            }
+            None if !content.contains('<') => (0, content.len()),
+            None => return None,
+        };
+
+        return Some(format!(
+            "{}{}{}",
+            &xmp[..content_start + start],
+            value,
+            &xmp[content_start + end..]
+        ));
+    }
+
+    for quote in ['"', '\''] {
+        let attribute = format!("{}={}", property, quote);
+
+        let mut search = 0;
+
+        while let Some(position) = xmp[search..].find(&attribute) {
+            let start = search + position;
+
+            search = start + attribute.len();
+
+            if !matches!(xmp[..start].chars().next_back(), Some(c) if c.is_whitespace()) {
+                continue;
+            }
+
+            let end = search + xmp[search..].find(quote)?;
+
+            return Some(format!("{}{}{}", &xmp[..search], value, &xmp[end..]));
+        }
+    }
+
+    None
+}
+
+/// Escapes the characters in the given text that have special meaning in XML.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Returns the value of the boolean entry with the given key in the viewer preferences
+/// dictionary of the document catalog in the given document data. Returns `None` if the
+/// catalog could not be located, or if the catalog has no viewer preferences dictionary
+/// or the dictionary has no boolean entry with the given key.
+pub(crate) fn viewer_preference_flag(bytes: &[u8], key: &[u8]) -> Option<bool> {
+    let objects = find_object_offsets(bytes);
+
+    let preferences = dictionary_after_key(
+        bytes,
+        &objects,
+        catalog(bytes, &objects)?,
+        b"/ViewerPreferences",
+    )?;
+
+    parse_boolean_at(preferences, find_key(preferences, key)?)
+}
+
+/// Returns the value of the boolean entry with the given key in the mark information
+/// dictionary of the document catalog in the given document data, or `false` if the catalog
+/// has no mark information dictionary or the dictionary has no boolean entry with the given key.
+/// Returns `None` if the catalog could not be located.
+pub(crate) fn mark_info_flag(bytes: &[u8], key: &[u8]) -> Option<bool> {
+    let objects = find_object_offsets(bytes);
+
+    let catalog = catalog(bytes, &objects)?;
+
+    Some(
+        dictionary_after_key(bytes, &objects, catalog, b"/MarkInfo")
+            .and_then(|mark_info| parse_boolean_at(mark_info, find_key(mark_info, key)?))
+            .unwrap_or(false),
+    )
+}
+
+/// Appends an incremental update to the given document data that sets the boolean entry with
+/// the given key in the viewer preferences dictionary of the document catalog to the given value.
+/// The viewer preferences dictionary is created if it does not already exist.
+///
+/// The existing document data is left untouched, so the original byte offsets of all objects
+/// remain valid. Returns `None` if the document catalog or its existing viewer preferences
+/// dictionary could not be located.
+pub(crate) fn append_viewer_preference_flag(
+    bytes: &[u8],
+    key: &str,
+    value: bool,
+) -> Option<Vec<u8>> {
+    let objects = find_object_offsets(bytes);
+
+    let trailer = last_trailer(bytes, &objects)?;
+
+    let root = trailer.root;
+
+    let catalog = object_definition(bytes, &objects, root.0)?;
+
+    let updated = match find_key(catalog, b"/ViewerPreferences") {
+        Some(position) => match parse_reference_at(catalog, position) {
+            Some(((number, generation), _)) => (
+                number,
+                generation,
+                set_entry(object_definition(bytes, &objects, number)?, key, value)?,
+            ),
+            None => {
+                // The viewer preferences dictionary is inline in the catalog.
+
+                let start = skip_whitespace_forwards(catalog, position);
+
+                let end = dictionary_end(catalog, start)?;
+
+                (
+                    root.0,
+                    root.1,
+                    format!(
+                        "{}{}{}",
+                        std::str::from_utf8(&catalog[..start]).ok()?,
+                        set_entry(&catalog[start..end], key, value)?,
+                        std::str::from_utf8(&catalog[end..]).ok()?
+                    )
+                    .trim()
+                    .to_owned(),
+                )
+            }
+        },
+        None => (
+            root.0,
+            root.1,
+            insert_into_dictionary(
+                catalog,
+                &format!("/ViewerPreferences << /{} {} >>", key, value),
+            )?,
+        ),
+    };
+
+    append_incremental_update(
+        bytes,
+        &trailer,
+        vec![updated],
+        trailer.size,
+        &trailer.entries(),
+    )
+}
+
+/// Returns the object number of the dictionary of every page in the given document data,
+/// in page order, together with the value of the page's `/UserUnit` entry, or `1.0` if the
+/// page dictionary has no numeric `/UserUnit` entry. Pdfium preserves object numbers when
+/// saving a document, so the object number continues to identify the page after pages are
+/// inserted, deleted, or moved. Returns `None` if the document catalog, page tree, or any of
+/// the pages could not be located.
+pub(crate) fn page_user_units(bytes: &[u8]) -> Option<Vec<(u32, f32)>> {
+    let objects = find_object_offsets(bytes);
+
+    page_references(
+        bytes,
+        &objects,
+        find_reference_after_key(catalog(bytes, &objects)?, b"/Pages")?,
+    )?
+    .into_iter()
+    .map(|(number, _)| {
+        let page = dictionary_part(object_definition(bytes, &objects, number)?);
+
+        Some((
+            number,
+            find_key(page, b"/UserUnit")
+                .and_then(|position| parse_number_at(page, position))
+                .unwrap_or(1.0),
+        ))
+    })
+    .collect()
+}
+
+/// Appends an incremental update to the given document data that sets the `/UserUnit` entry
+/// in the dictionary of each page in the given map, keyed by the object number of the page
+/// dictionary, to the given value. Object numbers that no longer belong to a page in the
+/// document's page tree, for instance because the page was deleted, are ignored.
+///
+/// The existing document data is left untouched, so the original byte offsets of all objects
+/// remain valid. Returns `None` if the document catalog, page tree, or any of the pages
+/// could not be located.
+pub(crate) fn append_page_user_units(
+    bytes: &[u8],
+    user_units: &BTreeMap<u32, f32>,
+) -> Option<Vec<u8>> {
+    let objects = find_object_offsets(bytes);
+
+    let trailer = last_trailer(bytes, &objects)?;
+
+    let pages = page_references(
+        bytes,
+        &objects,
+        find_reference_after_key(catalog(bytes, &objects)?, b"/Pages")?,
+    )?;
+
+    let updated = user_units
+        .iter()
+        .filter_map(|(number, user_unit)| {
+            pages
+                .iter()
+                .find(|(page, _)| page == number)
+                .map(|reference| (*reference, user_unit))
+        })
+        .map(|((number, generation), user_unit)| {
+            Some((
+                number,
+                generation,
+                set_entry(
+                    object_definition(bytes, &objects, number)?,
+                    "UserUnit",
+                    user_unit,
+                )?,
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    append_incremental_update(bytes, &trailer, updated, trailer.size, &trailer.entries())
+}
+
+/// Returns the object number of the structure element dictionary reached by following the
+/// given path of kid indices from the structure tree root of the document catalog in the given
+/// document data. Each index counts the entries in the `/K` entry of the structure tree root or
+/// structure element, whether they are structure elements, marked content, or object references,
+/// in the same way as Pdfium's `FPDF_StructTree_GetChildAtIndex()` and
+/// `FPDF_StructElement_GetChildAtIndex()` functions. Returns `None` if the structure element
+/// could not be located, or if it is a direct object rather than an indirect object.
+pub(crate) fn struct_element_object_number(bytes: &[u8], path: &[usize]) -> Option<u32> {
+    let objects = find_object_offsets(bytes);
+
+    let mut container = dictionary_after_key(
+        bytes,
+        &objects,
+        catalog(bytes, &objects)?,
+        b"/StructTreeRoot",
+    )?;
+
+    let mut number = None;
+
+    for index in path {
+        let (kid, reference) = struct_kid(bytes, &objects, container, *index)?;
+
+        container = kid;
+        number = reference;
+    }
+
+    number
+}
+
+/// Appends an incremental update to the given document data that sets the `/Lang` entry in
+/// the dictionary of each structure element in the given map, keyed by the object number of
+/// the structure element dictionary, to the given language. Languages are stored as text strings
+/// without any interpretation.
+///
+/// The existing document data is left untouched, so the original byte offsets of all objects
+/// remain valid. Returns `None` if the last trailer in the document could not be located,
+/// if the document appears to be encrypted, or if any of the structure element dictionaries
+/// could not be located.
+pub(crate) fn append_struct_element_langs(
+    bytes: &[u8],
+    langs: &BTreeMap<u32, String>,
+) -> Option<Vec<u8>> {
+    let objects = find_object_offsets(bytes);
+
+    let trailer = last_trailer(bytes, &objects)?;
+
+    if trailer.is_encrypted {
+        // Strings in an encrypted document must themselves be encrypted.
+
+        return None;
+    }
+
+    let updated = langs
+        .iter()
+        .map(|(number, lang)| {
+            Some((
+                *number,
+                objects.get(number)?.0,
+                set_entry(
+                    object_definition(bytes, &objects, *number)?,
+                    "Lang",
+                    encode_text_string(lang),
+                )?,
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    append_incremental_update(bytes, &trailer, updated, trailer.size, &trailer.entries())
+}
+
+/// Returns the multimedia resources embedded in each annotation in the `/Annots` array of
+/// the page at the given index in the given document data, in array order, so that the position
+/// of each entry matches the annotation index used by Pdfium. Each entry lists the resources
+/// in the order in which they appear in the annotation's `/Assets` name tree, or is `None`
+/// if the annotation is not a RichMedia annotation or its `/RichMediaContent` dictionary
+/// could not be located. Returns `None` if the page or its `/Annots` array could not be located.
+///
+/// Assets whose embedded file stream could not be located are skipped. Stream data
+/// is returned as stored in the document, without reversing any stream filters.
+pub(crate) fn rich_media_assets(
+    bytes: &[u8],
+    page_index: usize,
+) -> Option<Vec<Option<Vec<RawEmbeddedFile>>>> {
+    let objects = find_object_offsets(bytes);
+
+    let page = page_dictionary(bytes, &objects, page_index)?;
+
+    Some(
+        annotation_dictionaries(bytes, &objects, page)?
+            .into_iter()
+            .map(|annotation| {
+                let annotation = annotation?;
+
+                if find_key(annotation, b"/Subtype")
+                    .and_then(|position| parse_name_at(annotation, position))
+                    .as_deref()
+                    != Some("RichMedia")
+                {
+                    return None;
+                }
+
+                let content =
+                    dictionary_after_key(bytes, &objects, annotation, b"/RichMediaContent")?;
+
+                Some(rich_media_content_assets(bytes, &objects, content))
+            })
+            .collect(),
+    )
+}
+
+/// Returns the embedded file streams referenced by the `/Assets` name tree of the given
+/// `/RichMediaContent` dictionary, skipping any that could not be located.
+fn rich_media_content_assets(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    content: &[u8],
+) -> Vec<RawEmbeddedFile> {
+    let assets = match dictionary_after_key(bytes, objects, content, b"/Assets") {
+        Some(assets) => name_tree_entries(bytes, objects, assets),
+        None => return Vec::new(),
+    };
+
+    assets
+        .into_iter()
+        .filter_map(|(name, (number, _))| {
+            let specification = dictionary_part(object_definition(bytes, objects, number)?);
+
+            let embedded_files = dictionary_after_key(bytes, objects, specification, b"/EF")?;
+
+            let (stream, _) = find_reference_after_key(embedded_files, b"/F")?;
+
+            let definition = object_definition(bytes, objects, stream)?;
+
+            let dictionary = dictionary_part(definition);
+
+            let mime_type = find_key(dictionary, b"/Subtype")
+                .and_then(|position| parse_name_at(dictionary, position));
+
+            Some(RawEmbeddedFile {
+                name,
+                mime_type,
+                filters: stream_filters(dictionary),
+                data: stream_data(bytes, objects, definition)?.to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the stream filters and data of the metadata stream referenced by the `/Metadata`
+/// entry of the document catalog in the given document data. Stream filters are not reversed.
+/// Returns `None` if the catalog could not be located, or if it has no metadata stream.
+pub(crate) fn catalog_metadata_stream(bytes: &[u8]) -> Option<(Vec<String>, Vec<u8>)> {
+    let objects = find_object_offsets(bytes);
+
+    let (number, _) = find_reference_after_key(catalog(bytes, &objects)?, b"/Metadata")?;
+
+    let definition = object_definition(bytes, &objects, number)?;
+
+    Some((
+        stream_filters(dictionary_part(definition)),
+        stream_data(bytes, &objects, definition)?.to_vec(),
+    ))
+}
+
+/// Returns the stream data of every image XObject in the given document data, together with
+/// `true` if the image is drawn through a mask: a stencil mask, indicated by an `/ImageMask`
+/// entry set to `true`; a soft mask, indicated by an `/SMask` entry; or an explicit or color key
+/// mask, indicated by a `/Mask` entry. Stream filters are not reversed.
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+pub(crate) fn image_streams(bytes: &[u8]) -> Vec<(&[u8], bool)> {
+    let objects = find_object_offsets(bytes);
+
+    objects
+        .keys()
+        .filter_map(|number| {
+            let definition = object_definition(bytes, &objects, *number)?;
+
+            let dictionary = dictionary_part(definition);
+
+            if find_key(dictionary, b"/Subtype")
+                .and_then(|position| parse_name_at(dictionary, position))
+                .as_deref()
+                != Some("Image")
+            {
+                return None;
+            }
+
+            let is_masked = find_key(dictionary, b"/SMask").is_some()
+                || find_key(dictionary, b"/Mask").is_some()
+                || find_key(dictionary, b"/ImageMask")
+                    .and_then(|position| parse_boolean_at(dictionary, position))
+                    == Some(true);
+
+            Some((stream_data(bytes, &objects, definition)?, is_masked))
+        })
+        .collect()
+}
+
+/// Returns the usage rights granted by the usage rights signature in the `/Perms` dictionary
+/// of the document catalog in the given document data. The `/UR3` signature is preferred,
+/// falling back to the obsolete `/UR` signature. Returns `None` if the catalog could not be
+/// located, or if it contains no usage rights signature.
+pub(crate) fn usage_rights(bytes: &[u8]) -> Option<RawUsageRights> {
+    let objects = find_object_offsets(bytes);
+
+    let permissions = dictionary_after_key(bytes, &objects, catalog(bytes, &objects)?, b"/Perms")?;
+
+    let signature = dictionary_after_key(bytes, &objects, permissions, b"/UR3")
+        .or_else(|| dictionary_after_key(bytes, &objects, permissions, b"/UR"))?;
+
+    // The transform parameters are held in a signature reference dictionary inside the
+    // signature's /Reference array. The reference dictionary is usually a direct object,
+    // but may also be an indirect reference.
+
+    let parameters = dictionary_after_key(bytes, &objects, signature, b"/TransformParams")
+        .or_else(|| {
+            find_key(signature, b"/Reference")
+                .and_then(|position| parse_reference_array(signature, position))?
+                .into_iter()
+                .find_map(|(number, _)| {
+                    let reference = dictionary_part(object_definition(bytes, &objects, number)?);
+
+                    dictionary_after_key(bytes, &objects, reference, b"/TransformParams")
+                })
+        })
+        .unwrap_or_default();
+
+    Some(RawUsageRights {
+        name: find_string_after_key(signature, b"/Name"),
+        message: find_string_after_key(parameters, b"/Msg"),
+        document: name_array_after_key(parameters, b"/Document"),
+        form: name_array_after_key(parameters, b"/Form"),
+        annotations: name_array_after_key(parameters, b"/Annots"),
+        signature: name_array_after_key(parameters, b"/Signature"),
+        embedded_files: name_array_after_key(parameters, b"/EF"),
+        is_restrictive: find_key(parameters, b"/P")
+            .and_then(|position| parse_boolean_at(parameters, position))
+            .unwrap_or(false),
+    })
+}
+
+/// An action located by the [document_actions()] function.
+pub(crate) struct RawAction {
+    /// The zero-based index of the page on which the action was found, or `None` if the
+    /// action belongs to the document catalog or to a bookmark.
+    pub(crate) page_index: Option<usize>,
+
+    /// The `/S` entry of the action dictionary, such as `JavaScript` or `SubmitForm`.
+    pub(crate) action_type: String,
+
+    /// Whether the action is the `/A` entry of a link annotation or a bookmark. Pdfium
+    /// exposes these actions, although it does not interpret every type of action.
+    pub(crate) is_exposed_by_pdfium: bool,
+}
+
+/// Returns the type of every action in the given document data that can be triggered by
+/// the document catalog's `/OpenAction` and `/AA` entries, the `/A` entries of bookmarks,
+/// the `/AA` entries of pages, and the `/A` and `/AA` entries of annotations, including
+/// any actions chained to them using `/Next` entries. Returns `None` if the document catalog
+/// or page tree could not be located.
+pub(crate) fn document_actions(bytes: &[u8]) -> Option<Vec<RawAction>> {
+    let objects = find_object_offsets(bytes);
+
+    let catalog = catalog(bytes, &objects)?;
+
+    let mut actions = Vec::new();
+
+    collect_actions(
+        bytes,
+        &objects,
+        catalog,
+        b"/OpenAction",
+        None,
+        false,
+        &mut actions,
+    );
+
+    collect_additional_actions(bytes, &objects, catalog, None, &mut actions);
+
+    if let Some(outline) = dictionary_after_key(bytes, &objects, catalog, b"/Outlines") {
+        let mut visited = Vec::new();
+
+        let mut stack = find_reference_after_key(outline, b"/First")
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        while let Some((number, _)) = stack.pop() {
+            if visited.contains(&number) {
+                // A malformed outline containing a cycle.
+
+                continue;
+            }
+
+            visited.push(number);
+
+            if let Some(item) = object_definition(bytes, &objects, number) {
+                let item = dictionary_part(item);
+
+                collect_actions(bytes, &objects, item, b"/A", None, true, &mut actions);
+
+                stack.extend(find_reference_after_key(item, b"/Next"));
+                stack.extend(find_reference_after_key(item, b"/First"));
+            }
+        }
+    }
+
+    let pages = page_references(
+        bytes,
+        &objects,
+        find_reference_after_key(catalog, b"/Pages")?,
+    )?;
+
+    for (index, (number, _)) in pages.into_iter().enumerate() {
+        let page = dictionary_part(object_definition(bytes, &objects, number)?);
+
+        collect_additional_actions(bytes, &objects, page, Some(index), &mut actions);
+
+        for annotation in annotation_dictionaries(bytes, &objects, page)
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+        {
+            let is_link = find_key(annotation, b"/Subtype")
+                .and_then(|position| parse_name_at(annotation, position))
+                .as_deref()
+                == Some("Link");
+
+            collect_actions(
+                bytes,
+                &objects,
+                annotation,
+                b"/A",
+                Some(index),
+                is_link,
+                &mut actions,
+            );
+
+            collect_additional_actions(bytes, &objects, annotation, Some(index), &mut actions);
+        }
+    }
+
+    Some(actions)
+}
+
+/// The keys of an additional-actions dictionary, across the additional-actions dictionaries
+/// of the document catalog, pages, annotations, and form fields.
+const ADDITIONAL_ACTION_TRIGGERS: [&[u8]; 20] = [
+    b"/E", b"/X", b"/D", b"/U", b"/Fo", b"/Bl", b"/PO", b"/PC", b"/PV", b"/PI", b"/O", b"/C",
+    b"/K", b"/F", b"/V", b"/WC", b"/WS", b"/DS", b"/WP", b"/DP",
+];
+
+/// Adds every action in the additional-actions dictionary held in the `/AA` entry of the
+/// given container to the given list.
+fn collect_additional_actions(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    container: &[u8],
+    page_index: Option<usize>,
+    actions: &mut Vec<RawAction>,
+) {
+    if let Some(triggers) = dictionary_after_key(bytes, objects, container, b"/AA") {
+        for trigger in ADDITIONAL_ACTION_TRIGGERS.iter() {
+            collect_actions(
+                bytes, objects, triggers, trigger, page_index, false, actions,
+            );
+        }
+    }
+}
+
+/// Adds the action dictionary held in the given key of the given container, along with
+/// any actions chained to it using `/Next` entries, to the given list. Nothing is added
+/// if the value of the key is not a dictionary; for instance, the `/OpenAction` entry of
+/// the document catalog may instead hold a destination array.
+fn collect_actions(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    container: &[u8],
+    key: &[u8],
+    page_index: Option<usize>,
+    is_exposed_by_pdfium: bool,
+    actions: &mut Vec<RawAction>,
+) {
+    let mut pending = match dictionary_after_key(bytes, objects, container, key) {
+        Some(action) => vec![(action, is_exposed_by_pdfium)],
+        None => return,
+    };
+
+    let mut visited = Vec::new();
+
+    while let Some((action, is_exposed_by_pdfium)) = pending.pop() {
+        let start = skip_whitespace_forwards(action, 0);
+
+        if !action[start..].starts_with(b"<<") {
+            continue;
+        }
+
+        if let Some(action_type) =
+            find_key(action, b"/S").and_then(|position| parse_name_at(action, position))
+        {
+            actions.push(RawAction {
+                page_index,
+                action_type,
+                is_exposed_by_pdfium,
+            });
+        }
+
+        // Pdfium does not follow /Next entries, so chained actions are never exposed by it.
+
+        let position = match find_key(action, b"/Next") {
+            Some(position) => position,
+            None => continue,
+        };
+
+        let next = match parse_reference_array(action, position) {
+            Some(references) => references,
+            None => match parse_reference_at(action, position) {
+                Some((reference, _)) => vec![reference],
+                None => {
+                    // A single inline action dictionary.
+
+                    pending.extend(
+                        dictionary_after_key(bytes, objects, action, b"/Next")
+                            .map(|next| (next, false)),
+                    );
+
+                    continue;
+                }
+            },
+        };
+
+        for (number, _) in next {
+            if visited.contains(&number) {
+                // A malformed action chain containing a cycle.
+
+                continue;
+            }
+
+            visited.push(number);
+
+            if let Some(definition) = object_definition(bytes, objects, number) {
+                pending.push((dictionary_part(definition), false));
+            }
+        }
+    }
+}
+
+/// Returns the dictionary of each entry in the `/Annots` array of the given page dictionary,
+/// in array order. Entries that are not dictionaries, such as `null`, are returned as `None`,
+/// so that the position of each entry matches the annotation index used by Pdfium.
+fn annotation_dictionaries<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    page: &'a [u8],
+) -> Option<Vec<Option<&'a [u8]>>> {
+    array_dictionaries(bytes, objects, page, b"/Annots")
+}
+
+/// Returns the dictionary of each entry in the array that is the value of the first occurrence
+/// of the given key in the given container, in array order. The array and each of its entries
+/// may be either direct objects or indirect references. Entries that are not dictionaries
+/// are returned as `None`, so that each entry keeps its position in the array.
+fn array_dictionaries<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    container: &'a [u8],
+    key: &[u8],
+) -> Option<Vec<Option<&'a [u8]>>> {
+    let position = find_key(container, key)?;
+
+    let (array, position) = match parse_reference_at(container, position) {
+        Some(((number, _), _)) => (object_definition(bytes, objects, number)?, 0),
+        None => (container, position),
+    };
+
+    let mut position = skip_whitespace_forwards(array, position);
+
+    if array.get(position) != Some(&b'[') {
+        return None;
+    }
+
+    position += 1;
+
+    let mut entries = Vec::new();
+
+    loop {
+        position = skip_whitespace_forwards(array, position);
+
+        if array.get(position)? == &b']' {
+            return Some(entries);
+        }
+
+        if array[position..].starts_with(b"<<") {
+            let end = dictionary_end(array, position)?;
+
+            entries.push(Some(&array[position..end]));
+
+            position = end;
+        } else if let Some(((number, _), end)) = parse_reference_at(array, position) {
+            entries.push(object_definition(bytes, objects, number).map(dictionary_part));
+
+            position = end;
+        } else if let Some(end) = skip_string_or_comment(array, position) {
+            if array[position] != b'%' {
+                entries.push(None);
+            }
+
+            position = end;
+        } else {
+            // Some other direct object, such as null.
+
+            entries.push(None);
+
+            position += 1;
+
+            while !is_token_end(array, position) {
+                position += 1;
+            }
+        }
+    }
+}
+
+/// Returns the dictionary of the kid at the given index in the `/K` entry of the given structure
+/// tree root or structure element, along with its object number if the kid is an indirect object.
+/// The `/K` entry may hold either a single kid or an array of kids. Returns `None` if there is no
+/// such kid, or if the kid is marked content or an object reference rather than a dictionary.
+fn struct_kid<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    container: &'a [u8],
+    index: usize,
+) -> Option<(&'a [u8], Option<u32>)> {
+    let position = find_key(container, b"/K")?;
+
+    let (kids, position) = match parse_reference_at(container, position) {
+        Some(((number, _), _)) => {
+            let definition = dictionary_part(object_definition(bytes, objects, number)?);
+
+            if definition[skip_whitespace_forwards(definition, 0)..].starts_with(b"<<") {
+                // A single kid, held as an indirect object.
+
+                return if index == 0 {
+                    Some((definition, Some(number)))
+                } else {
+                    None
+                };
+            }
+
+            (definition, 0)
+        }
+        None => (container, position),
+    };
+
+    let mut position = skip_whitespace_forwards(kids, position);
+
+    if kids.get(position) != Some(&b'[') {
+        // A single kid, held as a direct object.
+
+        return if index == 0 && kids[position..].starts_with(b"<<") {
+            Some((&kids[position..dictionary_end(kids, position)?], None))
+        } else {
+            None
+        };
+    }
+
+    position += 1;
+
+    let mut current = 0;
+
+    loop {
+        position = skip_whitespace_forwards(kids, position);
+
+        if kids.get(position)? == &b']' {
+            return None;
+        }
+
+        let (kid, end) = if kids[position..].starts_with(b"<<") {
+            let end = dictionary_end(kids, position)?;
+
+            (Some((&kids[position..end], None)), end)
+        } else if let Some(((number, _), end)) = parse_reference_at(kids, position) {
+            (
+                object_definition(bytes, objects, number)
+                    .map(|definition| (dictionary_part(definition), Some(number))),
+                end,
+            )
+        } else if let Some(end) = skip_string_or_comment(kids, position) {
+            if kids[position] == b'%' {
+                position = end;
+
+                continue;
+            }
+
+            (None, end)
+        } else {
+            // Some other direct object, such as the integer identifying marked content.
+
+            let mut end = position + 1;
+
+            while !is_token_end(kids, end) {
+                end += 1;
+            }
+
+            (None, end)
+        };
+
+        if current == index {
+            return kid;
+        }
+
+        current += 1;
+        position = end;
+    }
+}
+
+/// Returns the dictionary of the page at the given index in the given document data.
+fn page_dictionary<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    index: usize,
+) -> Option<&'a [u8]> {
+    let pages = page_references(
+        bytes,
+        objects,
+        find_reference_after_key(catalog(bytes, objects)?, b"/Pages")?,
+    )?;
+
+    Some(dictionary_part(object_definition(
+        bytes,
+        objects,
+        pages.get(index)?.0,
+    )?))
+}
+
+/// Returns the dictionary that is the value of the first occurrence of the given key in
+/// the given container, following an indirect reference if necessary.
+fn dictionary_after_key<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    container: &'a [u8],
+    key: &[u8],
+) -> Option<&'a [u8]> {
+    let position = find_key(container, key)?;
+
+    match parse_reference_at(container, position) {
+        Some(((number, _), _)) => Some(dictionary_part(object_definition(bytes, objects, number)?)),
+        None => {
+            let start = skip_whitespace_forwards(container, position);
+
+            Some(&container[start..dictionary_end(container, start)?])
+        }
+    }
+}
+
+/// Returns the decoded keys and referenced values of every entry in the name tree
+/// rooted at the given node, in the order in which they appear. Entries whose values
+/// are not indirect references are skipped.
+fn name_tree_entries(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    root: &[u8],
+) -> Vec<(String, (u32, u16))> {
+    let mut entries = Vec::new();
+
+    let mut visited = Vec::new();
+
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if let Some(position) = find_key(node, b"/Names") {
+            let mut position = skip_whitespace_forwards(node, position);
+
+            if node.get(position) == Some(&b'[') {
+                position += 1;
+
+                while let Some((key, end)) = parse_string_and_end_at(node, position) {
+                    match parse_reference_at(node, end) {
+                        Some((reference, end)) => {
+                            entries.push((decode_text_string(key.as_slice()), reference));
+
+                            position = end;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        if let Some(kids) =
+            find_key(node, b"/Kids").and_then(|position| parse_reference_array(node, position))
+        {
+            // Push in reverse so that kids are visited in order.
+
+            for (number, _) in kids.into_iter().rev() {
+                if visited.contains(&number) {
+                    // A malformed name tree containing a cycle.
+
+                    continue;
+                }
+
+                visited.push(number);
+
+                if let Some(kid) = object_definition(bytes, objects, number) {
+                    stack.push(dictionary_part(kid));
+                }
+            }
+        }
+    }
+
+    entries
+}
+
+/// Returns the names of the stream filters listed in the `/Filter` entry of the given
+/// stream dictionary, which may be either a single name or an array of names.
+fn stream_filters(dictionary: &[u8]) -> Vec<String> {
+    name_array_after_key(dictionary, b"/Filter")
+}
+
+/// Returns the names listed in the value of the first occurrence of the given key in the
+/// given dictionary, which may be either a single name or an array of names.
+fn name_array_after_key(dictionary: &[u8], key: &[u8]) -> Vec<String> {
+    let position = match find_key(dictionary, key) {
+        Some(position) => skip_whitespace_forwards(dictionary, position),
+        None => return Vec::new(),
+    };
+
+    if dictionary.get(position) == Some(&b'[') {
+        let end = find_from(dictionary, b"]", position).unwrap_or(dictionary.len());
+
+        let array = &dictionary[position + 1..end];
+
+        array
+            .iter()
+            .enumerate()
+            .filter(|(_, byte)| **byte == b'/')
+            .filter_map(|(position, _)| parse_name_at(array, position))
+            .collect()
+    } else {
+        parse_name_at(dictionary, position).into_iter().collect()
+    }
+}
+
+/// Returns the data of the given stream object definition, using the stream's `/Length`
+/// entry, which may be an indirect reference, to determine where the data ends.
+fn stream_data<'a>(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    definition: &'a [u8],
+) -> Option<&'a [u8]> {
+    let dictionary = dictionary_part(definition);
+
+    let mut start = find_key(definition, b"stream")?;
+
+    // The stream keyword is followed by either a carriage return and line feed,
+    // or a line feed alone.
+
+    if definition.get(start) == Some(&b'\r') {
+        start += 1;
+    }
+
+    if definition.get(start) == Some(&b'\n') {
+        start += 1;
+    }
+
+    let position = find_key(dictionary, b"/Length")?;
+
+    let length = match parse_reference_at(dictionary, position) {
+        Some(((number, _), _)) => {
+            parse_number_at(object_definition(bytes, objects, number)?, 0)? as usize
+        }
+        None => find_integer_after_key(dictionary, b"/Length")? as usize,
+    };
+
+    definition.get(start..start.checked_add(length)?)
+}
+
+/// Returns a copy of the given dictionary with the entry with the given key set to the given
+/// value, replacing any existing value, including an indirect reference.
+fn set_entry(definition: &[u8], key: &str, value: impl Display) -> Option<String> {
+    match find_key(definition, format!("/{}", key).as_bytes()) {
+        Some(position) => {
+            let start = skip_whitespace_forwards(definition, position);
+
+            let end = value_end(definition, start)?;
+
+            Some(
+                format!(
+                    "{} {}{}",
+                    std::str::from_utf8(&definition[..position]).ok()?,
+                    value,
+                    std::str::from_utf8(&definition[end..]).ok()?
+                )
+                .trim()
+                .to_owned(),
+            )
+        }
+        None => insert_into_dictionary(definition, &format!("/{} {}", key, value)),
+    }
+}
+
+/// Returns the key, without its leading `/`, and the value of each entry in the given
+/// dictionary, in order. Returns `None` if the dictionary could not be parsed.
+fn dictionary_entries(dictionary: &[u8]) -> Option<Vec<(&[u8], &[u8])>> {
+    let mut position = skip_whitespace_forwards(dictionary, 0);
+
+    if !dictionary.get(position..)?.starts_with(b"<<") {
+        return None;
+    }
+
+    position += 2;
+
+    let mut entries = Vec::new();
+
+    loop {
+        position = skip_whitespace_forwards(dictionary, position);
+
+        if dictionary.get(position..)?.starts_with(b">>") {
+            return Some(entries);
+        }
+
+        if dictionary.get(position)? == &b'%' {
+            position = skip_string_or_comment(dictionary, position)?;
+
+            continue;
+        }
+
+        if dictionary[position] != b'/' {
+            return None;
+        }
+
+        let key_start = position + 1;
+
+        position = key_start;
+
+        while !is_token_end(dictionary, position) {
+            position += 1;
+        }
+
+        let key = &dictionary[key_start..position];
+
+        let start = skip_whitespace_forwards(dictionary, position);
+
+        let end = value_end(dictionary, start)?;
+
+        entries.push((key, &dictionary[start..end]));
+
+        position = end;
+    }
+}
+
+/// Returns the position immediately after the direct object or indirect reference
+/// beginning at the given position.
+fn value_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if let Some((_, end)) = parse_reference_at(bytes, start) {
+        return Some(end);
+    }
+
+    match bytes.get(start)? {
+        b'<' if bytes.get(start + 1) == Some(&b'<') => dictionary_end(bytes, start),
+        b'(' | b'<' => skip_string_or_comment(bytes, start),
+        b'[' => {
+            let mut position = start + 1;
+
+            loop {
+                position = skip_whitespace_forwards(bytes, position);
+
+                if bytes.get(position)? == &b']' {
+                    return Some(position + 1);
+                }
+
+                position = value_end(bytes, position)?;
+            }
+        }
+        _ => {
+            // A name, number, boolean, or null. Names begin with a delimiter.
+
+            let mut end = start + 1;
+
+            while !is_token_end(bytes, end) {
+                end += 1;
+            }
+
+            Some(end)
+        }
+    }
+}
+
+/// Parses the boolean keyword at the given position, skipping any leading whitespace.
+fn parse_boolean_at(bytes: &[u8], position: usize) -> Option<bool> {
+    let position = skip_whitespace_forwards(bytes, position);
+
+    let (value, length) = if bytes[position..].starts_with(b"true") {
+        (true, 4)
+    } else if bytes[position..].starts_with(b"false") {
+        (false, 5)
+    } else {
+        return None;
+    };
+
+    match bytes.get(position + length) {
+        Some(byte) if !is_whitespace(*byte) && !is_delimiter(*byte) => None,
+        _ => Some(value),
+    }
+}
+
+/// Parses the integer or real number at the given position, skipping any leading whitespace.
+fn parse_number_at(bytes: &[u8], position: usize) -> Option<f32> {
+    // A PDF number is an optional sign followed by at least one digit, with at most one
+    // decimal point anywhere among the digits. Exponents are not permitted.
+
+    let start = skip_whitespace_forwards(bytes, position);
+
+    let mut end = start;
+
+    if matches!(bytes.get(end), Some(b'+' | b'-')) {
+        end += 1;
+    }
+
+    let mut digits = 0;
+
+    let mut has_point = false;
+
+    loop {
+        match bytes.get(end) {
+            Some(byte) if byte.is_ascii_digit() => digits += 1,
+            Some(b'.') if !has_point => has_point = true,
+            _ => break,
+        }
+
+        end += 1;
+    }
+
+    if digits == 0 || !is_token_end(bytes, end) {
+        return None;
+    }
+
+    std::str::from_utf8(&bytes[start..end]).ok()?.parse().ok()
+}
+
+/// Returns the position immediately after the `>>` that closes the dictionary opened
+/// by the `<<` at the given position, taking nested dictionaries and strings into account.
+fn dictionary_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if !bytes.get(start..)?.starts_with(b"<<") {
+        return None;
+    }
+
+    let mut depth = 0;
+
+    let mut position = start;
+
+    while position < bytes.len() {
+        if let Some(end) = skip_string_or_comment(bytes, position) {
+            position = end;
+        } else if bytes[position..].starts_with(b"<<") {
+            depth += 1;
+            position += 2;
+        } else if bytes[position..].starts_with(b">>") {
+            depth -= 1;
+            position += 2;
+
+            if depth == 0 {
+                return Some(position);
+            }
+        } else {
+            position += 1;
+        }
+    }
+
+    None
+}
+
+/// Returns the object number and generation number of every page in the page tree rooted
+/// at the given reference, in page order.
+fn page_references(
+    bytes: &[u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    root: (u32, u16),
+) -> Option<Vec<(u32, u16)>> {
+    let mut pages = Vec::new();
+
+    let mut visited = Vec::new();
+
+    let mut stack = vec![root];
+
+    while let Some((number, generation)) = stack.pop() {
+        if visited.contains(&number) {
+            // A malformed page tree containing a cycle.
+
+            return None;
+        }
+
+        visited.push(number);
+
+        let definition = object_definition(bytes, objects, number)?;
+
+        match find_key(definition, b"/Kids") {
+            Some(position) => {
+                let mut kids = parse_reference_array(definition, position)?;
+
+                // Push in reverse so that kids are visited in order.
+
+                kids.reverse();
+
+                stack.extend(kids);
+            }
+            None => pages.push((number, generation)),
+        }
+    }
+
+    Some(pages)
+}
+
+/// Parses an array of indirect references starting at the given position.
+fn parse_reference_array(bytes: &[u8], position: usize) -> Option<Vec<(u32, u16)>> {
+    let mut position = skip_whitespace_forwards(bytes, position);
+
+    if bytes.get(position) != Some(&b'[') {
+        return None;
+    }
+
+    position += 1;
+
+    let mut references = Vec::new();
+
+    loop {
+        position = skip_whitespace_forwards(bytes, position);
+
+        if bytes.get(position) == Some(&b']') {
+            return Some(references);
+        }
+
+        let (reference, end) = parse_reference_at(bytes, position)?;
+
+        references.push(reference);
+
+        position = end;
+    }
+}
+
+/// Returns the body of the given indirect object, between the `obj` and `endobj` keywords.
+fn object_definition<'a>(
+    bytes: &'a [u8],
+    objects: &BTreeMap<u32, (u16, usize)>,
+    number: u32,
+) -> Option<&'a [u8]> {
+    let (_, offset) = objects.get(&number)?;
+
+    let start = find_from(bytes, b"obj", *offset)? + 3;
+
+    let end = find_from(bytes, b"endobj", start)?;
+
+    Some(&bytes[start..end])
+}
+
+/// Returns a copy of the given object body with the given entry inserted at the end of
+/// its outermost dictionary.
+fn insert_into_dictionary(definition: &[u8], entry: &str) -> Option<String> {
+    let definition = std::str::from_utf8(definition).ok()?.trim();
+
+    if !definition.starts_with("<<") || !definition.ends_with(">>") {
+        // Not a plain dictionary, for example a stream.
+
+        return None;
+    }
+
+    Some(format!(
+        "{} {} >>",
+        definition[..definition.len() - 2].trim_end(),
+        entry
+    ))
+}
+
+/// Encodes the given value as a hexadecimal UTF-16BE text string.
+fn encode_text_string(value: &str) -> String {
+    let mut result = String::from("<FEFF");
+
+    for unit in value.encode_utf16() {
+        result.push_str(&format!("{:04X}", unit));
+    }
+
+    result.push('>');
+
+    result
+}
+
+/// Appends an incremental update containing the given objects to a copy of the given
+/// document data, whose last trailer is the given trailer. Each object is given as an object
+/// number, a generation number, and the object body. The new trailer contains the given size,
+/// the given additional entries, and a reference to the previous cross-reference section.
+fn append_incremental_update(
+    bytes: &[u8],
+    trailer: &Trailer,
+    objects: Vec<(u32, u16, String)>,
+    size: u32,
+    trailer_entries: &str,
+) -> Option<Vec<u8>> {
+    let previous_xref_offset = trailer.xref_offset;
+
+    let mut result = Vec::with_capacity(bytes.len() + 256 * objects.len());
+
+    result.extend_from_slice(bytes);
+
+    if !bytes.ends_with(b"\n") && !bytes.ends_with(b"\r") {
+        result.push(b'\n');
+    }
+
+    let mut offsets = Vec::with_capacity(objects.len());
+
+    for (number, generation, body) in objects {
+        offsets.push((number, generation, result.len()));
+
+        result.extend_from_slice(
+            format!("{} {} obj\n{}\nendobj\n", number, generation, body).as_bytes(),
+        );
+    }
+
+    offsets.sort_unstable();
+
+    let xref_offset = result.len();
+
+    result.extend_from_slice(b"xref\n");
+
+    for (number, generation, offset) in offsets {
+        result.extend_from_slice(
+            format!("{} 1\n{:010} {:05} n\r\n", number, offset, generation).as_bytes(),
+        );
+    }
+
+    result.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} {} /Prev {} >>\nstartxref\n{}\n%%EOF\n",
+            size, trailer_entries, previous_xref_offset, xref_offset,
+        )
+        .as_bytes(),
+    );
+
+    Some(result)
+}
+
+/// The entries of the last trailer in a document that are needed to locate the document
+/// catalog and to append an incremental update.
+struct Trailer {
+    /// The object number and generation number of the document catalog.
+    root: (u32, u16),
+
+    /// The object number and generation number of the information dictionary, if any.
+    info: Option<(u32, u16)>,
+
+    /// One greater than the largest object number in use in the document.
+    size: u32,
+
+    /// The byte offset of the last cross-reference section.
+    xref_offset: u32,
+
+    /// Whether the trailer has an `/Encrypt` entry.
+    is_encrypted: bool,
+}
+
+impl Trailer {
+    /// Returns the entries referencing the document catalog and the existing information
+    /// dictionary, if any, for use in the trailer of an incremental update.
+    fn entries(&self) -> String {
+        let mut entries = format!("/Root {} {} R", self.root.0, self.root.1);
+
+        if let Some((number, generation)) = self.info {
+            entries.push_str(&format!(" /Info {} {} R", number, generation));
+        }
+
+        entries
+    }
+}
+
+/// Locates the trailer of the last cross-reference section in the given document data,
+/// by following the final `startxref` offset. Returns `None` if the offset does not point to
+/// a classic cross-reference table, as is the case for a cross-reference stream, if the
+/// trailer refers to a cross-reference stream using a `/XRefStm` entry, or if the trailer
+/// has no `/Root` or `/Size` entry.
+///
+/// The `/Size` entry is not trusted: if any object in the given collection has an object number
+/// at or above it, the size is raised so that new objects cannot collide with existing ones.
+fn last_trailer(bytes: &[u8], objects: &BTreeMap<u32, (u16, usize)>) -> Option<Trailer> {
+    let xref_offset = find_integer_after_last(bytes, b"startxref")?;
+
+    let xref = skip_whitespace_forwards(bytes, usize::try_from(xref_offset).ok()?);
+
+    if !bytes.get(xref..)?.starts_with(b"xref") {
+        return None;
+    }
+
+    let keyword = find_from(bytes, b"trailer", xref)?;
+
+    if !bytes[xref + 4..keyword]
+        .iter()
+        .all(|byte| byte.is_ascii_digit() || is_whitespace(*byte) || matches!(byte, b'f' | b'n'))
+    {
+        // Something other than cross-reference subsections precedes the trailer, so the
+        // trailer found may not be the one belonging to this cross-reference section.
+
+        return None;
+    }
+
+    let start = skip_whitespace_forwards(bytes, keyword + 7);
+
+    let trailer = &bytes[start..dictionary_end(bytes, start)?];
+
+    if find_key(trailer, b"/XRefStm").is_some() {
+        return None;
+    }
+
+    let info = find_reference_after_key(trailer, b"/Info");
+
+    if info.is_none() && find_key(trailer, b"/Info").is_some() {
+        // A direct information dictionary cannot be carried over into the trailer
+        // of an incremental update.
+
+        return None;
+    }
+
+    let size = match objects.range(..MAXIMUM_OBJECT_NUMBER).next_back() {
+        Some((number, _)) => find_integer_after_key(trailer, b"/Size")?.max(number + 1),
+        None => find_integer_after_key(trailer, b"/Size")?,
+    };
+
+    Some(Trailer {
+        root: find_reference_after_key(trailer, b"/Root")?,
+        info,
+        size,
+        xref_offset,
+        is_encrypted: find_key(trailer, b"/Encrypt").is_some(),
+    })
+}
+
+/// Returns the dictionary of the document catalog referenced by the last trailer in the
+/// given document data.
+fn catalog<'a>(bytes: &'a [u8], objects: &BTreeMap<u32, (u16, usize)>) -> Option<&'a [u8]> {
+    let root = last_trailer(bytes, objects)?.root;
+
+    Some(dictionary_part(object_definition(bytes, objects, root.0)?))
+}
+
+/// Returns the generation number and byte offset of every object in use in the given document
+/// data, keyed by object number, as recorded in its cross-reference tables. The last
+/// cross-reference section is read first, followed by each earlier section referenced by
+/// a `/Prev` entry, so that the entries of later sections take precedence.
+///
+/// Only classic cross-reference tables can be read. An entry is ignored if the definition of
+/// the object it describes does not begin at its byte offset. Returns an empty collection if
+/// any cross-reference section could not be read.
+///
+/// Objects are deliberately not located by scanning the data, as [scan_object_offsets()] does,
+/// because text that looks like an object definition can appear inside a string or a stream.
+/// An embedded file may itself be a document, for instance, whose objects would otherwise
+/// shadow those of the document containing it.
+fn find_object_offsets(bytes: &[u8]) -> BTreeMap<u32, (u16, usize)> {
+    read_cross_reference_tables(bytes).unwrap_or_default()
+}
+
+/// Reads the cross-reference tables of the given document data for [find_object_offsets()].
+fn read_cross_reference_tables(bytes: &[u8]) -> Option<BTreeMap<u32, (u16, usize)>> {
+    let mut objects = BTreeMap::new();
+
+    // The object numbers of all entries read so far, including free entries, which
+    // take precedence over entries for the same object numbers in earlier sections.
+
+    let mut seen = BTreeSet::new();
+
+    let mut visited = Vec::new();
+
+    let mut next = find_integer_after_last(bytes, b"startxref");
+
+    while let Some(offset) = next {
+        if visited.contains(&offset) {
+            // A malformed document whose /Prev entries form a cycle.
+
+            return None;
+        }
+
+        visited.push(offset);
+
+        let mut position = skip_whitespace_forwards(bytes, usize::try_from(offset).ok()?);
+
+        if !bytes.get(position..)?.starts_with(b"xref") {
+            return None;
+        }
+
+        position += 4;
+
+        loop {
+            position = skip_whitespace_forwards(bytes, position);
+
+            if bytes.get(position..)?.starts_with(b"trailer") {
+                break;
+            }
+
+            let (first, end) = parse_integer_at(bytes, position)?;
+
+            let (count, end) = parse_integer_at(bytes, end)?;
+
+            position = end;
+
+            for number in first..first.checked_add(count)? {
+                let (entry_offset, end) = parse_integer_at(bytes, position)?;
+
+                let (generation, end) = parse_integer_at(bytes, end)?;
+
+                position = skip_whitespace_forwards(bytes, end);
+
+                let kind = *bytes.get(position)?;
+
+                position += 1;
+
+                if !matches!(kind, b'n' | b'f') {
+                    return None;
+                }
+
+                if !seen.insert(number) || kind == b'f' {
+                    continue;
+                }
+
+                let generation = u16::try_from(generation).ok()?;
+
+                let entry_offset = usize::try_from(entry_offset).ok()?;
+
+                if is_object_definition_at(bytes, entry_offset, number, generation) {
+                    objects.insert(number, (generation, entry_offset));
+                }
+            }
+        }
+
+        let start = skip_whitespace_forwards(bytes, position + 7);
+
+        let trailer = &bytes[start..dictionary_end(bytes, start)?];
+
+        if find_key(trailer, b"/XRefStm").is_some() {
+            return None;
+        }
+
+        next = find_integer_after_key(trailer, b"/Prev");
+    }
+
+    Some(objects)
+}
+
+/// Returns `true` if the definition `<number> <generation> obj` of the object with the given
+/// object number and generation number begins at the given position.
+fn is_object_definition_at(bytes: &[u8], position: usize, number: u32, generation: u16) -> bool {
+    if !matches!(bytes.get(position), Some(byte) if byte.is_ascii_digit()) {
+        return false;
+    }
+
+    match parse_integer_at(bytes, position)
+        .and_then(|(found, end)| Some((found, parse_integer_at(bytes, end)?)))
+    {
+        Some((found_number, (found_generation, end))) => {
+            found_number == number
+                && found_generation == u32::from(generation)
+                && bytes[skip_whitespace_forwards(bytes, end)..].starts_with(b"obj")
+        }
+        None => false,
+    }
+}
+
+/// Returns the generation number and byte offset of every indirect object definition
+/// in the given document data, keyed by object number, by scanning the data for the `obj`
+/// keyword. If an object is defined more than once, as happens when a document has been
+/// incrementally updated, the last definition wins.
+///
+/// Scanning can locate objects in a document whose cross-reference table is damaged, but it
+/// cannot distinguish object definitions from similar text inside strings and streams, so it
+/// is only used by [rebuild_cross_reference_table()].
+fn scan_object_offsets(bytes: &[u8]) -> BTreeMap<u32, (u16, usize)> {
+    let mut objects = BTreeMap::new();
+
+    let mut position = 0;
+
+    while let Some(found) = find_from(bytes, b"obj", position) {
+        position = found + 3;
+
+        // The keyword must be followed by a delimiter; this excludes "object" and similar.
+
+        if matches!(bytes.get(position), Some(byte) if !is_whitespace(*byte) && !is_delimiter(*byte))
+        {
+            continue;
+        }
+
+        if let Some((number, generation, offset)) = parse_object_header_before(bytes, found) {
+            objects.insert(number, (generation, offset));
+        }
+    }
+
+    objects
+}
+
+/// Parses the `<number> <generation>` pair immediately preceding the `obj` keyword
+/// at the given position, returning the object number, the generation number, and the
+/// byte offset at which the object definition begins.
+fn parse_object_header_before(bytes: &[u8], keyword: usize) -> Option<(u32, u16, usize)> {
+    let generation_end = skip_whitespace_backwards(bytes, keyword, true)?;
+
+    let generation_start = skip_digits_backwards(bytes, generation_end)?;
+
+    let number_end = skip_whitespace_backwards(bytes, generation_start, true)?;
+
+    let number_start = skip_digits_backwards(bytes, number_end)?;
+
+    if number_start > 0 && !is_whitespace(bytes[number_start - 1]) {
+        return None;
+    }
+
+    let number = parse_digits(&bytes[number_start..number_end])?;
+
+    let generation = parse_digits(&bytes[generation_start..generation_end])?;
+
+    Some((number, u16::try_from(generation).ok()?, number_start))
+}
+
+/// Returns the object number and generation number of the indirect reference
+/// `<number> <generation> R` that immediately follows the last occurrence of the given key.
+fn find_reference_after_last(bytes: &[u8], key: &[u8]) -> Option<(u32, u16)> {
+    parse_reference_at(bytes, find_last(bytes, key)? + key.len()).map(|(reference, _)| reference)
+}
+
+/// Returns the object number and generation number of the indirect reference
+/// `<number> <generation> R` that immediately follows the first occurrence of the given key.
+fn find_reference_after_key(bytes: &[u8], key: &[u8]) -> Option<(u32, u16)> {
+    parse_reference_at(bytes, find_key(bytes, key)?).map(|(reference, _)| reference)
+}
+
+/// Parses the non-negative integer at the given position, skipping any leading whitespace.
+/// Returns the integer, along with the position immediately after it.
+fn parse_integer_at(bytes: &[u8], position: usize) -> Option<(u32, usize)> {
+    let start = skip_whitespace_forwards(bytes, position);
+
+    let mut end = start;
+
+    while matches!(bytes.get(end), Some(byte) if byte.is_ascii_digit()) {
+        end += 1;
+    }
+
+    Some((parse_digits(&bytes[start..end])?, end))
+}
+
+/// Parses the indirect reference `<number> <generation> R` at the given position, skipping
+/// any leading whitespace. Returns the object number and generation number, along with the
+/// position immediately after the reference.
+fn parse_reference_at(bytes: &[u8], mut position: usize) -> Option<((u32, u16), usize)> {
+    let mut values = [0u32; 2];
+
+    for value in values.iter_mut() {
+        position = skip_whitespace_forwards(bytes, position);
+
+        let start = position;
+
+        while matches!(bytes.get(position), Some(byte) if byte.is_ascii_digit()) {
+            position += 1;
+        }
+
+        *value = parse_digits(&bytes[start..position])?;
+    }
+
+    position = skip_whitespace_forwards(bytes, position);
+
+    if bytes.get(position) == Some(&b'R') {
+        Some(((values[0], u16::try_from(values[1]).ok()?), position + 1))
+    } else {
+        None
+    }
+}
+
+/// Returns the object number and generation number of the last object in the given
+/// collection whose definition includes a `/Type /Catalog` entry.
+fn find_catalog(bytes: &[u8], objects: &BTreeMap<u32, (u16, usize)>) -> Option<(u32, u16)> {
+    objects
+        .iter()
+        .filter(|(_, (_, offset))| {
+            let end = find_from(bytes, b"endobj", *offset).unwrap_or(bytes.len());
+
+            let definition = &bytes[*offset..end];
+
+            find_from(definition, b"/Type/Catalog", 0).is_some()
+                || find_from(definition, b"/Type /Catalog", 0).is_some()
+        })
+        .max_by_key(|(_, (_, offset))| *offset)
+        .map(|(number, (generation, _))| (*number, *generation))
+}
+
+/// Returns the non-negative integer that immediately follows the last occurrence
+/// of the given key.
+fn find_integer_after_last(bytes: &[u8], key: &[u8]) -> Option<u32> {
+    let start = skip_whitespace_forwards(bytes, find_last(bytes, key)? + key.len());
+
+    let mut end = start;
+
+    while matches!(bytes.get(end), Some(byte) if byte.is_ascii_digit()) {
+        end += 1;
+    }
+
+    parse_digits(&bytes[start..end])
+}
+
+/// Returns the position immediately after the first occurrence of the given key in the
+/// outermost dictionary of the given data, or outside any dictionary at all. The key must
+/// be followed by whitespace, a delimiter, or the end of the data, so that (for example)
+/// an `/L` key is not matched by a `/Linearized` key.
+///
+/// Occurrences inside nested dictionaries, arrays, strings, and comments are skipped,
+/// so that (for example) the `/Type` entry of a page is not matched by the `/Type`
+/// entry of an inline resource dictionary.
+fn find_key(bytes: &[u8], key: &[u8]) -> Option<usize> {
+    let mut dictionaries = 0_usize;
+
+    let mut arrays = 0_usize;
+
+    let mut position = 0;
+
+    while position < bytes.len() {
+        if let Some(end) = skip_string_or_comment(bytes, position) {
+            position = end;
+
+            continue;
+        }
+
+        if bytes[position..].starts_with(b"<<") {
+            dictionaries += 1;
+            position += 2;
+
+            continue;
+        }
+
+        if bytes[position..].starts_with(b">>") {
+            dictionaries = dictionaries.saturating_sub(1);
+            position += 2;
+
+            continue;
+        }
+
+        match bytes[position] {
+            b'[' => arrays += 1,
+            b']' => arrays = arrays.saturating_sub(1),
+            _ => {}
+        }
+
+        if dictionaries <= 1
+            && arrays == 0
+            && bytes[position..].starts_with(key)
+            && (key.first() == Some(&b'/')
+                || position == 0
+                || is_whitespace(bytes[position - 1])
+                || is_delimiter(bytes[position - 1]))
+        {
+            let end = position + key.len();
+
+            match bytes.get(end) {
+                Some(byte) if !is_whitespace(*byte) && !is_delimiter(*byte) => {}
+                _ => return Some(end),
+            }
+        }
+
+        position += 1;
+    }
+
+    None
+}
+
+/// If a literal string, hexadecimal string, or comment begins at the given position, returns
+/// the position immediately after it, or the end of the data if it is not terminated.
+fn skip_string_or_comment(bytes: &[u8], position: usize) -> Option<usize> {
+    match bytes.get(position)? {
+        b'(' => Some(
+            parse_string_and_end_at(bytes, position)
+                .map(|(_, end)| end)
+                .unwrap_or(bytes.len()),
+        ),
+        b'<' if bytes.get(position + 1) != Some(&b'<') => Some(
+            find_from(bytes, b">", position)
+                .map(|end| end + 1)
+                .unwrap_or(bytes.len()),
+        ),
+        b'%' => Some(
+            bytes[position..]
+                .iter()
+                .position(|byte| matches!(byte, b'\r' | b'\n'))
+                .map(|end| position + end)
+                .unwrap_or(bytes.len()),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns the non-negative integer that immediately follows the first occurrence
+/// of the given key.
+fn find_integer_after_key(bytes: &[u8], key: &[u8]) -> Option<u32> {
+    let start = skip_whitespace_forwards(bytes, find_key(bytes, key)?);
+
+    let mut end = start;
+
+    while matches!(bytes.get(end), Some(byte) if byte.is_ascii_digit()) {
+        end += 1;
+    }
+
+    parse_digits(&bytes[start..end])
+}
+
+#[inline]
+fn find_from(haystack: &[u8], needle: &[u8], start: usize) -> Option<usize> {
+    haystack
+        .get(start..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| position + start)
+}
+
+#[inline]
+fn find_last(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .rposition(|window| window == needle)
+}
+
+/// Moves backwards from the given position over any whitespace, returning the position
+/// immediately after the last non-whitespace byte. If `required` is `true`, at least one
+/// whitespace byte must be present.
+fn skip_whitespace_backwards(bytes: &[u8], mut position: usize, required: bool) -> Option<usize> {
+    let start = position;
+
+    while position > 0 && is_whitespace(bytes[position - 1]) {
+        position -= 1;
+    }
+
+    if required && position == start {
+        None
+    } else {
+        Some(position)
+    }
+}
+
+/// Moves backwards from the given position over any decimal digits, returning the position
+/// of the first digit. At least one digit must be present.
+fn skip_digits_backwards(bytes: &[u8], mut position: usize) -> Option<usize> {
+    let start = position;
+
+    while position > 0 && bytes[position - 1].is_ascii_digit() {
+        position -= 1;
+    }
+
+    if position == start {
+        None
+    } else {
+        Some(position)
+    }
+}
+
+#[inline]
+fn skip_whitespace_forwards(bytes: &[u8], mut position: usize) -> usize {
+    while matches!(bytes.get(position), Some(byte) if is_whitespace(*byte)) {
+        position += 1;
+    }
+
+    position
+}
+
+#[inline]
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+/// Returns `true` if the given byte is a whitespace character as defined in
+/// section 7.2.2 of the PDF specification.
+#[inline]
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\0' | b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+/// Returns `true` if the given byte is a delimiter character as defined in
+/// section 7.2.2 of the PDF specification.
+#[inline]
+fn is_delimiter(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%'
+    )
+}
+
+/// Returns `true` if the token ending at the given position in the given data is complete,
+/// i.e. if the position is at the end of the data or at a whitespace or delimiter character.
+#[inline]
+fn is_token_end(bytes: &[u8], position: usize) -> bool {
+    bytes
+        .get(position)
+        .map_or(true, |byte| is_whitespace(*byte) || is_delimiter(*byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test::test_document;
+
+    const MALFORMED: &[u8] = b"%PDF-1.4\n\
+        1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+        2 0 obj\n<< /Type /Pages /Kids [3 0 R] /Count 1 >>\nendobj\n\
+        3 0 obj\n<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>\nendobj\n\
+        xref\n0 1\n0000000000 65535 f\r\n\
+        trailer\n<< /Size 4 /Root 1 0 R >>\nstartxref\n99999\n%%EOF\n";
+
+    #[test]
+    fn test_scan_object_offsets() {
+        let objects = scan_object_offsets(MALFORMED);
+
+        assert_eq!(objects.len(), 3);
+
+        for (number, (generation, offset)) in objects {
+            assert_eq!(generation, 0);
+            assert!(MALFORMED[offset..].starts_with(format!("{} 0 obj", number).as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_rebuild_cross_reference_table() {
+        let rebuilt = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        assert!(rebuilt.starts_with(MALFORMED));
+
+        let appended = std::str::from_utf8(&rebuilt[MALFORMED.len()..]).unwrap();
+
+        assert!(appended.starts_with("xref\n0 4\n0000000000 65535 f\r\n"));
+        assert!(appended.contains("trailer\n<< /Size 4 /Root 1 0 R >>"));
+        assert!(appended.ends_with(&format!("startxref\n{}\n%%EOF\n", MALFORMED.len())));
+
+        let first_entry = &appended["xref\n0 4\n0000000000 65535 f\r\n".len()..][..20];
+
+        let offset: usize = first_entry[..10].parse().unwrap();
+
+        assert!(MALFORMED[offset..].starts_with(b"1 0 obj"));
+    }
+
+    #[test]
+    fn test_rebuild_writes_subsections_for_sparse_object_numbers() {
+        let mut sparse = MALFORMED.to_vec();
+
+        sparse.extend_from_slice(b"999999 0 obj\n(sparse)\nendobj\n");
+
+        let rebuilt = rebuild_cross_reference_table(&sparse).unwrap();
+
+        let appended = std::str::from_utf8(&rebuilt[sparse.len()..]).unwrap();
+
+        assert!(appended.starts_with("xref\n0 4\n"));
+        assert!(appended.contains(&format!("999999 1\n{:010} 00000 n\r\n", MALFORMED.len())));
+        assert!(appended.contains("/Size 1000000 "));
+        assert!(appended.len() < 512);
+    }
+
+    #[test]
+    fn test_rebuild_ignores_out_of_range_object_numbers() {
+        let mut oversized = MALFORMED.to_vec();
+
+        oversized.extend_from_slice(b"4294967295 0 obj\n(oversized)\nendobj\n");
+
+        let rebuilt = rebuild_cross_reference_table(&oversized).unwrap();
+
+        let appended = std::str::from_utf8(&rebuilt[oversized.len()..]).unwrap();
+
+        assert!(appended.starts_with("xref\n0 4\n"));
+        assert!(!appended.contains("4294967295"));
+        assert!(appended.contains("/Size 4 "));
+    }
+
+    #[test]
+    fn test_rebuild_locates_catalog_without_trailer() {
+        let truncated = &MALFORMED[..find_last(MALFORMED, b"xref").unwrap()];
+
+        let rebuilt = rebuild_cross_reference_table(truncated).unwrap();
+
+        let appended = std::str::from_utf8(&rebuilt[truncated.len()..]).unwrap();
+
+        assert!(appended.contains("/Root 1 0 R"));
+    }
+
+    #[test]
+    fn test_find_key_skips_nested_values() {
+        let page = b"<< /Resources << /Type /Font >> /Kids [/Type] /T (/Type) /Type /Page >>";
+
+        let position = find_key(page, b"/Type").unwrap();
+
+        assert_eq!(parse_name_at(page, position).as_deref(), Some("Page"));
+        assert_eq!(find_key(page, b"/Typ"), None);
+        assert_eq!(find_key(b"<< /A << /B 1 >> >>", b"/B"), None);
+    }
+
+    #[test]
+    fn test_last_trailer_rejects_cross_reference_streams() {
+        let repaired = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        let trailer = last_trailer(&repaired, &find_object_offsets(&repaired)).unwrap();
+
+        assert_eq!(trailer.root, (1, 0));
+        assert_eq!(trailer.size, 4);
+        assert_eq!(trailer.xref_offset as usize, MALFORMED.len());
+
+        let mut hybrid = repaired.clone();
+
+        let position = find_last(&hybrid, b"/Size").unwrap();
+
+        hybrid.splice(position..position, b"/XRefStm 0 ".iter().copied());
+
+        assert!(last_trailer(&hybrid, &find_object_offsets(&hybrid)).is_none());
+
+        let mut stream = MALFORMED[..find_last(MALFORMED, b"xref").unwrap()].to_vec();
+
+        let offset = stream.len();
+
+        stream.extend_from_slice(
+            format!(
+                "4 0 obj\n<< /Type /XRef /Size 5 /Root 1 0 R /Length 0 >>\nstream\n\nendstream\n\
+                endobj\nstartxref\n{}\n%%EOF\n",
+                offset
+            )
+            .as_bytes(),
+        );
+
+        assert!(last_trailer(&stream, &find_object_offsets(&stream)).is_none());
+        assert!(append_information_dictionary(&stream, &[("Title", "Title")]).is_none());
+    }
+
+    #[test]
+    fn test_last_trailer_does_not_trust_size() {
+        let mut repaired = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        let position = find_last(&repaired, b"/Size 4").unwrap();
+
+        repaired[position + 6] = b'2';
+
+        let trailer = last_trailer(&repaired, &find_object_offsets(&repaired)).unwrap();
+
+        assert_eq!(trailer.size, 4);
+    }
+
+    #[test]
+    fn test_catalog_contains_key() {
+        // The trailer of the malformed document points to a cross-reference section
+        // that does not exist, so its catalog cannot be located reliably.
+
+        assert_eq!(catalog_contains_key(MALFORMED, b"/Pages"), None);
+
+        let repaired = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        assert_eq!(catalog_contains_key(&repaired, b"/Pages"), Some(true));
+        assert_eq!(catalog_contains_key(&repaired, b"/Collection"), Some(false));
+        assert_eq!(catalog_contains_key(&repaired, b"/Page"), Some(false));
+    }
+
+    #[test]
+    fn test_append_information_dictionary() {
+        let repaired = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        let updated =
+            append_information_dictionary(&repaired, &[("Title", "Caf\u{e9} \u{1F600}")]).unwrap();
+
+        assert!(updated.starts_with(&repaired));
+
+        let appended = std::str::from_utf8(&updated[repaired.len()..]).unwrap();
+
+        assert!(appended.starts_with("4 0 obj\n<< /Title <FEFF00430061006600E90020D83DDE00> >>"));
+        assert!(appended.contains(&format!("xref\n4 1\n{:010} 00000 n\r\n", repaired.len())));
+        assert!(appended.contains(&format!(
+            "<< /Size 5 /Root 1 0 R /Info 4 0 R /Prev {} >>",
+            MALFORMED.len()
+        )));
+    }
+
+    #[test]
+    fn test_append_information_dictionary_merges_existing_entries() {
+        let xmp = "<x:xmpmeta xmlns:x=\"adobe:ns:meta/\"><rdf:RDF><rdf:Description \
+            pdf:Producer=\"Old producer\"><dc:title><rdf:Alt><rdf:li xml:lang=\"x-default\">\
+            Old title</rdf:li></rdf:Alt></dc:title><dc:titles>Unrelated</dc:titles>\
+            </rdf:Description></rdf:RDF></x:xmpmeta>";
+
+        let bytes = rebuild_cross_reference_table(
+            format!(
+                "%PDF-1.7\n\
+                1 0 obj\n<< /Type /Catalog /Pages 2 0 R /Metadata 4 0 R >>\nendobj\n\
+                2 0 obj\n<< /Type /Pages /Kids [] /Count 0 >>\nendobj\n\
+                3 0 obj\n<< /Title (Old title) /Custom (Caf\\351) /Trapped /False \
+                /Links [1 0 R << /A 2 >>] >>\nendobj\n\
+                4 0 obj\n<< /Type /Metadata /Subtype /XML /Length {} >>\nstream\n{}\n\
+                endstream\nendobj\n\
+                trailer\n<< /Root 1 0 R /Info 3 0 R >>\n",
+                xmp.len(),
+                xmp
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let updated = append_information_dictionary(
+            &bytes,
+            &[("Title", "A & B"), ("Producer", "New producer")],
+        )
+        .unwrap();
+
+        let appended = std::str::from_utf8(&updated[bytes.len()..]).unwrap();
+
+        // The existing information dictionary is replaced in place, keeping its custom entries.
+
+        assert!(appended.starts_with(
+            "3 0 obj\n<< /Custom <FEFF00430061006600E9> /Trapped /False \
+            /Links [1 0 R << /A 2 >>] /Title <FEFF00410020002600200042> \
+            /Producer <FEFF004E00650077002000700072006F00640075006300650072> >>"
+        ));
+        assert!(appended.contains("<< /Size 5 /Root 1 0 R /Info 3 0 R /Prev "));
+
+        // The matching XMP properties are updated, and no others.
+
+        assert!(appended.contains("4 0 obj\n<< /Type /Metadata /Subtype /XML /Length "));
+        assert!(appended.contains("pdf:Producer=\"New producer\""));
+        assert!(appended.contains("<rdf:li xml:lang=\"x-default\">A &amp; B</rdf:li>"));
+        assert!(appended.contains("<dc:titles>Unrelated</dc:titles>"));
+
+        // A trailer whose information dictionary cannot be located is rejected rather than
+        // replaced with a dictionary that would drop the existing entries.
+
+        let position = find_last(&bytes, b"/Info 3 0 R").unwrap();
+
+        let mut missing = bytes.clone();
+
+        missing[position + 6] = b'9';
+
+        assert!(append_information_dictionary(&missing, &[("Title", "Title")]).is_none());
+    }
+
+    #[test]
+    fn test_replace_xmp_property() {
+        let xmp = "<rdf:Description xmp:CreatorTool='Tool'><dc:creator><rdf:Seq>\
+            <rdf:li>First</rdf:li><rdf:li>Second</rdf:li></rdf:Seq></dc:creator>\
+            <pdf:Keywords>one</pdf:Keywords></rdf:Description>";
+
+        assert_eq!(
+            replace_xmp_property(xmp, "dc:creator", "Author").unwrap(),
+            xmp.replace("First", "Author")
+        );
+        assert_eq!(
+            replace_xmp_property(xmp, "pdf:Keywords", "two").unwrap(),
+            xmp.replace(">one<", ">two<")
+        );
+        assert_eq!(
+            replace_xmp_property(xmp, "xmp:CreatorTool", "Other").unwrap(),
+            xmp.replace("'Tool'", "'Other'")
+        );
+        assert_eq!(replace_xmp_property(xmp, "dc:title", "Title"), None);
+        assert_eq!(replace_xmp_property(xmp, "pdf:Key", "Key"), None);
+    }
+
+    #[test]
+    fn test_last_trailer_rejects_unexpected_cross_reference_content() {
+        let repaired = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        assert!(last_trailer(&repaired, &find_object_offsets(&repaired)).is_some());
+
+        // Insert an unexpected token between the cross-reference table and the trailer.
+
+        let position = find_last(&repaired, b"trailer").unwrap();
+
+        let mut corrupted = repaired[..position].to_vec();
+
+        corrupted.extend_from_slice(b"<< /Root 9 0 R >>\n");
+        corrupted.extend_from_slice(&repaired[position..]);
+
+        assert!(last_trailer(&corrupted, &find_object_offsets(&corrupted)).is_none());
+
+        // A direct information dictionary is also rejected.
+
+        let mut direct = repaired.clone();
+
+        let position = find_last(&direct, b"/Root 1 0 R").unwrap();
+
+        direct.splice(
+            position..position,
+            b"/Info << /Title (T) >> ".iter().copied(),
+        );
+
+        assert!(last_trailer(&direct, &find_object_offsets(&direct)).is_none());
+    }
+
+    #[test]
+    fn test_rebuild_rejects_encrypted_document() {
+        let mut encrypted = MALFORMED.to_vec();
+
+        encrypted.extend_from_slice(b"trailer\n<< /Encrypt 5 0 R >>\n");
+
+        assert!(rebuild_cross_reference_table(&encrypted).is_none());
+    }
+
+    #[test]
+    fn test_embedded_file_entries() {
+        // The /EmbeddedFiles name tree is split across two kids, and the second leaf holds
+        // a direct file specification dictionary. Object 5 is a file specification with the
+        // same file name as the first entry that is not part of the name tree.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /Names << /EmbeddedFiles 3 0 R >> >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+            b"<< /Kids [4 0 R 8 0 R] >>",
+            b"<< /Limits [(invoice.xml) (invoice.xml)] /Names [(invoice.xml) 6 0 R] >>",
+            b"<< /Type /Filespec /F (invoice.xml) /AFRelationship /Source /EF << /F 9 0 R >> >>",
+            b"<< /Type /Filespec /F (invoice\\137a.xml) \
+            /UF <FEFF0069006E0076006F006900630065002E0078006D006C> \
+            /AFRelationship /Alternative /EF << /F 7 0 R /UF 7 0 R >> >>",
+            b"<< /Type /EmbeddedFile /Subtype /text#2Fxml >>\nstream\n<x/>",
+            b"<< /Limits [(notes.txt) (notes.txt)] /Names [(notes.txt) << /Type /Filespec \
+            /F (notes.txt) /EF << /F 9 0 R >> >>] >>",
+            b"<< /Type /EmbeddedFile >>\nstream\nnote",
+        ]);
+
+        assert_eq!(
+            embedded_file_entries(&bytes),
+            Some(vec![
+                RawEmbeddedFileEntry {
+                    mime_type: Some("text/xml".to_owned()),
+                    relationship: Some("Alternative".to_owned()),
+                },
+                RawEmbeddedFileEntry {
+                    mime_type: None,
+                    relationship: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_mark_info_flag() {
+        let marked = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /MarkInfo 3 0 R >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+            b"<< /Marked true /UserProperties true /Suspects false >>",
+        ]);
+
+        assert_eq!(mark_info_flag(&marked, b"/Marked"), Some(true));
+        assert_eq!(mark_info_flag(&marked, b"/UserProperties"), Some(true));
+        assert_eq!(mark_info_flag(&marked, b"/Suspects"), Some(false));
+
+        let unmarked = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+        ]);
+
+        assert_eq!(mark_info_flag(&unmarked, b"/Suspects"), Some(false));
+
+        assert_eq!(mark_info_flag(b"%PDF-1.7\n", b"/Suspects"), None);
+    }
+
+    #[test]
+    fn test_viewer_preference_flag() {
+        let repaired = rebuild_cross_reference_table(MALFORMED).unwrap();
+
+        assert_eq!(viewer_preference_flag(&repaired, b"/DisplayDocTitle"), None);
+
+        // Creating the viewer preferences dictionary.
+
+        let created = append_viewer_preference_flag(&repaired, "DisplayDocTitle", true).unwrap();
+
+        assert!(created.starts_with(&repaired));
+        assert_eq!(
+            viewer_preference_flag(&created, b"/DisplayDocTitle"),
+            Some(true)
+        );
+
+        // Replacing the value in an existing inline dictionary.
+
+        let replaced = append_viewer_preference_flag(&created, "DisplayDocTitle", false).unwrap();
+
+        assert_eq!(
+            viewer_preference_flag(&replaced, b"/DisplayDocTitle"),
+            Some(false)
+        );
+        assert!(find_from(&replaced[created.len()..], b"/DisplayDocTitle false", 0).is_some());
+
+        // Updating an indirect viewer preferences dictionary.
+
+        let indirect = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /ViewerPreferences 4 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+            b"<< /HideToolbar true /DisplayDocTitle false >>",
+        ]);
+
+        assert_eq!(
+            viewer_preference_flag(&indirect, b"/DisplayDocTitle"),
+            Some(false)
+        );
+
+        let updated = append_viewer_preference_flag(&indirect, "DisplayDocTitle", true).unwrap();
+
+        let appended = std::str::from_utf8(&updated[indirect.len()..]).unwrap();
+
+        assert!(appended
+            .starts_with("4 0 obj\n<< /HideToolbar true /DisplayDocTitle true >>\nendobj\n"));
+        assert_eq!(
+            viewer_preference_flag(&updated, b"/DisplayDocTitle"),
+            Some(true)
+        );
+        assert_eq!(
+            viewer_preference_flag(&updated, b"/HideToolbar"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_page_user_units() {
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 200 200] /UserUnit 72 >>",
+        ]);
+
+        assert_eq!(page_user_units(&bytes), Some(vec![(3, 1.0), (4, 72.0)]));
+
+        // A page that cannot be located is reported, rather than given the default user unit.
+
+        let missing = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R 9 0 R] /Count 2 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+        ]);
+
+        assert_eq!(page_user_units(&missing), None);
+
+        let mut user_units = BTreeMap::new();
+
+        user_units.insert(3, 2.5);
+        user_units.insert(4, 36.0);
+
+        // Object 2 is the page tree root rather than a page, so it is ignored.
+
+        user_units.insert(2, 1.0);
+
+        let updated = append_page_user_units(&bytes, &user_units).unwrap();
+
+        assert!(updated.starts_with(&bytes));
+        assert_eq!(page_user_units(&updated), Some(vec![(3, 2.5), (4, 36.0)]));
+        assert_eq!(
+            find_object_offsets(&updated).get(&2),
+            find_object_offsets(&bytes).get(&2)
+        );
+    }
+
+    #[test]
+    fn test_struct_element_langs() {
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /StructTreeRoot 4 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+            b"<< /Type /StructTreeRoot /K [5 0 R << /Type /StructElem /S /Note /K 6 0 R >>] >>",
+            b"<< /Type /StructElem /S /P /P 4 0 R /K [0 << /Type /MCR /MCID 1 >> 7 0 R] >>",
+            b"<< /Type /StructElem /S /Span /K 2 >>",
+            b"<< /Type /StructElem /S /Quote /P 5 0 R /K 3 /Lang (en) >>",
+        ]);
+
+        assert_eq!(struct_element_object_number(&bytes, &[0]), Some(5));
+        assert_eq!(struct_element_object_number(&bytes, &[0, 2]), Some(7));
+        assert_eq!(struct_element_object_number(&bytes, &[1, 0]), Some(6));
+
+        // Marked content, direct objects, and missing kids cannot be located.
+
+        assert_eq!(struct_element_object_number(&bytes, &[0, 0]), None);
+        assert_eq!(struct_element_object_number(&bytes, &[1]), None);
+        assert_eq!(struct_element_object_number(&bytes, &[0, 3]), None);
+
+        let mut langs = BTreeMap::new();
+
+        langs.insert(7, "de-CH".to_owned());
+
+        let updated = append_struct_element_langs(&bytes, &langs).unwrap();
+
+        assert!(updated.starts_with(&bytes));
+
+        let objects = find_object_offsets(&updated);
+
+        let quote = object_definition(&updated, &objects, 7).unwrap();
+
+        assert_eq!(
+            find_string_after_key(quote, b"/Lang").as_deref(),
+            Some("de-CH")
+        );
+    }
+
+    #[test]
+    fn test_parse_number_at() {
+        assert_eq!(parse_number_at(b" 72", 0), Some(72.0));
+        assert_eq!(parse_number_at(b"-.5 ", 0), Some(-0.5));
+        assert_eq!(parse_number_at(b"+4.", 0), Some(4.0));
+        assert_eq!(parse_number_at(b"2.5/Next", 0), Some(2.5));
+        assert_eq!(parse_number_at(b"+-.", 0), None);
+        assert_eq!(parse_number_at(b"-", 0), None);
+        assert_eq!(parse_number_at(b"1.2.3", 0), None);
+        assert_eq!(parse_number_at(b"1-2", 0), None);
+        assert_eq!(parse_number_at(b"1e5", 0), None);
+    }
+
+    #[test]
+    fn test_rich_media_assets() {
+        let bytes = rebuild_cross_reference_table(
+            b"%PDF-1.7\n\
+            1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+            2 0 obj\n<< /Type /Pages /Kids [3 0 R] /Count 1 >>\nendobj\n\
+            3 0 obj\n<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R 5 0 R] >>\nendobj\n\
+            4 0 obj\n<< /Type /Annot /Subtype /Text /Rect [0 0 10 10] >>\nendobj\n\
+            5 0 obj\n<< /Type /Annot /Subtype /RichMedia /Rect [100 100 300 250] \
+            /RichMediaContent << /Assets 6 0 R /Configurations [<< /Subtype /Video \
+            /Instances [<< /Asset 7 0 R >>] >>] >> >>\nendobj\n\
+            6 0 obj\n<< /Names [(clip.mp4) 7 0 R (poster.txt) 9 0 R] >>\nendobj\n\
+            7 0 obj\n<< /Type /Filespec /F (clip.mp4) /UF (clip.mp4) /EF << /F 8 0 R >> >>\nendobj\n\
+            8 0 obj\n<< /Type /EmbeddedFile /Subtype /video#2Fmp4 /Length 24 >>\nstream\n\
+            \x00\x00\x00\x18ftypmp42\x00\x00\x00\x00mp42isom\nendstream\nendobj\n\
+            9 0 obj\n<< /Type /Filespec /F (poster.txt) /EF << /F 10 0 R >> >>\nendobj\n\
+            10 0 obj\n<< /Type /EmbeddedFile /Filter [/ASCIIHexDecode] /Length 11 0 R >>\nstream\n\
+            4869>\nendstream\nendobj\n\
+            11 0 obj\n5\nendobj\n",
+        )
+        .unwrap();
+
+        let annotations = rich_media_assets(&bytes, 0).unwrap();
+
+        assert_eq!(annotations.len(), 2);
+        assert!(annotations[0].is_none());
+
+        let assets = annotations[1].as_ref().unwrap();
+
+        assert_eq!(assets.len(), 2);
+
+        assert_eq!(assets[0].name, "clip.mp4");
+        assert_eq!(assets[0].mime_type.as_deref(), Some("video/mp4"));
+        assert!(assets[0].filters.is_empty());
+        assert_eq!(
+            assets[0].data,
+            b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00mp42isom"
+        );
+
+        assert_eq!(assets[1].name, "poster.txt");
+        assert_eq!(assets[1].mime_type, None);
+        assert_eq!(assets[1].filters, ["ASCIIHexDecode".to_string()]);
+        assert_eq!(assets[1].data, b"4869>");
+    }
+
+    #[test]
+    fn test_rich_media_assets_with_indirect_annotations_array() {
+        // The /Annots array is an indirect object containing a null entry, an inline
+        // annotation dictionary, and a reference to the RichMedia annotation. Each entry
+        // must keep its position in the array.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots 4 0 R >>",
+            b"[null << /Type /Annot /Subtype /Square /Rect [0 0 10 10] >> 5 0 R]",
+            b"<< /Type /Annot /Subtype /RichMedia /Rect [100 100 300 250] \
+            /RichMediaContent << /Assets << /Names [(clip.mp4) 6 0 R] >> >> >>",
+            b"<< /Type /Filespec /F (clip.mp4) /EF << /F 7 0 R >> >>",
+            b"<< /Type /EmbeddedFile /Subtype /video#2Fmp4 >>\nstream\nmp4",
+        ]);
+
+        let annotations = rich_media_assets(&bytes, 0).unwrap();
+
+        assert_eq!(annotations.len(), 3);
+        assert!(annotations[0].is_none());
+        assert!(annotations[1].is_none());
+
+        let assets = annotations[2].as_ref().unwrap();
+
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].name, "clip.mp4");
+        assert_eq!(assets[0].data, b"mp4");
+    }
+
+    #[test]
+    fn test_usage_rights() {
+        let without = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+        ]);
+
+        assert!(usage_rights(&without).is_none());
+
+        // An obsolete /UR signature whose signature reference dictionary is
+        // an indirect object.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /Perms 3 0 R >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+            b"<< /UR 4 0 R >>",
+            b"<< /Type /Sig /Reference [5 0 R] /Contents <00> >>",
+            b"<< /Type /SigRef /TransformMethod /UR /TransformParams << /Type /TransformParams \
+            /Form /FillIn /EF [/Create /Import] /Msg (Reader-enabled) /P true >> >>",
+        ]);
+
+        let rights = usage_rights(&bytes).unwrap();
+
+        assert_eq!(rights.name, None);
+        assert_eq!(rights.message.as_deref(), Some("Reader-enabled"));
+        assert_eq!(rights.form, ["FillIn"]);
+        assert!(rights.document.is_empty());
+        assert!(rights.signature.is_empty());
+        assert_eq!(rights.embedded_files, ["Create", "Import"]);
+        assert!(rights.is_restrictive);
+    }
+
+    #[test]
+    fn test_document_actions() {
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /OpenAction << /S /JavaScript /JS (app.alert\\(1\\)) \
+            /Next [6 0 R] >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R 5 0 R] \
+            /AA << /O << /S /Named /N /NextPage >> >> >>",
+            b"<< /Type /Annot /Subtype /Link /Rect [0 0 10 10] /A << /S /URI /URI (https://a) >> \
+            >>",
+            b"<< /Type /Annot /Subtype /Widget /Rect [0 0 10 10] /AA << /Fo << /S /SubmitForm \
+            /F (https://b) >> >> >>",
+            b"<< /S /Launch /F (calc.exe) /Next 6 0 R >>",
+        ]);
+
+        let mut actions = document_actions(&bytes)
+            .unwrap()
+            .into_iter()
+            .map(|action| {
+                (
+                    action.page_index,
+                    action.action_type,
+                    action.is_exposed_by_pdfium,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        actions.sort();
+
+        assert_eq!(
+            actions,
+            [
+                (None, "JavaScript".to_string(), false),
+                (None, "Launch".to_string(), false),
+                (Some(0), "Named".to_string(), false),
+                (Some(0), "SubmitForm".to_string(), false),
+                (Some(0), "URI".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_actions_ignore_definitions_inside_streams() {
+        // Object 4 is an embedded document whose own catalog, if it were mistaken for
+        // object 1, would hide the open action of the real catalog.
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /OpenAction << /S /JavaScript /JS (app.alert\\(1\\)) \
+            >> >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+            b"<< /Type /Filespec /F (embedded.pdf) /EF << /F 4 0 R >> >>",
+            b"<< /Type /EmbeddedFile >>\nstream\n%PDF-1.7\n\
+            1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n",
+        ]);
+
+        let objects = find_object_offsets(&bytes);
+
+        assert_eq!(objects.len(), 4);
+        assert_ne!(scan_object_offsets(&bytes).get(&1), objects.get(&1));
+
+        let actions = document_actions(&bytes).unwrap();
+
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action_type, "JavaScript");
+
+        // A cross-reference entry that does not point to the object it describes is ignored.
+
+        let position = find_last(&bytes, b"0000000009 00000 n").unwrap();
+
+        let mut shifted = bytes.clone();
+
+        shifted[position + 9] = b'8';
+
+        assert!(!find_object_offsets(&shifted).contains_key(&1));
+        assert!(document_actions(&shifted).is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    fn test_image_streams() {
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+            b"<< /Subtype /Image /Width 1 /Height 1 /SMask 4 0 R >>\nstream\nAAA",
+            b"<< /Subtype /Image /Width 1 /Height 1 >>\nstream\nB",
+            b"<< /Subtype /Image /ImageMask true /Width 1 /Height 1 >>\nstream\nC",
+            b"<< /Subtype /Image /ImageMask false /Decode [0 1] >>\nstream\nD",
+            b"<< /Subtype /Form /BBox [0 0 1 1] /Mask 4 0 R >>\nstream\nE",
+        ]);
+
+        assert_eq!(
+            image_streams(&bytes),
+            [
+                (b"AAA".as_slice(), true),
+                (b"B".as_slice(), false),
+                (b"C".as_slice(), true),
+                (b"D".as_slice(), false),
+            ]
+        );
+    }
+}
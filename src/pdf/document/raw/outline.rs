@@ -0,0 +1,272 @@
+//! Functions that write bookmarks to the outline of a serialized document.
+//!
+//! Pdfium can read a document's outline but cannot add bookmarks to it, so new bookmarks are
+//! written as an incremental update appended to the serialized document data.
+
+use crate::pdf::document::raw::{
+    append_incremental_update, encode_text_string, find_integer_after_key, find_object_offsets,
+    find_reference_after_key, insert_into_dictionary, last_trailer, object_definition,
+    page_references,
+};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// A single bookmark to be written to a document's outline by the [append_outline()] function.
+#[derive(Clone)]
+pub(crate) struct PdfOutlineEntry {
+    /// The title displayed for the bookmark.
+    pub(crate) title: String,
+
+    /// The index, within the list of entries being written, of this bookmark's parent,
+    /// or `None` if this is a top-level bookmark.
+    pub(crate) parent: Option<usize>,
+
+    /// The zero-based index of the page targeted by this bookmark.
+    pub(crate) page_index: usize,
+
+    /// The destination view type and parameters, for example `/Fit` or `/XYZ 0 792 null`.
+    pub(crate) view: String,
+}
+
+/// Appends an incremental update to the given document data that adds the given bookmarks to
+/// the document's outline. New top-level bookmarks are placed after any existing top-level
+/// bookmarks; the parent of every other new bookmark must precede it in the given list.
+///
+/// The existing document data is left untouched, so the original byte offsets of all objects
+/// remain valid. Returns `None` if the document catalog, page tree, or existing outline
+/// could not be located, if a bookmark targets a page that does not exist, or if the document
+/// appears to be encrypted.
+pub(crate) fn append_outline(bytes: &[u8], entries: &[PdfOutlineEntry]) -> Option<Vec<u8>> {
+    let objects = find_object_offsets(bytes);
+
+    let trailer = last_trailer(bytes, &objects)?;
+
+    if trailer.is_encrypted {
+        // Strings in the outline of an encrypted document must themselves be encrypted.
+
+        return None;
+    }
+
+    let root = trailer.root;
+
+    let catalog = object_definition(bytes, &objects, root.0)?;
+
+    let pages = page_references(
+        bytes,
+        &objects,
+        find_reference_after_key(catalog, b"/Pages")?,
+    )?;
+
+    let mut next_number = trailer.size;
+
+    let mut allocate = || {
+        next_number += 1;
+
+        next_number - 1
+    };
+
+    let entry_numbers = entries.iter().map(|_| allocate()).collect::<Vec<_>>();
+
+    // Locate the existing outline dictionary, if any, so the new bookmarks can be appended to it.
+
+    let existing_outline = find_reference_after_key(catalog, b"/Outlines");
+
+    let (outline_number, outline_generation, existing_first, existing_last, existing_count) =
+        match existing_outline {
+            Some((number, generation)) => {
+                let outline = object_definition(bytes, &objects, number)?;
+
+                (
+                    number,
+                    generation,
+                    find_reference_after_key(outline, b"/First"),
+                    find_reference_after_key(outline, b"/Last"),
+                    find_integer_after_key(outline, b"/Count").unwrap_or(0),
+                )
+            }
+            None => (allocate(), 0, None, None, 0),
+        };
+
+    // Group the new bookmarks by parent. `None` represents the outline dictionary itself.
+
+    let mut children: BTreeMap<Option<usize>, Vec<usize>> = BTreeMap::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        if matches!(entry.parent, Some(parent) if parent >= index) {
+            return None;
+        }
+
+        children.entry(entry.parent).or_default().push(index);
+    }
+
+    let reference = |index: usize| format!("{} 0 R", entry_numbers[index]);
+
+    let mut updated = Vec::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        let (page_number, page_generation) = *pages.get(entry.page_index)?;
+
+        let mut dictionary = format!(
+            "<< /Title {} /Parent {} /Dest [{} {} R {}]",
+            encode_text_string(&entry.title),
+            match entry.parent {
+                Some(parent) => reference(parent),
+                None => format!("{} {} R", outline_number, outline_generation),
+            },
+            page_number,
+            page_generation,
+            entry.view,
+        );
+
+        let siblings = &children[&entry.parent];
+
+        let position = siblings.iter().position(|sibling| *sibling == index)?;
+
+        if position > 0 {
+            dictionary.push_str(&format!(" /Prev {}", reference(siblings[position - 1])));
+        } else if entry.parent.is_none() {
+            if let Some((number, generation)) = existing_last {
+                dictionary.push_str(&format!(" /Prev {} {} R", number, generation));
+            }
+        }
+
+        if let Some(next) = siblings.get(position + 1) {
+            dictionary.push_str(&format!(" /Next {}", reference(*next)));
+        }
+
+        if let Some(own_children) = children.get(&Some(index)) {
+            // All new bookmarks are created open, so every descendant is visible.
+
+            dictionary.push_str(&format!(
+                " /First {} /Last {} /Count {}",
+                reference(own_children[0]),
+                reference(own_children[own_children.len() - 1]),
+                count_descendants(&children, index),
+            ));
+        }
+
+        dictionary.push_str(" >>");
+
+        updated.push((entry_numbers[index], 0, dictionary));
+    }
+
+    if let Some(top_level) = children.get(&None) {
+        let first = existing_first
+            .map(|(number, generation)| format!("{} {} R", number, generation))
+            .unwrap_or_else(|| reference(top_level[0]));
+
+        updated.push((
+            outline_number,
+            outline_generation,
+            format!(
+                "<< /Type /Outlines /First {} /Last {} /Count {} >>",
+                first,
+                reference(top_level[top_level.len() - 1]),
+                existing_count + u32::try_from(entries.len()).ok()?,
+            ),
+        ));
+
+        if let Some((number, generation)) = existing_last {
+            // Link the previous last top-level bookmark to the first new top-level bookmark.
+
+            let previous = object_definition(bytes, &objects, number)?;
+
+            updated.push((
+                number,
+                generation,
+                insert_into_dictionary(previous, &format!("/Next {}", reference(top_level[0])))?,
+            ));
+        }
+    }
+
+    if existing_outline.is_none() {
+        updated.push((
+            root.0,
+            root.1,
+            insert_into_dictionary(catalog, &format!("/Outlines {} 0 R", outline_number))?,
+        ));
+    }
+
+    append_incremental_update(bytes, &trailer, updated, next_number, &trailer.entries())
+}
+
+/// Returns the total number of descendants of the entry at the given index.
+fn count_descendants(children: &BTreeMap<Option<usize>, Vec<usize>>, index: usize) -> usize {
+    children
+        .get(&Some(index))
+        .map(|own_children| {
+            own_children
+                .iter()
+                .map(|child| 1 + count_descendants(children, *child))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf::document::raw::find_integer_after_last;
+    use crate::utils::test::test_document;
+
+    #[test]
+    fn test_append_outline() {
+        let repaired = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+        ]);
+
+        let entries = [
+            PdfOutlineEntry {
+                title: "Chapter".to_string(),
+                parent: None,
+                page_index: 0,
+                view: "/Fit".to_string(),
+            },
+            PdfOutlineEntry {
+                title: "Section".to_string(),
+                parent: Some(0),
+                page_index: 0,
+                view: "/XYZ 0 792 null".to_string(),
+            },
+        ];
+
+        let updated = append_outline(&repaired, &entries).unwrap();
+
+        assert!(updated.starts_with(&repaired));
+
+        let appended = std::str::from_utf8(&updated[repaired.len()..]).unwrap();
+
+        assert!(appended.contains("4 0 obj\n<< /Title <FEFF0043006800610070007400650072> /Parent 6 0 R /Dest [3 0 R /Fit] /First 5 0 R /Last 5 0 R /Count 1 >>"));
+        assert!(appended.contains("/Parent 4 0 R /Dest [3 0 R /XYZ 0 792 null] >>"));
+        assert!(
+            appended.contains("6 0 obj\n<< /Type /Outlines /First 4 0 R /Last 4 0 R /Count 2 >>")
+        );
+        assert!(appended.contains("1 0 obj\n<< /Type /Catalog /Pages 2 0 R /Outlines 6 0 R >>"));
+        assert!(appended.contains(&format!(
+            "<< /Size 7 /Root 1 0 R /Prev {} >>",
+            find_integer_after_last(&repaired, b"startxref").unwrap()
+        )));
+
+        // Appending to the now-existing outline should link the new bookmark after the first.
+
+        let appended_again = append_outline(&updated, &entries[..1]).unwrap();
+
+        let appended_again = std::str::from_utf8(&appended_again[updated.len()..]).unwrap();
+
+        assert!(appended_again.contains("/Parent 6 0 R /Dest [3 0 R /Fit] /Prev 4 0 R >>"));
+        assert!(appended_again
+            .contains("6 0 obj\n<< /Type /Outlines /First 4 0 R /Last 7 0 R /Count 3 >>"));
+        assert!(appended_again.contains("/Last 5 0 R /Count 1 /Next 7 0 R >>"));
+
+        assert!(append_outline(
+            &repaired,
+            &[PdfOutlineEntry {
+                page_index: 1,
+                ..entries[0].clone()
+            }]
+        )
+        .is_none());
+    }
+}
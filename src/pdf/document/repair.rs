@@ -0,0 +1,26 @@
+//! Defines the [PdfDocumentRepair] enum, indicating whether a [PdfDocument] loaded by the
+//! [Pdfium::load_pdf_from_file_lenient()] function needed to be repaired before it could be opened.
+
+#[cfg(doc)]
+use {crate::pdf::document::PdfDocument, crate::pdfium::Pdfium};
+
+/// Indicates whether a [PdfDocument] loaded by the [Pdfium::load_pdf_from_file_lenient()]
+/// function needed to be repaired before it could be opened.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfDocumentRepair {
+    /// The document was opened by Pdfium without any additional repair.
+    NotRequired,
+
+    /// Pdfium could not open the document as given. The cross-reference table of the
+    /// document was rebuilt by rescanning the document for object offsets, after which
+    /// Pdfium was able to open it.
+    CrossReferenceTableRebuilt,
+}
+
+impl PdfDocumentRepair {
+    /// Returns `true` if the document needed to be repaired before it could be opened.
+    #[inline]
+    pub fn was_repaired(&self) -> bool {
+        *self != PdfDocumentRepair::NotRequired
+    }
+}
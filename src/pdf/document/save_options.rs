@@ -0,0 +1,86 @@
+//! Defines the [PdfSaveOptions] struct, a builder-based approach to configuring
+//! how a `PdfDocument` is serialized when it is saved.
+
+use crate::bindgen::{FPDF_DWORD, FPDF_INCREMENTAL, FPDF_REMOVE_SECURITY};
+use crate::pdf::document::PdfDocumentVersion;
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// Configures how a [PdfDocument] is serialized by the [PdfDocument::save_to_bytes_with_options()]
+/// and [PdfDocument::save_to_writer_with_options()] functions.
+///
+/// The default options match the behaviour of [PdfDocument::save_to_bytes()]: the document
+/// is written in full, using the version set by [PdfDocument::set_version()] if any.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PdfSaveOptions {
+    version: Option<PdfDocumentVersion>,
+    is_incremental: bool,
+    do_remove_security: bool,
+}
+
+impl PdfSaveOptions {
+    /// Creates a new [PdfSaveOptions] object with all settings initialized with their default values.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the PDF file version that should be written to the document header,
+    /// overriding any version set by [PdfDocument::set_version()].
+    #[inline]
+    pub fn set_version(mut self, version: PdfDocumentVersion) -> Self {
+        self.version = Some(version);
+
+        self
+    }
+
+    /// Controls whether changes to the document should be appended to the original document
+    /// data as an incremental update, rather than rewriting the whole document.
+    /// Incremental saving preserves existing digital signatures. Default: `false`.
+    #[inline]
+    pub fn set_incremental(mut self, is_incremental: bool) -> Self {
+        self.is_incremental = is_incremental;
+
+        self
+    }
+
+    /// Controls whether any encryption should be removed from the saved document.
+    /// A document cannot be saved incrementally with its security removed, so setting this
+    /// option overrides [PdfSaveOptions::set_incremental()]. Default: `false`.
+    #[inline]
+    pub fn set_remove_security(mut self, do_remove_security: bool) -> Self {
+        self.do_remove_security = do_remove_security;
+
+        self
+    }
+
+    /// Returns the PDF file version that will be written to the document header, if any.
+    #[inline]
+    pub fn version(&self) -> Option<PdfDocumentVersion> {
+        self.version
+    }
+
+    /// Returns `true` if the document will be saved as an incremental update.
+    #[inline]
+    pub fn is_incremental(&self) -> bool {
+        self.is_incremental && !self.do_remove_security
+    }
+
+    /// Returns `true` if any encryption will be removed from the saved document.
+    #[inline]
+    pub fn is_remove_security(&self) -> bool {
+        self.do_remove_security
+    }
+
+    /// Returns the Pdfium save flags that correspond to these options.
+    pub(crate) fn as_pdfium_flags(&self) -> FPDF_DWORD {
+        if self.do_remove_security {
+            FPDF_REMOVE_SECURITY as FPDF_DWORD
+        } else if self.is_incremental {
+            FPDF_INCREMENTAL as FPDF_DWORD
+        } else {
+            0
+        }
+    }
+}
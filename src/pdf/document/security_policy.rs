@@ -0,0 +1,396 @@
+//! Defines the [PdfSecurityPolicy] struct, used to reject documents containing
+//! potentially dangerous content such as JavaScript or launch actions.
+
+use crate::pdf::action::PdfAction;
+use crate::pdf::document::page::field::{PdfFormFieldCommon, PdfFormFieldTrigger};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::raw::{document_actions, RawAction};
+use crate::pdf::document::PdfDocument;
+
+#[cfg(doc)]
+use crate::pdfium::Pdfium;
+
+/// A set of rules describing the kinds of potentially dangerous content a [PdfDocument]
+/// may contain. Use the [PdfDocument::security_policy_violations()] function to check
+/// an open document against a policy, or the [Pdfium::load_pdf_from_bytes_with_policy()]
+/// function to refuse to open documents that break a policy.
+///
+/// The default policy is [PdfSecurityPolicy::permissive()], which allows all content.
+/// The [PdfSecurityPolicy::strict()] policy forbids all content covered by the policy.
+///
+/// Content is checked using the information Pdfium exposes about a document:
+/// document-level JavaScript, form field scripts, the actions of link annotations
+/// and bookmarks, and embedded file attachments. Pdfium does not expose a document's
+/// open action, the additional actions of the document, its pages, and its annotations,
+/// or the actions of annotations other than links, so the document is also serialized in
+/// memory and these actions are read from the serialized data. Actions of a type that
+/// Pdfium cannot interpret, such as JavaScript, form submission, and named actions on
+/// links and bookmarks, are classified using the serialized data; if the document could not
+/// be serialized, they are reported as [PdfSecurityPolicyViolation::UnsupportedAction].
+///
+/// If the actions could not be read from the serialized data, for instance because they are
+/// stored in compressed object streams, any policy that restricts actions reports
+/// [PdfSecurityPolicyViolation::UnverifiableActions] rather than treating the document as clean.
+/// Even so, a policy check is a first line of defence, not a guarantee that a document is safe.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PdfSecurityPolicy {
+    allow_javascript: bool,
+    allow_launch_actions: bool,
+    allow_external_references: bool,
+    allow_embedded_files: bool,
+    allow_unsupported_actions: bool,
+}
+
+impl PdfSecurityPolicy {
+    /// Creates a new [PdfSecurityPolicy] that allows all content.
+    #[inline]
+    pub const fn permissive() -> Self {
+        PdfSecurityPolicy {
+            allow_javascript: true,
+            allow_launch_actions: true,
+            allow_external_references: true,
+            allow_embedded_files: true,
+            allow_unsupported_actions: true,
+        }
+    }
+
+    /// Creates a new [PdfSecurityPolicy] that forbids JavaScript, launch actions,
+    /// external references, embedded files, and actions of unrecognized types.
+    #[inline]
+    pub const fn strict() -> Self {
+        PdfSecurityPolicy {
+            allow_javascript: false,
+            allow_launch_actions: false,
+            allow_external_references: false,
+            allow_embedded_files: false,
+            allow_unsupported_actions: false,
+        }
+    }
+
+    /// Controls whether document-level JavaScript and JavaScript form field actions
+    /// are allowed.
+    #[inline]
+    pub fn allow_javascript(mut self, allow: bool) -> Self {
+        self.allow_javascript = allow;
+
+        self
+    }
+
+    /// Controls whether actions that launch an application or open a file are allowed.
+    #[inline]
+    pub fn allow_launch_actions(mut self, allow: bool) -> Self {
+        self.allow_launch_actions = allow;
+
+        self
+    }
+
+    /// Controls whether actions that refer to content outside the document, such as
+    /// URIs, destinations in other documents, and form submissions, are allowed.
+    #[inline]
+    pub fn allow_external_references(mut self, allow: bool) -> Self {
+        self.allow_external_references = allow;
+
+        self
+    }
+
+    /// Controls whether embedded file attachments, and actions that navigate to
+    /// embedded documents, are allowed.
+    #[inline]
+    pub fn allow_embedded_files(mut self, allow: bool) -> Self {
+        self.allow_embedded_files = allow;
+
+        self
+    }
+
+    /// Controls whether actions of any type not covered by the other rules of this policy,
+    /// other than navigation within the document, are allowed. This includes named actions,
+    /// multimedia actions, and actions that show or hide annotations. It also includes
+    /// actions that Pdfium cannot interpret, when the document could not be serialized
+    /// to determine their type.
+    #[inline]
+    pub fn allow_unsupported_actions(mut self, allow: bool) -> Self {
+        self.allow_unsupported_actions = allow;
+
+        self
+    }
+
+    /// Returns every way in which the given [PdfDocument] breaks this [PdfSecurityPolicy].
+    pub(crate) fn violations(&self, document: &PdfDocument) -> Vec<PdfSecurityPolicyViolation> {
+        let mut violations = Vec::new();
+
+        if !self.allow_javascript {
+            let bindings = document.bindings();
+
+            if bindings.FPDFDoc_GetJavaScriptActionCount(document.handle()) > 0 {
+                violations.push(PdfSecurityPolicyViolation::JavaScript { page_index: None });
+            }
+        }
+
+        if !self.allow_embedded_files {
+            for attachment in document.attachments().iter() {
+                violations.push(PdfSecurityPolicyViolation::EmbeddedFile {
+                    name: attachment.name(),
+                });
+            }
+        }
+
+        // Pdfium does not expose every action in a document, nor the type of every action
+        // it does expose, so the remaining actions are read from the serialized document.
+
+        let actions = document
+            .save_to_bytes()
+            .ok()
+            .and_then(|bytes| document_actions(bytes.as_slice()));
+
+        let is_classified = actions.is_some();
+
+        for bookmark in document.bookmarks().iter() {
+            if let Some(action) = bookmark.action() {
+                self.check_action(&action, None, is_classified, &mut violations);
+            }
+        }
+
+        for (index, page) in document.pages().iter().enumerate() {
+            let page_index = Some(index as PdfPageIndex);
+
+            for link in page.links().iter() {
+                if let Some(action) = link.action() {
+                    self.check_action(&action, page_index, is_classified, &mut violations);
+                }
+            }
+
+            if !self.allow_javascript {
+                for annotation in page.annotations().iter() {
+                    if let Some(field) = annotation.as_form_field() {
+                        if [
+                            PdfFormFieldTrigger::Keystroke,
+                            PdfFormFieldTrigger::Format,
+                            PdfFormFieldTrigger::Validate,
+                            PdfFormFieldTrigger::Calculate,
+                        ]
+                        .iter()
+                        .any(|trigger| field.action_script(*trigger).is_some())
+                        {
+                            violations.push(PdfSecurityPolicyViolation::JavaScript { page_index });
+                        }
+                    }
+                }
+            }
+        }
+
+        match actions {
+            Some(actions) => {
+                for action in actions {
+                    self.check_raw_action(&action, &mut violations);
+                }
+            }
+            None => {
+                if self.restricts_actions() {
+                    violations.push(PdfSecurityPolicyViolation::UnverifiableActions);
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// Returns `true` if this [PdfSecurityPolicy] forbids any kind of action.
+    #[inline]
+    fn restricts_actions(&self) -> bool {
+        !(self.allow_javascript
+            && self.allow_launch_actions
+            && self.allow_external_references
+            && self.allow_embedded_files
+            && self.allow_unsupported_actions)
+    }
+
+    /// Checks an action exposed by Pdfium. If `is_classified` is `true`, actions of types
+    /// Pdfium cannot interpret will be checked by [PdfSecurityPolicy::check_raw_action()]
+    /// instead.
+    fn check_action(
+        &self,
+        action: &PdfAction,
+        page_index: Option<PdfPageIndex>,
+        is_classified: bool,
+        violations: &mut Vec<PdfSecurityPolicyViolation>,
+    ) {
+        match action {
+            PdfAction::Launch(_) if !self.allow_launch_actions => {
+                violations.push(PdfSecurityPolicyViolation::LaunchAction { page_index })
+            }
+            PdfAction::RemoteDestination(_) | PdfAction::Uri(_)
+                if !self.allow_external_references =>
+            {
+                violations.push(PdfSecurityPolicyViolation::ExternalReference { page_index })
+            }
+            PdfAction::EmbeddedDestination(_) if !self.allow_embedded_files => {
+                violations.push(PdfSecurityPolicyViolation::EmbeddedReference { page_index })
+            }
+            PdfAction::Unsupported(_) if !is_classified && !self.allow_unsupported_actions => {
+                violations.push(PdfSecurityPolicyViolation::UnsupportedAction { page_index })
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks an action read from the serialized document.
+    fn check_raw_action(
+        &self,
+        action: &RawAction,
+        violations: &mut Vec<PdfSecurityPolicyViolation>,
+    ) {
+        let page_index = action.page_index.map(|index| index as PdfPageIndex);
+
+        let violation = match action.action_type.as_str() {
+            "GoTo" => None,
+            "GoToR" | "GoToE" | "Launch" | "URI" if action.is_exposed_by_pdfium => {
+                // Already checked by PdfSecurityPolicy::check_action().
+
+                None
+            }
+            "JavaScript" if !self.allow_javascript => {
+                Some(PdfSecurityPolicyViolation::JavaScript { page_index })
+            }
+            "Launch" if !self.allow_launch_actions => {
+                Some(PdfSecurityPolicyViolation::LaunchAction { page_index })
+            }
+            "URI" | "GoToR" | "SubmitForm" | "ImportData" if !self.allow_external_references => {
+                Some(PdfSecurityPolicyViolation::ExternalReference { page_index })
+            }
+            "GoToE" if !self.allow_embedded_files => {
+                Some(PdfSecurityPolicyViolation::EmbeddedReference { page_index })
+            }
+            "JavaScript" | "Launch" | "URI" | "GoToR" | "SubmitForm" | "ImportData" | "GoToE" => {
+                None
+            }
+            _ if !self.allow_unsupported_actions => {
+                Some(PdfSecurityPolicyViolation::UnsupportedAction { page_index })
+            }
+            _ => None,
+        };
+
+        if let Some(violation) = violation {
+            // Form field scripts are also reported by Pdfium, so avoid reporting them twice.
+
+            if !violations.contains(&violation) {
+                violations.push(violation);
+            }
+        }
+    }
+}
+
+impl Default for PdfSecurityPolicy {
+    #[inline]
+    fn default() -> Self {
+        PdfSecurityPolicy::permissive()
+    }
+}
+
+/// A single way in which a [PdfDocument] breaks a [PdfSecurityPolicy].
+///
+/// Where a violation was found on a page, the zero-based index of the page is given;
+/// violations found in document-level content, such as bookmarks, have no page index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfSecurityPolicyViolation {
+    /// The document contains document-level JavaScript, or an action that runs JavaScript.
+    JavaScript { page_index: Option<PdfPageIndex> },
+
+    /// An action launches an application or opens a file.
+    LaunchAction { page_index: Option<PdfPageIndex> },
+
+    /// An action refers to a URI or to a destination in another document, or submits
+    /// or imports form data.
+    ExternalReference { page_index: Option<PdfPageIndex> },
+
+    /// An action navigates to a destination in an embedded document.
+    EmbeddedReference { page_index: Option<PdfPageIndex> },
+
+    /// An action is of a type not covered by the other rules of the policy, such as
+    /// a named action, or is of a type that could not be determined.
+    UnsupportedAction { page_index: Option<PdfPageIndex> },
+
+    /// The document contains an embedded file attachment with the given name.
+    EmbeddedFile { name: String },
+
+    /// The document's open action and additional actions, and the actions of its
+    /// annotations, could not be read from the serialized document, so they could not be
+    /// checked against the policy. This is reported by any policy that forbids some kind
+    /// of action.
+    UnverifiableActions,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_launch_action_rejected_by_strict_policy() {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R] >>",
+            b"<< /Type /Annot /Subtype /Link /Rect [100 700 300 720] /A << /S /Launch /F \
+            (calc.exe) >> >>",
+        ]);
+
+        match pdfium.load_pdf_from_bytes_with_policy(
+            bytes.clone(),
+            None,
+            PdfSecurityPolicy::strict(),
+        ) {
+            Err(PdfiumError::SecurityPolicyViolated(violations)) => assert_eq!(
+                violations,
+                vec![PdfSecurityPolicyViolation::LaunchAction {
+                    page_index: Some(0)
+                }]
+            ),
+            _ => panic!("expected the document to be rejected"),
+        }
+
+        assert!(pdfium
+            .load_pdf_from_bytes_with_policy(
+                bytes,
+                None,
+                PdfSecurityPolicy::strict().allow_launch_actions(true),
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_javascript_link_rejected_by_strict_policy() {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Annots [4 0 R] >>",
+            b"<< /Type /Annot /Subtype /Link /Rect [100 700 300 720] /A << /S /JavaScript /JS \
+            (app.alert\\(1\\)) >> >>",
+        ]);
+
+        match pdfium.load_pdf_from_bytes_with_policy(
+            bytes.clone(),
+            None,
+            PdfSecurityPolicy::strict(),
+        ) {
+            Err(PdfiumError::SecurityPolicyViolated(violations)) => assert_eq!(
+                violations,
+                vec![PdfSecurityPolicyViolation::JavaScript {
+                    page_index: Some(0)
+                }]
+            ),
+            _ => panic!("expected the document to be rejected"),
+        }
+
+        assert!(pdfium
+            .load_pdf_from_bytes_with_policy(
+                bytes,
+                None,
+                PdfSecurityPolicy::strict().allow_javascript(true),
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,146 @@
+//! Defines the [PdfUsageRights] struct, exposing the additional rights granted to users of
+//! Adobe Reader by a usage rights signature in a single `PdfDocument`.
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// The additional rights granted by a usage rights signature in the `/Perms` dictionary
+/// of the document catalog of a single [PdfDocument].
+///
+/// Documents saved with "Reader extensions" enabled, often called Reader-enabled documents,
+/// carry a usage rights signature, conventionally stored under the `/UR3` key of the `/Perms`
+/// dictionary. The signature's transform parameters list the features that Adobe Reader
+/// should enable for the document, such as filling in form fields or creating annotations,
+/// as described in section 12.8.2.2 of the PDF Reference Manual, version 1.7.
+///
+/// Each right is reported as the PDF name used in the transform parameters dictionary,
+/// without its leading `/`; for instance, the right to fill in form fields is reported
+/// as `FillIn` in the list returned by [PdfUsageRights::form_rights()].
+///
+/// Note that `pdfium-render` does not validate the usage rights signature itself.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PdfUsageRights {
+    signer_name: Option<String>,
+    message: Option<String>,
+    document_rights: Vec<String>,
+    form_rights: Vec<String>,
+    annotation_rights: Vec<String>,
+    signature_rights: Vec<String>,
+    embedded_file_rights: Vec<String>,
+    is_restrictive: bool,
+}
+
+impl PdfUsageRights {
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub(crate) fn new(
+        signer_name: Option<String>,
+        message: Option<String>,
+        document_rights: Vec<String>,
+        form_rights: Vec<String>,
+        annotation_rights: Vec<String>,
+        signature_rights: Vec<String>,
+        embedded_file_rights: Vec<String>,
+        is_restrictive: bool,
+    ) -> Self {
+        PdfUsageRights {
+            signer_name,
+            message,
+            document_rights,
+            form_rights,
+            annotation_rights,
+            signature_rights,
+            embedded_file_rights,
+            is_restrictive,
+        }
+    }
+
+    /// Returns the name of the person or authority that applied the usage rights signature,
+    /// taken from the `/Name` entry of the signature dictionary, if any.
+    #[inline]
+    pub fn signer_name(&self) -> Option<&str> {
+        self.signer_name.as_deref()
+    }
+
+    /// Returns the message that a PDF viewer should display when the document is opened,
+    /// taken from the `/Msg` entry of the transform parameters dictionary, if any.
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the rights granted over the document as a whole, such as `FullSave`,
+    /// taken from the `/Document` entry of the transform parameters dictionary.
+    #[inline]
+    pub fn document_rights(&self) -> &[String] {
+        self.document_rights.as_slice()
+    }
+
+    /// Returns the rights granted over the document's interactive form fields, such as
+    /// `FillIn`, `Import`, `Export`, or `SubmitStandalone`, taken from the `/Form` entry
+    /// of the transform parameters dictionary.
+    #[inline]
+    pub fn form_rights(&self) -> &[String] {
+        self.form_rights.as_slice()
+    }
+
+    /// Returns the rights granted over the document's annotations, such as `Create`,
+    /// `Delete`, or `Modify`, taken from the `/Annots` entry of the transform
+    /// parameters dictionary.
+    #[inline]
+    pub fn annotation_rights(&self) -> &[String] {
+        self.annotation_rights.as_slice()
+    }
+
+    /// Returns the rights granted over the document's signature fields, such as `Modify`,
+    /// taken from the `/Signature` entry of the transform parameters dictionary.
+    #[inline]
+    pub fn signature_rights(&self) -> &[String] {
+        self.signature_rights.as_slice()
+    }
+
+    /// Returns the rights granted over the document's embedded files, such as `Create`
+    /// or `Import`, taken from the `/EF` entry of the transform parameters dictionary.
+    #[inline]
+    pub fn embedded_file_rights(&self) -> &[String] {
+        self.embedded_file_rights.as_slice()
+    }
+
+    /// Returns `true` if the `/P` entry of the transform parameters dictionary is set,
+    /// indicating that a PDF viewer other than Adobe Reader should restrict its features
+    /// to those granted by this usage rights signature.
+    #[inline]
+    pub fn is_restrictive(&self) -> bool {
+        self.is_restrictive
+    }
+
+    /// Returns `true` if these usage rights allow the document to be saved in full,
+    /// including any changes made to it.
+    #[inline]
+    pub fn can_save(&self) -> bool {
+        Self::grants(&self.document_rights, "FullSave")
+    }
+
+    /// Returns `true` if these usage rights allow the values of form fields to be filled in.
+    #[inline]
+    pub fn can_fill_in_forms(&self) -> bool {
+        Self::grants(&self.form_rights, "FillIn")
+    }
+
+    /// Returns `true` if these usage rights allow new annotations to be created.
+    #[inline]
+    pub fn can_create_annotations(&self) -> bool {
+        Self::grants(&self.annotation_rights, "Create")
+    }
+
+    /// Returns `true` if these usage rights allow signature fields to be signed.
+    #[inline]
+    pub fn can_sign(&self) -> bool {
+        Self::grants(&self.signature_rights, "Modify")
+    }
+
+    #[inline]
+    fn grants(rights: &[String], right: &str) -> bool {
+        rights.iter().any(|granted| granted == right)
+    }
+}
@@ -3,6 +3,7 @@
 
 pub mod glyph;
 pub mod glyphs;
+pub(crate) mod substitution;
 
 use crate::bindgen::{FPDF_FONT, FPDF_FONT_TRUETYPE, FPDF_FONT_TYPE1};
 use crate::bindings::PdfiumLibraryBindings;
@@ -42,15 +43,27 @@ use web_sys::{window, Blob, Response};
 struct Blob;
 
 bitflags! {
-    pub(crate) struct FpdfFontDescriptorFlags: u32 {
+    /// The font descriptor flags of a [PdfFont], as defined in section 9.8.2 of
+    /// The PDF Reference Manual, version 1.7.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct FpdfFontDescriptorFlags: u32 {
+        /// All glyphs have the same width.
         const FIXED_PITCH_BIT_1 =  0b00000000000000000000000000000001;
+        /// Glyphs have serifs.
         const SERIF_BIT_2 =        0b00000000000000000000000000000010;
+        /// The font contains glyphs outside the Adobe standard Latin character set.
         const SYMBOLIC_BIT_3 =     0b00000000000000000000000000000100;
+        /// Glyphs resemble cursive handwriting.
         const SCRIPT_BIT_4 =       0b00000000000000000000000000001000;
+        /// The font uses the Adobe standard Latin character set or a subset of it.
         const NON_SYMBOLIC_BIT_6 = 0b00000000000000000000000000100000;
+        /// Glyphs have dominant vertical strokes that are slanted.
         const ITALIC_BIT_7 =       0b00000000000000000000000001000000;
+        /// The font contains no lowercase letters.
         const ALL_CAP_BIT_17 =     0b00000000000000010000000000000000;
+        /// Lowercase letters are drawn as small capitals.
         const SMALL_CAP_BIT_18 =   0b00000000000000100000000000000000;
+        /// Bold glyphs are painted with extra pixels at small font sizes.
         const FORCE_BOLD_BIT_19 =  0b00000000000001000000000000000000;
     }
 }
@@ -768,45 +781,63 @@ impl<'a> PdfFont<'a> {
         self.family()
     }
 
-    // TODO: AJRC - 4-Aug-2024 - FPDFFont_GetBaseFontName() is in Pdfium export headers
-    // but changes not yet released. Tracking issue: https://github.com/ajrcarey/pdfium-render/issues/152
-    /// Returns the name of this [PdfFont].
-    // pub fn name(&self) -> String {
-    //     // Retrieving the font name from Pdfium is a two-step operation. First, we call
-    //     // FPDFFont_GetBaseFontName() with a null buffer; this will retrieve the length of
-    //     // the font name in bytes. If the length is zero, then there is no font name.
+    /// Returns the base name of this [PdfFont]. This is the value of the font's `BaseFont` entry,
+    /// typically the font's PostScript name, such as `Helvetica-Bold` or a subset-tagged name
+    /// such as `ABCDEF+Calibri`.
+    ///
+    /// Pdfium only supports retrieving the base font name in Pdfium API versions 6666 and later.
+    /// When using earlier Pdfium API versions, this function returns the same value as
+    /// [PdfFont::family()].
+    pub fn base_name(&self) -> String {
+        #[cfg(any(
+            feature = "pdfium_future",
+            feature = "pdfium_6721",
+            feature = "pdfium_6666"
+        ))]
+        {
+            // Retrieving the font name from Pdfium is a two-step operation. First, we call
+            // FPDFFont_GetBaseFontName() with a null buffer; this will retrieve the length of
+            // the font name in bytes. If the length is zero, then there is no font name.
 
-    //     // If the length is non-zero, then we reserve a byte buffer of the given
-    //     // length and call FPDFFont_GetBaseFontName() again with a pointer to the buffer;
-    //     // this will write the font name into the buffer. Unlike most text handling in
-    //     // Pdfium, font names are returned in UTF-8 format.
+            // If the length is non-zero, then we reserve a byte buffer of the given
+            // length and call FPDFFont_GetBaseFontName() again with a pointer to the buffer;
+            // this will write the font name into the buffer. Unlike most text handling in
+            // Pdfium, font names are returned in UTF-8 format.
 
-    //     let buffer_length =
-    //         self.bindings
-    //             .FPDFFont_GetBaseFontName(self.handle, std::ptr::null_mut(), 0);
+            let buffer_length =
+                self.bindings
+                    .FPDFFont_GetBaseFontName(self.handle, std::ptr::null_mut(), 0);
 
-    //     if buffer_length == 0 {
-    //         // The font name is not present.
+            if buffer_length == 0 {
+                // The font name is not present.
 
-    //         return String::new();
-    //     }
+                return String::new();
+            }
 
-    //     let mut buffer = create_byte_buffer(buffer_length as usize);
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings.FPDFFont_GetBaseFontName(
+                self.handle,
+                buffer.as_mut_ptr() as *mut c_char,
+                buffer_length,
+            );
 
-    //     let result = self.bindings.FPDFFont_GetBaseFontName(
-    //         self.handle,
-    //         buffer.as_mut_ptr() as *mut c_char,
-    //         buffer_length,
-    //     );
+            assert_eq!(result, buffer_length);
 
-    //     assert_eq!(result, buffer_length);
+            String::from_utf8(buffer)
+                // Trim any trailing nulls. All strings returned from Pdfium are generally terminated
+                // by one null byte.
+                .map(|str| str.trim_end_matches(char::from(0)).to_owned())
+                .unwrap_or_else(|_| String::new())
+        }
 
-    //     String::from_utf8(buffer)
-    //         // Trim any trailing nulls. All strings returned from Pdfium are generally terminated
-    //         // by one null byte.
-    //         .map(|str| str.trim_end_matches(char::from(0)).to_owned())
-    //         .unwrap_or_else(|_| String::new())
-    // }
+        #[cfg(not(any(
+            feature = "pdfium_future",
+            feature = "pdfium_6721",
+            feature = "pdfium_6666"
+        )))]
+        self.family()
+    }
 
     /// Returns the family of this [PdfFont].
     pub fn family(&self) -> String {
@@ -968,6 +999,52 @@ impl<'a> PdfFont<'a> {
         }
     }
 
+    /// Returns the total advance width of the given text when set in this [PdfFont] at the
+    /// given font size, without creating any page objects. This is the sum of the widths
+    /// of each character in the text; kerning, character spacing, and word spacing
+    /// are not applied.
+    ///
+    /// Characters are measured individually, so characters encoded as multiple bytes in UTF-8
+    /// are measured as a single glyph. Characters that this [PdfFont] does not cover are
+    /// measured using the font's default width, as given by its `/MissingWidth` or `/DW` entry.
+    ///
+    /// Each character is passed to Pdfium as a Unicode value that Pdfium narrows to a `wchar_t`.
+    /// On Windows, where `wchar_t` is 16 bits wide, characters outside the Basic Multilingual
+    /// Plane are therefore truncated and measured as whichever character the truncated value
+    /// happens to identify.
+    pub fn measure_text(&self, text: &str, font_size: PdfPoints) -> Result<PdfPoints, PdfiumError> {
+        text.chars()
+            .try_fold(PdfPoints::ZERO, |total, char| {
+                let mut width = 0.0;
+
+                // FPDFFont_GetGlyphWidth() maps the given Unicode value to a character code
+                // in this font before retrieving its width.
+
+                if self.bindings.is_true(self.bindings.FPDFFont_GetGlyphWidth(
+                    self.handle,
+                    char as c_uint,
+                    font_size.value,
+                    &mut width,
+                )) {
+                    Some(total + PdfPoints::new(width))
+                } else {
+                    None
+                }
+            })
+            .ok_or(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+    }
+
+    /// Returns the font descriptor flags of this [PdfFont]. The individual flags can also be
+    /// checked using functions such as [PdfFont::is_serif()] and [PdfFont::is_italic()].
+    ///
+    /// Pdfium may not reliably return the correct value of these flags for built-in fonts.
+    #[inline]
+    pub fn flags(&self) -> FpdfFontDescriptorFlags {
+        self.get_flags_bits()
+    }
+
     /// Returns the raw font descriptor bitflags for the containing [PdfFont].
     #[inline]
     fn get_flags_bits(&self) -> FpdfFontDescriptorFlags {
@@ -1170,6 +1247,20 @@ impl<'a> PdfFont<'a> {
         }
     }
 
+    /// Returns the raw bytes of the font program embedded in the containing [PdfDocument]
+    /// for this [PdfFont], or `None` if this [PdfFont] is not embedded.
+    ///
+    /// The returned bytes are the contents of the font file stream referenced by the font's
+    /// descriptor, e.g. a TrueType or OpenType CFF font program, and can be written directly
+    /// to a font file or embedded into another document. Unlike the [PdfFont::data()] function,
+    /// this function never returns data for a substitution font.
+    pub fn embedded_data(&self) -> Option<Vec<u8>> {
+        match self.is_embedded() {
+            Ok(true) => self.data().ok(),
+            _ => None,
+        }
+    }
+
     /// Returns a collection of all the [PdfFontGlyphs] defined for this [PdfFont] in the containing
     /// `PdfDocument`.
     ///
@@ -1203,3 +1294,143 @@ impl<'a> Drop for PdfFont<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    fn is_sfnt(data: &[u8]) -> bool {
+        data.len() >= 4 && matches!(&data[0..4], [0, 1, 0, 0] | b"true" | b"OTTO")
+    }
+
+    #[test]
+    fn test_embedded_data() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let data = page
+            .objects()
+            .iter()
+            .filter_map(|object| {
+                object
+                    .as_text_object()
+                    .and_then(|object| object.font().embedded_data())
+            })
+            .next()
+            .expect("test document should contain an embedded font");
+
+        assert!(is_sfnt(&data));
+
+        // Re-embedding the extracted font program into a new document should
+        // round-trip the font data.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let token = document
+            .fonts_mut()
+            .load_true_type_from_bytes(&data, false)?;
+
+        let font = document.fonts().get(token).unwrap();
+
+        assert!(is_sfnt(&font.embedded_data().unwrap()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_built_in_font_has_no_embedded_data() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let token = document.fonts_mut().helvetica();
+
+        assert!(document
+            .fonts()
+            .get(token)
+            .unwrap()
+            .embedded_data()
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_measure_text() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let courier = document.fonts_mut().courier();
+
+        let helvetica = document.fonts_mut().helvetica();
+
+        let fonts = document.fonts();
+
+        // Every Courier glyph is 600 units wide.
+
+        let courier = fonts.get(courier).unwrap();
+
+        assert_eq!(
+            courier.measure_text("Hello", PdfPoints::new(10.0))?.value,
+            30.0
+        );
+        assert_eq!(
+            courier.measure_text("", PdfPoints::new(10.0))?,
+            PdfPoints::ZERO
+        );
+
+        // In Helvetica, "H" is 722 units wide and "i" is 222 units wide.
+
+        let helvetica = fonts.get(helvetica).unwrap();
+
+        assert!((helvetica.measure_text("Hi", PdfPoints::new(10.0))?.value - 9.44).abs() < 0.01);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_font_metadata() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            "Font metadata",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let object = page.objects().first()?;
+
+        let font = object.as_text_object().unwrap().font();
+
+        assert_eq!(font.base_name(), "Helvetica");
+        assert!(!font.is_embedded()?);
+
+        let flags = font.flags();
+
+        assert!(!flags.contains(FpdfFontDescriptorFlags::SERIF_BIT_2));
+        assert!(!flags.contains(FpdfFontDescriptorFlags::FIXED_PITCH_BIT_1));
+        assert!(!flags.contains(FpdfFontDescriptorFlags::ITALIC_BIT_7));
+        assert_eq!(
+            flags.contains(FpdfFontDescriptorFlags::SERIF_BIT_2),
+            font.is_serif()
+        );
+        assert_eq!(font.italic_angle()?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,465 @@
+//! Defines the [PdfFontSubstitutionInfo] struct, a system font information interface
+//! that maps the names of fonts not embedded in a document to replacement font files
+//! before falling back to Pdfium's default system font handling.
+
+use crate::bindgen::{FPDF_BOOL, FPDF_SYSFONTINFO, FXFONT_ANSI_CHARSET};
+use crate::pdf::document::fonts::PdfFontBuiltin;
+use crate::pdfium::config::PdfiumConfig;
+use std::convert::TryInto;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_uchar, c_uint, c_ulong, c_void};
+use std::path::PathBuf;
+
+/// The 14 standard fonts, which Pdfium always provides.
+const STANDARD_FONTS: [PdfFontBuiltin; 14] = [
+    PdfFontBuiltin::TimesRoman,
+    PdfFontBuiltin::TimesBold,
+    PdfFontBuiltin::TimesItalic,
+    PdfFontBuiltin::TimesBoldItalic,
+    PdfFontBuiltin::Helvetica,
+    PdfFontBuiltin::HelveticaBold,
+    PdfFontBuiltin::HelveticaOblique,
+    PdfFontBuiltin::HelveticaBoldOblique,
+    PdfFontBuiltin::Courier,
+    PdfFontBuiltin::CourierBold,
+    PdfFontBuiltin::CourierOblique,
+    PdfFontBuiltin::CourierBoldOblique,
+    PdfFontBuiltin::Symbol,
+    PdfFontBuiltin::ZapfDingbats,
+];
+
+/// The maximum number of directories searched for font files, guarding against
+/// symbolic link cycles.
+const MAX_FONT_DIRECTORIES: usize = 4096;
+
+/// The file extensions of the font files Pdfium accepts from the user font paths.
+const FONT_FILE_EXTENSIONS: [&str; 5] = ["ttf", "ttc", "otf", "pfa", "pfb"];
+
+/// The TrueType table tag Pdfium requests to determine whether a font file is a
+/// TrueType collection.
+const TABLE_TTCF: c_uint = u32::from_be_bytes(*b"ttcf");
+
+/// A single replacement font registered with a [PdfFontSubstitutionInfo].
+pub(crate) struct PdfFontSubstitute {
+    name: String,
+    face: CString,
+    data: Vec<u8>,
+}
+
+impl PdfFontSubstitute {
+    /// Creates a new [PdfFontSubstitute] that replaces the font with the given name
+    /// with the given font file data.
+    pub(crate) fn new(name: &str, data: Vec<u8>) -> Self {
+        PdfFontSubstitute {
+            name: name.to_owned(),
+            face: CString::new(name.replace('\0', "")).unwrap_or_default(),
+            data,
+        }
+    }
+
+    /// Returns the requested TrueType table from this font's data, or the entire font file
+    /// if the given table tag is zero.
+    fn table(&self, table: c_uint) -> Option<&[u8]> {
+        if table == 0 {
+            return Some(self.data.as_slice());
+        }
+
+        if table == TABLE_TTCF {
+            return self.data.starts_with(b"ttcf").then(|| self.data.as_slice());
+        }
+
+        // The table directory of an sfnt font file begins with a 12-byte header, followed by
+        // one 16-byte record per table giving the table's tag, checksum, offset, and length.
+
+        let read_u32 = |offset: usize| -> Option<u32> {
+            Some(u32::from_be_bytes(
+                self.data.get(offset..offset + 4)?.try_into().ok()?,
+            ))
+        };
+
+        let count = u16::from_be_bytes(self.data.get(4..6)?.try_into().ok()?) as usize;
+
+        (0..count).find_map(|index| {
+            let record = 12 + index * 16;
+
+            if read_u32(record)? != table {
+                return None;
+            }
+
+            let offset = read_u32(record + 8)? as usize;
+
+            let length = read_u32(record + 12)? as usize;
+
+            self.data.get(offset..offset.checked_add(length)?)
+        })
+    }
+}
+
+/// A system font information interface that Pdfium consults whenever a document uses
+/// a font that is not embedded in the document. Fonts matching one of the registered
+/// [PdfFontSubstitute] entries are mapped to the replacement font data; all other requests
+/// are passed through to Pdfium's default system font information interface.
+///
+/// Pdfium retains a pointer to this structure, so it must remain at a fixed address
+/// until the Pdfium library is destroyed.
+#[repr(C)]
+pub(crate) struct PdfFontSubstitutionInfo {
+    // The interface must be the first field, so that the pointer Pdfium passes back
+    // to each callback can be cast to a pointer to this structure.
+    interface: FPDF_SYSFONTINFO,
+    default: *mut FPDF_SYSFONTINFO,
+    substitutes: Vec<PdfFontSubstitute>,
+}
+
+impl PdfFontSubstitutionInfo {
+    /// Creates a new [PdfFontSubstitutionInfo] that maps fonts to the given substitutes.
+    /// All other requests are ignored until a default system font information interface
+    /// is provided using the [PdfFontSubstitutionInfo::set_default_font_info()] function.
+    pub(crate) fn new(substitutes: Vec<PdfFontSubstitute>) -> Box<Self> {
+        Box::new(PdfFontSubstitutionInfo {
+            interface: FPDF_SYSFONTINFO {
+                version: 1,
+                Release: Some(release),
+                EnumFonts: Some(enum_fonts),
+                MapFont: Some(map_font),
+                GetFont: Some(get_font),
+                GetFontData: Some(get_font_data),
+                GetFaceName: Some(get_face_name),
+                GetFontCharset: Some(get_font_charset),
+                DeleteFont: Some(delete_font),
+            },
+            default: std::ptr::null_mut(),
+            substitutes,
+        })
+    }
+
+    /// Returns a pointer to the system font information interface that should be passed to Pdfium.
+    #[inline]
+    pub(crate) fn as_fpdf_sysfontinfo_mut_ptr(&mut self) -> *mut FPDF_SYSFONTINFO {
+        &mut self.interface
+    }
+
+    /// Returns the default system font information interface wrapped by this
+    /// [PdfFontSubstitutionInfo]. The caller is responsible for releasing it once
+    /// Pdfium no longer uses this [PdfFontSubstitutionInfo].
+    #[inline]
+    pub(crate) fn default_font_info(&self) -> *mut FPDF_SYSFONTINFO {
+        self.default
+    }
+
+    /// Sets the default system font information interface, which may be null, to which
+    /// this [PdfFontSubstitutionInfo] passes all requests for fonts that are not substituted.
+    #[inline]
+    pub(crate) fn set_default_font_info(&mut self, default: *mut FPDF_SYSFONTINFO) {
+        self.default = default;
+    }
+
+    /// Returns the substitute matching the given typeface name, if any.
+    fn find_by_face(&self, face: *const c_char) -> Option<&PdfFontSubstitute> {
+        if face.is_null() {
+            return None;
+        }
+
+        let face = unsafe { CStr::from_ptr(face) }.to_string_lossy();
+
+        find_font_by_name(&self.substitutes, |substitute| &substitute.name, &face)
+    }
+
+    /// Returns the substitute whose font handle is the given handle, if any.
+    fn find_by_handle(&self, handle: *mut c_void) -> Option<&PdfFontSubstitute> {
+        self.substitutes
+            .iter()
+            .find(|substitute| *substitute as *const PdfFontSubstitute as *mut c_void == handle)
+    }
+}
+
+/// Returns the first item whose name matches the given font name. An item matches if its
+/// normalized name is identical to the normalized font name or, failing that, if the
+/// family portions of both names before any style suffix such as `,Bold` or `-Italic`
+/// are identical.
+pub(crate) fn find_font_by_name<'a, T>(
+    items: &'a [T],
+    name: impl Fn(&T) -> &str,
+    font: &str,
+) -> Option<&'a T> {
+    let font = normalize_font_name(font);
+
+    items
+        .iter()
+        .find(|item| normalize_font_name(name(item)) == font)
+        .or_else(|| {
+            let family = font_family_name(&font);
+
+            items
+                .iter()
+                .find(|item| font_family_name(&normalize_font_name(name(item))) == family)
+        })
+}
+
+/// Returns `true` if Pdfium can provide a font with the given name without falling back
+/// to a substitute system font, either because it is one of the 14 standard fonts, because
+/// the given configuration contains a matching font substitution, or because the name
+/// matches one of the given font file names.
+pub(crate) fn is_font_available(
+    name: &str,
+    config: &PdfiumConfig,
+    font_file_names: &[String],
+) -> bool {
+    find_font_by_name(&STANDARD_FONTS, |font| font.to_pdf_font_name(), name).is_some()
+        || find_font_by_name(config.font_substitutions(), |(from, _)| from, name).is_some()
+        || find_font_by_name(font_file_names, |file_name| file_name, name).is_some()
+}
+
+/// Returns the file names, without extensions, of all font files located in the given
+/// directories or any of their subdirectories.
+pub(crate) fn font_file_names(paths: &[PathBuf]) -> Vec<String> {
+    let mut result = Vec::new();
+
+    let mut directories = paths.to_vec();
+
+    let mut visited = 0;
+
+    while let Some(directory) = directories.pop() {
+        visited += 1;
+
+        if visited > MAX_FONT_DIRECTORIES {
+            break;
+        }
+
+        let entries = match std::fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for path in entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+        {
+            if path.is_dir() {
+                directories.push(path);
+            } else if let (Some(stem), Some(extension)) = (path.file_stem(), path.extension()) {
+                let extension = extension.to_string_lossy().to_lowercase();
+
+                if FONT_FILE_EXTENSIONS.contains(&extension.as_str()) {
+                    result.push(stem.to_string_lossy().into_owned());
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the given font name in lower case with spaces and any subset prefix,
+/// such as `ABCDEF+`, removed.
+pub(crate) fn normalize_font_name(name: &str) -> String {
+    let name = match name.split_once('+') {
+        Some((prefix, rest))
+            if prefix.len() == 6 && prefix.chars().all(|c| c.is_ascii_uppercase()) =>
+        {
+            rest
+        }
+        _ => name,
+    };
+
+    name.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Returns the family portion of the given normalized font name, before any style suffix.
+pub(crate) fn font_family_name(name: &str) -> &str {
+    name.split([',', '-']).next().unwrap_or(name)
+}
+
+/// Returns the [PdfFontSubstitutionInfo] containing the given interface pointer.
+///
+/// # Safety
+///
+/// The given pointer must be the `interface` field of a live [PdfFontSubstitutionInfo].
+#[inline]
+unsafe fn info<'a>(this: *mut FPDF_SYSFONTINFO) -> &'a PdfFontSubstitutionInfo {
+    &*(this as *const PdfFontSubstitutionInfo)
+}
+
+unsafe extern "C" fn release(this: *mut FPDF_SYSFONTINFO) {
+    let default = info(this).default;
+
+    if let Some(callback) = default.as_ref().and_then(|default| default.Release) {
+        callback(default);
+    }
+}
+
+unsafe extern "C" fn enum_fonts(this: *mut FPDF_SYSFONTINFO, mapper: *mut c_void) {
+    let default = info(this).default;
+
+    if let Some(callback) = default.as_ref().and_then(|default| default.EnumFonts) {
+        callback(default, mapper);
+    }
+}
+
+unsafe extern "C" fn map_font(
+    this: *mut FPDF_SYSFONTINFO,
+    weight: c_int,
+    italic: FPDF_BOOL,
+    charset: c_int,
+    pitch_family: c_int,
+    face: *const c_char,
+    exact: *mut FPDF_BOOL,
+) -> *mut c_void {
+    let info = info(this);
+
+    if let Some(substitute) = info.find_by_face(face) {
+        return substitute as *const PdfFontSubstitute as *mut c_void;
+    }
+
+    match info.default.as_ref().and_then(|default| default.MapFont) {
+        Some(callback) => callback(
+            info.default,
+            weight,
+            italic,
+            charset,
+            pitch_family,
+            face,
+            exact,
+        ),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn get_font(this: *mut FPDF_SYSFONTINFO, face: *const c_char) -> *mut c_void {
+    let info = info(this);
+
+    if let Some(substitute) = info.find_by_face(face) {
+        return substitute as *const PdfFontSubstitute as *mut c_void;
+    }
+
+    match info.default.as_ref().and_then(|default| default.GetFont) {
+        Some(callback) => callback(info.default, face),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn get_font_data(
+    this: *mut FPDF_SYSFONTINFO,
+    handle: *mut c_void,
+    table: c_uint,
+    buffer: *mut c_uchar,
+    buffer_length: c_ulong,
+) -> c_ulong {
+    let info = info(this);
+
+    if let Some(substitute) = info.find_by_handle(handle) {
+        return match substitute.table(table) {
+            Some(data) => copy_to_buffer(data, buffer, buffer_length),
+            None => 0,
+        };
+    }
+
+    match info
+        .default
+        .as_ref()
+        .and_then(|default| default.GetFontData)
+    {
+        Some(callback) => callback(info.default, handle, table, buffer, buffer_length),
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn get_face_name(
+    this: *mut FPDF_SYSFONTINFO,
+    handle: *mut c_void,
+    buffer: *mut c_char,
+    buffer_length: c_ulong,
+) -> c_ulong {
+    let info = info(this);
+
+    if let Some(substitute) = info.find_by_handle(handle) {
+        return copy_to_buffer(
+            substitute.face.as_bytes_with_nul(),
+            buffer as *mut c_uchar,
+            buffer_length,
+        );
+    }
+
+    match info
+        .default
+        .as_ref()
+        .and_then(|default| default.GetFaceName)
+    {
+        Some(callback) => callback(info.default, handle, buffer, buffer_length),
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn get_font_charset(this: *mut FPDF_SYSFONTINFO, handle: *mut c_void) -> c_int {
+    let info = info(this);
+
+    if info.find_by_handle(handle).is_some() {
+        return FXFONT_ANSI_CHARSET as c_int;
+    }
+
+    match info
+        .default
+        .as_ref()
+        .and_then(|default| default.GetFontCharset)
+    {
+        Some(callback) => callback(info.default, handle),
+        None => FXFONT_ANSI_CHARSET as c_int,
+    }
+}
+
+unsafe extern "C" fn delete_font(this: *mut FPDF_SYSFONTINFO, handle: *mut c_void) {
+    let info = info(this);
+
+    // Substitute font handles point to data owned by the PdfFontSubstitutionInfo,
+    // so there is nothing to delete.
+
+    if info.find_by_handle(handle).is_none() {
+        if let Some(callback) = info.default.as_ref().and_then(|default| default.DeleteFont) {
+            callback(info.default, handle);
+        }
+    }
+}
+
+/// Copies the given data into the given buffer if the buffer is large enough,
+/// returning the length of the data in either case.
+unsafe fn copy_to_buffer(data: &[u8], buffer: *mut c_uchar, buffer_length: c_ulong) -> c_ulong {
+    if !buffer.is_null() && buffer_length as usize >= data.len() {
+        std::ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len());
+    }
+
+    data.len() as c_ulong
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_font_by_name() {
+        let names = ["Garamond", "Futura-Bold", "Optima"];
+
+        let find = |font| find_font_by_name(&names, |name| name, font).copied();
+
+        assert_eq!(find("Garamond"), Some("Garamond"));
+        assert_eq!(find("ABCDEF+Garamond,Bold"), Some("Garamond"));
+        assert_eq!(find("Futura-Bold"), Some("Futura-Bold"));
+        assert_eq!(find("futura bold"), None);
+        assert_eq!(find("Futura-Medium"), Some("Futura-Bold"));
+        assert_eq!(find("Abcdef+Optima"), None);
+        assert_eq!(find("Palatino"), None);
+
+        let config = PdfiumConfig::new().add_font_substitution("Palatino", "palatino.ttf");
+
+        assert!(is_font_available("Helvetica-Bold", &config, &[]));
+        assert!(is_font_available("Palatino,Italic", &config, &[]));
+        assert!(is_font_available(
+            "Optima",
+            &config,
+            &["Optima-Regular".to_owned()]
+        ));
+        assert!(!is_font_available("Optima", &config, &[]));
+    }
+}
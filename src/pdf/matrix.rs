@@ -12,6 +12,51 @@ use vecmath::{mat3_add, mat3_det, mat3_inv, mat3_sub, mat3_transposed, row_mat3_
 /// The floating-point data type used internally by a [PdfMatrix].
 pub type PdfMatrixValue = f32;
 
+/// The human-meaningful components of a [PdfMatrix], as returned by [PdfMatrix::decompose()].
+///
+/// The components are applied in the order scale, then skew, then rotation, then translation.
+/// Recomposing the components using [PdfTransformComponents::to_matrix()] returns the
+/// original matrix, within floating-point tolerance.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfTransformComponents {
+    /// The horizontal translation, equivalent to matrix value `e`.
+    pub tx: PdfMatrixValue,
+
+    /// The vertical translation, equivalent to matrix value `f`.
+    pub ty: PdfMatrixValue,
+
+    /// The horizontal scale factor. This value is always zero or positive.
+    pub scale_x: PdfMatrixValue,
+
+    /// The vertical scale factor. This value is negative if the matrix includes a flip,
+    /// i.e. if the determinant of the matrix is negative.
+    pub scale_y: PdfMatrixValue,
+
+    /// The counter-clockwise rotation, in radians.
+    pub rotation_radians: PdfMatrixValue,
+
+    /// The skew angle along the x axis, in radians.
+    pub skew: PdfMatrixValue,
+}
+
+impl PdfTransformComponents {
+    /// Recomposes these components into a [PdfMatrix].
+    pub fn to_matrix(&self) -> PdfMatrix {
+        let (sin, cos) = self.rotation_radians.sin_cos();
+
+        let shear = self.scale_y * self.skew.tan();
+
+        PdfMatrix::new(
+            self.scale_x * cos,
+            self.scale_x * sin,
+            shear * cos - self.scale_y * sin,
+            shear * sin + self.scale_y * cos,
+            self.tx,
+            self.ty,
+        )
+    }
+}
+
 /// Six floating-point values, labelled `a`, `b`, `c`, `d`, `e`, and `f`, that represent
 /// the six configurable elements of a nine-element 3x3 PDF transformation matrix.
 ///
@@ -29,6 +74,9 @@ pub type PdfMatrixValue = f32;
 ///
 /// An overview of PDF transformation matrices can be found in the PDF Reference Manual
 /// version 1.7 on page 204; a detailed description can be founded in section 4.2.3 on page 207.
+///
+/// When the `serde` crate feature is enabled, [PdfMatrix] implements `serde::Serialize`
+/// and `serde::Deserialize`, and is serialized as its six matrix values `a` to `f`.
 #[derive(Debug, Copy, Clone)]
 pub struct PdfMatrix {
     matrix: Matrix3<PdfMatrixValue>,
@@ -234,6 +282,42 @@ impl PdfMatrix {
         )
     }
 
+    /// Decomposes this [PdfMatrix] into its translation, rotation, scale, and skew components.
+    ///
+    /// A matrix that flips its content, i.e. a matrix with a negative determinant, is reported
+    /// as having a negative vertical scale. A degenerate matrix, i.e. a matrix with a determinant
+    /// of zero, is reported as having a zero vertical scale and no skew.
+    pub fn decompose(&self) -> PdfTransformComponents {
+        let (a, b, c, d) = (self.a(), self.b(), self.c(), self.d());
+
+        let determinant = a * d - b * c;
+
+        let scale_x = (a * a + b * b).sqrt();
+
+        let (scale_y, rotation_radians, skew) = if scale_x == 0.0 {
+            // The first row is empty, so take the rotation from the second row instead.
+
+            ((c * c + d * d).sqrt(), (-c).atan2(d), 0.0)
+        } else if determinant == 0.0 {
+            (0.0, b.atan2(a), 0.0)
+        } else {
+            (
+                determinant / scale_x,
+                b.atan2(a),
+                ((a * c + b * d) / determinant).atan(),
+            )
+        };
+
+        PdfTransformComponents {
+            tx: self.e(),
+            ty: self.f(),
+            scale_x,
+            scale_y,
+            rotation_radians,
+            skew,
+        }
+    }
+
     create_transform_setters!(
         Self,
         Result<Self, PdfiumError>,
@@ -342,10 +426,60 @@ impl Mul for PdfMatrix {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for PdfMatrix {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("PdfMatrix", 6)?;
+
+        state.serialize_field("a", &self.a())?;
+        state.serialize_field("b", &self.b())?;
+        state.serialize_field("c", &self.c())?;
+        state.serialize_field("d", &self.d())?;
+        state.serialize_field("e", &self.e())?;
+        state.serialize_field("f", &self.f())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PdfMatrix {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "PdfMatrix")]
+        struct Fields {
+            a: PdfMatrixValue,
+            b: PdfMatrixValue,
+            c: PdfMatrixValue,
+            d: PdfMatrixValue,
+            e: PdfMatrixValue,
+            f: PdfMatrixValue,
+        }
+
+        let fields = <Fields as serde::Deserialize>::deserialize(deserializer)?;
+
+        Ok(PdfMatrix::new(
+            fields.a, fields.b, fields.c, fields.d, fields.e, fields.f,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_matrix_serde_round_trip() {
+        let matrix = PdfMatrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+        let json = serde_json::to_string(&matrix).unwrap();
+
+        assert_eq!(json, r#"{"a":1.0,"b":2.0,"c":3.0,"d":4.0,"e":5.0,"f":6.0}"#);
+        assert_eq!(serde_json::from_str::<PdfMatrix>(&json).unwrap(), matrix);
+    }
+
     #[test]
     fn test_matrix_apply_to_points() {
         let delta_x = PdfPoints::new(50.0);
@@ -361,4 +495,33 @@ mod tests {
         assert_eq!(result.0, x + delta_x);
         assert_eq!(result.1, y + delta_y);
     }
+
+    #[test]
+    fn test_matrix_decompose_round_trip() -> Result<(), PdfiumError> {
+        let matrix = PdfMatrix::identity()
+            .scale(2.0, 3.0)?
+            .rotate_counter_clockwise_degrees(30.0)?
+            .translate(PdfPoints::new(40.0), PdfPoints::new(-50.0))?;
+
+        let components = matrix.decompose();
+
+        assert!((components.tx - 40.0).abs() < 0.0001);
+        assert!((components.ty + 50.0).abs() < 0.0001);
+        assert!((components.scale_x - 2.0).abs() < 0.0001);
+        assert!((components.scale_y - 3.0).abs() < 0.0001);
+        assert!((components.rotation_radians - 30.0_f32.to_radians()).abs() < 0.0001);
+        assert!(components.skew.abs() < 0.0001);
+        assert_eq!(components.to_matrix(), matrix);
+
+        // A flipped matrix should report a negative vertical scale.
+
+        let flipped = PdfMatrix::new(1.0, 0.0, 0.5, -2.0, 0.0, 0.0);
+
+        let components = flipped.decompose();
+
+        assert!(components.scale_y < 0.0);
+        assert_eq!(components.to_matrix(), flipped);
+
+        Ok(())
+    }
 }
@@ -1,3 +1,3 @@
-mod clip_path; // Keep private while PdfClipPath is still in development.
+pub mod clip_path;
 pub mod segment;
 pub mod segments;
@@ -1,43 +1,97 @@
 //! Defines the [PdfClipPath] struct, exposing functionality related to a clip path.
-#[doc(hidden)]
+
 use crate::bindgen::FPDF_CLIPPATH;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::path::segment::PdfPathSegment;
 use crate::pdf::path::segments::{PdfPathSegmentIndex, PdfPathSegments, PdfPathSegmentsIterator};
 use std::convert::TryInto;
+use std::ops::Range;
 use std::os::raw::c_int;
 
-// TODO: AJRC - 22/10/22 - "clip path" is a slight misnomer, since a single clip path can actually
-// contain zero or more path objects. Each path object can then return a PdfClipPathSegments object
-// that implements the PdfPathSegments trait. Want to complete implementation of top-level PdfClipPath
-// collection, then add clip path support to pages and page objects.
+#[cfg(doc)]
+use crate::pdf::document::page::object::PdfPageObjectCommon;
+
+/// The zero-based index of a single path inside its containing [PdfClipPath].
+pub type PdfClipPathIndex = u32;
 
-#[allow(dead_code)]
-// The PdfClipPath struct is not currently used, but we expect it to be in future
+/// The clip path applied to a single page object, as returned by the
+/// [PdfPageObjectCommon::clip_path()] function.
+///
+/// A single clip path can contain zero or more paths; the visible area of the page object
+/// is the intersection of all of them. The segments of each path are available as a
+/// [PdfClipPathSegments] collection.
 pub struct PdfClipPath<'a> {
-    // TODO: AJRC - 22/10/22 - this will contain a collection of paths
-    // each of which can return a PdfClipPathSegments object
     handle: FPDF_CLIPPATH,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
 impl<'a> PdfClipPath<'a> {
+    // Clip paths retrieved from a page object are owned by that page object, and remain
+    // valid until the containing page is closed, so there is no corresponding Drop
+    // implementation that destroys the clip path.
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_CLIPPATH,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfClipPath { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfClipPath].
     #[inline]
     pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
-}
 
-impl<'a> Drop for PdfClipPath<'a> {
-    /// Closes this [PdfClipPath], releasing held memory.
+    /// Returns the number of paths in this [PdfClipPath].
+    #[inline]
+    pub fn len(&self) -> PdfClipPathIndex {
+        self.bindings()
+            .FPDFClipPath_CountPaths(self.handle)
+            .try_into()
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if this [PdfClipPath] contains no paths.
     #[inline]
-    fn drop(&mut self) {
-        self.bindings.FPDF_DestroyClipPath(self.handle)
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of paths)` for this [PdfClipPath].
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfClipPathIndex> {
+        0..self.len()
+    }
+
+    /// Returns the segments of the path at the given index in this [PdfClipPath].
+    pub fn get(&self, index: PdfClipPathIndex) -> Result<PdfClipPathSegments<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        Ok(PdfClipPathSegments::from_pdfium(
+            self.handle,
+            index as c_int,
+            self.bindings(),
+        ))
+    }
+
+    /// Returns an iterator over the segments of every path in this [PdfClipPath].
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = PdfClipPathSegments<'a>> + '_ {
+        self.as_range()
+            .filter_map(move |index| self.get(index).ok())
     }
 }
 
 /// The collection of [PdfPathSegment] objects inside a single path within a clip path.
+///
+/// Segment coordinates are expressed in page space, since Pdfium stores clip paths
+/// with any transformation already applied.
 pub struct PdfClipPathSegments<'a> {
     handle: FPDF_CLIPPATH,
     path_index: c_int,
@@ -46,8 +100,6 @@ pub struct PdfClipPathSegments<'a> {
 
 impl<'a> PdfClipPathSegments<'a> {
     #[inline]
-    #[allow(dead_code)]
-    // The from_pdfium() function is not currently used, but we expect it to be in future
     pub(crate) fn from_pdfium(
         handle: FPDF_CLIPPATH,
         path_index: c_int,
@@ -96,3 +148,46 @@ impl<'a> PdfPathSegments<'a> for PdfClipPathSegments<'a> {
         PdfPathSegmentsIterator::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_rectangular_clip_path_segments() -> Result<(), PdfiumError> {
+        // The text on the first page of this file is clipped by a rectangle, drawn using
+        // the `re` operator, so its clip path should consist of a single closed sub-path.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-clip-crop-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page
+            .objects()
+            .iter()
+            .find(|object| {
+                object.object_type() == PdfPageObjectType::Text && object.clip_path().is_some()
+            })
+            .unwrap();
+
+        let clip_path = object.clip_path().unwrap();
+
+        assert_eq!(clip_path.len(), 1);
+
+        let segments = clip_path.get(0)?;
+
+        let segments = segments.iter().collect::<Vec<_>>();
+
+        assert!(segments.len() >= 4);
+        assert_eq!(segments[0].segment_type(), PdfPathSegmentType::MoveTo);
+        assert!(segments[1..]
+            .iter()
+            .all(|segment| segment.segment_type() == PdfPathSegmentType::LineTo));
+        assert!(segments.last().unwrap().is_close());
+
+        Ok(())
+    }
+}
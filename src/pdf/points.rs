@@ -14,6 +14,9 @@ use {
 /// The internal coordinate system inside a [PdfDocument] is measured in Points, a
 /// device-independent unit equal to 1/72 inches, roughly 0.358 mm. Points are converted to pixels
 /// when a [PdfPage] is rendered into a [PdfBitmap].
+///
+/// When the `serde` crate feature is enabled, [PdfPoints] implements `serde::Serialize`
+/// and `serde::Deserialize`, and is serialized as a single floating-point value.
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 pub struct PdfPoints {
     pub value: f32,
@@ -206,3 +209,19 @@ mod tests {
         assert!(PdfPoints::ZERO > PdfPoints::new(-1.0));
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PdfPoints {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f32(self.value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PdfPoints {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <f32 as serde::Deserialize>::deserialize(deserializer).map(PdfPoints::new)
+    }
+}
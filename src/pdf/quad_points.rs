@@ -279,6 +279,46 @@ impl PdfQuadPoints {
         )
     }
 
+    /// Returns `true` if the given point lies inside the quadrilateral outlined by this
+    /// [PdfQuadPoints], including on its edges.
+    ///
+    /// Unlike testing against the result of [PdfQuadPoints::to_rect()], this takes any rotation
+    /// or skew of the quadrilateral into account, making it suitable for hit-testing against
+    /// rotated text selections and annotation quads. The quadrilateral is assumed to be convex,
+    /// as all quadrilaterals created from a rotated, scaled, or skewed [PdfRect] are.
+    pub fn contains(&self, x: PdfPoints, y: PdfPoints) -> bool {
+        let vertices = [
+            (self.x1.value, self.y1.value),
+            (self.x2.value, self.y2.value),
+            (self.x3.value, self.y3.value),
+            (self.x4.value, self.y4.value),
+        ];
+
+        let mut has_positive = false;
+
+        let mut has_negative = false;
+
+        for (index, (x_start, y_start)) in vertices.iter().enumerate() {
+            let (x_end, y_end) = vertices[(index + 1) % vertices.len()];
+
+            // The sign of the cross product indicates which side of this edge the point lies on.
+
+            let cross =
+                (x_end - x_start) * (y.value - y_start) - (y_end - y_start) * (x.value - x_start);
+
+            if cross > 0.0 {
+                has_positive = true;
+            } else if cross < 0.0 {
+                has_negative = true;
+            }
+        }
+
+        // The point lies inside the quadrilateral if it is on the same side of every edge,
+        // irrespective of whether the vertices are ordered clockwise or counter-clockwise.
+
+        !(has_positive && has_negative)
+    }
+
     #[inline]
     pub(crate) fn as_pdfium(&self) -> FS_QUADPOINTSF {
         FS_QUADPOINTSF {
@@ -393,4 +433,46 @@ mod tests {
         assert!((s.left() - r.left()).abs() < threshold);
         assert!((s.right() - r.right()).abs() < threshold);
     }
+
+    #[test]
+    fn test_quadpoints_from_rect_round_trip() {
+        let r = PdfRect::new_from_values(50.0, 100.0, 300.0, 200.0);
+
+        assert_eq!(PdfQuadPoints::from_rect(&r).to_rect(), r);
+    }
+
+    #[test]
+    fn test_quadpoints_contains() {
+        let r = PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0);
+
+        let q = PdfQuadPoints::from_rect(&r);
+
+        assert!(q.contains(PdfPoints::new(150.0), PdfPoints::new(150.0)));
+        assert!(q.contains(PdfPoints::new(100.0), PdfPoints::new(100.0)));
+        assert!(!q.contains(PdfPoints::new(99.0), PdfPoints::new(150.0)));
+        assert!(!q.contains(PdfPoints::new(150.0), PdfPoints::new(201.0)));
+    }
+
+    #[test]
+    fn test_quadpoints_contains_rotated() {
+        // Rotating a square by 45 degrees about the origin produces a diamond. The corners
+        // of the diamond's bounding rectangle lie outside the diamond itself.
+
+        let m = PdfMatrix::identity()
+            .rotate_counter_clockwise_degrees(45.0)
+            .unwrap();
+
+        let q = PdfQuadPoints::from_rect(&PdfRect::new_from_values(-50.0, -50.0, 50.0, 50.0))
+            .transform(m);
+
+        let bounds = q.to_rect();
+
+        assert!(q.contains(PdfPoints::new(0.0), PdfPoints::new(0.0)));
+        assert!(q.contains(PdfPoints::new(0.0), PdfPoints::new(60.0)));
+        assert!(q.contains(PdfPoints::new(60.0), PdfPoints::new(0.0)));
+
+        assert!(bounds.contains(PdfPoints::new(60.0), PdfPoints::new(60.0)));
+        assert!(!q.contains(PdfPoints::new(60.0), PdfPoints::new(60.0)));
+        assert!(!q.contains(PdfPoints::new(-60.0), PdfPoints::new(-60.0)));
+    }
 }
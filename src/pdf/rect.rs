@@ -15,6 +15,9 @@ use std::hash::{Hash, Hasher};
 /// The coordinate space of a `PdfPage` has its origin (0,0) at the bottom left of the page,
 /// with x values increasing as coordinates move horizontally to the right and
 /// y values increasing as coordinates move vertically up.
+///
+/// When the `serde` crate feature is enabled, [PdfRect] implements `serde::Serialize`
+/// and `serde::Deserialize`.
 #[derive(Debug, Copy, Clone)]
 pub struct PdfRect {
     // TODO: AJRC - 28/12/24 - direct field access to be removed as part of release 0.9.0.
@@ -98,6 +101,21 @@ impl PdfRect {
         }
     }
 
+    /// Creates a new [PdfRect] from the given [PdfPoints] measurements, given in
+    /// left, top, right, bottom order.
+    ///
+    /// This is equivalent to calling [PdfRect::new()] with the arguments reordered;
+    /// it is provided for callers who find the left, top, right, bottom order more familiar.
+    #[inline]
+    pub const fn from_ltrb(
+        left: PdfPoints,
+        top: PdfPoints,
+        right: PdfPoints,
+        bottom: PdfPoints,
+    ) -> Self {
+        Self::new(bottom, left, top, right)
+    }
+
     /// Creates a new [PdfRect] from the given raw points values.
     ///
     /// The coordinate space of a `PdfPage` has its origin (0,0) at the bottom left of the page,
@@ -201,6 +219,17 @@ impl PdfRect {
             && self.bottom() < other.top()
     }
 
+    /// Returns the smallest [PdfRect] that contains both this [PdfRect] and the given rectangle.
+    #[inline]
+    pub fn union(&self, other: &PdfRect) -> PdfRect {
+        PdfRect::new_from_values(
+            self.bottom().value.min(other.bottom().value),
+            self.left().value.min(other.left().value),
+            self.top().value.max(other.top().value),
+            self.right().value.max(other.right().value),
+        )
+    }
+
     /// Returns the result of applying the given [PdfMatrix] to each corner point of this [PdfRect].
     #[inline]
     pub fn transform(&self, matrix: PdfMatrix) -> PdfRect {
@@ -273,6 +302,47 @@ impl Display for PdfRect {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for PdfRect {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        // We serialize via the accessor functions rather than deriving the implementation,
+        // since the derived implementation would access the deprecated public fields.
+
+        let mut state = serializer.serialize_struct("PdfRect", 4)?;
+
+        state.serialize_field("left", &self.left())?;
+        state.serialize_field("bottom", &self.bottom())?;
+        state.serialize_field("right", &self.right())?;
+        state.serialize_field("top", &self.top())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PdfRect {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "PdfRect")]
+        struct Fields {
+            left: PdfPoints,
+            bottom: PdfPoints,
+            right: PdfPoints,
+            top: PdfPoints,
+        }
+
+        let fields = <Fields as serde::Deserialize>::deserialize(deserializer)?;
+
+        Ok(PdfRect::new(
+            fields.bottom,
+            fields.left,
+            fields.top,
+            fields.right,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -295,6 +365,34 @@ mod tests {
             .is_inside(&PdfRect::new_from_values(5.0, 8.0, 10.0, 10.0)));
     }
 
+    #[test]
+    fn test_from_ltrb() {
+        let rect = PdfRect::from_ltrb(
+            PdfPoints::new(10.0),
+            PdfPoints::new(200.0),
+            PdfPoints::new(110.0),
+            PdfPoints::new(50.0),
+        );
+
+        assert_eq!(rect, PdfRect::new_from_values(50.0, 10.0, 200.0, 110.0));
+        assert_eq!(rect.width(), PdfPoints::new(100.0));
+        assert_eq!(rect.height(), PdfPoints::new(150.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_rect_serde_round_trip() {
+        let rect = PdfRect::new_from_values(50.0, 10.0, 200.0, 110.0);
+
+        let json = serde_json::to_string(&rect).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"left":10.0,"bottom":50.0,"right":110.0,"top":200.0}"#
+        );
+        assert_eq!(serde_json::from_str::<PdfRect>(&json).unwrap(), rect);
+    }
+
     #[test]
     fn test_rect_does_overlap() {
         assert!(PdfRect::new_from_values(2.0, 2.0, 7.0, 7.0)
@@ -307,6 +405,22 @@ mod tests {
             .does_overlap(&PdfRect::new_from_values(5.0, 8.0, 10.0, 10.0)));
     }
 
+    #[test]
+    fn test_rect_union() {
+        let a = PdfRect::new_from_values(2.0, 2.0, 7.0, 7.0);
+        let b = PdfRect::new_from_values(5.0, 4.0, 10.0, 12.0);
+
+        assert_eq!(a.union(&b), PdfRect::new_from_values(2.0, 2.0, 10.0, 12.0));
+        assert_eq!(b.union(&a), a.union(&b));
+        assert_eq!(a.union(&a), a);
+
+        // Disjoint rectangles are joined by the space between them.
+
+        let c = PdfRect::new_from_values(20.0, 20.0, 30.0, 30.0);
+
+        assert_eq!(a.union(&c), PdfRect::new_from_values(2.0, 2.0, 30.0, 30.0));
+    }
+
     #[test]
     fn test_transform_rect() {
         let delta_x = PdfPoints::new(50.0);
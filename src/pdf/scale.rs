@@ -0,0 +1,136 @@
+//! Defines the [PdfScale] struct, used to convert measurements taken on a scaled drawing
+//! into real-world distances and areas.
+
+use crate::pdf::points::PdfPoints;
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// Converts measurements taken on a [PdfPage] into real-world distances and areas,
+/// taking into account both the scale of the drawing on the page and the page's user unit.
+///
+/// Measurements returned by `pdfium-render` are expressed in default user space units
+/// of 1/72 inch. A page may set a larger user unit using its `/UserUnit` entry, in which case
+/// each default unit represents `user_unit / 72` inches on paper. A drawing scale then maps
+/// paper distances to real-world distances; for instance, a drawing at a scale of 1:100
+/// has a drawing scale of `100.0`.
+///
+/// Use the [PdfPage::measure()] function to create a [PdfScale] that uses a page's user unit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfScale {
+    drawing_scale: f32,
+    user_unit: f32,
+}
+
+impl PdfScale {
+    /// Creates a new [PdfScale] with the given drawing scale and a user unit of 1.0.
+    /// For a drawing at a scale of 1:100, pass a drawing scale of `100.0`.
+    #[inline]
+    pub fn new(drawing_scale: f32) -> Self {
+        PdfScale {
+            drawing_scale,
+            user_unit: 1.0,
+        }
+    }
+
+    /// Sets the user unit of the page being measured, as returned by [PdfPage::user_unit()].
+    #[inline]
+    pub fn set_user_unit(mut self, user_unit: f32) -> Self {
+        self.user_unit = user_unit;
+
+        self
+    }
+
+    /// Returns the drawing scale applied by this [PdfScale].
+    #[inline]
+    pub fn drawing_scale(&self) -> f32 {
+        self.drawing_scale
+    }
+
+    /// Returns the user unit applied by this [PdfScale].
+    #[inline]
+    pub fn user_unit(&self) -> f32 {
+        self.user_unit
+    }
+
+    /// Converts the given length on the page, measured in default user space units,
+    /// to the equivalent real-world length. Use the [PdfPoints::to_mm()], [PdfPoints::to_cm()],
+    /// or [PdfPoints::to_inches()] functions on the returned value to obtain the length
+    /// in the unit of your choice.
+    #[inline]
+    pub fn to_real_world(&self, length: PdfPoints) -> PdfPoints {
+        length * (self.user_unit * self.drawing_scale)
+    }
+
+    /// Returns the real-world distance between the two given positions on the page.
+    pub fn distance(&self, from: (PdfPoints, PdfPoints), to: (PdfPoints, PdfPoints)) -> PdfPoints {
+        let dx = (to.0 - from.0).value;
+        let dy = (to.1 - from.1).value;
+
+        self.to_real_world(PdfPoints::new(dx.hypot(dy)))
+    }
+
+    /// Returns the real-world length of the open polyline joining the given positions
+    /// on the page, in order.
+    pub fn path_length(&self, points: &[(PdfPoints, PdfPoints)]) -> PdfPoints {
+        points.windows(2).fold(PdfPoints::ZERO, |length, pair| {
+            length + self.distance(pair[0], pair[1])
+        })
+    }
+
+    /// Returns the real-world area enclosed by the closed polygon with the given vertices,
+    /// measured in square points. The polygon is closed automatically; the first vertex
+    /// does not need to be repeated at the end. Divide the result by `72.0 * 72.0` to obtain
+    /// the area in square inches.
+    ///
+    /// The polygon must not intersect itself.
+    pub fn area(&self, points: &[(PdfPoints, PdfPoints)]) -> f32 {
+        if points.len() < 3 {
+            return 0.0;
+        }
+
+        // Shoelace formula.
+
+        let twice_area = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|((x1, y1), (x2, y2))| x1.value * y2.value - x2.value * y1.value)
+            .sum::<f32>()
+            .abs();
+
+        let factor = self.user_unit * self.drawing_scale;
+
+        twice_area / 2.0 * factor * factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_scale_distance_and_area() {
+        // A 1:50 drawing on a page with a user unit of 2.0: one default unit on the page
+        // represents 100 points in the real world.
+
+        let scale = PdfScale::new(50.0).set_user_unit(2.0);
+
+        let origin = (PdfPoints::ZERO, PdfPoints::ZERO);
+
+        assert_eq!(
+            scale.distance(origin, (PdfPoints::new(3.0), PdfPoints::new(4.0))),
+            PdfPoints::new(500.0)
+        );
+
+        let square = [
+            origin,
+            (PdfPoints::new(72.0), PdfPoints::ZERO),
+            (PdfPoints::new(72.0), PdfPoints::new(72.0)),
+            (PdfPoints::ZERO, PdfPoints::new(72.0)),
+        ];
+
+        assert_eq!(scale.path_length(&square), PdfPoints::new(21_600.0));
+        assert_eq!(scale.area(&square) / (72.0 * 72.0), 10_000.0);
+        assert_eq!(scale.area(&square[..2]), 0.0);
+    }
+}
@@ -0,0 +1,217 @@
+//! Defines the [PdfUnsupportedFeature] enum, the set of document features that Pdfium
+//! can report as unsupported while loading or processing a document.
+
+use crate::bindgen::{
+    FPDF_UNSP_ANNOT_3DANNOT, FPDF_UNSP_ANNOT_ATTACHMENT, FPDF_UNSP_ANNOT_MOVIE,
+    FPDF_UNSP_ANNOT_SCREEN_MEDIA, FPDF_UNSP_ANNOT_SCREEN_RICHMEDIA, FPDF_UNSP_ANNOT_SIG,
+    FPDF_UNSP_ANNOT_SOUND, FPDF_UNSP_DOC_ATTACHMENT, FPDF_UNSP_DOC_PORTABLECOLLECTION,
+    FPDF_UNSP_DOC_SECURITY, FPDF_UNSP_DOC_SHAREDFORM_ACROBAT, FPDF_UNSP_DOC_SHAREDFORM_EMAIL,
+    FPDF_UNSP_DOC_SHAREDFORM_FILESYSTEM, FPDF_UNSP_DOC_SHAREDREVIEW, FPDF_UNSP_DOC_XFAFORM,
+};
+use std::os::raw::c_int;
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::bindgen::UNSUPPORT_INFO,
+    crate::bindings::PdfiumLibraryBindings,
+    crate::error::{PdfiumError, PdfiumInternalError},
+    once_cell::sync::Lazy,
+    std::panic::{catch_unwind, AssertUnwindSafe},
+    std::sync::{Arc, Mutex},
+};
+
+#[cfg(doc)]
+use crate::pdfium::Pdfium;
+
+/// A document feature that Pdfium encountered but does not support. Pdfium reports
+/// these features to the handler registered using [Pdfium::set_unsupported_feature_handler()].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfUnsupportedFeature {
+    /// The document contains an XFA form, and Pdfium was not built with XFA support.
+    XfaForm,
+
+    /// The document is a portable collection, also known as a PDF portfolio.
+    PortableCollection,
+
+    /// The document contains embedded file attachments.
+    Attachment,
+
+    /// The document uses an unsupported security handler.
+    Security,
+
+    /// The document is part of a shared review workflow.
+    SharedReview,
+
+    /// The document contains a shared form stored in an Acrobat-specific location.
+    SharedFormAcrobat,
+
+    /// The document contains a shared form stored on a file system.
+    SharedFormFilesystem,
+
+    /// The document contains a shared form distributed by email.
+    SharedFormEmail,
+
+    /// The document contains a 3D annotation.
+    Annotation3D,
+
+    /// The document contains a movie annotation.
+    AnnotationMovie,
+
+    /// The document contains a sound annotation.
+    AnnotationSound,
+
+    /// The document contains a screen annotation referencing media content.
+    AnnotationScreenMedia,
+
+    /// The document contains a screen annotation referencing rich media content.
+    AnnotationScreenRichMedia,
+
+    /// The document contains a file attachment annotation.
+    AnnotationAttachment,
+
+    /// The document contains a signature annotation.
+    AnnotationSignature,
+}
+
+impl PdfUnsupportedFeature {
+    pub(crate) fn from_pdfium(value: c_int) -> Option<Self> {
+        match value as u32 {
+            FPDF_UNSP_DOC_XFAFORM => Some(PdfUnsupportedFeature::XfaForm),
+            FPDF_UNSP_DOC_PORTABLECOLLECTION => Some(PdfUnsupportedFeature::PortableCollection),
+            FPDF_UNSP_DOC_ATTACHMENT => Some(PdfUnsupportedFeature::Attachment),
+            FPDF_UNSP_DOC_SECURITY => Some(PdfUnsupportedFeature::Security),
+            FPDF_UNSP_DOC_SHAREDREVIEW => Some(PdfUnsupportedFeature::SharedReview),
+            FPDF_UNSP_DOC_SHAREDFORM_ACROBAT => Some(PdfUnsupportedFeature::SharedFormAcrobat),
+            FPDF_UNSP_DOC_SHAREDFORM_FILESYSTEM => {
+                Some(PdfUnsupportedFeature::SharedFormFilesystem)
+            }
+            FPDF_UNSP_DOC_SHAREDFORM_EMAIL => Some(PdfUnsupportedFeature::SharedFormEmail),
+            FPDF_UNSP_ANNOT_3DANNOT => Some(PdfUnsupportedFeature::Annotation3D),
+            FPDF_UNSP_ANNOT_MOVIE => Some(PdfUnsupportedFeature::AnnotationMovie),
+            FPDF_UNSP_ANNOT_SOUND => Some(PdfUnsupportedFeature::AnnotationSound),
+            FPDF_UNSP_ANNOT_SCREEN_MEDIA => Some(PdfUnsupportedFeature::AnnotationScreenMedia),
+            FPDF_UNSP_ANNOT_SCREEN_RICHMEDIA => {
+                Some(PdfUnsupportedFeature::AnnotationScreenRichMedia)
+            }
+            FPDF_UNSP_ANNOT_ATTACHMENT => Some(PdfUnsupportedFeature::AnnotationAttachment),
+            FPDF_UNSP_ANNOT_SIG => Some(PdfUnsupportedFeature::AnnotationSignature),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type PdfUnsupportedFeatureHandler = Arc<dyn Fn(PdfUnsupportedFeature) + Send + Sync>;
+
+/// The handler most recently registered with Pdfium. Pdfium only supports a single
+/// process-wide handler, so a single process-wide slot suffices.
+#[cfg(not(target_arch = "wasm32"))]
+static HANDLER: Lazy<Mutex<Option<PdfUnsupportedFeatureHandler>>> = Lazy::new(|| Mutex::new(None));
+
+/// The structure passed to Pdfium. Pdfium retains a pointer to this structure,
+/// so it must live for the lifetime of the process.
+#[cfg(not(target_arch = "wasm32"))]
+static UNSUPPORT_INFO_INSTANCE: Lazy<Box<UNSUPPORT_INFO>> = Lazy::new(|| {
+    Box::new(UNSUPPORT_INFO {
+        version: 1,
+        FSDK_UnSupport_Handler: Some(unsupported_feature_callback),
+    })
+});
+
+#[cfg(not(target_arch = "wasm32"))]
+extern "C" fn unsupported_feature_callback(_info: *mut UNSUPPORT_INFO, feature: c_int) {
+    if let Some(feature) = PdfUnsupportedFeature::from_pdfium(feature) {
+        // Clone the handler out of the mutex before calling it, so that the handler
+        // can itself register a new handler without deadlocking.
+
+        let handler = match HANDLER.lock() {
+            Ok(handler) => handler.clone(),
+            Err(_) => None,
+        };
+
+        if let Some(handler) = handler {
+            // Unwinding across an extern "C" boundary into Pdfium is undefined behaviour,
+            // so any panic raised by the handler is caught and discarded here.
+
+            let _ = catch_unwind(AssertUnwindSafe(|| handler(feature)));
+        }
+    }
+}
+
+/// Registers the given handler with Pdfium, replacing any previously registered handler.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn set_unsupported_feature_handler(
+    bindings: &dyn PdfiumLibraryBindings,
+    handler: PdfUnsupportedFeatureHandler,
+) -> Result<(), PdfiumError> {
+    *HANDLER
+        .lock()
+        .map_err(|_| PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown))? =
+        Some(handler);
+
+    // Pdfium never writes through this pointer; it only passes it back to our callback.
+
+    let info = UNSUPPORT_INFO_INSTANCE.as_ref() as *const UNSUPPORT_INFO as *mut UNSUPPORT_INFO;
+
+    if bindings.is_true(bindings.FSDK_SetUnSpObjProcessHandler(info)) {
+        Ok(())
+    } else {
+        Err(PdfiumError::PdfiumLibraryInternalError(
+            PdfiumInternalError::Unknown,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_from_pdfium() {
+        assert_eq!(
+            PdfUnsupportedFeature::from_pdfium(crate::bindgen::FPDF_UNSP_DOC_XFAFORM as i32),
+            Some(PdfUnsupportedFeature::XfaForm)
+        );
+        assert_eq!(
+            PdfUnsupportedFeature::from_pdfium(crate::bindgen::FPDF_UNSP_ANNOT_SIG as i32),
+            Some(PdfUnsupportedFeature::AnnotationSignature)
+        );
+        assert_eq!(PdfUnsupportedFeature::from_pdfium(0), None);
+    }
+
+    #[test]
+    fn test_portable_collection_is_reported() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = reported.clone();
+
+        pdfium.set_unsupported_feature_handler(move |feature| {
+            sink.lock().unwrap().push(feature);
+        })?;
+
+        let bytes = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R /Collection << /Type /Collection >> >>",
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>",
+        ]);
+
+        let _document = pdfium.load_pdf_from_byte_vec(bytes.clone(), None)?;
+
+        assert!(reported
+            .lock()
+            .unwrap()
+            .contains(&PdfUnsupportedFeature::PortableCollection));
+
+        // A panicking handler must not unwind into Pdfium.
+
+        pdfium.set_unsupported_feature_handler(|_| panic!("unsupported feature"))?;
+
+        assert!(pdfium.load_pdf_from_byte_vec(bytes, None).is_ok());
+
+        Ok(())
+    }
+}
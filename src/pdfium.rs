@@ -1,7 +1,12 @@
 //! Defines the [Pdfium] struct, a high-level idiomatic Rust wrapper around Pdfium.
 
+pub mod config;
+
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::raw::rebuild_cross_reference_table;
+use crate::pdf::document::repair::PdfDocumentRepair;
+use crate::pdf::document::security_policy::PdfSecurityPolicy;
 use crate::pdf::document::{PdfDocument, PdfDocumentVersion};
 use std::fmt::{Debug, Formatter};
 
@@ -16,10 +21,20 @@ use crate::bindings::static_bindings::StaticPdfiumBindings;
 
 #[cfg(not(target_arch = "wasm32"))]
 use {
+    crate::bindgen::{
+        FPDF_BOOL, FPDF_LIBRARY_CONFIG, FPDF_RENDERER_TYPE_FPDF_RENDERERTYPE_AGG, FX_FILEAVAIL,
+        PDF_LINEARIZED,
+    },
+    crate::pdf::font::substitution::{PdfFontSubstitute, PdfFontSubstitutionInfo},
+    crate::pdf::unsupported_feature::{set_unsupported_feature_handler, PdfUnsupportedFeature},
+    crate::pdfium::config::{set_active_config, PdfiumConfig},
     crate::utils::files::get_pdfium_file_accessor_from_reader,
+    std::ffi::CString,
     std::fs::File,
-    std::io::{Read, Seek},
+    std::io::{Cursor, Read, Seek},
+    std::os::raw::{c_char, c_int},
     std::path::Path,
+    std::sync::Arc,
 };
 
 #[cfg(target_arch = "wasm32")]
@@ -45,6 +60,20 @@ struct Blob;
 /// the Google Chromium project.
 pub struct Pdfium {
     bindings: Box<dyn PdfiumLibraryBindings>,
+    #[cfg(not(target_arch = "wasm32"))]
+    state: Option<PdfiumLibraryState>,
+}
+
+/// The configuration used to initialize the Pdfium library when a [Pdfium] instance
+/// is created using the [Pdfium::new_with_config()] function. Pdfium retains pointers to
+/// the user font paths and to the font substitution interface, so both must remain
+/// valid for as long as the library is initialized.
+#[cfg(not(target_arch = "wasm32"))]
+struct PdfiumLibraryState {
+    config: PdfiumConfig,
+    user_font_paths: Vec<CString>,
+    user_font_path_pointers: Vec<*const c_char>,
+    font_substitution: Option<Box<PdfFontSubstitutionInfo>>,
 }
 
 impl Pdfium {
@@ -151,7 +180,147 @@ impl Pdfium {
     pub fn new(bindings: Box<dyn PdfiumLibraryBindings>) -> Self {
         bindings.FPDF_InitLibrary();
 
-        Self { bindings }
+        Self {
+            bindings,
+            #[cfg(not(target_arch = "wasm32"))]
+            state: None,
+        }
+    }
+
+    /// Creates a new [Pdfium] instance from the given external Pdfium library bindings,
+    /// initializing the Pdfium library using the font handling options in the given [PdfiumConfig].
+    ///
+    /// Any font files given to [PdfiumConfig::add_font_substitution()] are read immediately;
+    /// an error is returned if any of them cannot be read. The configuration applies to
+    /// the Pdfium library as a whole, so only one [Pdfium] instance should be created
+    /// using this function at a time.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new_with_config(
+        bindings: Box<dyn PdfiumLibraryBindings>,
+        config: PdfiumConfig,
+    ) -> Result<Self, PdfiumError> {
+        let substitutes = config
+            .font_substitutions()
+            .iter()
+            .map(|(name, path)| {
+                std::fs::read(path)
+                    .map(|data| PdfFontSubstitute::new(name, data))
+                    .map_err(PdfiumError::IoError)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Paths cannot contain null bytes on any supported platform,
+        // so no paths are lost by this conversion.
+
+        let user_font_paths = config
+            .user_font_paths()
+            .iter()
+            .filter_map(|path| CString::new(path.to_string_lossy().as_bytes()).ok())
+            .collect::<Vec<_>>();
+
+        // Pdfium expects a null-terminated array of pointers.
+
+        let user_font_path_pointers = user_font_paths
+            .iter()
+            .map(|path| path.as_ptr())
+            .chain(std::iter::once(std::ptr::null()))
+            .collect();
+
+        let font_substitution = if substitutes.is_empty() {
+            None
+        } else {
+            Some(PdfFontSubstitutionInfo::new(substitutes))
+        };
+
+        set_active_config(Some(config.clone()));
+
+        let mut pdfium = Self {
+            bindings,
+            state: Some(PdfiumLibraryState {
+                config,
+                user_font_paths,
+                user_font_path_pointers,
+                font_substitution,
+            }),
+        };
+
+        pdfium.init_library();
+
+        Ok(pdfium)
+    }
+
+    /// Returns the [PdfiumConfig] used to initialize this [Pdfium] instance, if it was
+    /// created using the [Pdfium::new_with_config()] function.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn config(&self) -> Option<&PdfiumConfig> {
+        self.state.as_ref().map(|state| &state.config)
+    }
+
+    /// Initializes the Pdfium library, applying any configuration given
+    /// to [Pdfium::new_with_config()].
+    fn init_library(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(state) = self.state.as_mut() {
+            let config = FPDF_LIBRARY_CONFIG {
+                version: 2,
+                m_pUserFontPaths: if state.user_font_paths.is_empty() {
+                    std::ptr::null_mut()
+                } else {
+                    state.user_font_path_pointers.as_mut_ptr()
+                },
+                m_pIsolate: std::ptr::null_mut(),
+                m_v8EmbedderSlot: 0,
+                m_pPlatform: std::ptr::null_mut(),
+                m_RendererType: FPDF_RENDERER_TYPE_FPDF_RENDERERTYPE_AGG,
+            };
+
+            self.bindings.FPDF_InitLibraryWithConfig(&config);
+
+            if let Some(font_substitution) = state.font_substitution.as_mut() {
+                // Fonts that are not substituted are passed through to Pdfium's default
+                // system font handling, which also searches the user font paths.
+
+                font_substitution
+                    .set_default_font_info(self.bindings.FPDF_GetDefaultSystemFontInfo());
+
+                self.bindings
+                    .FPDF_SetSystemFontInfo(font_substitution.as_fpdf_sysfontinfo_mut_ptr());
+            }
+
+            return;
+        }
+
+        self.bindings.FPDF_InitLibrary();
+    }
+
+    /// Destroys the Pdfium library, releasing any font handling resources allocated
+    /// by [Pdfium::init_library()].
+    fn destroy_library(&mut self) {
+        self.bindings.FPDF_DestroyLibrary();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(font_substitution) = self
+            .state
+            .as_mut()
+            .and_then(|state| state.font_substitution.as_mut())
+        {
+            // Pdfium has released the font substitution interface, which in turn has
+            // released the default system font handling it wraps. All that remains is
+            // to free the default interface itself.
+
+            let default = font_substitution.default_font_info();
+
+            if !default.is_null() {
+                self.bindings.FPDF_FreeDefaultSystemFontInfo(default);
+            }
+
+            font_substitution.set_default_font_info(std::ptr::null_mut());
+        }
     }
 
     // TODO: AJRC - 17/9/22 - remove deprecated Pdfium::get_bindings() function in 0.9.0
@@ -230,6 +399,80 @@ impl Pdfium {
         })
     }
 
+    /// Attempts to open a [PdfDocument] from the given owned byte buffer, refusing to open
+    /// the document if it breaks the given [PdfSecurityPolicy].
+    ///
+    /// If the document is password protected, the given password will be used to unlock it.
+    ///
+    /// If the document breaks the policy, a [PdfiumError::SecurityPolicyViolated] error
+    /// listing every violation is returned. See the [PdfSecurityPolicy] documentation for
+    /// details of which content is checked.
+    pub fn load_pdf_from_bytes_with_policy(
+        &self,
+        bytes: Vec<u8>,
+        password: Option<&str>,
+        policy: PdfSecurityPolicy,
+    ) -> Result<PdfDocument<'_>, PdfiumError> {
+        let document = self.load_pdf_from_byte_vec(bytes, password)?;
+
+        let violations = document.security_policy_violations(&policy);
+
+        if violations.is_empty() {
+            Ok(document)
+        } else {
+            Err(PdfiumError::SecurityPolicyViolated(violations))
+        }
+    }
+
+    /// Attempts to open a [PdfDocument] from the given owned byte buffer, attempting to
+    /// repair the document if Pdfium reports that it is malformed.
+    ///
+    /// If the document is password protected, the given password will be used to unlock it.
+    ///
+    /// If Pdfium fails to open the document with a `PdfiumInternalError::FormatError`,
+    /// the document's cross-reference table will be rebuilt by rescanning the document data
+    /// for object offsets, and Pdfium will be asked to open the rebuilt document.
+    /// The returned [PdfDocumentRepair] value indicates whether this repair was necessary.
+    /// If the repair fails, the original error is returned.
+    ///
+    /// Recovery is best-effort. Pdfium already attempts a similar repair internally,
+    /// so this function mainly helps with documents whose trailer is damaged or missing.
+    /// Objects stored inside compressed object streams cannot be recovered by rescanning,
+    /// and encrypted documents are never repaired.
+    pub fn load_pdf_from_byte_vec_lenient(
+        &self,
+        bytes: Vec<u8>,
+        password: Option<&str>,
+    ) -> Result<(PdfDocument<'_>, PdfDocumentRepair), PdfiumError> {
+        match Self::pdfium_document_handle_to_result(
+            self.bindings
+                .FPDF_LoadMemDocument64(bytes.as_slice(), password),
+            self.bindings(),
+        ) {
+            Ok(mut document) => {
+                document.set_source_byte_buffer(bytes);
+
+                Ok((document, PdfDocumentRepair::NotRequired))
+            }
+            Err(PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::FormatError)) => {
+                match rebuild_cross_reference_table(bytes.as_slice()) {
+                    Some(rebuilt) => self
+                        .load_pdf_from_byte_vec(rebuilt, password)
+                        .map(|document| (document, PdfDocumentRepair::CrossReferenceTableRebuilt))
+                        .map_err(|_| {
+                            PdfiumError::PdfiumLibraryInternalError(
+                                PdfiumInternalError::FormatError,
+                            )
+                        }),
+                    None => Err(PdfiumError::PdfiumLibraryInternalError(
+                        PdfiumInternalError::FormatError,
+                    )),
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Attempts to open a [PdfDocument] from the given file path.
     ///
     /// If the document is password protected, the given password will be used
@@ -257,6 +500,32 @@ impl Pdfium {
         self.load_pdf_from_reader(File::open(path).map_err(PdfiumError::IoError)?, password)
     }
 
+    /// Attempts to open a [PdfDocument] from the given file path, attempting to repair
+    /// the document if Pdfium reports that it is malformed.
+    ///
+    /// If the document is password protected, the given password will be used
+    /// to unlock it.
+    ///
+    /// Unlike the [Pdfium::load_pdf_from_file()] function, the entire file is read
+    /// into memory before being passed to Pdfium. See the
+    /// [Pdfium::load_pdf_from_byte_vec_lenient()] function for details of the repair
+    /// strategy and its limitations. Recovery is best-effort; the returned [PdfDocumentRepair]
+    /// value indicates whether repair was necessary.
+    ///
+    /// This function is not available when compiling to WASM. Use the
+    /// [Pdfium::load_pdf_from_byte_vec_lenient()] function instead.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_pdf_from_file_lenient<'a>(
+        &'a self,
+        path: &(impl AsRef<Path> + ?Sized),
+        password: Option<&'a str>,
+    ) -> Result<(PdfDocument<'a>, PdfDocumentRepair), PdfiumError> {
+        self.load_pdf_from_byte_vec_lenient(
+            std::fs::read(path).map_err(PdfiumError::IoError)?,
+            password,
+        )
+    }
+
     /// Attempts to open a [PdfDocument] from the given reader.
     ///
     /// Pdfium will only load the portions of the document it actually needs into memory.
@@ -372,6 +641,56 @@ impl Pdfium {
         self.load_pdf_from_byte_vec(bytes, password)
     }
 
+    /// Returns `true` if the given document data is linearized, also known as optimized for
+    /// "fast web view". A linearized document places all the objects needed to display its
+    /// first page at the start of the file, so a viewer can display the first page before
+    /// the rest of the file has been downloaded.
+    ///
+    /// The document data is checked using Pdfium's document availability provider; it does
+    /// not need to be loaded as a [PdfDocument]. A linearized document that has since been
+    /// incrementally updated is no longer considered linearized, since its recorded file length
+    /// no longer matches. Pdfium requires at least 1 KB of data to make a determination,
+    /// so shorter data is never considered linearized.
+    ///
+    /// Pdfium cannot produce linearized output, so documents saved using functions such as
+    /// [PdfDocument::save_to_writer()] are never linearized. To serve linearized documents,
+    /// post-process the saved output using an external tool such as `qpdf --linearize`.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn is_linearized(&self, bytes: &[u8]) -> bool {
+        // All the data is already in memory, so every section of the file is available.
+
+        extern "C" fn is_data_available(
+            _file_avail: *mut FX_FILEAVAIL,
+            _offset: usize,
+            _size: usize,
+        ) -> FPDF_BOOL {
+            1
+        }
+
+        let mut file_avail = FX_FILEAVAIL {
+            version: 1,
+            IsDataAvail: Some(is_data_available),
+        };
+
+        let mut reader = get_pdfium_file_accessor_from_reader(Cursor::new(bytes));
+
+        let avail = self
+            .bindings
+            .FPDFAvail_Create(&mut file_avail, reader.as_fpdf_file_access_mut_ptr());
+
+        if avail.is_null() {
+            return false;
+        }
+
+        let result = self.bindings.FPDFAvail_IsLinearized(avail);
+
+        self.bindings.FPDFAvail_Destroy(avail);
+
+        result == PDF_LINEARIZED as c_int
+    }
+
     /// Creates a new, empty [PdfDocument] in memory.
     pub fn create_new_pdf(&self) -> Result<PdfDocument, PdfiumError> {
         Self::pdfium_document_handle_to_result(
@@ -385,6 +704,57 @@ impl Pdfium {
         })
     }
 
+    /// Registers a handler that Pdfium will call whenever it encounters a document feature
+    /// it does not support, such as an XFA form in a build of Pdfium without XFA support.
+    /// Features are typically reported while a document is being loaded. Pdfium supports
+    /// only a single handler per process; registering a new handler replaces any handler
+    /// registered previously.
+    ///
+    /// The handler is called from within Pdfium, so it must not itself call any Pdfium
+    /// functions. Doing so may deadlock when this crate's `thread_safe` feature is enabled.
+    /// Any panic raised by the handler is caught and discarded, since a panic cannot safely
+    /// unwind back into Pdfium.
+    ///
+    /// Note that Pdfium does not expose any means of configuring its memory allocator or
+    /// of limiting the amount of memory it allocates, and Pdfium will abort the process if an
+    /// allocation fails. If you need to process untrusted documents under a strict memory
+    /// budget, run Pdfium in a separate child process with operating system resource limits
+    /// applied to that process.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_unsupported_feature_handler(
+        &self,
+        handler: impl Fn(PdfUnsupportedFeature) + Send + Sync + 'static,
+    ) -> Result<(), PdfiumError> {
+        set_unsupported_feature_handler(self.bindings(), Arc::new(handler))
+    }
+
+    /// Releases all memory held by Pdfium's internal caches, including its font cache,
+    /// by shutting down and then reinitializing the Pdfium library.
+    ///
+    /// Pdfium caches fonts, glyph renderings, and other shared resources for the lifetime
+    /// of the library, and does not expose any means of limiting or clearing these caches.
+    /// In a long-running process, calling this function periodically bounds the memory
+    /// consumed by these caches. The tradeoff is that the caches must be rebuilt afterwards,
+    /// so rendering immediately after a purge is slower than usual.
+    ///
+    /// Any handler registered using the [Pdfium::set_unsupported_feature_handler()] function
+    /// should be registered again after calling this function.
+    ///
+    /// # Safety
+    ///
+    /// This function takes a mutable reference to this [Pdfium] instance, so it can only be
+    /// called when no documents loaded by this instance remain open. Pdfium's library state
+    /// is global to the process, however, so the caller must ensure that no documents, pages,
+    /// fonts, or other Pdfium objects are open in any _other_ [Pdfium] instance, for instance
+    /// on another thread. Shutting down the library invalidates the handles held by those
+    /// objects, and using them afterwards is undefined behaviour.
+    pub unsafe fn purge_caches(&mut self) {
+        self.destroy_library();
+        self.init_library();
+    }
+
     /// Returns a [PdfDocument] from the given `FPDF_DOCUMENT` handle, if possible.
     pub(crate) fn pdfium_document_handle_to_result(
         handle: crate::bindgen::FPDF_DOCUMENT,
@@ -428,7 +798,12 @@ impl Drop for Pdfium {
     /// Closes the external Pdfium library, releasing held memory.
     #[inline]
     fn drop(&mut self) {
-        self.bindings.FPDF_DestroyLibrary();
+        self.destroy_library();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.state.is_some() {
+            set_active_config(None);
+        }
     }
 }
 
@@ -495,3 +870,82 @@ unsafe impl Sync for Pdfium {}
 
 #[cfg(feature = "sync")]
 unsafe impl Send for Pdfium {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::{test_bind_to_pdfium, test_document};
+
+    #[test]
+    fn test_rendering_after_purge_caches() -> Result<(), PdfiumError> {
+        let mut pdfium = test_bind_to_pdfium();
+
+        let config = PdfRenderConfig::new().set_target_width(200);
+
+        let render = |pdfium: &Pdfium| -> Result<Vec<u8>, PdfiumError> {
+            pdfium
+                .load_pdf_from_file("./test/text-test.pdf", None)?
+                .pages()
+                .first()?
+                .render_with_config(&config)
+                .map(|bitmap| bitmap.as_raw_bytes())
+        };
+
+        let before = render(&pdfium)?;
+
+        // The document rendered above has been closed before the caches are purged.
+
+        unsafe {
+            pdfium.purge_caches();
+        }
+
+        let after = render(&pdfium)?;
+
+        assert_eq!(before, after);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_linearized() {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = test_document(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>",
+            b"<< /Type /Pages /Kids [] /Count 0 >>",
+        ]);
+
+        // Pdfium requires at least 1 KB of data to detect linearization, so the data is padded
+        // with a comment. The /L entry records the total length of the data.
+
+        let linearized = |length_adjustment: usize| {
+            let mut bytes = b"%PDF-1.7\n\
+                4 0 obj\n<< /Linearized 1 /L 0000000000 /H [ 500 120 ] /O 6 /E 900 /N 1 /T 1 >>\n\
+                endobj\n"
+                .to_vec();
+
+            bytes.extend_from_slice(document.as_slice());
+            bytes.push(b'%');
+            bytes.extend_from_slice(&[b' '; 1024]);
+            bytes.push(b'\n');
+
+            let length = format!("{:010}", bytes.len() + length_adjustment);
+
+            let position = bytes
+                .windows(10)
+                .position(|window| window == b"0000000000")
+                .unwrap();
+
+            bytes[position..position + 10].copy_from_slice(length.as_bytes());
+
+            bytes
+        };
+
+        assert!(pdfium.is_linearized(&linearized(0)));
+
+        // A mismatched file length indicates the document was modified after linearization.
+
+        assert!(!pdfium.is_linearized(&linearized(1)));
+        assert!(!pdfium.is_linearized(&document));
+    }
+}
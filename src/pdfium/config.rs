@@ -0,0 +1,98 @@
+//! Defines the [PdfiumConfig] struct, used to configure the font handling of the Pdfium
+//! library when it is initialized using the [Pdfium::new_with_config()] function.
+
+use once_cell::sync::Lazy;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+#[cfg(doc)]
+use crate::pdfium::Pdfium;
+
+/// Configures the font handling of the Pdfium library when it is initialized using
+/// the [Pdfium::new_with_config()] function.
+///
+/// When a document uses a font that is not embedded in the document, Pdfium substitutes
+/// a font installed on the system. This is unreliable on servers that have few or no system
+/// fonts installed. A [PdfiumConfig] can add directories to the locations Pdfium searches
+/// for fonts, and can map specific missing fonts to replacement font files. Use the
+/// [PdfDocument::missing_fonts()] function to determine which fonts a document needs.
+#[derive(Debug, Clone, Default)]
+pub struct PdfiumConfig {
+    user_font_paths: Vec<PathBuf>,
+    font_substitutions: Vec<(String, PathBuf)>,
+}
+
+impl PdfiumConfig {
+    /// Creates a new [PdfiumConfig] with Pdfium's default font handling.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the directories Pdfium should search for font files, in addition to the
+    /// platform's default font directories. Any previously set directories are replaced.
+    pub fn set_user_font_paths<I>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<Path>,
+    {
+        self.user_font_paths = paths
+            .into_iter()
+            .map(|path| path.as_ref().to_path_buf())
+            .collect();
+
+        self
+    }
+
+    /// Returns the directories Pdfium should search for font files, in addition to the
+    /// platform's default font directories.
+    #[inline]
+    pub fn user_font_paths(&self) -> &[PathBuf] {
+        self.user_font_paths.as_slice()
+    }
+
+    /// Maps the non-embedded font with the given name to the TrueType, OpenType, or Type 1
+    /// font file at the given path. Whenever a document uses a non-embedded font with the
+    /// given name, Pdfium will use the given font file instead of searching for a system font.
+    ///
+    /// Font names are matched case-insensitively, ignoring spaces and any subset prefix
+    /// such as `ABCDEF+`. If no substitution matches a font's full name, such as `Garamond,Bold`,
+    /// then a substitution matching its family name, such as `Garamond`, is used instead.
+    /// The font file is read when the Pdfium library is initialized.
+    pub fn add_font_substitution(mut self, from: &str, to_path: impl AsRef<Path>) -> Self {
+        self.font_substitutions
+            .push((from.to_owned(), to_path.as_ref().to_path_buf()));
+
+        self
+    }
+
+    /// Returns the font name and replacement font file of each font substitution
+    /// added to this [PdfiumConfig], in the order in which they were added.
+    #[inline]
+    pub fn font_substitutions(&self) -> &[(String, PathBuf)] {
+        self.font_substitutions.as_slice()
+    }
+}
+
+/// The configuration of the most recently initialized [Pdfium] instance, if it was initialized
+/// using the [Pdfium::new_with_config()] function. Pdfium's library state is global to the
+/// process, so a single process-wide slot suffices.
+static ACTIVE_CONFIG: Lazy<Mutex<Option<PdfiumConfig>>> = Lazy::new(|| Mutex::new(None));
+
+/// Records the given configuration as the configuration in effect for the Pdfium library.
+pub(crate) fn set_active_config(config: Option<PdfiumConfig>) {
+    if let Ok(mut active) = ACTIVE_CONFIG.lock() {
+        *active = config;
+    }
+}
+
+/// Returns the configuration in effect for the Pdfium library, if any.
+pub(crate) fn active_config() -> Option<PdfiumConfig> {
+    ACTIVE_CONFIG
+        .lock()
+        .ok()
+        .and_then(|active| active.as_ref().cloned())
+}
@@ -153,6 +153,116 @@ pub(crate) mod dates {
 
         format!("D:{}{}", date_part, timezone_part)
     }
+
+    /// Parses a formatted PDF date string, as defined in The PDF Reference Manual, sixth edition,
+    /// section 3.8.3, on page 160, into a [DateTime]. All fields other than the year are optional;
+    /// missing fields take their earliest possible value, and a missing timezone is taken to be UTC.
+    /// Returns `None` if the given string is not a valid PDF date string.
+    pub(crate) fn pdf_string_to_date_time(value: &str) -> Option<DateTime<FixedOffset>> {
+        let value = value.trim();
+
+        let value = value.strip_prefix("D:").unwrap_or(value).as_bytes();
+
+        let mut position = 0;
+
+        // Parses the next two or four ASCII digits, returning the given default value if there
+        // are no more digits available. A field that is cut short, or that contains anything
+        // other than digits, is rejected.
+
+        let mut next_field = |digits: usize, default: u32| -> Option<u32> {
+            match value.get(position) {
+                Some(byte) if byte.is_ascii_digit() => {
+                    let field = parse_ascii_digits(value.get(position..position + digits)?)?;
+
+                    position += digits;
+
+                    Some(field)
+                }
+                _ => Some(default),
+            }
+        };
+
+        let year = next_field(4, 0)?;
+        let month = next_field(2, 1)?;
+        let day = next_field(2, 1)?;
+        let hour = next_field(2, 0)?;
+        let minute = next_field(2, 0)?;
+        let second = next_field(2, 0)?;
+
+        if position < 4 {
+            // The year is mandatory.
+
+            return None;
+        }
+
+        let offset = match value.get(position) {
+            None => 0,
+            Some(b'Z') => {
+                // Some producers follow the Z with a zero offset, as in Z00'00'.
+
+                match parse_time_zone_offset(&value[position + 1..])? {
+                    0 => 0,
+                    _ => return None,
+                }
+            }
+            Some(b'+') if position + 1 < value.len() => {
+                parse_time_zone_offset(&value[position + 1..])?
+            }
+            Some(b'-') if position + 1 < value.len() => {
+                -parse_time_zone_offset(&value[position + 1..])?
+            }
+            _ => return None,
+        };
+
+        FixedOffset::east_opt(offset)?
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(year as i32, month, day)?
+                    .and_hms_opt(hour, minute, second)?,
+            )
+            .single()
+    }
+
+    /// Parses a timezone offset of the form HH'mm', where the minutes and the apostrophes
+    /// are optional, into a number of seconds. An empty string is a zero offset.
+    fn parse_time_zone_offset(value: &[u8]) -> Option<i32> {
+        if value.is_empty() {
+            return Some(0);
+        }
+
+        let hours = parse_ascii_digits(value.get(0..2)?)?;
+
+        let rest = value[2..].strip_prefix(b"'").unwrap_or(&value[2..]);
+
+        let minutes = match rest.get(0..2) {
+            Some(minutes) => {
+                let rest = &rest[2..];
+
+                if !(rest.is_empty() || rest == b"'") {
+                    return None;
+                }
+
+                parse_ascii_digits(minutes)?
+            }
+            None if rest.is_empty() => 0,
+            None => return None,
+        };
+
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        Some((hours * 3600 + minutes * 60) as i32)
+    }
+
+    /// Parses the given bytes as an unsigned decimal integer, rejecting signs, whitespace,
+    /// and any other byte that is not an ASCII digit.
+    fn parse_ascii_digits(digits: &[u8]) -> Option<u32> {
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        std::str::from_utf8(digits).ok()?.parse().ok()
+    }
 }
 
 pub(crate) mod mem {
@@ -236,8 +346,9 @@ pub(crate) mod utf16le {
 }
 
 pub(crate) mod files {
-    use crate::bindgen::{FPDF_FILEACCESS, FPDF_FILEWRITE};
-    use std::io::{Read, Seek, SeekFrom, Write};
+    use crate::bindgen::{FPDF_DOCUMENT, FPDF_FILEACCESS, FPDF_FILEWRITE};
+    use crate::bindings::PdfiumLibraryBindings;
+    use std::io::{Cursor, Read, Seek, SeekFrom, Write};
     use std::ops::Deref;
     use std::os::raw::{c_int, c_uchar, c_ulong, c_void};
     use std::ptr::null_mut;
@@ -371,6 +482,30 @@ pub(crate) mod files {
         }
     }
 
+    /// Serializes the given document into a new byte buffer using Pdfium's `FPDF_SaveAsCopy()`
+    /// function, so that information Pdfium does not expose can be read from the serialized
+    /// data. Returns `None` if Pdfium could not save the document.
+    pub(crate) fn get_pdfium_document_bytes(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Option<Vec<u8>> {
+        let mut cursor = Cursor::new(Vec::new());
+
+        let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(&mut cursor);
+
+        let result = bindings.FPDF_SaveAsCopy(
+            document_handle,
+            pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
+            0,
+        );
+
+        if bindings.is_true(result) && pdfium_file_writer.flush().is_ok() {
+            Some(cursor.into_inner())
+        } else {
+            None
+        }
+    }
+
     trait PdfiumDocumentWriter: Write {
         // A tiny trait that lets us perform type-erasure on the user-provided Rust writer.
         // This means FpdfFileWriteExt does not need to carry a generic parameter, which simplifies
@@ -455,6 +590,72 @@ pub(crate) mod test {
                 .unwrap(),
         )
     }
+
+    /// Builds a minimal document containing the given indirect objects, numbered from 1 in
+    /// the order given, with a valid cross-reference table and a trailer whose `/Root` entry
+    /// references object 1. Object 1 must therefore be the document catalog.
+    ///
+    /// An object that is a stream should be given as its stream dictionary, without a `/Length`
+    /// entry, followed by the `stream` keyword, a line feed, and the stream data; the `/Length`
+    /// entry and the `endstream` keyword are added automatically.
+    pub(crate) fn test_document(objects: &[&[u8]]) -> Vec<u8> {
+        let mut result = b"%PDF-1.7\n".to_vec();
+
+        let mut offsets = Vec::with_capacity(objects.len());
+
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(result.len());
+
+            result.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+
+            match object
+                .windows(b"stream\n".len())
+                .position(|window| window == b"stream\n")
+            {
+                Some(position) => {
+                    let dictionary = std::str::from_utf8(&object[..position]).unwrap().trim_end();
+
+                    let data = &object[position + b"stream\n".len()..];
+
+                    result.extend_from_slice(
+                        format!(
+                            "{} /Length {} >>\nstream\n",
+                            dictionary.strip_suffix(">>").unwrap().trim_end(),
+                            data.len()
+                        )
+                        .as_bytes(),
+                    );
+
+                    result.extend_from_slice(data);
+                    result.extend_from_slice(b"\nendstream");
+                }
+                None => result.extend_from_slice(object),
+            }
+
+            result.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = result.len();
+
+        result.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f\r\n", objects.len() + 1).as_bytes(),
+        );
+
+        for offset in offsets {
+            result.extend_from_slice(format!("{:010} 00000 n\r\n", offset).as_bytes());
+        }
+
+        result.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+
+        result
+    }
 }
 
 #[cfg(test)]
@@ -596,4 +797,83 @@ mod tests {
             "D:19981223195200-08'00'"
         )
     }
+
+    #[test]
+    fn test_pdf_date_string_to_date_time() {
+        let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+
+        assert_eq!(
+            pdf_string_to_date_time("D:20240315093000+05'30'"),
+            Some(offset.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap())
+        );
+
+        assert_eq!(
+            pdf_string_to_date_time("D:19981223195200-08'00'"),
+            Some(
+                FixedOffset::west_opt(8 * 3600)
+                    .unwrap()
+                    .with_ymd_and_hms(1998, 12, 23, 19, 52, 0)
+                    .unwrap()
+            )
+        );
+
+        assert_eq!(
+            pdf_string_to_date_time("D:19981223195200Z00'00'"),
+            Some(
+                Utc.with_ymd_and_hms(1998, 12, 23, 19, 52, 0)
+                    .unwrap()
+                    .into()
+            )
+        );
+
+        // All fields other than the year are optional.
+
+        assert_eq!(
+            pdf_string_to_date_time("D:2024"),
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap().into())
+        );
+
+        assert_eq!(
+            pdf_string_to_date_time("20240315"),
+            Some(Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap().into())
+        );
+
+        assert_eq!(pdf_string_to_date_time(""), None);
+        assert_eq!(pdf_string_to_date_time("D:"), None);
+        assert_eq!(pdf_string_to_date_time("D:202"), None);
+        assert_eq!(pdf_string_to_date_time("D:20241315"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315+5"), None);
+        assert_eq!(pdf_string_to_date_time("March 15, 2024"), None);
+
+        // Every field must consist only of ASCII digits.
+
+        assert_eq!(pdf_string_to_date_time("D:2024-03-15"), None);
+        assert_eq!(pdf_string_to_date_time("D:2024+1"), None);
+        assert_eq!(pdf_string_to_date_time("D:2024+"), None);
+        assert_eq!(pdf_string_to_date_time("D:+0240315"), None);
+        assert_eq!(pdf_string_to_date_time("D:202403 5"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315093000+-5'30'"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315093000+05'+3'"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315093000+05'30'00"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315093000+24'00'"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315093000Z05'00'"), None);
+        assert_eq!(pdf_string_to_date_time("D:20240315093000X"), None);
+
+        // The minutes and apostrophes of the timezone offset are optional.
+
+        assert_eq!(
+            pdf_string_to_date_time("D:20240315093000+05'"),
+            Some(
+                FixedOffset::east_opt(5 * 3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2024, 3, 15, 9, 30, 0)
+                    .unwrap()
+            )
+        );
+
+        assert_eq!(
+            pdf_string_to_date_time("D:20240315093000+0530"),
+            Some(offset.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap())
+        );
+    }
 }